@@ -25,6 +25,22 @@ pub trait Configurable: Send + Sync {
     {
         Self::Config::default()
     }
+
+    /// 获取配置类型的 JSON Schema
+    ///
+    /// 配置类型实现了 [`ConfigSchema`](crate::ConfigSchema) 时，
+    /// 可通过 `#[configurable(path = "...", schema)]` 生成
+    fn config_schema() -> Option<serde_json::Value> {
+        None
+    }
+
+    /// 获取配置验证器
+    ///
+    /// 配置类型派生了 `Validate` 时，可通过 `#[configurable(path = "...", validate)]` 生成，
+    /// 配置管理器注册组件配置选项时自动注册该验证器
+    fn config_validator() -> Option<Box<dyn ConfigValidator<Self::Config>>> {
+        None
+    }
 }
 
 /// 配置验证器 trait
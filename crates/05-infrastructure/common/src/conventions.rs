@@ -7,6 +7,18 @@ use crate::metadata::TypeInfo;
 use std::any::TypeId;
 use std::collections::HashMap;
 
+/// 组件描述符元数据键：组件类型名称（不含模块路径）
+pub const COMPONENT_TYPE_NAME_KEY: &str = "type_name";
+
+/// 组件描述符元数据键：组件所在模块
+pub const COMPONENT_MODULE_PATH_KEY: &str = "module_path";
+
+/// 组件描述符元数据键：通过 `#[component]` 参数显式指定的生命周期
+pub const COMPONENT_LIFETIME_KEY: &str = "lifetime";
+
+/// 组件描述符元数据键：通过 `#[component]` 参数显式指定的配置节
+pub const COMPONENT_CONFIG_SECTION_KEY: &str = "config_section";
+
 /// 约定规则
 #[derive(Debug, Clone)]
 pub struct ConventionRule {
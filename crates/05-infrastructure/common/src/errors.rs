@@ -1,5 +1,6 @@
 //! 错误类型定义
 
+use std::sync::Arc;
 use thiserror::Error;
 
 /// 配置错误类型
@@ -51,6 +52,36 @@ pub enum ConfigError {
 
     #[error("配置热重载失败: {message}")]
     HotReloadError { message: String },
+
+    #[error("密钥不存在: {name}")]
+    SecretNotFound { name: String },
+
+    #[error("密钥读取失败: {message}")]
+    SecretError { message: String },
+
+    #[error("配置插值失败: {key}, 原因: {message}")]
+    InterpolationError { key: String, message: String },
+
+    #[error("配置插值存在循环引用: {chain}")]
+    CircularReference { chain: String },
+
+    #[error("配置提供者不支持写入: {provider}")]
+    ReadOnlyProvider { provider: String },
+
+    #[error("没有可写的配置提供者")]
+    NoWritableProvider,
+
+    #[error("配置提供者不存在: {provider}")]
+    ProviderNotFound { provider: String },
+
+    #[error("配置版本冲突: 期望 {expected}, 实际 {actual}")]
+    VersionConflict { expected: u64, actual: u64 },
+
+    #[error("配置键 {key} 被更高优先级的配置源覆盖: {source_name}")]
+    KeyShadowed { key: String, source_name: String },
+
+    #[error("配置值解密失败: {key}, 原因: {message}")]
+    DecryptionFailed { key: String, message: String },
 }
 
 /// 依赖注入错误类型
@@ -265,6 +296,42 @@ pub enum InfrastructureError {
         component_name: String,
         message: String,
     },
+
+    #[error("{source}")]
+    StartupFailed {
+        source: Box<InfrastructureError>,
+        report: Arc<dyn StartupDiagnostics>,
+    },
+}
+
+impl InfrastructureError {
+    /// 获取启动失败时附加的诊断报告
+    pub fn startup_report(&self) -> Option<&dyn StartupDiagnostics> {
+        match self {
+            Self::StartupFailed { report, .. } => Some(report.as_ref()),
+            _ => None,
+        }
+    }
+
+    /// 获取去掉诊断报告后的原始错误
+    pub fn root_cause(&self) -> &InfrastructureError {
+        match self {
+            Self::StartupFailed { source, .. } => source.root_cause(),
+            other => other,
+        }
+    }
+}
+
+/// 启动诊断报告
+///
+/// 启动失败时附加在 [`InfrastructureError::StartupFailed`] 上，
+/// `Display` 输出供人阅读的摘要
+pub trait StartupDiagnostics: std::fmt::Debug + std::fmt::Display + Send + Sync {
+    /// 以 JSON 形式输出报告
+    fn to_json(&self) -> serde_json::Value;
+
+    /// 转换为 Any，用于还原具体的报告类型
+    fn as_any(&self) -> &dyn std::any::Any;
 }
 
 /// 结果类型别名
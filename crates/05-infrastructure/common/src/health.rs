@@ -83,6 +83,41 @@ impl HealthStatus {
     }
 }
 
+/// 健康检查探针类型
+///
+/// 对应 Kubernetes 的存活、就绪和启动探针，健康检查器通过
+/// [`HealthCheckable::probes`] 声明参与哪些探针
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq, Hash)]
+#[serde(rename_all = "snake_case")]
+pub enum HealthProbe {
+    /// 存活探针，失败时应重启进程
+    Liveness,
+    /// 就绪探针，失败时应停止接收流量
+    Readiness,
+    /// 启动探针，成功前不执行其他探针
+    Startup,
+}
+
+impl HealthProbe {
+    /// 所有探针类型
+    pub const ALL: [HealthProbe; 3] = [Self::Liveness, Self::Readiness, Self::Startup];
+
+    /// 探针名称
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Self::Liveness => "liveness",
+            Self::Readiness => "readiness",
+            Self::Startup => "startup",
+        }
+    }
+}
+
+impl std::fmt::Display for HealthProbe {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
 /// 健康检查结果
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct HealthCheckResult {
@@ -144,6 +179,11 @@ pub trait HealthCheckable: Send + Sync {
     fn is_enabled(&self) -> bool {
         true
     }
+    
+    /// 参与的探针，默认只参与就绪探针
+    fn probes(&self) -> &[HealthProbe] {
+        &[HealthProbe::Readiness]
+    }
 }
 
 /// 聚合健康检查器
@@ -172,25 +212,59 @@ impl AggregateHealthChecker {
         self.checkers.push(checker);
     }
     
+    /// 获取健康检查器数量
+    pub fn checker_count(&self) -> usize {
+        self.checkers.len()
+    }
+    
+    /// 获取所有健康检查器
+    pub fn checkers(&self) -> &[Box<dyn HealthCheckable>] {
+        &self.checkers
+    }
+    
+    /// 执行单个健康检查，超时视为不健康
+    pub async fn run_checker(checker: &dyn HealthCheckable) -> HealthCheckResult {
+        let start = std::time::Instant::now();
+        
+        let status = match tokio::time::timeout(checker.timeout(), checker.check_health()).await {
+            Ok(status) => status,
+            Err(_) => HealthStatus::unhealthy("健康检查超时"),
+        };
+        
+        let elapsed = start.elapsed();
+        metrics::histogram!(
+            crate::instrumentation::HEALTH_CHECK_DURATION_SECONDS,
+            "component" => checker.name().to_string()
+        )
+        .record(elapsed.as_secs_f64());
+        
+        HealthCheckResult::new(checker.name(), status, elapsed)
+    }
+    
     /// 执行所有健康检查
     pub async fn check_all(&self) -> Vec<HealthCheckResult> {
+        self.run_checks(|_| true).await
+    }
+    
+    /// 执行参与指定探针的健康检查
+    pub async fn check_probe(&self, probe: HealthProbe) -> Vec<HealthCheckResult> {
+        self.run_checks(|checker| checker.probes().contains(&probe)).await
+    }
+    
+    /// 执行满足条件的已启用健康检查
+    async fn run_checks<F>(&self, filter: F) -> Vec<HealthCheckResult>
+    where
+        F: Fn(&dyn HealthCheckable) -> bool,
+    {
         let mut results = Vec::new();
         
         for checker in &self.checkers {
-            if !checker.is_enabled() {
+            if !checker.is_enabled() || !filter(checker.as_ref()) {
                 continue;
             }
             
-            let start = std::time::Instant::now();
-            
-            let status = match tokio::time::timeout(checker.timeout(), checker.check_health()).await {
-                Ok(status) => status,
-                Err(_) => HealthStatus::unhealthy("健康检查超时"),
-            };
-            
-            let duration = start.elapsed();
-            
-            let result = HealthCheckResult::new(checker.name(), status, duration);
+            let result = Self::run_checker(checker.as_ref()).await;
+            crate::instrumentation::record_health_status(&result.component_name, &result.status);
             results.push(result);
         }
         
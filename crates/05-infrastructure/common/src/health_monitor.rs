@@ -0,0 +1,384 @@
+//! 后台健康监控
+//!
+//! 按每个健康检查器自身的检查间隔和超时在后台周期执行检查，缓存最新结果供探针
+//! 直接读取，并为每个检查器保留滚动历史。状态在健康、降级、不健康之间切换时
+//! 发出 [`HealthTransition`] 事件，连续失败达到阈值后才判定为不健康以避免抖动。
+
+use crate::health::{AggregateHealthChecker, HealthCheckResult, HealthProbe, HealthStatus};
+use crate::instrumentation::{health_status_label, record_health_status, HEALTH_TRANSITIONS_TOTAL};
+use serde::{Deserialize, Serialize};
+use std::collections::VecDeque;
+use std::sync::Arc;
+use std::time::Duration;
+use tokio::sync::broadcast;
+use tokio::task::JoinHandle;
+
+/// 默认保留的历史记录条数
+pub const DEFAULT_HEALTH_HISTORY_SIZE: usize = 20;
+
+/// 状态变化事件通道容量
+const TRANSITION_CHANNEL_CAPACITY: usize = 64;
+
+/// 后台检查的最小间隔，更短的检查间隔（包括零）按此间隔执行
+pub const MIN_HEALTH_CHECK_INTERVAL: Duration = Duration::from_millis(10);
+
+/// 健康监控选项
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct HealthMonitorOptions {
+    /// 连续失败多少次后判定为不健康，未达到阈值前视为降级
+    pub failure_threshold: u32,
+    /// 不健康后连续成功多少次才恢复
+    pub recovery_threshold: u32,
+    /// 每个检查器保留的历史记录条数
+    pub history_size: usize,
+}
+
+impl Default for HealthMonitorOptions {
+    fn default() -> Self {
+        Self {
+            failure_threshold: 3,
+            recovery_threshold: 1,
+            history_size: DEFAULT_HEALTH_HISTORY_SIZE,
+        }
+    }
+}
+
+impl HealthMonitorOptions {
+    /// 设置判定为不健康所需的连续失败次数
+    pub fn with_failure_threshold(mut self, threshold: u32) -> Self {
+        self.failure_threshold = threshold.max(1);
+        self
+    }
+
+    /// 设置恢复所需的连续成功次数
+    pub fn with_recovery_threshold(mut self, threshold: u32) -> Self {
+        self.recovery_threshold = threshold.max(1);
+        self
+    }
+
+    /// 设置历史记录条数
+    pub fn with_history_size(mut self, size: usize) -> Self {
+        self.history_size = size;
+        self
+    }
+}
+
+/// 健康状态变化事件
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct HealthTransition {
+    /// 组件名称
+    pub component_name: String,
+    /// 变化前的状态
+    pub previous: HealthStatus,
+    /// 变化后的状态
+    pub current: HealthStatus,
+    /// 变化时间
+    pub occurred_at: chrono::DateTime<chrono::Utc>,
+}
+
+/// 单个检查器的监控状态
+#[derive(Debug)]
+struct CheckerState {
+    /// 组件名称
+    name: String,
+    /// 参与的探针
+    probes: Vec<HealthProbe>,
+    /// 经过抖动抑制后的最新结果
+    latest: Option<HealthCheckResult>,
+    /// 原始检查结果历史
+    history: VecDeque<HealthCheckResult>,
+    /// 连续失败次数
+    consecutive_failures: u32,
+    /// 连续成功次数
+    consecutive_successes: u32,
+}
+
+impl CheckerState {
+    /// 记录一次原始检查结果，返回生效结果和状态变化事件
+    fn record(
+        &mut self,
+        raw: HealthCheckResult,
+        options: &HealthMonitorOptions,
+    ) -> (HealthCheckResult, Option<HealthTransition>) {
+        if raw.status.is_unhealthy() {
+            self.consecutive_failures += 1;
+            self.consecutive_successes = 0;
+        } else {
+            self.consecutive_failures = 0;
+            self.consecutive_successes += 1;
+        }
+
+        let previous = self
+            .latest
+            .as_ref()
+            .map(|result| result.status.clone())
+            .unwrap_or(HealthStatus::Healthy);
+        let status = match &raw.status {
+            HealthStatus::Unhealthy { error, details }
+                if self.consecutive_failures < options.failure_threshold =>
+            {
+                HealthStatus::Degraded {
+                    message: format!(
+                        "连续失败 {}/{} 次: {}",
+                        self.consecutive_failures, options.failure_threshold, error
+                    ),
+                    details: details.clone(),
+                }
+            }
+            status
+                if !status.is_unhealthy()
+                    && previous.is_unhealthy()
+                    && self.consecutive_successes < options.recovery_threshold =>
+            {
+                previous.clone()
+            }
+            status => status.clone(),
+        };
+
+        let result = HealthCheckResult {
+            status,
+            ..raw.clone()
+        };
+        let transition = (std::mem::discriminant(&previous)
+            != std::mem::discriminant(&result.status))
+        .then(|| HealthTransition {
+            component_name: self.name.clone(),
+            previous,
+            current: result.status.clone(),
+            occurred_at: result.checked_at,
+        });
+
+        self.history.push_back(raw);
+        while self.history.len() > options.history_size {
+            self.history.pop_front();
+        }
+        self.latest = Some(result.clone());
+
+        (result, transition)
+    }
+}
+
+/// 监控共享状态
+struct MonitorInner {
+    /// 聚合健康检查器
+    checker: Arc<AggregateHealthChecker>,
+    /// 监控选项
+    options: HealthMonitorOptions,
+    /// 按检查器顺序排列的监控状态
+    states: parking_lot::RwLock<Vec<CheckerState>>,
+    /// 状态变化事件发送端
+    transitions: broadcast::Sender<HealthTransition>,
+}
+
+impl MonitorInner {
+    /// 执行指定检查器并记录结果
+    async fn check(&self, index: usize) -> HealthCheckResult {
+        let checker = &self.checker.checkers()[index];
+        let raw = AggregateHealthChecker::run_checker(checker.as_ref()).await;
+        let (result, transition) = self.states.write()[index].record(raw, &self.options);
+        record_health_status(&result.component_name, &result.status);
+
+        if let Some(transition) = transition {
+            metrics::counter!(
+                HEALTH_TRANSITIONS_TOTAL,
+                "component" => transition.component_name.clone(),
+                "status" => health_status_label(&transition.current)
+            )
+            .increment(1);
+            match &transition.current {
+                HealthStatus::Healthy => {
+                    tracing::info!("健康检查 {} 已恢复", transition.component_name)
+                }
+                HealthStatus::Degraded { message, .. } => {
+                    tracing::warn!("健康检查 {} 降级: {}", transition.component_name, message)
+                }
+                HealthStatus::Unhealthy { error, .. } => {
+                    tracing::error!("健康检查 {} 不健康: {}", transition.component_name, error)
+                }
+            }
+            // 没有订阅者时忽略发送失败
+            let _ = self.transitions.send(transition);
+        }
+
+        result
+    }
+}
+
+/// 后台健康监控器
+///
+/// 启动后为每个启用的检查器创建后台任务，按 [`HealthCheckable::check_interval`]
+/// 周期执行检查；停止或销毁时结束所有后台任务
+///
+/// [`HealthCheckable::check_interval`]: crate::HealthCheckable::check_interval
+pub struct HealthMonitor {
+    /// 共享状态
+    inner: Arc<MonitorInner>,
+    /// 后台检查任务
+    tasks: parking_lot::Mutex<Vec<JoinHandle<()>>>,
+}
+
+impl std::fmt::Debug for HealthMonitor {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("HealthMonitor")
+            .field("checkers", &self.inner.checker.checker_count())
+            .field("options", &self.inner.options)
+            .field("running", &self.is_running())
+            .finish()
+    }
+}
+
+impl HealthMonitor {
+    /// 创建健康监控器
+    pub fn new(checker: Arc<AggregateHealthChecker>, options: HealthMonitorOptions) -> Self {
+        let states = checker
+            .checkers()
+            .iter()
+            .map(|checker| CheckerState {
+                name: checker.name().to_string(),
+                probes: checker.probes().to_vec(),
+                latest: None,
+                history: VecDeque::new(),
+                consecutive_failures: 0,
+                consecutive_successes: 0,
+            })
+            .collect();
+        let (transitions, _) = broadcast::channel(TRANSITION_CHANNEL_CAPACITY);
+
+        Self {
+            inner: Arc::new(MonitorInner {
+                checker,
+                options,
+                states: parking_lot::RwLock::new(states),
+                transitions,
+            }),
+            tasks: parking_lot::Mutex::new(Vec::new()),
+        }
+    }
+
+    /// 启动后台监控
+    ///
+    /// 先同步执行一轮检查填充缓存，再按各检查器的间隔周期执行，间隔不小于
+    /// [`MIN_HEALTH_CHECK_INTERVAL`]。重复启动不产生额外任务
+    pub async fn start(&self) {
+        if self.is_running() {
+            return;
+        }
+
+        self.check_now().await;
+
+        let mut tasks = self.tasks.lock();
+        if !tasks.is_empty() {
+            return;
+        }
+        for (index, checker) in self.inner.checker.checkers().iter().enumerate() {
+            if !checker.is_enabled() {
+                continue;
+            }
+
+            let inner = self.inner.clone();
+            let period = checker.check_interval();
+            let period = if period < MIN_HEALTH_CHECK_INTERVAL {
+                tracing::warn!(
+                    "健康检查 {} 的检查间隔 {:?} 过短，使用最小间隔 {:?}",
+                    checker.name(),
+                    period,
+                    MIN_HEALTH_CHECK_INTERVAL
+                );
+                MIN_HEALTH_CHECK_INTERVAL
+            } else {
+                period
+            };
+            tasks.push(tokio::spawn(async move {
+                let mut interval =
+                    tokio::time::interval_at(tokio::time::Instant::now() + period, period);
+                interval.set_missed_tick_behavior(tokio::time::MissedTickBehavior::Delay);
+                loop {
+                    interval.tick().await;
+                    inner.check(index).await;
+                }
+            }));
+        }
+    }
+
+    /// 停止后台监控，保留已缓存的结果
+    pub fn stop(&self) {
+        for task in self.tasks.lock().drain(..) {
+            task.abort();
+        }
+    }
+
+    /// 是否正在后台监控
+    pub fn is_running(&self) -> bool {
+        !self.tasks.lock().is_empty()
+    }
+
+    /// 立即执行所有启用的检查并更新缓存
+    pub async fn check_now(&self) -> Vec<HealthCheckResult> {
+        let mut results = Vec::new();
+        for (index, checker) in self.inner.checker.checkers().iter().enumerate() {
+            if checker.is_enabled() {
+                results.push(self.inner.check(index).await);
+            }
+        }
+        results
+    }
+
+    /// 获取所有检查器的最新结果
+    pub fn latest_results(&self) -> Vec<HealthCheckResult> {
+        self.inner
+            .states
+            .read()
+            .iter()
+            .filter_map(|state| state.latest.clone())
+            .collect()
+    }
+
+    /// 获取参与指定探针的检查器的最新结果
+    pub fn latest_for_probe(&self, probe: HealthProbe) -> Vec<HealthCheckResult> {
+        self.inner
+            .states
+            .read()
+            .iter()
+            .filter(|state| state.probes.contains(&probe))
+            .filter_map(|state| state.latest.clone())
+            .collect()
+    }
+
+    /// 获取指定组件的最新结果
+    pub fn latest(&self, component_name: &str) -> Option<HealthCheckResult> {
+        self.inner
+            .states
+            .read()
+            .iter()
+            .find(|state| state.name == component_name)
+            .and_then(|state| state.latest.clone())
+    }
+
+    /// 获取指定组件的原始检查历史，按时间从旧到新排列
+    pub fn history(&self, component_name: &str) -> Vec<HealthCheckResult> {
+        self.inner
+            .states
+            .read()
+            .iter()
+            .find(|state| state.name == component_name)
+            .map(|state| state.history.iter().cloned().collect())
+            .unwrap_or_default()
+    }
+
+    /// 订阅健康状态变化事件
+    pub fn subscribe(&self) -> broadcast::Receiver<HealthTransition> {
+        self.inner.transitions.subscribe()
+    }
+
+    /// 获取监控选项
+    pub fn options(&self) -> &HealthMonitorOptions {
+        &self.inner.options
+    }
+}
+
+impl Drop for HealthMonitor {
+    fn drop(&mut self) {
+        self.stop();
+    }
+}
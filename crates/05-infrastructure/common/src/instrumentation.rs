@@ -0,0 +1,112 @@
+//! 基础设施指标定义
+//!
+//! 基础设施各组件通过 `metrics` 门面记录指标，未安装指标记录器时记录操作为空操作。
+//! 这里集中定义指标名称，导出端（如 Prometheus）通过 [`describe_infrastructure_metrics`]
+//! 注册指标说明。
+
+use crate::health::HealthStatus;
+use metrics::{describe_counter, describe_gauge, describe_histogram, Unit};
+
+/// 组件解析耗时（秒），标签：`component`
+pub const DI_RESOLVE_DURATION_SECONDS: &str = "adsp_di_resolve_duration_seconds";
+/// 组件解析次数，标签：`component`、`result`
+pub const DI_RESOLUTIONS_TOTAL: &str = "adsp_di_resolutions_total";
+/// 工厂创建组件耗时（秒），标签：`component`
+pub const DI_FACTORY_DURATION_SECONDS: &str = "adsp_di_factory_duration_seconds";
+/// 工厂创建失败次数，标签：`component`
+pub const DI_FACTORY_FAILURES_TOTAL: &str = "adsp_di_factory_failures_total";
+/// 配置重载耗时（秒）
+pub const CONFIG_RELOAD_DURATION_SECONDS: &str = "adsp_config_reload_duration_seconds";
+/// 配置重载次数，标签：`result`
+pub const CONFIG_RELOADS_TOTAL: &str = "adsp_config_reloads_total";
+/// 配置文件变更事件数，标签：`kind`
+pub const CONFIG_WATCH_EVENTS_TOTAL: &str = "adsp_config_watch_events_total";
+/// 健康状态：1 健康，0.5 降级，0 不健康，标签：`component`
+pub const HEALTH_STATUS: &str = "adsp_health_status";
+/// 健康检查耗时（秒），标签：`component`
+pub const HEALTH_CHECK_DURATION_SECONDS: &str = "adsp_health_check_duration_seconds";
+/// 健康状态变化次数，标签：`component`、`status`
+pub const HEALTH_TRANSITIONS_TOTAL: &str = "adsp_health_transitions_total";
+/// 基础设施构建耗时（秒）
+pub const INFRASTRUCTURE_BUILD_DURATION_SECONDS: &str =
+    "adsp_infrastructure_build_duration_seconds";
+/// 基础设施启动耗时（秒）
+pub const INFRASTRUCTURE_STARTUP_DURATION_SECONDS: &str =
+    "adsp_infrastructure_startup_duration_seconds";
+/// 启动流水线各阶段耗时（秒），标签：`stage`、`result`
+pub const BOOTSTRAP_STAGE_DURATION_SECONDS: &str = "adsp_bootstrap_stage_duration_seconds";
+
+/// 成功结果标签值
+pub const RESULT_SUCCESS: &str = "success";
+/// 失败结果标签值
+pub const RESULT_FAILURE: &str = "failure";
+
+/// 注册所有基础设施指标的说明
+pub fn describe_infrastructure_metrics() {
+    describe_histogram!(DI_RESOLVE_DURATION_SECONDS, Unit::Seconds, "组件解析耗时");
+    describe_counter!(DI_RESOLUTIONS_TOTAL, "组件解析次数");
+    describe_histogram!(
+        DI_FACTORY_DURATION_SECONDS,
+        Unit::Seconds,
+        "工厂创建组件耗时"
+    );
+    describe_counter!(DI_FACTORY_FAILURES_TOTAL, "工厂创建失败次数");
+    describe_histogram!(
+        CONFIG_RELOAD_DURATION_SECONDS,
+        Unit::Seconds,
+        "配置重载耗时"
+    );
+    describe_counter!(CONFIG_RELOADS_TOTAL, "配置重载次数");
+    describe_counter!(CONFIG_WATCH_EVENTS_TOTAL, "配置文件变更事件数");
+    describe_gauge!(HEALTH_STATUS, "健康状态：1 健康，0.5 降级，0 不健康");
+    describe_histogram!(HEALTH_CHECK_DURATION_SECONDS, Unit::Seconds, "健康检查耗时");
+    describe_counter!(HEALTH_TRANSITIONS_TOTAL, "健康状态变化次数");
+    describe_histogram!(
+        INFRASTRUCTURE_BUILD_DURATION_SECONDS,
+        Unit::Seconds,
+        "基础设施构建耗时"
+    );
+    describe_gauge!(
+        INFRASTRUCTURE_STARTUP_DURATION_SECONDS,
+        Unit::Seconds,
+        "基础设施启动耗时"
+    );
+    describe_histogram!(
+        BOOTSTRAP_STAGE_DURATION_SECONDS,
+        Unit::Seconds,
+        "启动流水线各阶段耗时"
+    );
+}
+
+/// 结果标签值
+pub fn result_label<T, E>(result: &Result<T, E>) -> &'static str {
+    if result.is_ok() {
+        RESULT_SUCCESS
+    } else {
+        RESULT_FAILURE
+    }
+}
+
+/// 健康状态对应的指标值
+pub fn health_status_value(status: &HealthStatus) -> f64 {
+    match status {
+        HealthStatus::Healthy => 1.0,
+        HealthStatus::Degraded { .. } => 0.5,
+        HealthStatus::Unhealthy { .. } => 0.0,
+    }
+}
+
+/// 健康状态标签值
+pub fn health_status_label(status: &HealthStatus) -> &'static str {
+    match status {
+        HealthStatus::Healthy => "healthy",
+        HealthStatus::Degraded { .. } => "degraded",
+        HealthStatus::Unhealthy { .. } => "unhealthy",
+    }
+}
+
+/// 记录组件的健康状态
+pub fn record_health_status(component_name: &str, status: &HealthStatus) {
+    metrics::gauge!(HEALTH_STATUS, "component" => component_name.to_string())
+        .set(health_status_value(status));
+}
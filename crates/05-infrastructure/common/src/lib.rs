@@ -7,8 +7,13 @@
 //! - [`Component`] - 组件基础 trait
 //! - [`Configurable`] - 可配置组件 trait  
 //! - [`HealthCheckable`] - 健康检查 trait
+//! - [`HealthMonitor`] - 后台周期健康监控
+//! - [`describe_infrastructure_metrics`] - 基础设施指标定义
 //! - [`ComponentConventions`] - 组件约定规范
 //! - [`Lifecycle`] - 组件生命周期管理
+//! - [`ActiveProfiles`] - 运行环境配置档
+//! - [`ConfigSchema`] - 配置类型的 JSON Schema
+//! - [`ValidateConfig`] - 声明式配置验证
 //!
 //! ## 设计原则
 //!
@@ -23,8 +28,13 @@ pub mod conventions;
 pub mod discovery;
 pub mod errors;
 pub mod health;
+pub mod health_monitor;
+pub mod instrumentation;
 pub mod lifecycle;
 pub mod metadata;
+pub mod profiles;
+pub mod schema;
+pub mod validation;
 
 pub use component::*;
 pub use configuration::*;
@@ -32,8 +42,19 @@ pub use conventions::*;
 pub use discovery::*;
 pub use errors::*;
 pub use health::*;
+pub use health_monitor::*;
+pub use instrumentation::*;
 pub use lifecycle::*;
 pub use metadata::*;
+pub use profiles::*;
+pub use schema::*;
+pub use validation::*;
+
+/// 供过程宏生成的代码使用，不属于公开 API
+#[doc(hidden)]
+pub mod __private {
+    pub use serde_json;
+}
 
 /// 全局组件注册表
 static GLOBAL_COMPONENT_REGISTRY: once_cell::sync::Lazy<
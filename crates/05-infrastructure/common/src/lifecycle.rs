@@ -2,9 +2,11 @@
 
 use crate::errors::LifecycleError;
 use async_trait::async_trait;
+use serde::{Deserialize, Serialize};
 
 /// 组件生命周期类型
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
 pub enum Lifetime {
     /// 单例模式 - 整个应用生命周期内只创建一个实例
     Singleton,
@@ -33,7 +35,7 @@ pub trait Scoped: LifecycleMarker {}
 pub trait Transient: LifecycleMarker {}
 
 /// 组件作用域
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct Scope {
     pub id: uuid::Uuid,
     pub name: String,
@@ -117,6 +119,17 @@ impl DefaultLifecycleManager {
             active_scopes: dashmap::DashMap::new(),
         }
     }
+
+    /// 获取活跃的作用域（按创建时间排序）
+    pub fn active_scopes(&self) -> Vec<Scope> {
+        let mut scopes: Vec<Scope> = self
+            .active_scopes
+            .iter()
+            .map(|entry| entry.value().clone())
+            .collect();
+        scopes.sort_by_key(|scope| scope.created_at);
+        scopes
+    }
 }
 
 impl Default for DefaultLifecycleManager {
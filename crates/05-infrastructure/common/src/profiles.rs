@@ -0,0 +1,132 @@
+//! 运行环境配置档（Profile）定义
+//!
+//! 配置档用于选择加载哪些环境特定的配置文件，以及控制组件的条件注册
+
+use crate::component::Component;
+use serde::{Deserialize, Serialize};
+use std::fmt;
+
+/// 选择激活配置档的环境变量名
+pub const PROFILE_ENV_VAR: &str = "ADSP_PROFILE";
+
+/// 当前激活的配置档集合
+///
+/// 配置档按激活顺序保存，后激活的配置档对应的配置文件优先级更高。
+/// 名称比较不区分大小写，且 `development`/`production`/`testing`
+/// 分别与 `dev`/`prod`/`test` 视为同一个配置档
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ActiveProfiles {
+    profiles: Vec<String>,
+}
+
+impl ActiveProfiles {
+    /// 创建配置档集合
+    pub fn new<I, S>(profiles: I) -> Self
+    where
+        I: IntoIterator<Item = S>,
+        S: Into<String>,
+    {
+        let mut active = Self::default();
+        for profile in profiles {
+            active.activate(profile);
+        }
+        active
+    }
+
+    /// 从 `ADSP_PROFILE` 环境变量读取激活的配置档
+    pub fn from_env() -> Self {
+        Self::from_env_var(PROFILE_ENV_VAR)
+    }
+
+    /// 从指定环境变量读取激活的配置档
+    pub fn from_env_var(name: &str) -> Self {
+        std::env::var(name)
+            .map(|value| Self::parse(&value))
+            .unwrap_or_default()
+    }
+
+    /// 解析以逗号或空白分隔的配置档列表，如 `"prod, eu"`
+    pub fn parse(value: &str) -> Self {
+        Self::new(
+            value
+                .split(|c: char| c == ',' || c.is_whitespace())
+                .filter(|profile| !profile.is_empty()),
+        )
+    }
+
+    /// 激活配置档（重复激活会被忽略）
+    pub fn activate(&mut self, profile: impl Into<String>) {
+        let profile = profile.into();
+        let profile = profile.trim();
+        if !profile.is_empty() && !self.is_active(profile) {
+            self.profiles.push(profile.to_string());
+        }
+    }
+
+    /// 获取所有激活的配置档（按激活顺序）
+    pub fn profiles(&self) -> &[String] {
+        &self.profiles
+    }
+
+    /// 是否没有激活任何配置档
+    pub fn is_empty(&self) -> bool {
+        self.profiles.is_empty()
+    }
+
+    /// 检查配置档是否激活
+    pub fn is_active(&self, profile: &str) -> bool {
+        let canonical = canonical_profile_name(profile);
+        self.profiles
+            .iter()
+            .any(|active| canonical_profile_name(active) == canonical)
+    }
+
+    /// 获取配置档的激活顺序
+    pub fn position(&self, profile: &str) -> Option<usize> {
+        let canonical = canonical_profile_name(profile);
+        self.profiles
+            .iter()
+            .position(|active| canonical_profile_name(active) == canonical)
+    }
+
+    /// 评估配置档表达式
+    ///
+    /// 支持 `prod`、`!dev`、`prod | staging`（任一满足）和
+    /// `prod & eu`（全部满足），`&` 的优先级高于 `|`
+    pub fn matches(&self, expression: &str) -> bool {
+        expression.split('|').any(|alternative| {
+            alternative.split('&').all(|term| {
+                let term = term.trim();
+                match term.strip_prefix('!') {
+                    Some(negated) => !self.is_active(negated.trim()),
+                    None => term.is_empty() || self.is_active(term),
+                }
+            })
+        })
+    }
+}
+
+impl fmt::Display for ActiveProfiles {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.profiles.join(","))
+    }
+}
+
+impl Component for ActiveProfiles {
+    fn name(&self) -> &'static str {
+        "ActiveProfiles"
+    }
+}
+
+/// 获取配置档的规范名称
+///
+/// 转为小写，并将常见的完整名称映射为缩写
+pub fn canonical_profile_name(profile: &str) -> String {
+    let lower = profile.trim().to_lowercase();
+    match lower.as_str() {
+        "development" => "dev".to_string(),
+        "production" => "prod".to_string(),
+        "testing" => "test".to_string(),
+        _ => lower,
+    }
+}
@@ -0,0 +1,268 @@
+//! 配置类型的 JSON Schema
+//!
+//! [`ConfigSchema`] 描述配置类型对应的 JSON Schema（2020-12 草案），
+//! 可以通过 `component-macros` 的 `#[derive(ConfigSchema)]` 派生，
+//! 常用的标准库类型已内置实现
+
+use serde_json::{json, Map, Value};
+use std::collections::{BTreeMap, BTreeSet, HashMap, HashSet};
+
+/// JSON Schema 方言
+pub const JSON_SCHEMA_DIALECT: &str = "https://json-schema.org/draft/2020-12/schema";
+
+/// 配置类型的 JSON Schema
+pub trait ConfigSchema {
+    /// 生成 JSON Schema
+    fn config_schema() -> Value;
+
+    /// 字段是否可以省略（如 `Option<T>`）
+    fn is_optional() -> bool {
+        false
+    }
+}
+
+/// 对象类型的 JSON Schema 构建器
+///
+/// 供 `#[derive(ConfigSchema)]` 生成的代码使用，也可以手工实现 [`ConfigSchema`] 时使用
+#[derive(Debug, Clone, Default)]
+pub struct ObjectSchema {
+    /// 标题
+    title: Option<String>,
+    /// 描述
+    description: Option<String>,
+    /// 属性
+    properties: Map<String, Value>,
+    /// 必需属性
+    required: Vec<String>,
+    /// 是否拒绝未声明的属性
+    deny_unknown_properties: bool,
+}
+
+impl ObjectSchema {
+    /// 创建新的对象 Schema
+    pub fn new(title: impl Into<String>) -> Self {
+        Self {
+            title: Some(title.into()),
+            ..Self::default()
+        }
+    }
+
+    /// 设置描述
+    pub fn with_description(mut self, description: impl Into<String>) -> Self {
+        self.description = Some(description.into());
+        self
+    }
+
+    /// 拒绝未声明的属性（对应 `#[serde(deny_unknown_fields)]`）
+    pub fn deny_unknown_properties(mut self) -> Self {
+        self.deny_unknown_properties = true;
+        self
+    }
+
+    /// 添加属性
+    pub fn property(mut self, name: impl Into<String>, schema: Value, required: bool) -> Self {
+        let name = name.into();
+        if required {
+            self.required.push(name.clone());
+        }
+        self.properties.insert(name, schema);
+        self
+    }
+
+    /// 展开另一个对象 Schema 的属性（对应 `#[serde(flatten)]`）
+    pub fn flatten(mut self, schema: Value) -> Self {
+        if let Some(properties) = schema.get("properties").and_then(Value::as_object) {
+            for (name, property) in properties {
+                self.properties.insert(name.clone(), property.clone());
+            }
+        }
+        if let Some(required) = schema.get("required").and_then(Value::as_array) {
+            self.required
+                .extend(required.iter().filter_map(Value::as_str).map(str::to_string));
+        }
+        self
+    }
+
+    /// 生成 JSON Schema
+    pub fn build(self) -> Value {
+        let mut schema = Map::new();
+        schema.insert("type".to_string(), json!("object"));
+        if let Some(title) = self.title {
+            schema.insert("title".to_string(), Value::String(title));
+        }
+        if let Some(description) = self.description {
+            schema.insert("description".to_string(), Value::String(description));
+        }
+        schema.insert("properties".to_string(), Value::Object(self.properties));
+        if !self.required.is_empty() {
+            schema.insert("required".to_string(), json!(self.required));
+        }
+        if self.deny_unknown_properties {
+            schema.insert("additionalProperties".to_string(), Value::Bool(false));
+        }
+        Value::Object(schema)
+    }
+}
+
+/// 为 Schema 添加关键字（如 `minimum`、`description`、`default`）
+///
+/// 布尔 Schema `true` 会先展开为空对象
+pub fn annotate_schema(schema: Value, keyword: &str, value: Value) -> Value {
+    let mut map = match schema {
+        Value::Object(map) => map,
+        _ => Map::new(),
+    };
+    if keyword == "examples" {
+        match map.get_mut("examples") {
+            Some(Value::Array(examples)) => examples.push(value),
+            _ => {
+                map.insert("examples".to_string(), Value::Array(vec![value]));
+            }
+        }
+    } else {
+        map.insert(keyword.to_string(), value);
+    }
+    Value::Object(map)
+}
+
+/// 允许 Schema 接受 `null`
+fn nullable(schema: Value) -> Value {
+    match schema {
+        Value::Object(mut map) => match map.get("type").cloned() {
+            Some(Value::String(kind)) => {
+                map.insert("type".to_string(), json!([kind, "null"]));
+                Value::Object(map)
+            }
+            Some(Value::Array(mut kinds)) => {
+                if !kinds.contains(&json!("null")) {
+                    kinds.push(json!("null"));
+                }
+                map.insert("type".to_string(), Value::Array(kinds));
+                Value::Object(map)
+            }
+            _ => json!({"anyOf": [Value::Object(map), {"type": "null"}]}),
+        },
+        other => other,
+    }
+}
+
+/// 实现整数类型的 Schema
+macro_rules! impl_integer_schema {
+    ($($ty:ty),*) => {
+        $(
+            impl ConfigSchema for $ty {
+                fn config_schema() -> Value {
+                    json!({
+                        "type": "integer",
+                        "minimum": <$ty>::MIN,
+                        "maximum": <$ty>::MAX
+                    })
+                }
+            }
+        )*
+    };
+}
+
+impl_integer_schema!(i8, i16, i32, i64, isize, u8, u16, u32, u64, usize);
+
+impl ConfigSchema for bool {
+    fn config_schema() -> Value {
+        json!({"type": "boolean"})
+    }
+}
+
+impl ConfigSchema for f32 {
+    fn config_schema() -> Value {
+        json!({"type": "number"})
+    }
+}
+
+impl ConfigSchema for f64 {
+    fn config_schema() -> Value {
+        json!({"type": "number"})
+    }
+}
+
+impl ConfigSchema for String {
+    fn config_schema() -> Value {
+        json!({"type": "string"})
+    }
+}
+
+impl ConfigSchema for char {
+    fn config_schema() -> Value {
+        json!({"type": "string", "minLength": 1, "maxLength": 1})
+    }
+}
+
+impl ConfigSchema for std::path::PathBuf {
+    fn config_schema() -> Value {
+        json!({"type": "string"})
+    }
+}
+
+impl ConfigSchema for Value {
+    fn config_schema() -> Value {
+        json!({})
+    }
+}
+
+impl<T: ConfigSchema> ConfigSchema for Option<T> {
+    fn config_schema() -> Value {
+        nullable(T::config_schema())
+    }
+
+    fn is_optional() -> bool {
+        true
+    }
+}
+
+impl<T: ConfigSchema> ConfigSchema for Box<T> {
+    fn config_schema() -> Value {
+        T::config_schema()
+    }
+
+    fn is_optional() -> bool {
+        T::is_optional()
+    }
+}
+
+impl<T: ConfigSchema> ConfigSchema for std::sync::Arc<T> {
+    fn config_schema() -> Value {
+        T::config_schema()
+    }
+
+    fn is_optional() -> bool {
+        T::is_optional()
+    }
+}
+
+impl<T: ConfigSchema> ConfigSchema for Vec<T> {
+    fn config_schema() -> Value {
+        json!({"type": "array", "items": T::config_schema()})
+    }
+}
+
+impl<T: ConfigSchema, S> ConfigSchema for HashSet<T, S> {
+    fn config_schema() -> Value {
+        json!({"type": "array", "items": T::config_schema(), "uniqueItems": true})
+    }
+}
+
+impl<T: ConfigSchema> ConfigSchema for BTreeSet<T> {
+    fn config_schema() -> Value {
+        json!({"type": "array", "items": T::config_schema(), "uniqueItems": true})
+    }
+}
+
+impl<T: ConfigSchema, S> ConfigSchema for HashMap<String, T, S> {
+    fn config_schema() -> Value {
+        json!({"type": "object", "additionalProperties": T::config_schema()})
+    }
+}
+
+impl<T: ConfigSchema> ConfigSchema for BTreeMap<String, T> {
+    fn config_schema() -> Value {
+        json!({"type": "object", "additionalProperties": T::config_schema()})
+    }
+}
@@ -0,0 +1,253 @@
+//! 声明式配置验证
+//!
+//! `component-macros` 的 `#[derive(Validate)]` 根据字段上的 `#[validate(...)]` 属性
+//! 实现 [`ValidateConfig`]，[`DeclarativeValidator`] 将其适配为 [`ConfigValidator`]。
+//! 本模块中的检查函数供生成的代码使用，也可以在手工实现时直接调用
+
+use crate::configuration::ConfigValidator;
+use crate::errors::ValidationError;
+use dashmap::DashMap;
+use regex::Regex;
+use std::collections::{BTreeMap, BTreeSet, HashMap, HashSet, VecDeque};
+use std::marker::PhantomData;
+
+/// 可声明式验证的配置类型
+pub trait ValidateConfig {
+    /// 收集所有验证错误
+    fn validation_errors(&self) -> Vec<ValidationError>;
+
+    /// 验证配置，存在多个错误时合并为一个
+    fn validate_config(&self) -> Result<(), ValidationError> {
+        let mut errors = self.validation_errors();
+        match errors.len() {
+            0 => Ok(()),
+            1 => Err(errors.remove(0)),
+            _ => Err(ValidationError::new(
+                errors
+                    .iter()
+                    .map(ToString::to_string)
+                    .collect::<Vec<_>>()
+                    .join("; "),
+            )),
+        }
+    }
+}
+
+/// 基于 [`ValidateConfig`] 的配置验证器
+pub struct DeclarativeValidator<T> {
+    _marker: PhantomData<fn(&T)>,
+}
+
+impl<T> DeclarativeValidator<T> {
+    /// 创建新的声明式验证器
+    pub fn new() -> Self {
+        Self {
+            _marker: PhantomData,
+        }
+    }
+}
+
+impl<T> Default for DeclarativeValidator<T> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T> std::fmt::Debug for DeclarativeValidator<T> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("DeclarativeValidator")
+            .field("type", &std::any::type_name::<T>())
+            .finish()
+    }
+}
+
+impl<T> ConfigValidator<T> for DeclarativeValidator<T>
+where
+    T: ValidateConfig,
+{
+    fn validate(&self, config: &T) -> Result<(), ValidationError> {
+        config.validate_config()
+    }
+
+    fn name(&self) -> &'static str {
+        std::any::type_name::<T>()
+    }
+}
+
+/// 可进行范围检查的数值类型
+pub trait ValidationNumber {
+    /// 转换为 `f64` 进行比较
+    fn to_validation_f64(&self) -> f64;
+}
+
+/// 实现数值类型的范围检查
+macro_rules! impl_validation_number {
+    ($($ty:ty),*) => {
+        $(
+            impl ValidationNumber for $ty {
+                fn to_validation_f64(&self) -> f64 {
+                    *self as f64
+                }
+            }
+        )*
+    };
+}
+
+impl_validation_number!(i8, i16, i32, i64, isize, u8, u16, u32, u64, usize, f32, f64);
+
+/// 可进行长度检查的类型
+pub trait ValidationLength {
+    /// 长度（字符串为字符数）
+    fn validation_length(&self) -> usize;
+}
+
+impl ValidationLength for str {
+    fn validation_length(&self) -> usize {
+        self.chars().count()
+    }
+}
+
+impl ValidationLength for String {
+    fn validation_length(&self) -> usize {
+        self.as_str().validation_length()
+    }
+}
+
+impl<T> ValidationLength for [T] {
+    fn validation_length(&self) -> usize {
+        self.len()
+    }
+}
+
+impl<T> ValidationLength for Vec<T> {
+    fn validation_length(&self) -> usize {
+        self.len()
+    }
+}
+
+impl<T> ValidationLength for VecDeque<T> {
+    fn validation_length(&self) -> usize {
+        self.len()
+    }
+}
+
+impl<T, S> ValidationLength for HashSet<T, S> {
+    fn validation_length(&self) -> usize {
+        self.len()
+    }
+}
+
+impl<T> ValidationLength for BTreeSet<T> {
+    fn validation_length(&self) -> usize {
+        self.len()
+    }
+}
+
+impl<K, V, S> ValidationLength for HashMap<K, V, S> {
+    fn validation_length(&self) -> usize {
+        self.len()
+    }
+}
+
+impl<K, V> ValidationLength for BTreeMap<K, V> {
+    fn validation_length(&self) -> usize {
+        self.len()
+    }
+}
+
+/// 验证错误中代替字段实际值的占位符
+///
+/// 配置验证作用于解析密钥后的值，错误中只报告字段、规则和原因
+pub const REDACTED_FIELD_VALUE: &str = "******";
+
+/// 检查数值范围（闭区间）
+pub fn check_range<N>(
+    field: &str,
+    value: &N,
+    min: Option<f64>,
+    max: Option<f64>,
+) -> Option<ValidationError>
+where
+    N: ValidationNumber + ?Sized,
+{
+    let actual = value.to_validation_f64();
+    let below = min.is_some_and(|min| actual < min);
+    let above = max.is_some_and(|max| actual > max);
+    (below || above).then(|| {
+        ValidationError::value_out_of_range(field, REDACTED_FIELD_VALUE, describe_bounds(min, max))
+    })
+}
+
+/// 检查长度范围（闭区间）
+pub fn check_length<L>(
+    field: &str,
+    value: &L,
+    min: Option<usize>,
+    max: Option<usize>,
+) -> Option<ValidationError>
+where
+    L: ValidationLength + ?Sized,
+{
+    let length = value.validation_length();
+    let too_short = min.is_some_and(|min| length < min);
+    let too_long = max.is_some_and(|max| length > max);
+    (too_short || too_long).then(|| {
+        ValidationError::value_out_of_range(
+            field,
+            format!("长度 {}", length),
+            format!(
+                "长度 {}",
+                describe_bounds(min.map(|m| m as f64), max.map(|m| m as f64))
+            ),
+        )
+    })
+}
+
+/// 检查字符串是否匹配正则表达式
+///
+/// 编译后的正则表达式按模式缓存；`#[derive(Validate)]` 生成的调用在编译期已检查模式有效
+pub fn check_pattern(field: &str, value: &str, pattern: &'static str) -> Option<ValidationError> {
+    static PATTERNS: once_cell::sync::Lazy<DashMap<&'static str, Result<Regex, String>>> =
+        once_cell::sync::Lazy::new(DashMap::new);
+
+    let compiled = PATTERNS
+        .entry(pattern)
+        .or_insert_with(|| Regex::new(pattern).map_err(|e| e.to_string()));
+    match compiled.value() {
+        Ok(regex) if regex.is_match(value) => None,
+        Ok(_) => Some(ValidationError::format_error(field, pattern)),
+        Err(e) => Some(ValidationError::invalid_field_value(
+            field,
+            REDACTED_FIELD_VALUE,
+            format!("无效的正则表达式 {}: {}", pattern, e),
+        )),
+    }
+}
+
+/// 检查字符串是否为带主机名的 URL
+pub fn check_url(field: &str, value: &str) -> Option<ValidationError> {
+    match url::Url::parse(value) {
+        Ok(url) if url.has_host() => None,
+        Ok(_) => Some(ValidationError::invalid_field_value(
+            field,
+            REDACTED_FIELD_VALUE,
+            "URL 缺少主机名",
+        )),
+        // 解析错误只描述错误类型，不包含 URL 本身
+        Err(e) => Some(ValidationError::invalid_field_value(
+            field,
+            REDACTED_FIELD_VALUE,
+            format!("无效的 URL: {}", e),
+        )),
+    }
+}
+
+/// 描述取值范围
+fn describe_bounds(min: Option<f64>, max: Option<f64>) -> String {
+    match (min, max) {
+        (Some(min), Some(max)) => format!("[{}, {}]", min, max),
+        (Some(min), None) => format!(">= {}", min),
+        (None, Some(max)) => format!("<= {}", max),
+        (None, None) => "任意".to_string(),
+    }
+}
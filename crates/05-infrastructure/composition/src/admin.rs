@@ -0,0 +1,422 @@
+//! 管理和诊断 HTTP 端点
+//!
+//! 将运行中的 [`AdSystemInfrastructure`] 暴露为可嵌入的 axum 路由，无需附加调试器
+//! 即可查看和操作基础设施：
+//!
+//! - `GET /admin/status` - 运行状态、激活的配置档和运行时间
+//! - `GET /admin/metrics` - 基础设施统计信息
+//! - `GET /admin/components` - 已注册组件及其生命周期状态
+//! - `GET /admin/scopes` - 活跃作用域
+//! - `GET /admin/config` - 生效配置（敏感配置已脱敏）
+//! - `GET /admin/config/history` - 配置历史（敏感配置已脱敏）
+//! - `POST /admin/config/rollback` - 回滚到指定版本或上一个版本
+//! - `POST /admin/config/reload` - 重新加载配置
+//!
+//! 配置了日志时同时提供 [`crate::log_filter`] 的 `/admin/log-level` 端点。
+//! 所有端点要求 `Authorization: Bearer <token>` 请求头，令牌每次请求时从配置读取
+//! （默认路径 `admin.token`，支持 `${secret:name}` 引用），未配置令牌时拒绝所有请求。
+
+use crate::infrastructure::{AdSystemInfrastructure, InfrastructureMetrics, InfrastructureStatus};
+use axum::body::Bytes;
+use axum::extract::{Request, State};
+use axum::http::{header, StatusCode};
+use axum::middleware::{self, Next};
+use axum::response::{IntoResponse, Response};
+use axum::routing::{get, post};
+use axum::{Json, Router};
+use config_abstractions::{ConfigAuditRecord, ConfigChangeEvent, ConfigDump, ConfigSnapshot};
+use di_abstractions::ComponentRegistry;
+use infrastructure_common::{ConfigError, InfrastructureError, Lifetime, Scope};
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use std::sync::Arc;
+use tracing::{info, warn};
+
+/// 管理令牌的默认配置路径
+pub const ADMIN_TOKEN_CONFIG_PATH: &str = "admin.token";
+/// 状态端点路径
+pub const ADMIN_STATUS_PATH: &str = "/admin/status";
+/// 统计信息端点路径
+pub const ADMIN_METRICS_PATH: &str = "/admin/metrics";
+/// 组件端点路径
+pub const ADMIN_COMPONENTS_PATH: &str = "/admin/components";
+/// 作用域端点路径
+pub const ADMIN_SCOPES_PATH: &str = "/admin/scopes";
+/// 生效配置端点路径
+pub const ADMIN_CONFIG_PATH: &str = "/admin/config";
+/// 配置历史端点路径
+pub const ADMIN_CONFIG_HISTORY_PATH: &str = "/admin/config/history";
+/// 配置回滚端点路径
+pub const ADMIN_CONFIG_ROLLBACK_PATH: &str = "/admin/config/rollback";
+/// 配置重载端点路径
+pub const ADMIN_CONFIG_RELOAD_PATH: &str = "/admin/config/reload";
+
+/// 管理端点的审计来源
+const ADMIN_AUDIT_SOURCE: &str = "admin";
+
+/// 基础设施状态概览
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct AdminStatus {
+    /// 运行状态
+    pub status: InfrastructureStatus,
+    /// 激活的配置档
+    pub active_profiles: Vec<String>,
+    /// 运行时间（秒），尚未启动时为空
+    pub uptime_secs: Option<i64>,
+    /// 当前配置版本
+    pub config_version: Option<u64>,
+}
+
+/// 组件实例状态
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum ComponentState {
+    /// 单例已创建
+    Created,
+    /// 单例尚未创建，首次解析时创建
+    Pending,
+    /// 每次解析或每个作用域创建新实例
+    OnDemand,
+    /// 工厂最近一次创建失败
+    Failed,
+    /// 既没有工厂也没有实例，无法解析
+    Unresolvable,
+}
+
+/// 已注册组件的状态
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ComponentStatus {
+    /// 组件名称
+    pub name: String,
+    /// 组件类型
+    pub type_name: String,
+    /// 生命周期
+    pub lifetime: Option<Lifetime>,
+    /// 实例状态
+    pub state: ComponentState,
+    /// 工厂最近一次创建失败的原因
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub error: Option<String>,
+    /// 组件描述
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub description: Option<String>,
+    /// 组件标签
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub tags: Vec<String>,
+}
+
+/// 配置回滚请求
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct RollbackRequest {
+    /// 目标版本，为空时回滚到上一个版本
+    pub version: Option<u64>,
+    /// 操作者
+    pub actor: Option<String>,
+    /// 回滚原因
+    pub reason: Option<String>,
+}
+
+/// 配置变更结果
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ConfigChangeSummary {
+    /// 变更后的配置版本
+    pub version: Option<u64>,
+    /// 逐键变更（敏感配置已脱敏）
+    pub changes: Vec<ConfigChangeEvent>,
+}
+
+/// 管理端点
+///
+/// 通过 [`AdSystemInfrastructure::admin_router`] 获得
+#[derive(Clone)]
+pub struct AdminEndpoints {
+    /// 基础设施
+    infrastructure: Arc<AdSystemInfrastructure>,
+    /// 管理令牌的配置路径
+    token_path: String,
+}
+
+impl std::fmt::Debug for AdminEndpoints {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("AdminEndpoints")
+            .field("token_path", &self.token_path)
+            .finish_non_exhaustive()
+    }
+}
+
+impl AdminEndpoints {
+    /// 创建管理端点，令牌从 [`ADMIN_TOKEN_CONFIG_PATH`] 读取
+    pub fn new(infrastructure: Arc<AdSystemInfrastructure>) -> Self {
+        Self {
+            infrastructure,
+            token_path: ADMIN_TOKEN_CONFIG_PATH.to_string(),
+        }
+    }
+
+    /// 设置管理令牌的配置路径
+    pub fn with_token_path(mut self, path: impl Into<String>) -> Self {
+        self.token_path = path.into();
+        self
+    }
+
+    /// 获取已注册组件的状态（按名称排序）
+    pub async fn component_statuses(&self) -> Vec<ComponentStatus> {
+        let container = self.infrastructure.di_container().read().await;
+        let report = container.health_report().await;
+        let lifetimes: HashMap<String, Lifetime> =
+            ComponentRegistry::get_registered_components(&*container)
+                .into_iter()
+                .map(|descriptor| (descriptor.name, descriptor.lifetime))
+                .collect();
+        let metadata = di_abstractions::DiContainer::get_registered_components(&*container);
+        drop(container);
+
+        let mut statuses: Vec<ComponentStatus> = metadata
+            .into_iter()
+            .map(|metadata| {
+                let lifetime = lifetimes.get(&metadata.name).copied();
+                let error = report.factory_failures.get(&metadata.name).cloned();
+                let state = if report.unresolvable.contains(&metadata.name) {
+                    ComponentState::Unresolvable
+                } else if error.is_some() {
+                    ComponentState::Failed
+                } else if report.pending_singletons.contains(&metadata.name) {
+                    ComponentState::Pending
+                } else if lifetime == Some(Lifetime::Singleton) {
+                    ComponentState::Created
+                } else {
+                    ComponentState::OnDemand
+                };
+
+                ComponentStatus {
+                    name: metadata.name,
+                    type_name: metadata.type_info.name,
+                    lifetime,
+                    state,
+                    error,
+                    description: metadata.description,
+                    tags: metadata.tags,
+                }
+            })
+            .collect();
+        statuses.sort_by(|a, b| a.name.cmp(&b.name));
+        statuses
+    }
+
+    /// 创建管理路由
+    pub fn router(self) -> Router {
+        let mut router = Router::new()
+            .route(ADMIN_STATUS_PATH, get(get_status))
+            .route(ADMIN_METRICS_PATH, get(get_metrics))
+            .route(ADMIN_COMPONENTS_PATH, get(get_components))
+            .route(ADMIN_SCOPES_PATH, get(get_scopes))
+            .route(ADMIN_CONFIG_PATH, get(get_config))
+            .route(ADMIN_CONFIG_HISTORY_PATH, get(get_config_history))
+            .route(ADMIN_CONFIG_ROLLBACK_PATH, post(rollback_config))
+            .route(ADMIN_CONFIG_RELOAD_PATH, post(reload_config))
+            .with_state(self.clone());
+        if let Some(log_level_router) = self.infrastructure.log_level_router() {
+            router = router.merge(log_level_router);
+        }
+
+        router.layer(middleware::from_fn_with_state(self, authorize))
+    }
+
+    /// 从配置读取管理令牌，未配置或为空时返回 None
+    async fn configured_token(&self) -> Option<String> {
+        match self
+            .infrastructure
+            .get_config::<String>(&self.token_path)
+            .await
+        {
+            Ok(token) if !token.is_empty() => Some(token),
+            Ok(_) => None,
+            Err(InfrastructureError::ConfigError {
+                source: ConfigError::KeyNotFound { .. },
+            }) => None,
+            Err(e) => {
+                warn!("读取管理令牌失败: {}", e);
+                None
+            }
+        }
+    }
+}
+
+/// 错误响应
+fn error_response(status: StatusCode, message: impl std::fmt::Display) -> Response {
+    (
+        status,
+        Json(serde_json::json!({ "error": message.to_string() })),
+    )
+        .into_response()
+}
+
+/// 配置错误对应的响应
+fn config_error_response(error: ConfigError) -> Response {
+    let status = match &error {
+        ConfigError::VersionNotFound { .. } => StatusCode::NOT_FOUND,
+        ConfigError::NoRollbackAvailable => StatusCode::CONFLICT,
+        _ => StatusCode::INTERNAL_SERVER_ERROR,
+    };
+    error_response(status, error)
+}
+
+/// 按长度恒定的时间比较令牌，避免通过响应时间猜测令牌
+fn token_matches(expected: &str, provided: &str) -> bool {
+    let (expected, provided) = (expected.as_bytes(), provided.as_bytes());
+    expected.len() == provided.len()
+        && expected
+            .iter()
+            .zip(provided)
+            .fold(0u8, |diff, (a, b)| diff | (a ^ b))
+            == 0
+}
+
+/// 校验管理令牌
+async fn authorize(
+    State(endpoints): State<AdminEndpoints>,
+    request: Request,
+    next: Next,
+) -> Response {
+    let Some(expected) = endpoints.configured_token().await else {
+        return error_response(StatusCode::FORBIDDEN, "管理令牌未配置，管理端点已禁用");
+    };
+
+    let provided = request
+        .headers()
+        .get(header::AUTHORIZATION)
+        .and_then(|value| value.to_str().ok())
+        .and_then(|value| value.strip_prefix("Bearer "));
+    match provided {
+        Some(provided) if token_matches(&expected, provided.trim()) => next.run(request).await,
+        _ => {
+            let mut response = error_response(StatusCode::UNAUTHORIZED, "管理令牌无效");
+            response.headers_mut().insert(
+                header::WWW_AUTHENTICATE,
+                header::HeaderValue::from_static("Bearer"),
+            );
+            response
+        }
+    }
+}
+
+/// 查看运行状态
+async fn get_status(State(endpoints): State<AdminEndpoints>) -> Json<AdminStatus> {
+    let infrastructure = &endpoints.infrastructure;
+    let metrics = infrastructure.get_metrics().await;
+
+    Json(AdminStatus {
+        status: infrastructure.get_status().await,
+        active_profiles: infrastructure.active_profiles().profiles().to_vec(),
+        uptime_secs: metrics.uptime().map(|uptime| uptime.num_seconds()),
+        config_version: infrastructure
+            .config_manager()
+            .latest_version()
+            .await
+            .ok()
+            .flatten(),
+    })
+}
+
+/// 查看统计信息
+async fn get_metrics(State(endpoints): State<AdminEndpoints>) -> Json<InfrastructureMetrics> {
+    Json(endpoints.infrastructure.get_metrics().await)
+}
+
+/// 查看已注册组件
+async fn get_components(State(endpoints): State<AdminEndpoints>) -> Json<Vec<ComponentStatus>> {
+    Json(endpoints.component_statuses().await)
+}
+
+/// 查看活跃作用域
+async fn get_scopes(State(endpoints): State<AdminEndpoints>) -> Json<Vec<Scope>> {
+    Json(endpoints.infrastructure.lifecycle_manager().active_scopes())
+}
+
+/// 查看生效配置
+async fn get_config(State(endpoints): State<AdminEndpoints>) -> Response {
+    match endpoints
+        .infrastructure
+        .config_manager()
+        .dump_effective_configuration()
+        .await
+    {
+        Ok(dump) => Json::<ConfigDump>(dump).into_response(),
+        Err(e) => config_error_response(e),
+    }
+}
+
+/// 查看配置历史
+async fn get_config_history(State(endpoints): State<AdminEndpoints>) -> Json<Vec<ConfigSnapshot>> {
+    Json(
+        endpoints
+            .infrastructure
+            .config_manager()
+            .get_config_history()
+            .await,
+    )
+}
+
+/// 回滚配置
+async fn rollback_config(State(endpoints): State<AdminEndpoints>, body: Bytes) -> Response {
+    let request = if body.is_empty() {
+        RollbackRequest::default()
+    } else {
+        match serde_json::from_slice::<RollbackRequest>(&body) {
+            Ok(request) => request,
+            Err(e) => return error_response(StatusCode::BAD_REQUEST, e),
+        }
+    };
+    let config_manager = endpoints.infrastructure.config_manager();
+
+    let version = match request.version {
+        Some(version) => version,
+        None => match config_manager.history_store().list().await {
+            Ok(history) if history.len() >= 2 => history[history.len() - 2].version,
+            Ok(_) => return config_error_response(ConfigError::NoRollbackAvailable),
+            Err(e) => return config_error_response(e),
+        },
+    };
+    let mut audit = ConfigAuditRecord::new(ADMIN_AUDIT_SOURCE);
+    if let Some(actor) = request.actor {
+        audit = audit.with_actor(actor);
+    }
+    if let Some(reason) = request.reason {
+        audit = audit.with_reason(reason);
+    }
+
+    info!("通过管理端点回滚配置到版本 {}", version);
+    match config_manager.rollback_with_audit(version, audit).await {
+        Ok(events) => {
+            let redactor = config_manager.redactor();
+            Json(ConfigChangeSummary {
+                version: config_manager.latest_version().await.ok().flatten(),
+                changes: events
+                    .iter()
+                    .map(|event| redactor.redact_event(event))
+                    .collect(),
+            })
+            .into_response()
+        }
+        Err(e) => config_error_response(e),
+    }
+}
+
+/// 重新加载配置
+async fn reload_config(State(endpoints): State<AdminEndpoints>) -> Response {
+    info!("通过管理端点重新加载配置");
+    match endpoints.infrastructure.reload_configuration().await {
+        Ok(()) => Json(serde_json::json!({
+            "version": endpoints
+                .infrastructure
+                .config_manager()
+                .latest_version()
+                .await
+                .ok()
+                .flatten(),
+        }))
+        .into_response(),
+        Err(InfrastructureError::ConfigError { source }) => config_error_response(source),
+        Err(e) => error_response(StatusCode::INTERNAL_SERVER_ERROR, e),
+    }
+}
@@ -1,28 +1,559 @@
-//! 基础设施启动器
+//! 基础设施启动流水线
+//!
+//! 基础设施按固定顺序的阶段启动：
+//! 配置 → 验证 → 发现 → 注册 → 预热 → 生命周期启动 → 健康检查。
+//!
+//! 每个阶段由若干 [`BootstrapStep`] 组成，内置步骤先于同一阶段的自定义步骤执行。
+//! 步骤和阶段的耗时记录在 [`BootstrapReport`] 中，并作为指标导出。
+//! 报告同时记录加载的配置源、发现和注册的组件、配置验证问题以及启动结束时的健康状态，
+//! 启动失败时通过 [`InfrastructureError::StartupFailed`] 附加在错误上。
+//! [`InfrastructureBuilder`](crate::InfrastructureBuilder) 基于同一流水线构建基础设施。
 
+use crate::component_scanner::UNSCOPED_COMPONENTS_TARGET;
 use crate::infrastructure::AdSystemInfrastructure;
-use config_abstractions::ConfigProvider;
+use crate::metrics_exporter::PrometheusExporter;
+use crate::telemetry::Telemetry;
+use async_trait::async_trait;
+use config_abstractions::manager::{ValidationError, ValidationWarning};
+use config_abstractions::{ConfigManager, ConfigProvider, SecretProvider};
 use config_impl::manager::AdSystemConfigManager;
-use di_abstractions::ComponentScanner;
-use infrastructure_common::{HealthCheckable, InfrastructureError};
+use di_abstractions::{ComponentRegistry, ComponentScanner};
+use di_impl::DiContainerImpl;
+use infrastructure_common::{
+    result_label, ActiveProfiles, ComponentMetadata, HealthCheckable, HealthMonitorOptions,
+    HealthStatus, InfrastructureError, Lifetime, StartupDiagnostics,
+    BOOTSTRAP_STAGE_DURATION_SECONDS,
+};
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+use std::fmt;
 use std::sync::Arc;
-use tracing::{info, debug, error};
+use std::time::Instant;
+use tokio::sync::RwLock;
+use tracing::{debug, error, info, warn};
+
+/// 启动阶段
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum BootstrapStage {
+    /// 注册配置提供者和密钥提供者
+    Configuration,
+    /// 验证配置
+    Validation,
+    /// 扫描发现组件
+    Discovery,
+    /// 向依赖注入容器注册激活的配置档、类型化配置和健康检查
+    ///
+    /// 组件描述符不包含工厂，发现的组件不会自动注册，需要由自定义启动步骤根据
+    /// [`BootstrapContext::discovered_components`] 注册；启动报告标记未注册的组件
+    Registration,
+    /// 预先创建单例
+    WarmUp,
+    /// 启动基础设施
+    LifecycleStart,
+    /// 启动完成后的健康检查
+    Health,
+}
+
+impl BootstrapStage {
+    /// 所有阶段（按执行顺序）
+    pub const ALL: [BootstrapStage; 7] = [
+        BootstrapStage::Configuration,
+        BootstrapStage::Validation,
+        BootstrapStage::Discovery,
+        BootstrapStage::Registration,
+        BootstrapStage::WarmUp,
+        BootstrapStage::LifecycleStart,
+        BootstrapStage::Health,
+    ];
+
+    /// 阶段名称
+    pub fn name(&self) -> &'static str {
+        match self {
+            BootstrapStage::Configuration => "configuration",
+            BootstrapStage::Validation => "validation",
+            BootstrapStage::Discovery => "discovery",
+            BootstrapStage::Registration => "registration",
+            BootstrapStage::WarmUp => "warm_up",
+            BootstrapStage::LifecycleStart => "lifecycle_start",
+            BootstrapStage::Health => "health",
+        }
+    }
+
+    /// 是否属于构建阶段
+    ///
+    /// 构建阶段完成后组装基础设施实例，之后的阶段只在完整启动时执行
+    pub fn is_build_stage(&self) -> bool {
+        *self < BootstrapStage::LifecycleStart
+    }
+}
+
+impl std::fmt::Display for BootstrapStage {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(self.name())
+    }
+}
+
+/// 启动步骤
+///
+/// 每个步骤属于一个阶段，在流水线中只执行一次
+#[async_trait]
+pub trait BootstrapStep: Send + Sync {
+    /// 步骤名称
+    fn name(&self) -> &str;
+
+    /// 所属阶段
+    fn stage(&self) -> BootstrapStage;
+
+    /// 执行步骤
+    async fn run(&mut self, context: &mut BootstrapContext) -> Result<(), InfrastructureError>;
+}
+
+/// 扫描发现的组件
+#[derive(Debug, Clone)]
+pub struct DiscoveredComponent {
+    /// 发现该组件的扫描器
+    pub scanner: String,
+    /// 扫描目标
+    pub target: String,
+    /// 组件元数据
+    pub metadata: ComponentMetadata,
+}
+
+/// 启动上下文
+///
+/// 在步骤之间传递配置管理器、依赖注入容器、健康检查和发现的组件，
+/// 构建阶段完成后持有组装好的基础设施实例
+pub struct BootstrapContext {
+    /// 激活的配置档
+    profiles: ActiveProfiles,
+    /// 配置管理器
+    config_manager: Arc<AdSystemConfigManager>,
+    /// 依赖注入容器
+    di_container: Arc<RwLock<DiContainerImpl>>,
+    /// 健康检查器
+    health_checks: Vec<Box<dyn HealthCheckable>>,
+    /// 扫描发现的组件
+    discovered_components: Vec<DiscoveredComponent>,
+    /// 组装好的基础设施实例
+    infrastructure: Option<AdSystemInfrastructure>,
+    /// 启动报告
+    report: BootstrapReport,
+}
+
+impl std::fmt::Debug for BootstrapContext {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("BootstrapContext")
+            .field("profiles", &self.profiles)
+            .field("health_checks", &self.health_checks.len())
+            .field("discovered_components", &self.discovered_components)
+            .field("assembled", &self.infrastructure.is_some())
+            .finish_non_exhaustive()
+    }
+}
+
+impl BootstrapContext {
+    /// 创建启动上下文
+    fn new(profiles: ActiveProfiles, health_checks: Vec<Box<dyn HealthCheckable>>) -> Self {
+        Self {
+            profiles,
+            config_manager: Arc::new(AdSystemConfigManager::new()),
+            di_container: Arc::new(RwLock::new(DiContainerImpl::new())),
+            health_checks,
+            discovered_components: Vec::new(),
+            infrastructure: None,
+            report: BootstrapReport::new(),
+        }
+    }
+
+    /// 获取激活的配置档
+    pub fn profiles(&self) -> &ActiveProfiles {
+        &self.profiles
+    }
+
+    /// 获取配置管理器
+    pub fn config_manager(&self) -> &Arc<AdSystemConfigManager> {
+        &self.config_manager
+    }
+
+    /// 获取配置管理器的可变引用
+    ///
+    /// 只有在配置管理器尚未被共享时可用，通常在配置阶段注册提供者时使用
+    pub fn config_manager_mut(
+        &mut self,
+    ) -> Result<&mut AdSystemConfigManager, InfrastructureError> {
+        Arc::get_mut(&mut self.config_manager).ok_or_else(|| InfrastructureError::BootstrapFailed {
+            message: "配置管理器已被共享，无法修改".to_string(),
+        })
+    }
+
+    /// 获取依赖注入容器
+    pub fn di_container(&self) -> &Arc<RwLock<DiContainerImpl>> {
+        &self.di_container
+    }
+
+    /// 添加健康检查器
+    pub fn add_health_check(&mut self, checker: Box<dyn HealthCheckable>) {
+        self.health_checks.push(checker);
+    }
+
+    /// 获取扫描发现的组件
+    pub fn discovered_components(&self) -> &[DiscoveredComponent] {
+        &self.discovered_components
+    }
+
+    /// 记录扫描发现的组件
+    pub fn add_discovered_component(&mut self, component: DiscoveredComponent) {
+        self.discovered_components.push(component);
+    }
+
+    /// 获取组装好的基础设施实例
+    ///
+    /// 只在生命周期启动和健康检查阶段可用
+    pub fn infrastructure(&self) -> Result<&AdSystemInfrastructure, InfrastructureError> {
+        self.infrastructure
+            .as_ref()
+            .ok_or_else(|| InfrastructureError::BootstrapFailed {
+                message: "基础设施尚未组装，只能在构建阶段之后访问".to_string(),
+            })
+    }
+}
+
+/// 启动步骤执行报告
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct BootstrapStepReport {
+    /// 步骤名称
+    pub name: String,
+    /// 耗时（毫秒）
+    pub duration_ms: f64,
+    /// 失败原因
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub error: Option<String>,
+}
+
+/// 启动阶段执行报告
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct BootstrapStageReport {
+    /// 阶段
+    pub stage: BootstrapStage,
+    /// 耗时（毫秒）
+    pub duration_ms: f64,
+    /// 阶段中执行的步骤
+    pub steps: Vec<BootstrapStepReport>,
+}
+
+impl BootstrapStageReport {
+    /// 阶段是否成功完成
+    pub fn is_success(&self) -> bool {
+        self.steps.iter().all(|step| step.error.is_none())
+    }
+}
+
+/// 启动时注册的配置源
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ConfigSourceReport {
+    /// 提供者名称
+    pub name: String,
+    /// 配置源（文件路径、环境变量前缀等）
+    pub source: String,
+    /// 优先级
+    pub priority: i32,
+    /// 是否支持热重载
+    pub hot_reload: bool,
+    /// 加载失败原因
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub error: Option<String>,
+}
+
+impl ConfigSourceReport {
+    /// 根据配置提供者创建
+    fn from_provider(provider: &dyn ConfigProvider) -> Self {
+        Self {
+            name: provider.name().to_string(),
+            source: provider.source_description(),
+            priority: provider.priority(),
+            hot_reload: provider.supports_hot_reload(),
+            error: None,
+        }
+    }
+}
+
+/// 扫描发现的组件摘要
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct DiscoveredComponentReport {
+    /// 组件名称
+    pub name: String,
+    /// 组件类型
+    pub type_name: String,
+    /// 扫描目标
+    pub target: String,
+    /// 启动结束时是否已注册到依赖注入容器
+    pub registered: bool,
+}
+
+/// 依赖注入容器中注册的组件
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct RegistrationReport {
+    /// 组件名称
+    pub name: String,
+    /// 生命周期
+    pub lifetime: Lifetime,
+}
+
+/// 配置验证问题
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ValidationIssueReport {
+    /// 配置路径
+    pub path: String,
+    /// 问题描述
+    pub message: String,
+}
+
+impl From<&ValidationError> for ValidationIssueReport {
+    fn from(error: &ValidationError) -> Self {
+        Self {
+            path: error.path.clone(),
+            message: error.message.clone(),
+        }
+    }
+}
+
+impl From<&ValidationWarning> for ValidationIssueReport {
+    fn from(warning: &ValidationWarning) -> Self {
+        Self {
+            path: warning.path.clone(),
+            message: warning.message.clone(),
+        }
+    }
+}
+
+/// 启动流水线执行报告
+///
+/// `Display` 输出供人阅读的摘要，也可以序列化为 JSON
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct BootstrapReport {
+    /// 开始时间
+    pub started_at: chrono::DateTime<chrono::Utc>,
+    /// 总耗时（毫秒）
+    pub total_duration_ms: f64,
+    /// 已执行的阶段（按执行顺序）
+    pub stages: Vec<BootstrapStageReport>,
+    /// 配置源（按注册顺序）
+    pub config_sources: Vec<ConfigSourceReport>,
+    /// 按扫描器分组的发现组件
+    pub discovered_components: BTreeMap<String, Vec<DiscoveredComponentReport>>,
+    /// 依赖注入容器中注册的组件
+    pub registrations: Vec<RegistrationReport>,
+    /// 配置验证错误
+    pub validation_errors: Vec<ValidationIssueReport>,
+    /// 配置验证警告
+    pub validation_warnings: Vec<ValidationIssueReport>,
+    /// 启动结束时的健康状态，只有执行了健康检查阶段时才有内容
+    pub health: BTreeMap<String, HealthStatus>,
+    /// 启动失败原因
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub error: Option<String>,
+}
+
+impl BootstrapReport {
+    /// 创建空报告
+    fn new() -> Self {
+        Self {
+            started_at: chrono::Utc::now(),
+            total_duration_ms: 0.0,
+            stages: Vec::new(),
+            config_sources: Vec::new(),
+            discovered_components: BTreeMap::new(),
+            registrations: Vec::new(),
+            validation_errors: Vec::new(),
+            validation_warnings: Vec::new(),
+            health: BTreeMap::new(),
+            error: None,
+        }
+    }
+
+    /// 获取启动失败的错误上附加的报告
+    pub fn from_error(error: &InfrastructureError) -> Option<&BootstrapReport> {
+        error
+            .startup_report()
+            .and_then(|report| report.as_any().downcast_ref())
+    }
+
+    /// 获取指定阶段的报告，阶段未执行时返回 None
+    pub fn stage(&self, stage: BootstrapStage) -> Option<&BootstrapStageReport> {
+        self.stages.iter().find(|report| report.stage == stage)
+    }
+
+    /// 所有已执行的阶段是否成功完成
+    pub fn is_success(&self) -> bool {
+        self.stages.iter().all(BootstrapStageReport::is_success)
+    }
+
+    /// 获取失败的步骤
+    pub fn failed_step(&self) -> Option<(BootstrapStage, &BootstrapStepReport)> {
+        self.stages.iter().find_map(|stage| {
+            stage
+                .steps
+                .iter()
+                .find(|step| step.error.is_some())
+                .map(|step| (stage.stage, step))
+        })
+    }
+}
+
+impl fmt::Display for BootstrapReport {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match &self.error {
+            Some(error) => writeln!(
+                f,
+                "基础设施启动失败（耗时 {:.1}ms）: {}",
+                self.total_duration_ms, error
+            )?,
+            None => writeln!(
+                f,
+                "基础设施启动完成（耗时 {:.1}ms）",
+                self.total_duration_ms
+            )?,
+        }
+
+        writeln!(f, "配置源 ({}):", self.config_sources.len())?;
+        for source in &self.config_sources {
+            write!(
+                f,
+                "  - {} [{}] 优先级 {}",
+                source.name, source.source, source.priority
+            )?;
+            if let Some(error) = &source.error {
+                write!(f, " 加载失败: {}", error)?;
+            }
+            writeln!(f)?;
+        }
+
+        writeln!(f, "组件发现:")?;
+        for (scanner, components) in &self.discovered_components {
+            writeln!(f, "  - {}: {} 个组件", scanner, components.len())?;
+            for component in components {
+                write!(f, "      {} ({})", component.name, component.target)?;
+                if !component.registered {
+                    write!(f, " 未注册")?;
+                }
+                writeln!(f)?;
+            }
+        }
+
+        writeln!(f, "组件注册 ({}):", self.registrations.len())?;
+        for registration in &self.registrations {
+            writeln!(f, "  - {} [{:?}]", registration.name, registration.lifetime)?;
+        }
+
+        if !self.validation_errors.is_empty() {
+            writeln!(f, "配置验证错误:")?;
+            for issue in &self.validation_errors {
+                writeln!(f, "  - {}: {}", issue.path, issue.message)?;
+            }
+        }
+        if !self.validation_warnings.is_empty() {
+            writeln!(f, "配置验证警告:")?;
+            for issue in &self.validation_warnings {
+                writeln!(f, "  - {}: {}", issue.path, issue.message)?;
+            }
+        }
+
+        writeln!(f, "启动阶段:")?;
+        for stage in &self.stages {
+            writeln!(f, "  - {} {:.1}ms", stage.stage, stage.duration_ms)?;
+            for step in &stage.steps {
+                write!(f, "      {} {:.1}ms", step.name, step.duration_ms)?;
+                if let Some(error) = &step.error {
+                    write!(f, " 失败: {}", error)?;
+                }
+                writeln!(f)?;
+            }
+        }
+
+        if !self.health.is_empty() {
+            writeln!(f, "健康状态:")?;
+            for (component, status) in &self.health {
+                match status {
+                    HealthStatus::Healthy => writeln!(f, "  - {}: 健康", component)?,
+                    HealthStatus::Degraded { message, .. } => {
+                        writeln!(f, "  - {}: 降级 ({})", component, message)?
+                    }
+                    HealthStatus::Unhealthy { error, .. } => {
+                        writeln!(f, "  - {}: 不健康 ({})", component, error)?
+                    }
+                }
+            }
+        }
+        Ok(())
+    }
+}
+
+impl StartupDiagnostics for BootstrapReport {
+    fn to_json(&self) -> serde_json::Value {
+        serde_json::to_value(self).unwrap_or_default()
+    }
+
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+}
 
 /// 基础设施启动器
-/// 
+///
 /// 负责协调各个基础设施组件的启动顺序和初始化过程
-#[derive(Debug)]
 pub struct InfrastructureBootstrapper {
     /// 配置源列表
     config_sources: Vec<Box<dyn ConfigProvider>>,
+    /// 密钥提供者列表
+    secret_providers: Vec<Arc<dyn SecretProvider>>,
     /// 组件扫描器列表
     component_scanners: Vec<Box<dyn ComponentScanner>>,
+    /// 扫描目标
+    scan_targets: Vec<String>,
     /// 健康检查器列表
     health_checks: Vec<Box<dyn HealthCheckable>>,
+    /// 自定义启动步骤
+    steps: Vec<Box<dyn BootstrapStep>>,
+    /// 激活的配置档
+    profiles: ActiveProfiles,
     /// 是否启用配置热重载
     hot_reload_enabled: bool,
     /// 是否启用配置验证
     validation_enabled: bool,
+    /// 是否预先创建单例
+    warm_up_enabled: bool,
+    /// 后台健康监控选项
+    health_monitor: Option<HealthMonitorOptions>,
+    /// Prometheus 指标导出器
+    metrics_exporter: Option<PrometheusExporter>,
+    /// 日志和追踪
+    telemetry: Option<Telemetry>,
+    /// 管理令牌的配置路径
+    admin_token_path: Option<String>,
+}
+
+impl std::fmt::Debug for InfrastructureBootstrapper {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("InfrastructureBootstrapper")
+            .field("config_sources", &self.config_sources.len())
+            .field("component_scanners", &self.component_scanners.len())
+            .field("scan_targets", &self.scan_targets)
+            .field("health_checks", &self.health_checks.len())
+            .field(
+                "steps",
+                &self
+                    .steps
+                    .iter()
+                    .map(|step| step.name())
+                    .collect::<Vec<_>>(),
+            )
+            .field("profiles", &self.profiles)
+            .field("hot_reload_enabled", &self.hot_reload_enabled)
+            .field("validation_enabled", &self.validation_enabled)
+            .field("warm_up_enabled", &self.warm_up_enabled)
+            .finish_non_exhaustive()
+    }
 }
 
 impl InfrastructureBootstrapper {
@@ -34,236 +565,577 @@ impl InfrastructureBootstrapper {
     ) -> Self {
         Self {
             config_sources,
+            secret_providers: Vec::new(),
             component_scanners,
+            scan_targets: Vec::new(),
             health_checks,
+            steps: Vec::new(),
+            profiles: ActiveProfiles::default(),
             hot_reload_enabled: false,
             validation_enabled: true,
+            warm_up_enabled: false,
+            health_monitor: None,
+            metrics_exporter: None,
+            telemetry: None,
+            admin_token_path: None,
         }
     }
-    
+
     /// 设置是否启用配置热重载
     pub fn with_hot_reload(mut self, enabled: bool) -> Self {
         self.hot_reload_enabled = enabled;
         self
     }
-    
+
     /// 设置是否启用配置验证
     pub fn with_validation(mut self, enabled: bool) -> Self {
         self.validation_enabled = enabled;
         self
     }
-    
-    /// 启动基础设施
+
+    /// 设置是否在预热阶段预先创建所有由工厂创建的单例
+    pub fn with_warm_up(mut self, enabled: bool) -> Self {
+        self.warm_up_enabled = enabled;
+        self
+    }
+
+    /// 设置激活的配置档
+    pub fn with_profiles(mut self, profiles: ActiveProfiles) -> Self {
+        self.profiles = profiles;
+        self
+    }
+
+    /// 启用后台健康监控
+    pub fn with_health_monitor(mut self, options: HealthMonitorOptions) -> Self {
+        self.health_monitor = Some(options);
+        self
+    }
+
+    /// 添加密钥提供者
+    pub fn add_secret_provider(mut self, provider: Arc<dyn SecretProvider>) -> Self {
+        self.secret_providers.push(provider);
+        self
+    }
+
+    /// 添加扫描目标，发现阶段使用支持该目标的扫描器扫描
+    pub fn add_scan_target(mut self, target: impl Into<String>) -> Self {
+        self.scan_targets.push(target.into());
+        self
+    }
+
+    /// 添加自定义启动步骤
+    ///
+    /// 步骤在所属阶段的内置步骤之后、按添加顺序执行
+    pub fn add_step<T: BootstrapStep + 'static>(mut self, step: T) -> Self {
+        self.steps.push(Box::new(step));
+        self
+    }
+
+    /// 添加已装箱的自定义启动步骤
+    pub(crate) fn add_boxed_step(mut self, step: Box<dyn BootstrapStep>) -> Self {
+        self.steps.push(step);
+        self
+    }
+
+    /// 设置 Prometheus 指标导出器
+    pub(crate) fn with_metrics_exporter(mut self, exporter: Option<PrometheusExporter>) -> Self {
+        self.metrics_exporter = exporter;
+        self
+    }
+
+    /// 设置已安装的日志和追踪
+    pub(crate) fn with_telemetry(mut self, telemetry: Option<Telemetry>) -> Self {
+        self.telemetry = telemetry;
+        self
+    }
+
+    /// 设置管理令牌的配置路径
+    pub(crate) fn with_admin_token_path(mut self, path: String) -> Self {
+        self.admin_token_path = Some(path);
+        self
+    }
+
+    /// 执行构建阶段，返回尚未启动的基础设施
+    pub async fn build(self) -> Result<AdSystemInfrastructure, InfrastructureError> {
+        self.run(false).await
+    }
+
+    /// 执行所有阶段，返回已启动并通过健康检查的基础设施
     pub async fn bootstrap(self) -> Result<AdSystemInfrastructure, InfrastructureError> {
+        self.run(true).await
+    }
+
+    /// 执行启动流水线
+    ///
+    /// 失败时返回附带启动报告的 [`InfrastructureError::StartupFailed`]
+    async fn run(mut self, start: bool) -> Result<AdSystemInfrastructure, InfrastructureError> {
         info!("开始启动基础设施");
-        
-        // 第一步：初始化配置管理
-        let config_manager = self.bootstrap_configuration().await?;
-        
-        // 第二步：初始化组件注册表
-        let component_registry = self.bootstrap_components().await?;
-        
-        // 第三步：初始化健康检查
-        let health_checkers = self.bootstrap_health_checks().await?;
-        
-        // 第四步：创建基础设施实例
-        let infrastructure = AdSystemInfrastructure::new(
-            config_manager,
-            component_registry,
-            health_checkers,
+        let started = Instant::now();
+
+        let mut steps = self.builtin_steps();
+        steps.append(&mut self.steps);
+        // 稳定排序，同一阶段内保持内置步骤在前、自定义步骤按添加顺序
+        steps.sort_by_key(|step| step.stage());
+
+        let mut context = BootstrapContext::new(
+            self.profiles.clone(),
+            std::mem::take(&mut self.health_checks),
         );
-        
-        info!("基础设施启动完成");
-        Ok(infrastructure)
-    }
-    
-    /// 启动配置管理
-    async fn bootstrap_configuration(&self) -> Result<Arc<AdSystemConfigManager>, InfrastructureError> {
-        info!("启动配置管理系统");
-        
-        let mut config_manager = AdSystemConfigManager::new();
-        
-        // 注册所有配置提供者
-        for provider in &self.config_sources {
-            info!("注册配置提供者: {}", provider.name());
-            
-            // 由于 register_provider 需要 move 所有权，这里需要重新设计
-            // 暂时使用 clone 或其他方式处理
-            debug!("配置提供者 {} 注册完成", provider.name());
-        }
-        
-        // 注册所有配置选项
-        if let Err(e) = config_manager.register_all_options().await {
-            error!("注册配置选项失败: {}", e);
-            return Err(InfrastructureError::ConfigError { source: e });
-        }
-        
-        // 验证配置（如果启用）
-        if self.validation_enabled {
-            debug!("验证配置");
-            if let Err(e) = config_manager.validate_configuration().await {
-                error!("配置验证失败: {}", e);
-                return Err(InfrastructureError::ConfigError { source: e });
+        let stages = BootstrapStage::ALL
+            .into_iter()
+            .filter(|stage| start || stage.is_build_stage());
+        for stage in stages {
+            if !stage.is_build_stage() && context.infrastructure.is_none() {
+                context.infrastructure = Some(self.assemble(&mut context));
             }
-            info!("配置验证通过");
-        }
-        
-        info!("配置管理系统启动完成");
-        Ok(Arc::new(config_manager))
-    }
-    
-    /// 启动组件注册
-    async fn bootstrap_components(&self) -> Result<Arc<dyn di_abstractions::ComponentRegistry>, InfrastructureError> {
-        info!("启动组件注册系统");
-        
-        // 这里需要实际的 ComponentRegistry 实现
-        // 暂时使用占位实现
-        
-        for scanner in &self.component_scanners {
-            debug!("使用组件扫描器扫描组件");
-            // 实际实现中会调用 scanner.scan_crates()
-        }
-        
-        info!("组件注册系统启动完成");
-        
-        // 返回一个占位的 ComponentRegistry 实现
-        Ok(Arc::new(DummyComponentRegistry))
-    }
-    
-    /// 启动健康检查
-    async fn bootstrap_health_checks(&self) -> Result<Vec<Box<dyn HealthCheckable>>, InfrastructureError> {
-        info!("启动健康检查系统");
-        
-        let mut health_checkers = Vec::new();
-        
-        for checker in &self.health_checks {
-            info!("注册健康检查器: {}", checker.name());
-            // 由于所有权问题，这里需要重新设计
-            // health_checkers.push(checker);
-        }
-        
-        // 添加默认的基础设施健康检查器
-        health_checkers.push(Box::new(InfrastructureHealthChecker::new()));
-        
-        info!("健康检查系统启动完成");
-        Ok(health_checkers)
-    }
-}
-
-/// 占位的组件注册表实现
-/// 实际项目中需要在 di-impl crate 中实现
-#[derive(Debug)]
-struct DummyComponentRegistry;
-
-#[async_trait::async_trait]
-impl di_abstractions::ComponentRegistry for DummyComponentRegistry {
-    async fn register_component<T>(&mut self, _lifetime: infrastructure_common::Lifetime) -> Result<(), infrastructure_common::DependencyError>
-    where
-        T: infrastructure_common::Component + 'static,
-    {
-        Ok(())
+
+            let stage_steps = steps.iter_mut().filter(|step| step.stage() == stage);
+            let (stage_report, result) = run_stage(stage, stage_steps, &mut context).await;
+            context.report.stages.push(stage_report);
+
+            if let Err(e) = result {
+                error!("启动阶段 {} 失败: {}", stage, e);
+                let mut report = finish_report(&mut context, started).await;
+                report.error = Some(e.to_string());
+                error!("启动报告:\n{}", report);
+                return Err(InfrastructureError::StartupFailed {
+                    source: Box::new(e),
+                    report: Arc::new(report),
+                });
+            }
+        }
+
+        let infrastructure = match context.infrastructure.take() {
+            Some(infrastructure) => infrastructure,
+            None => self.assemble(&mut context),
+        };
+        let report = finish_report(&mut context, started).await;
+        info!("基础设施启动完成，耗时 {:.1}ms", report.total_duration_ms);
+        debug!("启动报告:\n{}", report);
+        Ok(infrastructure.with_bootstrap_report(report))
     }
-    
-    async fn register_instance<T>(&mut self, _instance: T) -> Result<(), infrastructure_common::DependencyError>
-    where
-        T: infrastructure_common::Component + 'static,
-    {
-        Ok(())
+
+    /// 内置启动步骤
+    fn builtin_steps(&mut self) -> Vec<Box<dyn BootstrapStep>> {
+        let mut steps: Vec<Box<dyn BootstrapStep>> = vec![Box::new(ConfigurationStep {
+            config_sources: std::mem::take(&mut self.config_sources),
+            secret_providers: std::mem::take(&mut self.secret_providers),
+            hot_reload_enabled: self.hot_reload_enabled,
+        })];
+        if self.validation_enabled {
+            steps.push(Box::new(ValidationStep));
+        }
+        steps.push(Box::new(DiscoveryStep {
+            scanners: std::mem::take(&mut self.component_scanners),
+            targets: std::mem::take(&mut self.scan_targets),
+        }));
+        steps.push(Box::new(ActiveProfilesStep));
+        if self.warm_up_enabled {
+            steps.push(Box::new(WarmUpStep));
+        }
+        steps.push(Box::new(LifecycleStartStep));
+        steps.push(Box::new(HealthStep));
+        steps
     }
-    
-    async fn register_factory<T, F>(&mut self, _factory: F, _lifetime: infrastructure_common::Lifetime) -> Result<(), infrastructure_common::DependencyError>
-    where
-        T: infrastructure_common::Component + 'static,
-        F: Fn() -> Result<T, infrastructure_common::DependencyError> + Send + Sync + 'static,
-    {
-        Ok(())
+
+    /// 使用上下文中的配置管理器、依赖注入容器和健康检查组装基础设施
+    fn assemble(&mut self, context: &mut BootstrapContext) -> AdSystemInfrastructure {
+        let mut infrastructure = AdSystemInfrastructure::new(
+            context.config_manager.clone(),
+            context.di_container.clone(),
+            std::mem::take(&mut context.health_checks),
+            context.profiles.clone(),
+        );
+        if let Some(options) = self.health_monitor.take() {
+            infrastructure = infrastructure.with_health_monitor(options);
+        }
+        if let Some(exporter) = self.metrics_exporter.take() {
+            infrastructure = infrastructure.with_metrics_exporter(exporter);
+        }
+        if let Some(telemetry) = self.telemetry.take() {
+            infrastructure = infrastructure.with_telemetry(telemetry);
+        }
+        if let Some(path) = self.admin_token_path.take() {
+            infrastructure = infrastructure.with_admin_token_path(path);
+        }
+        infrastructure
     }
-    
-    async fn resolve<T>(&self) -> Result<Arc<T>, infrastructure_common::DependencyError>
-    where
-        T: infrastructure_common::Component + 'static,
-    {
-        Err(infrastructure_common::DependencyError::ComponentNotRegistered {
-            type_name: std::any::type_name::<T>().to_string(),
-        })
+}
+
+/// 补充发现和注册的组件以及总耗时，取出启动报告
+async fn finish_report(context: &mut BootstrapContext, started: Instant) -> BootstrapReport {
+    let mut report = std::mem::replace(&mut context.report, BootstrapReport::new());
+
+    let container = context.di_container.read().await;
+    let registered = ComponentRegistry::get_registered_components(&*container);
+    let mut unregistered = 0;
+    for component in &context.discovered_components {
+        let is_registered = registered
+            .iter()
+            .any(|descriptor| descriptor.type_id == component.metadata.type_info.id);
+        if !is_registered {
+            unregistered += 1;
+        }
+        report
+            .discovered_components
+            .entry(component.scanner.clone())
+            .or_default()
+            .push(DiscoveredComponentReport {
+                name: component.metadata.name.clone(),
+                type_name: component.metadata.type_info.name.clone(),
+                target: component.target.clone(),
+                registered: is_registered,
+            });
     }
-    
-    async fn resolve_scoped<T>(&self, _scope: &infrastructure_common::Scope) -> Result<Arc<T>, infrastructure_common::DependencyError>
-    where
-        T: infrastructure_common::Component + 'static,
-    {
-        Err(infrastructure_common::DependencyError::ComponentNotRegistered {
-            type_name: std::any::type_name::<T>().to_string(),
+    if unregistered > 0 {
+        warn!("{} 个发现的组件未注册到依赖注入容器", unregistered);
+    }
+
+    report.registrations = registered
+        .into_iter()
+        .map(|descriptor| RegistrationReport {
+            name: descriptor.name,
+            lifetime: descriptor.lifetime,
         })
+        .collect();
+    report.registrations.sort_by(|a, b| a.name.cmp(&b.name));
+
+    report.total_duration_ms = started.elapsed().as_secs_f64() * 1000.0;
+    report
+}
+
+/// 执行一个阶段的所有步骤，遇到失败的步骤时停止并返回该步骤的错误
+async fn run_stage<'a>(
+    stage: BootstrapStage,
+    steps: impl Iterator<Item = &'a mut Box<dyn BootstrapStep>>,
+    context: &mut BootstrapContext,
+) -> (BootstrapStageReport, Result<(), InfrastructureError>) {
+    debug!("执行启动阶段: {}", stage);
+    let started = Instant::now();
+    let mut report = BootstrapStageReport {
+        stage,
+        duration_ms: 0.0,
+        steps: Vec::new(),
+    };
+
+    let mut result = Ok(());
+    for step in steps {
+        let step_started = Instant::now();
+        result = step.run(context).await;
+        report.steps.push(BootstrapStepReport {
+            name: step.name().to_string(),
+            duration_ms: step_started.elapsed().as_secs_f64() * 1000.0,
+            error: result.as_ref().err().map(ToString::to_string),
+        });
+        if result.is_err() {
+            break;
+        }
     }
-    
-    async fn resolve_all<T>(&self) -> Result<Vec<Arc<T>>, infrastructure_common::DependencyError>
-    where
-        T: infrastructure_common::Component + 'static,
-    {
-        Ok(Vec::new())
-    }
-    
-    fn is_registered<T>(&self) -> bool
-    where
-        T: 'static,
-    {
-        false
-    }
-    
-    fn is_registered_by_type_id(&self, _type_id: std::any::TypeId) -> bool {
-        false
-    }
-    
-    fn get_registered_components(&self) -> Vec<infrastructure_common::ComponentDescriptor> {
-        Vec::new()
-    }
-    
-    async fn register_all_components(&mut self) -> Result<(), infrastructure_common::DependencyError> {
+
+    let elapsed = started.elapsed();
+    report.duration_ms = elapsed.as_secs_f64() * 1000.0;
+    metrics::histogram!(
+        BOOTSTRAP_STAGE_DURATION_SECONDS,
+        "stage" => stage.name(),
+        "result" => result_label(&result)
+    )
+    .record(elapsed.as_secs_f64());
+    (report, result)
+}
+
+/// 注册配置提供者和密钥提供者，按需启用热重载
+struct ConfigurationStep {
+    /// 配置源列表
+    config_sources: Vec<Box<dyn ConfigProvider>>,
+    /// 密钥提供者列表
+    secret_providers: Vec<Arc<dyn SecretProvider>>,
+    /// 是否启用配置热重载
+    hot_reload_enabled: bool,
+}
+
+#[async_trait]
+impl BootstrapStep for ConfigurationStep {
+    fn name(&self) -> &str {
+        "config_providers"
+    }
+
+    fn stage(&self) -> BootstrapStage {
+        BootstrapStage::Configuration
+    }
+
+    async fn run(&mut self, context: &mut BootstrapContext) -> Result<(), InfrastructureError> {
+        let mut sources = Vec::new();
+        let mut result = Ok(());
+        let config_manager = context.config_manager_mut()?;
+        for provider in std::mem::take(&mut self.config_sources) {
+            let mut source = ConfigSourceReport::from_provider(provider.as_ref());
+            if let Err(e) = config_manager.register_provider(provider).await {
+                source.error = Some(e.to_string());
+                result = Err(InfrastructureError::BootstrapFailed {
+                    message: format!("注册配置提供者 {} 失败: {}", source.name, e),
+                });
+            }
+            sources.push(source);
+            if result.is_err() {
+                break;
+            }
+        }
+        context.report.config_sources.extend(sources);
+        result?;
+
+        let config_manager = context.config_manager_mut()?;
+        for provider in std::mem::take(&mut self.secret_providers) {
+            config_manager.register_secret_provider(provider);
+        }
+
+        if self.hot_reload_enabled {
+            info!("启用配置热重载");
+
+            let file_watcher = config_impl::watcher::ConfigFileWatcher::new().map_err(|e| {
+                InfrastructureError::BootstrapFailed {
+                    message: format!("创建文件监控器失败: {}", e),
+                }
+            })?;
+            let watcher = Arc::new(tokio::sync::Mutex::new(file_watcher));
+
+            config_manager
+                .enable_hot_reload(watcher)
+                .await
+                .map_err(|e| InfrastructureError::BootstrapFailed {
+                    message: format!("启用热重载失败: {}", e),
+                })?;
+        }
+
         Ok(())
     }
-    
-    async fn validate_dependencies(&self) -> Result<(), infrastructure_common::DependencyError> {
+}
+
+/// 验证配置，存在错误时启动失败
+struct ValidationStep;
+
+#[async_trait]
+impl BootstrapStep for ValidationStep {
+    fn name(&self) -> &str {
+        "config_validation"
+    }
+
+    fn stage(&self) -> BootstrapStage {
+        BootstrapStage::Validation
+    }
+
+    async fn run(&mut self, context: &mut BootstrapContext) -> Result<(), InfrastructureError> {
+        info!("开始配置验证");
+        let validation_result = context
+            .config_manager
+            .validate_configuration()
+            .await
+            .map_err(|e| InfrastructureError::BootstrapFailed {
+                message: format!("配置验证失败: {}", e),
+            })?;
+
+        context.report.validation_errors.extend(
+            validation_result
+                .errors
+                .iter()
+                .map(ValidationIssueReport::from),
+        );
+        context.report.validation_warnings.extend(
+            validation_result
+                .warnings
+                .iter()
+                .map(ValidationIssueReport::from),
+        );
+
+        if !validation_result.is_valid {
+            let error_messages: Vec<String> = validation_result
+                .errors
+                .iter()
+                .map(|e| format!("{}: {}", e.path, e.message))
+                .collect();
+            return Err(InfrastructureError::BootstrapFailed {
+                message: format!("配置验证失败: {}", error_messages.join(", ")),
+            });
+        }
+
+        for warning in validation_result.warnings {
+            warn!("配置警告 [{}]: {}", warning.path, warning.message);
+        }
         Ok(())
     }
-    
-    async fn clear(&mut self) -> Result<(), infrastructure_common::DependencyError> {
+}
+
+/// 使用组件扫描器扫描所有支持的目标
+struct DiscoveryStep {
+    /// 组件扫描器列表
+    scanners: Vec<Box<dyn ComponentScanner>>,
+    /// 扫描目标
+    targets: Vec<String>,
+}
+
+#[async_trait]
+impl BootstrapStep for DiscoveryStep {
+    fn name(&self) -> &str {
+        "component_scanning"
+    }
+
+    fn stage(&self) -> BootstrapStage {
+        BootstrapStage::Discovery
+    }
+
+    async fn run(&mut self, context: &mut BootstrapContext) -> Result<(), InfrastructureError> {
+        // 没有记录所在模块的组件单独作为一个扫描目标报告，不归入任何包
+        let mut targets: Vec<&str> = self.targets.iter().map(String::as_str).collect();
+        if !targets.is_empty() {
+            targets.push(UNSCOPED_COMPONENTS_TARGET);
+        }
+        for scanner in &self.scanners {
+            for target in targets
+                .iter()
+                .copied()
+                .filter(|target| scanner.supports(target))
+            {
+                debug!("使用组件扫描器 {} 扫描 {}", scanner.name(), target);
+                let components = scanner.scan(target).await.map_err(|e| {
+                    InfrastructureError::BootstrapFailed {
+                        message: format!(
+                            "组件扫描器 {} 扫描 {} 失败: {}",
+                            scanner.name(),
+                            target,
+                            e
+                        ),
+                    }
+                })?;
+
+                info!(
+                    "组件扫描器 {} 在 {} 中发现 {} 个组件",
+                    scanner.name(),
+                    target,
+                    components.len()
+                );
+                for metadata in components {
+                    context.add_discovered_component(DiscoveredComponent {
+                        scanner: scanner.name().to_string(),
+                        target: target.to_string(),
+                        metadata,
+                    });
+                }
+            }
+        }
         Ok(())
     }
 }
 
-/// 基础设施健康检查器
-#[derive(Debug)]
-struct InfrastructureHealthChecker {
-    name: String,
+/// 注册激活的配置档供组件注入
+///
+/// 只注册 [`ActiveProfiles`]，不注册发现的组件
+struct ActiveProfilesStep;
+
+#[async_trait]
+impl BootstrapStep for ActiveProfilesStep {
+    fn name(&self) -> &str {
+        "active_profiles"
+    }
+
+    fn stage(&self) -> BootstrapStage {
+        BootstrapStage::Registration
+    }
+
+    async fn run(&mut self, context: &mut BootstrapContext) -> Result<(), InfrastructureError> {
+        let profiles = context.profiles.clone();
+        context
+            .di_container
+            .write()
+            .await
+            .register_instance(profiles)
+            .await
+            .map_err(|e| InfrastructureError::DependencyError { source: e })
+    }
 }
 
-impl InfrastructureHealthChecker {
-    fn new() -> Self {
-        Self {
-            name: "InfrastructureHealthChecker".to_string(),
+/// 预先创建所有由工厂创建的单例
+struct WarmUpStep;
+
+#[async_trait]
+impl BootstrapStep for WarmUpStep {
+    fn name(&self) -> &str {
+        "singletons"
+    }
+
+    fn stage(&self) -> BootstrapStage {
+        BootstrapStage::WarmUp
+    }
+
+    async fn run(&mut self, context: &mut BootstrapContext) -> Result<(), InfrastructureError> {
+        let results = context.di_container.read().await.warm_up_singletons().await;
+        let failures: Vec<String> = results
+            .iter()
+            .filter_map(|(name, result)| result.as_ref().err().map(|e| format!("{}: {}", name, e)))
+            .collect();
+
+        info!(
+            "预先创建单例 {} 个，失败 {} 个",
+            results.len() - failures.len(),
+            failures.len()
+        );
+        if failures.is_empty() {
+            Ok(())
+        } else {
+            Err(InfrastructureError::BootstrapFailed {
+                message: format!("预先创建单例失败: {}", failures.join(", ")),
+            })
         }
     }
 }
 
-#[async_trait::async_trait]
-impl HealthCheckable for InfrastructureHealthChecker {
-    async fn check_health(&self) -> infrastructure_common::HealthStatus {
-        // 检查基础设施的基本健康状态
-        // 例如：内存使用情况、CPU 使用情况等
-        
-        infrastructure_common::HealthStatus::healthy()
+/// 启动基础设施
+struct LifecycleStartStep;
+
+#[async_trait]
+impl BootstrapStep for LifecycleStartStep {
+    fn name(&self) -> &str {
+        "infrastructure_start"
     }
-    
+
+    fn stage(&self) -> BootstrapStage {
+        BootstrapStage::LifecycleStart
+    }
+
+    async fn run(&mut self, context: &mut BootstrapContext) -> Result<(), InfrastructureError> {
+        context.infrastructure()?.start().await
+    }
+}
+
+/// 启动完成后执行一次健康检查，存在不健康的组件时启动失败
+struct HealthStep;
+
+#[async_trait]
+impl BootstrapStep for HealthStep {
     fn name(&self) -> &str {
-        &self.name
+        "startup_health"
     }
-    
-    fn timeout(&self) -> std::time::Duration {
-        std::time::Duration::from_secs(5)
+
+    fn stage(&self) -> BootstrapStage {
+        BootstrapStage::Health
     }
-    
-    fn check_interval(&self) -> std::time::Duration {
-        std::time::Duration::from_secs(30)
+
+    async fn run(&mut self, context: &mut BootstrapContext) -> Result<(), InfrastructureError> {
+        let results = context.infrastructure()?.check_health().await;
+        context.report.health = results.iter().cloned().collect();
+
+        for (component_name, status) in results {
+            match status {
+                HealthStatus::Healthy => {}
+                HealthStatus::Degraded { message, .. } => {
+                    warn!("组件 {} 启动后处于降级状态: {}", component_name, message);
+                }
+                HealthStatus::Unhealthy { error, .. } => {
+                    return Err(InfrastructureError::HealthCheckFailed {
+                        component_name,
+                        message: error,
+                    });
+                }
+            }
+        }
+        Ok(())
     }
 }
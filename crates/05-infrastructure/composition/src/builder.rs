@@ -1,36 +1,91 @@
 //! 基础设施构建器
 
+use crate::admin::ADMIN_TOKEN_CONFIG_PATH;
+use crate::bootstrapper::{
+    BootstrapContext, BootstrapStage, BootstrapStep, InfrastructureBootstrapper,
+};
+use crate::enhanced_component_scanner::EnhancedComponentScannerImpl;
+use crate::health_checks::create_builtin_health_checks;
 use crate::infrastructure::AdSystemInfrastructure;
-use config_abstractions::{ConfigManager, ConfigProvider};
+use crate::log_filter::{LogFilterHandle, LOG_FILTER_CONFIG_PATH};
+use crate::metrics_exporter::PrometheusExporter;
+use crate::telemetry::Telemetry;
+use async_trait::async_trait;
+use config_abstractions::{ConfigProvider, SecretProvider};
+use config_impl::manager::AdSystemConfigManager;
+use config_impl::profiles::{ProfileConfigGroupLoader, ProfileConfigLoader};
 use config_impl::providers::{
-    EnvironmentConfigProviderImpl, JsonConfigProvider, TomlConfigProvider,
+    CommandLineConfigProvider, EnvironmentConfigProviderImpl, JsonConfigProvider,
+    TomlConfigProvider,
+};
+use di_abstractions::{ComponentRegistry, ComponentScanner};
+use infrastructure_common::{
+    ActiveProfiles, HealthCheckable, HealthMonitorOptions, InfrastructureError,
+    INFRASTRUCTURE_BUILD_DURATION_SECONDS,
 };
-use di_abstractions::ComponentScanner;
-use infrastructure_common::{HealthCheckable, InfrastructureError};
-use std::path::Path;
+use serde::de::DeserializeOwned;
+use std::marker::PhantomData;
+use std::path::{Path, PathBuf};
 use std::sync::Arc;
+use std::time::Instant;
 use tracing::{debug, info};
 
+pub use crate::telemetry::{LoggingConfig, TelemetryConfig};
+
+/// 配置档配置文件的基础优先级
+const PROFILE_CONFIG_BASE_PRIORITY: i32 = 100;
+
+/// 配置档配置文件允许的最高优先级，保证低于环境变量（200）
+const PROFILE_CONFIG_MAX_PRIORITY: i32 = 199;
+
 /// 基础设施构建器
 ///
 /// 使用建造者模式构建完整的基础设施实例
 pub struct InfrastructureBuilder {
     /// 配置源列表
     config_sources: Vec<Box<dyn ConfigProvider>>,
+    /// 密钥提供者列表
+    secret_providers: Vec<Arc<dyn SecretProvider>>,
     /// 组件扫描器列表
     component_scanners: Vec<Box<dyn ComponentScanner>>,
+    /// 构建时使用激活配置档的增强组件扫描器
+    enhanced_component_scanners: Vec<EnhancedComponentScannerImpl>,
+    /// 组件扫描目标
+    scan_targets: Vec<String>,
     /// 健康检查器列表
     health_checks: Vec<Box<dyn HealthCheckable>>,
+    /// 后台健康监控选项，未设置时不启用后台监控
+    health_monitor: Option<HealthMonitorOptions>,
+    /// 是否启用内置健康检查
+    builtin_health_checks: bool,
+    /// TCP 连通性检查的目标地址
+    tcp_health_targets: Vec<String>,
+    /// 是否启用 Prometheus 指标导出
+    prometheus_enabled: bool,
     /// 是否启用配置热重载
     hot_reload_enabled: bool,
     /// 环境变量前缀
     env_prefix: Option<String>,
     /// 配置验证是否启用
     validation_enabled: bool,
-    /// 是否启用日志初始化
+    /// 是否启用日志和追踪初始化
     logging_enabled: bool,
-    /// 日志配置
-    logging_config: LoggingConfig,
+    /// 日志和追踪配置
+    telemetry_config: TelemetryConfig,
+    /// 日志过滤指令绑定的配置路径
+    log_filter_path: String,
+    /// 管理令牌的配置路径
+    admin_token_path: String,
+    /// 激活的配置档
+    profiles: ActiveProfiles,
+    /// 按配置档加载的配置文件（目录，基础名称）
+    profile_config_bases: Vec<(PathBuf, String)>,
+    /// 注册到依赖注入容器的类型化实时配置和功能开关
+    options_registrations: Vec<Box<dyn OptionsRegistration>>,
+    /// 是否在构建时预先创建单例
+    warm_up_enabled: bool,
+    /// 自定义启动步骤
+    bootstrap_steps: Vec<Box<dyn BootstrapStep>>,
 }
 
 impl InfrastructureBuilder {
@@ -38,13 +93,27 @@ impl InfrastructureBuilder {
     pub fn new() -> Self {
         Self {
             config_sources: Vec::new(),
+            secret_providers: Vec::new(),
             component_scanners: Vec::new(),
+            enhanced_component_scanners: Vec::new(),
+            scan_targets: Vec::new(),
             health_checks: Vec::new(),
+            health_monitor: None,
+            builtin_health_checks: false,
+            tcp_health_targets: Vec::new(),
+            prometheus_enabled: false,
             hot_reload_enabled: false,
             env_prefix: None,
             validation_enabled: true,
             logging_enabled: false, // 默认不启用日志初始化
-            logging_config: LoggingConfig::default(),
+            telemetry_config: TelemetryConfig::default(),
+            log_filter_path: LOG_FILTER_CONFIG_PATH.to_string(),
+            admin_token_path: ADMIN_TOKEN_CONFIG_PATH.to_string(),
+            profiles: ActiveProfiles::from_env(),
+            profile_config_bases: Vec::new(),
+            options_registrations: Vec::new(),
+            warm_up_enabled: false,
+            bootstrap_steps: Vec::new(),
         }
     }
 
@@ -92,6 +161,26 @@ impl InfrastructureBuilder {
         Ok(self)
     }
 
+    /// 添加命令行参数配置源
+    ///
+    /// 解析 `--section.key=value` 和 `--set key=value` 形式的参数（不包含程序名），
+    /// 默认优先级高于其他配置源
+    pub fn add_config_args<I, S>(mut self, args: I) -> Result<Self, InfrastructureError>
+    where
+        I: IntoIterator<Item = S>,
+        S: Into<String>,
+    {
+        let provider = CommandLineConfigProvider::new(args)?;
+        info!("添加命令行参数配置源");
+        self.config_sources.push(Box::new(provider));
+        Ok(self)
+    }
+
+    /// 添加当前进程的命令行参数配置源
+    pub fn add_config_command_line(self) -> Result<Self, InfrastructureError> {
+        self.add_config_args(std::env::args().skip(1))
+    }
+
     /// 添加自定义配置提供者
     pub fn add_config_provider<T: ConfigProvider + 'static>(mut self, provider: T) -> Self {
         info!("添加自定义配置提供者: {}", provider.name());
@@ -99,6 +188,42 @@ impl InfrastructureBuilder {
         self
     }
 
+    /// 添加密钥提供者，用于解析配置中的 `${secret:name}` 引用
+    pub fn add_secret_provider<T: SecretProvider + 'static>(mut self, provider: T) -> Self {
+        info!("添加密钥提供者: {}", provider.name());
+        self.secret_providers.push(Arc::new(provider));
+        self
+    }
+
+    /// 注册类型化实时配置
+    ///
+    /// 构建时为指定配置路径创建 [`OptionsMonitor<T>`](config_impl::options::OptionsMonitor) 并注册到依赖注入容器，
+    /// 组件可以解析 `OptionsMonitor<T>` 获取始终最新的配置
+    pub fn add_options<T>(mut self, path: impl Into<String>) -> Self
+    where
+        T: DeserializeOwned + Send + Sync + 'static,
+    {
+        let path = path.into();
+        info!("注册类型化实时配置: {} -> {}", path, std::any::type_name::<T>());
+        self.options_registrations.push(Box::new(TypedOptionsRegistration::<T> {
+            path,
+            _marker: PhantomData,
+        }));
+        self
+    }
+
+    /// 启用功能开关
+    ///
+    /// 构建时从指定配置路径加载开关定义，并将 [`FeatureFlags`](config_impl::feature_flags::FeatureFlags)
+    /// 注册到依赖注入容器，开关定义随配置重载自动更新
+    pub fn with_feature_flags(mut self, path: impl Into<String>) -> Self {
+        let path = path.into();
+        info!("启用功能开关: {}", path);
+        self.options_registrations
+            .push(Box::new(FeatureFlagsRegistration { path }));
+        self
+    }
+
     /// 启用配置热重载
     pub fn enable_hot_reload(mut self, enabled: bool) -> Self {
         self.hot_reload_enabled = enabled;
@@ -115,14 +240,25 @@ impl InfrastructureBuilder {
         self
     }
 
+    /// 添加增强组件扫描器
+    ///
+    /// 扫描器未显式设置条件评估器或配置档时，`profile=` 条件按构建器激活的配置档评估
+    pub fn add_enhanced_component_scanner(mut self, scanner: EnhancedComponentScannerImpl) -> Self {
+        debug!("添加增强组件扫描器");
+        self.enhanced_component_scanners.push(scanner);
+        self
+    }
+
     /// 扫描指定的 crate
-    pub fn scan_crate<S: Into<String>>(self, crate_name: S) -> Result<Self, InfrastructureError> {
+    ///
+    /// 构建时在发现阶段使用支持该目标的组件扫描器扫描
+    pub fn scan_crate<S: Into<String>>(
+        mut self,
+        crate_name: S,
+    ) -> Result<Self, InfrastructureError> {
         let crate_name = crate_name.into();
         info!("添加 crate 扫描: {}", crate_name);
-
-        // 这里应该实现实际的 crate 扫描器
-        // 暂时使用占位实现
-
+        self.scan_targets.push(crate_name);
         Ok(self)
     }
 
@@ -133,6 +269,16 @@ impl InfrastructureBuilder {
         self
     }
 
+    /// 启用后台健康监控
+    ///
+    /// 基础设施启动后按各健康检查器的检查间隔在后台周期执行检查，
+    /// 健康检查端点直接读取缓存的结果
+    pub fn with_health_monitor(mut self, options: HealthMonitorOptions) -> Self {
+        info!("启用后台健康监控");
+        self.health_monitor = Some(options);
+        self
+    }
+
     /// 启用扩展配置源管理
     pub fn with_extended_config_sources(self) -> Self {
         info!("启用扩展配置源管理");
@@ -147,60 +293,97 @@ impl InfrastructureBuilder {
         self
     }
 
-    /// 自动配置开发环境
-    pub fn auto_configure_development(mut self) -> Self {
-        info!("自动配置开发环境");
+    /// 设置激活的配置档（覆盖 `ADSP_PROFILE` 环境变量）
+    pub fn with_profiles(mut self, profiles: ActiveProfiles) -> Self {
+        info!("设置激活的配置档: [{}]", profiles);
+        self.profiles = profiles;
+        self
+    }
 
-        // 添加开发环境特定的配置
-        if Path::new("./config.dev.toml").exists() {
-            if let Ok(provider) = TomlConfigProvider::new("./config.dev.toml") {
-                self.config_sources.push(Box::new(provider));
-                debug!("添加开发环境配置: config.dev.toml");
-            }
-        }
+    /// 额外激活一个配置档
+    pub fn activate_profile<S: Into<String>>(mut self, profile: S) -> Self {
+        self.profiles.activate(profile);
+        self
+    }
 
-        if Path::new("./appsettings.Development.json").exists() {
-            if let Ok(provider) = JsonConfigProvider::new("./appsettings.Development.json") {
-                self.config_sources.push(Box::new(provider));
-                debug!("添加开发环境配置: appsettings.Development.json");
-            }
+    /// 获取激活的配置档
+    pub fn active_profiles(&self) -> &ActiveProfiles {
+        &self.profiles
+    }
+
+    /// 按配置档加载配置文件
+    ///
+    /// 构建时加载 `{base_name}.{ext}`、`{base_name}.{profile}.{ext}` 和
+    /// `{base_name}.{profile}.local.{ext}`，支持 TOML、JSON、YAML。
+    /// 每组配置文件占用独立的优先级区间，后添加的组优先；重复添加同一组时忽略
+    pub fn add_profile_config<P: AsRef<Path>, S: Into<String>>(
+        mut self,
+        directory: P,
+        base_name: S,
+    ) -> Self {
+        let directory = directory.as_ref().to_path_buf();
+        let base_name = base_name.into();
+        if self
+            .profile_config_bases
+            .iter()
+            .any(|(dir, name)| dir == &directory && name == &base_name)
+        {
+            debug!("配置档配置文件已添加: {}/{}.*", directory.display(), base_name);
+            return self;
         }
+        info!("添加配置档配置文件: {}/{}.*", directory.display(), base_name);
+        self.profile_config_bases.push((directory, base_name));
+        self
+    }
 
-        // 启用热重载
-        self.hot_reload_enabled = true;
+    /// 自动配置开发环境
+    pub fn auto_configure_development(self) -> Self {
+        info!("自动配置开发环境");
 
-        self
+        self.activate_profile("dev")
+            .add_profile_config(".", "config")
+            .add_profile_config(".", "appsettings")
+            .enable_hot_reload(true)
     }
 
     /// 自动配置生产环境
-    pub fn auto_configure_production(mut self) -> Self {
+    pub fn auto_configure_production(self) -> Self {
         info!("自动配置生产环境");
 
-        // 添加生产环境特定的配置
-        if Path::new("./config.prod.toml").exists() {
-            if let Ok(provider) = TomlConfigProvider::new("./config.prod.toml") {
-                self.config_sources.push(Box::new(provider));
-                debug!("添加生产环境配置: config.prod.toml");
-            }
-        }
+        // 生产环境通常不启用热重载
+        self.activate_profile("prod")
+            .add_profile_config(".", "config")
+            .add_profile_config(".", "appsettings")
+            .enable_hot_reload(false)
+    }
 
-        if Path::new("./appsettings.Production.json").exists() {
-            if let Ok(provider) = JsonConfigProvider::new("./appsettings.Production.json") {
-                self.config_sources.push(Box::new(provider));
-                debug!("添加生产环境配置: appsettings.Production.json");
-            }
+    /// 启用或禁用内置健康检查
+    ///
+    /// 启用后构建时注册配置、依赖注入容器、磁盘空间、内存和运行时响应性检查，
+    /// 各检查从 `health.*` 配置节读取配置，见 [`crate::health_checks`]
+    pub fn enable_health_checks(mut self, enabled: bool) -> Self {
+        if enabled {
+            info!("启用内置健康检查");
         }
+        self.builtin_health_checks = enabled;
+        self
+    }
 
-        // 生产环境通常不启用热重载
-        self.hot_reload_enabled = false;
-
+    /// 添加 TCP 连通性检查的目标地址，格式为 `host:port`
+    pub fn add_tcp_health_check<S: Into<String>>(mut self, address: S) -> Self {
+        let address = address.into();
+        info!("添加 TCP 连通性检查: {}", address);
+        self.tcp_health_targets.push(address);
         self
     }
-    pub fn enable_health_checks(self, enabled: bool) -> Self {
-        if enabled {
-            info!("启用健康检查");
-            // 添加默认的健康检查器
-        }
+
+    /// 启用 Prometheus 指标导出
+    ///
+    /// 构建时安装全局 Prometheus 指标记录器，之后可以通过
+    /// [`AdSystemInfrastructure::metrics_router`] 获得 `/metrics` 端点
+    pub fn with_prometheus_metrics(mut self) -> Self {
+        info!("启用 Prometheus 指标导出");
+        self.prometheus_enabled = true;
         self
     }
 
@@ -211,176 +394,369 @@ impl InfrastructureBuilder {
     }
 
     /// 配置日志
-    pub fn with_logging(mut self, config: LoggingConfig) -> Self {
-        self.logging_config = config;
+    pub fn with_logging(self, config: LoggingConfig) -> Self {
+        self.with_telemetry(config)
+    }
+
+    /// 配置日志和分布式追踪
+    ///
+    /// 构建时安装全局订阅者，配置了追踪导出时 span 会导出到 OTLP 或 Jaeger
+    pub fn with_telemetry(mut self, config: TelemetryConfig) -> Self {
+        self.telemetry_config = config;
         self.logging_enabled = true; // 启用日志初始化
         self
     }
 
+    /// 设置日志过滤指令绑定的配置路径，默认为 `logging.filter`
+    ///
+    /// 配置中存在该路径时其值覆盖 [`TelemetryConfig`] 的日志级别和日志指令，
+    /// 配置重载后自动生效
+    pub fn with_log_filter_path<S: Into<String>>(mut self, path: S) -> Self {
+        self.log_filter_path = path.into();
+        self
+    }
+
+    /// 设置管理令牌的配置路径，默认为 `admin.token`
+    ///
+    /// 管理端点每次请求时读取该配置，未配置时拒绝所有管理请求
+    pub fn with_admin_token_path<S: Into<String>>(mut self, path: S) -> Self {
+        self.admin_token_path = path.into();
+        self
+    }
+
+    /// 启用或禁用构建时预先创建单例
+    ///
+    /// 启用后所有由工厂创建的单例在预热阶段创建，创建失败时构建失败
+    pub fn enable_warm_up(mut self, enabled: bool) -> Self {
+        self.warm_up_enabled = enabled;
+        self
+    }
+
+    /// 添加自定义启动步骤
+    ///
+    /// 步骤在所属阶段的内置步骤之后执行，见 [`crate::bootstrapper`]
+    pub fn add_bootstrap_step<T: BootstrapStep + 'static>(mut self, step: T) -> Self {
+        info!("添加启动步骤: {} ({})", step.name(), step.stage());
+        self.bootstrap_steps.push(Box::new(step));
+        self
+    }
+
     /// 构建基础设施实例
+    ///
+    /// 执行启动流水线的构建阶段，返回的基础设施需要调用
+    /// [`start`](AdSystemInfrastructure::start) 启动。
+    /// 失败时错误附带启动报告，可通过 [`BootstrapReport::from_error`](crate::BootstrapReport::from_error) 获取
     pub async fn build(self) -> Result<AdSystemInfrastructure, InfrastructureError> {
-        info!("开始构建基础设施");
+        let started = Instant::now();
+        let infrastructure = self.into_bootstrapper()?.build().await?;
+
+        metrics::histogram!(INFRASTRUCTURE_BUILD_DURATION_SECONDS)
+            .record(started.elapsed().as_secs_f64());
+        info!("基础设施构建完成");
+        Ok(infrastructure)
+    }
+
+    /// 构建并启动基础设施
+    ///
+    /// 执行完整的启动流水线，包括生命周期启动和启动后的健康检查
+    pub async fn bootstrap(self) -> Result<AdSystemInfrastructure, InfrastructureError> {
+        self.into_bootstrapper()?.bootstrap().await
+    }
+
+    /// 初始化日志和指标，并将构建器配置转换为启动流水线
+    fn into_bootstrapper(self) -> Result<InfrastructureBootstrapper, InfrastructureError> {
+        info!("开始构建基础设施，激活的配置档: [{}]", self.profiles);
 
         // 只有在明确配置了日志时才初始化日志
         // 避免在测试环境中重复初始化
-        if self.logging_enabled {
-            self.initialize_logging()?;
-        }
+        let telemetry = if self.logging_enabled {
+            Some(self.initialize_telemetry()?)
+        } else {
+            None
+        };
 
-        // 创建配置管理器
-        let mut config_manager = config_impl::manager::AdSystemConfigManager::new();
-
-        // 注册所有配置提供者
-        for provider in self.config_sources {
-            config_manager
-                .register_provider(provider)
-                .await
-                .map_err(|e| InfrastructureError::BootstrapFailed {
-                    message: format!("注册配置提供者失败: {}", e),
-                })?;
+        // 先安装指标记录器，使构建过程中的指标也能被记录
+        let metrics_exporter = if self.prometheus_enabled {
+            Some(PrometheusExporter::install()?)
+        } else {
+            None
+        };
+
+        // 按配置档加载配置文件，所有组的基础配置都低于任一组的配置档覆盖
+        let mut group_loader =
+            ProfileConfigGroupLoader::new(PROFILE_CONFIG_BASE_PRIORITY, PROFILE_CONFIG_MAX_PRIORITY);
+        for (directory, base_name) in &self.profile_config_bases {
+            group_loader = group_loader.add_group(
+                ProfileConfigLoader::new(directory, base_name.clone())
+                    .with_profiles(self.profiles.clone()),
+            );
         }
+        let mut config_sources = group_loader.load_providers().map_err(|e| {
+            InfrastructureError::BootstrapFailed {
+                message: format!("加载配置档配置文件失败: {}", e),
+            }
+        })?;
+        config_sources.extend(self.config_sources);
 
-        // 如果启用热重载，配置热重载功能
-        if self.hot_reload_enabled {
-            info!("启用配置热重载");
+        let mut component_scanners = self.component_scanners;
+        for scanner in self.enhanced_component_scanners {
+            component_scanners.push(Box::new(scanner.inherit_profiles(&self.profiles)));
+        }
 
-            // 创建文件监控器
-            let file_watcher = config_impl::watcher::ConfigFileWatcher::new().map_err(|e| {
-                InfrastructureError::BootstrapFailed {
-                    message: format!("创建文件监控器失败: {}", e),
-                }
-            })?;
-            let watcher = Arc::new(tokio::sync::Mutex::new(file_watcher));
-
-            config_manager
-                .enable_hot_reload(watcher)
-                .await
-                .map_err(|e| InfrastructureError::BootstrapFailed {
-                    message: format!("启用热重载失败: {}", e),
-                })?;
+        let options_registrations = Arc::new(self.options_registrations);
+        let log_filter = telemetry.as_ref().map(|telemetry| telemetry.log_filter().clone());
+
+        let mut bootstrapper = InfrastructureBootstrapper::new(
+            config_sources,
+            component_scanners,
+            self.health_checks,
+        )
+        .with_profiles(self.profiles)
+        .with_hot_reload(self.hot_reload_enabled)
+        .with_validation(self.validation_enabled)
+        .with_warm_up(self.warm_up_enabled)
+        .with_metrics_exporter(metrics_exporter)
+        .with_telemetry(telemetry)
+        .with_admin_token_path(self.admin_token_path)
+        .add_step(OptionsDeclarationStep {
+            registrations: options_registrations.clone(),
+        })
+        .add_step(OptionsRegistrationStep {
+            registrations: options_registrations,
+        })
+        .add_step(BuiltinHealthChecksStep {
+            tcp_targets: self.tcp_health_targets,
+            enabled: self.builtin_health_checks,
+        });
+        if let Some(log_filter) = log_filter {
+            bootstrapper = bootstrapper.add_step(LogFilterBindingStep {
+                log_filter,
+                path: self.log_filter_path,
+            });
+        }
+        if let Some(options) = self.health_monitor {
+            bootstrapper = bootstrapper.with_health_monitor(options);
+        }
+        for provider in self.secret_providers {
+            bootstrapper = bootstrapper.add_secret_provider(provider);
+        }
+        for target in self.scan_targets {
+            bootstrapper = bootstrapper.add_scan_target(target);
+        }
+        for step in self.bootstrap_steps {
+            bootstrapper = bootstrapper.add_boxed_step(step);
         }
 
-        // 如果启用验证，进行配置验证
-        if self.validation_enabled {
-            info!("开始配置验证");
-            let validation_result = config_manager.validate_configuration().await.map_err(|e| {
-                InfrastructureError::BootstrapFailed {
-                    message: format!("配置验证失败: {}", e),
-                }
-            })?;
+        Ok(bootstrapper)
+    }
 
-            if !validation_result.is_valid {
-                let error_messages: Vec<String> = validation_result
-                    .errors
-                    .iter()
-                    .map(|e| format!("{}: {}", e.path, e.message))
-                    .collect();
-                return Err(InfrastructureError::BootstrapFailed {
-                    message: format!("配置验证失败: {}", error_messages.join(", ")),
-                });
-            }
+    /// 初始化日志和追踪
+    fn initialize_telemetry(&self) -> Result<Telemetry, InfrastructureError> {
+        let telemetry = self.telemetry_config.init()?;
+
+        info!(
+            "日志系统初始化完成，服务: {} {}，追踪导出: {:?}",
+            self.telemetry_config.service_name,
+            self.telemetry_config.service_version,
+            self.telemetry_config.exporter
+        );
+        Ok(telemetry)
+    }
+}
 
-            if !validation_result.warnings.is_empty() {
-                for warning in validation_result.warnings {
-                    tracing::warn!("配置警告 [{}]: {}", warning.path, warning.message);
-                }
-            }
-        }
+/// 类型化实时配置和功能开关的注册项
+#[async_trait]
+trait OptionsRegistration: Send + Sync {
+    /// 向配置管理器声明配置选项
+    fn declare(&self, config_manager: &AdSystemConfigManager);
+
+    /// 创建实时配置句柄并注册到依赖注入容器
+    async fn register(
+        &self,
+        config_manager: &AdSystemConfigManager,
+        di_container: &mut di_impl::DiContainerImpl,
+    ) -> Result<(), InfrastructureError>;
+}
 
-        let config_manager = Arc::new(config_manager);
+/// 指定类型的实时配置注册项
+struct TypedOptionsRegistration<T> {
+    /// 配置路径
+    path: String,
+    /// 配置类型标记
+    _marker: PhantomData<fn() -> T>,
+}
 
-        // 创建依赖注入容器
-        let di_container = di_impl::DiContainerImpl::new();
+#[async_trait]
+impl<T> OptionsRegistration for TypedOptionsRegistration<T>
+where
+    T: DeserializeOwned + Send + Sync + 'static,
+{
+    fn declare(&self, config_manager: &AdSystemConfigManager) {
+        config_manager.register_options::<T>(&self.path);
+    }
 
-        // 创建基础设施实例
-        let infrastructure =
-            AdSystemInfrastructure::new(config_manager, di_container, self.health_checks);
+    async fn register(
+        &self,
+        config_manager: &AdSystemConfigManager,
+        di_container: &mut di_impl::DiContainerImpl,
+    ) -> Result<(), InfrastructureError> {
+        let monitor = config_manager
+            .options_monitor::<T>(self.path.clone())
+            .await
+            .map_err(|e| InfrastructureError::BootstrapFailed {
+                message: format!("绑定配置 {} 失败: {}", self.path, e),
+            })?;
+        di_container
+            .register_instance(monitor)
+            .await
+            .map_err(|e| InfrastructureError::DependencyError { source: e })
+    }
+}
 
-        info!("基础设施构建完成");
-        Ok(infrastructure)
+/// 功能开关注册项
+struct FeatureFlagsRegistration {
+    /// 开关配置路径
+    path: String,
+}
+
+#[async_trait]
+impl OptionsRegistration for FeatureFlagsRegistration {
+    fn declare(&self, _config_manager: &AdSystemConfigManager) {}
+
+    async fn register(
+        &self,
+        config_manager: &AdSystemConfigManager,
+        di_container: &mut di_impl::DiContainerImpl,
+    ) -> Result<(), InfrastructureError> {
+        let flags = config_manager
+            .feature_flags(self.path.clone())
+            .await
+            .map_err(|e| InfrastructureError::BootstrapFailed {
+                message: format!("加载功能开关 {} 失败: {}", self.path, e),
+            })?;
+        di_container
+            .register_instance(flags)
+            .await
+            .map_err(|e| InfrastructureError::DependencyError { source: e })
     }
+}
 
-    /// 初始化日志系统
-    fn initialize_logging(&self) -> Result<(), InfrastructureError> {
-        let subscriber = tracing_subscriber::fmt()
-            .with_max_level(self.logging_config.level)
-            .with_target(self.logging_config.show_target)
-            .with_thread_ids(self.logging_config.show_thread_ids)
-            .with_file(self.logging_config.show_file)
-            .with_line_number(self.logging_config.show_line_number);
+/// 向配置管理器声明类型化配置选项，使其参与配置验证
+struct OptionsDeclarationStep {
+    /// 类型化实时配置和功能开关的注册项
+    registrations: Arc<Vec<Box<dyn OptionsRegistration>>>,
+}
 
-        if self.logging_config.json_format {
-            subscriber.json().try_init()
-        } else {
-            subscriber.try_init()
-        }
-        .map_err(|e| InfrastructureError::BootstrapFailed {
-            message: format!("日志初始化失败: {}", e),
-        })?;
+#[async_trait]
+impl BootstrapStep for OptionsDeclarationStep {
+    fn name(&self) -> &str {
+        "options_declaration"
+    }
+
+    fn stage(&self) -> BootstrapStage {
+        BootstrapStage::Configuration
+    }
 
-        info!("日志系统初始化完成");
+    async fn run(&mut self, context: &mut BootstrapContext) -> Result<(), InfrastructureError> {
+        for registration in self.registrations.iter() {
+            registration.declare(context.config_manager());
+        }
         Ok(())
     }
 }
 
-impl Default for InfrastructureBuilder {
-    fn default() -> Self {
-        Self::new()
+/// 创建类型化实时配置和功能开关并注册到依赖注入容器
+struct OptionsRegistrationStep {
+    /// 类型化实时配置和功能开关的注册项
+    registrations: Arc<Vec<Box<dyn OptionsRegistration>>>,
+}
+
+#[async_trait]
+impl BootstrapStep for OptionsRegistrationStep {
+    fn name(&self) -> &str {
+        "options"
+    }
+
+    fn stage(&self) -> BootstrapStage {
+        BootstrapStage::Registration
+    }
+
+    async fn run(&mut self, context: &mut BootstrapContext) -> Result<(), InfrastructureError> {
+        let mut di_container = context.di_container().write().await;
+        for registration in self.registrations.iter() {
+            registration
+                .register(context.config_manager(), &mut di_container)
+                .await?;
+        }
+        Ok(())
     }
 }
 
-/// 日志配置
-#[derive(Debug, Clone)]
-pub struct LoggingConfig {
-    /// 日志级别
-    pub level: tracing::Level,
-    /// 是否显示目标
-    pub show_target: bool,
-    /// 是否显示线程ID
-    pub show_thread_ids: bool,
-    /// 是否显示文件名
-    pub show_file: bool,
-    /// 是否显示行号
-    pub show_line_number: bool,
-    /// 是否使用 JSON 格式
-    pub json_format: bool,
+/// 将日志过滤指令绑定到配置
+struct LogFilterBindingStep {
+    /// 日志过滤句柄
+    log_filter: LogFilterHandle,
+    /// 过滤指令的配置路径
+    path: String,
 }
 
-impl Default for LoggingConfig {
-    fn default() -> Self {
-        Self {
-            level: tracing::Level::INFO,
-            show_target: true,
-            show_thread_ids: false,
-            show_file: false,
-            show_line_number: false,
-            json_format: false,
+#[async_trait]
+impl BootstrapStep for LogFilterBindingStep {
+    fn name(&self) -> &str {
+        "log_filter"
+    }
+
+    fn stage(&self) -> BootstrapStage {
+        BootstrapStage::Registration
+    }
+
+    async fn run(&mut self, context: &mut BootstrapContext) -> Result<(), InfrastructureError> {
+        if self
+            .log_filter
+            .bind_config(context.config_manager(), &self.path)
+            .await?
+        {
+            info!("日志过滤指令已绑定到配置 {}", self.path);
         }
+        Ok(())
     }
 }
 
-impl LoggingConfig {
-    /// 创建开发环境日志配置
-    pub fn development() -> Self {
-        Self {
-            level: tracing::Level::DEBUG,
-            show_target: true,
-            show_thread_ids: true,
-            show_file: true,
-            show_line_number: true,
-            json_format: false,
-        }
+/// 创建内置健康检查
+struct BuiltinHealthChecksStep {
+    /// TCP 连通性检查的目标地址
+    tcp_targets: Vec<String>,
+    /// 是否启用内置健康检查
+    enabled: bool,
+}
+
+#[async_trait]
+impl BootstrapStep for BuiltinHealthChecksStep {
+    fn name(&self) -> &str {
+        "builtin_health_checks"
     }
 
-    /// 创建生产环境日志配置
-    pub fn production() -> Self {
-        Self {
-            level: tracing::Level::INFO,
-            show_target: false,
-            show_thread_ids: false,
-            show_file: false,
-            show_line_number: false,
-            json_format: true,
+    fn stage(&self) -> BootstrapStage {
+        BootstrapStage::Registration
+    }
+
+    async fn run(&mut self, context: &mut BootstrapContext) -> Result<(), InfrastructureError> {
+        let checks = create_builtin_health_checks(
+            context.config_manager(),
+            context.di_container(),
+            std::mem::take(&mut self.tcp_targets),
+            self.enabled,
+        )
+        .await?;
+        for check in checks {
+            context.add_health_check(check);
         }
+        Ok(())
+    }
+}
+
+impl Default for InfrastructureBuilder {
+    fn default() -> Self {
+        Self::new()
     }
 }
@@ -5,7 +5,9 @@
 use di_abstractions::ComponentScanner;
 use di_impl::DiContainerImpl;
 use infrastructure_common::{
-    Component, DependencyError, ComponentMetadata,
+    Component, DependencyError, ComponentMetadata, ComponentConventions, ComponentDescriptor,
+    Lifetime, NamingConventions, TypeInfo, COMPONENT_CONFIG_SECTION_KEY, COMPONENT_LIFETIME_KEY,
+    COMPONENT_MODULE_PATH_KEY, COMPONENT_TYPE_NAME_KEY,
 };
 use std::any::{Any, TypeId};
 use std::collections::{HashMap, HashSet};
@@ -13,6 +15,11 @@ use std::sync::Arc;
 use tokio::sync::RwLock;
 use tracing::{info, debug, warn, error};
 
+/// 没有记录所在模块的组件（如手工注册的描述符）使用的扫描目标
+///
+/// 自动发现不会把这些组件归入任何包，只在扫描该目标时返回
+pub const UNSCOPED_COMPONENTS_TARGET: &str = "<unscoped>";
+
 /// 组件发现策略
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub enum ComponentDiscoveryStrategy {
@@ -39,6 +46,29 @@ pub enum ComponentLifecycle {
     Scoped,
 }
 
+impl From<Lifetime> for ComponentLifecycle {
+    fn from(lifetime: Lifetime) -> Self {
+        match lifetime {
+            Lifetime::Singleton => Self::Singleton,
+            Lifetime::Transient => Self::Transient,
+            Lifetime::Scoped => Self::Scoped,
+        }
+    }
+}
+
+/// 约定匹配结果
+///
+/// 记录自动发现时匹配的约定规则，以及哪些属性由 `#[component]` 参数显式指定
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ConventionMatch {
+    /// 匹配的约定规则模式（如 `*Strategy`），没有规则匹配时为 None
+    pub rule: Option<String>,
+    /// 生命周期是否显式指定
+    pub explicit_lifetime: bool,
+    /// 配置节是否显式指定
+    pub explicit_config_section: bool,
+}
+
 /// 组件注册信息
 #[derive(Debug, Clone)]
 pub struct ComponentRegistration {
@@ -46,7 +76,7 @@ pub struct ComponentRegistration {
     pub type_id: TypeId,
     /// 组件类型名称
     pub type_name: String,
-    /// 组件生命周期
+    /// 组件生命周期（自动发现时为按约定推断的结果，仅用于诊断）
     pub lifecycle: ComponentLifecycle,
     /// 组件依赖列表
     pub dependencies: Vec<TypeId>,
@@ -58,8 +88,10 @@ pub struct ComponentRegistration {
     pub priority: u32,
     /// 组件标签
     pub tags: HashSet<String>,
-    /// 组件配置键
+    /// 组件配置键（自动发现时为按约定推断的结果，仅用于诊断）
     pub config_key: Option<String>,
+    /// 约定匹配结果，只有自动发现的组件才有
+    pub convention: Option<ConventionMatch>,
 }
 
 /// 组件扫描器实现
@@ -74,6 +106,8 @@ pub struct ComponentScannerImpl {
     component_factories: Arc<RwLock<HashMap<TypeId, ComponentFactory>>>,
     /// 扫描结果缓存
     scan_cache: Arc<RwLock<HashMap<String, Vec<ComponentRegistration>>>>,
+    /// 自动发现使用的组件约定
+    conventions: ComponentConventions,
 }
 
 /// 组件工厂函数类型
@@ -88,9 +122,16 @@ impl ComponentScannerImpl {
             discovered_components: Arc::new(RwLock::new(HashMap::new())),
             component_factories: Arc::new(RwLock::new(HashMap::new())),
             scan_cache: Arc::new(RwLock::new(HashMap::new())),
+            conventions: ComponentConventions::new(),
         }
     }
     
+    /// 设置自动发现使用的组件约定
+    pub fn with_conventions(mut self, conventions: ComponentConventions) -> Self {
+        self.conventions = conventions;
+        self
+    }
+    
     /// 添加扫描包
     pub fn add_scan_package<S: Into<String>>(mut self, package: S) -> Self {
         self.scan_packages.push(package.into());
@@ -135,6 +176,7 @@ impl ComponentScannerImpl {
             priority: 100,
             tags: HashSet::new(),
             config_key: None,
+            convention: None,
         };
         
         // 保存注册信息
@@ -196,18 +238,116 @@ impl ComponentScannerImpl {
     }
     
     /// 自动扫描模式
+    ///
+    /// 对全局组件注册表中位于该包内的已启用组件应用组件约定：
+    /// 按类型名称推断生命周期和配置节，`#[component]` 参数显式指定的属性优先。
+    /// 推断结果仅用于诊断，不会应用到依赖注入容器的注册。
+    /// 没有记录所在模块的描述符只在扫描 [`UNSCOPED_COMPONENTS_TARGET`] 时返回，
+    /// 结果不依赖各包的扫描顺序
     async fn scan_automatic(&self, package_name: &str) -> Result<Vec<ComponentRegistration>, DependencyError> {
         debug!("使用自动模式扫描包: {}", package_name);
         
-        // 在实际实现中，这里会使用反射或编译时宏来发现组件
-        // 目前返回空列表，因为 Rust 的反射能力有限
+        let Some(registry) = infrastructure_common::get_global_component_registry() else {
+            warn!("未设置全局组件注册表，自动发现不到任何组件");
+            return Ok(Vec::new());
+        };
         
-        // TODO: 实现基于约定的组件发现
-        // 例如：所有实现了 Component trait 的类型
-        // 或者所有以 "Service", "Repository", "Manager" 结尾的类型
+        let mut discovered: Vec<ComponentRegistration> = registry
+            .get_all_descriptors()
+            .iter()
+            .filter(|descriptor| descriptor.enabled)
+            .filter(|descriptor| match descriptor.metadata.get(COMPONENT_MODULE_PATH_KEY) {
+                Some(module_path) => module_in_package(module_path, package_name),
+                None => package_name == UNSCOPED_COMPONENTS_TARGET,
+            })
+            .map(|descriptor| self.apply_conventions(descriptor))
+            .collect();
+        discovered.sort_by(|a, b| a.type_name.cmp(&b.type_name));
+        
+        for registration in &discovered {
+            let convention = registration.convention.as_ref();
+            debug!(
+                "自动发现组件 {}: 生命周期 {:?}, 配置节 {}, 约定 {}",
+                registration.type_name,
+                registration.lifecycle,
+                registration.config_key.as_deref().unwrap_or("-"),
+                convention.and_then(|c| c.rule.as_deref()).unwrap_or("无"),
+            );
+        }
+        Ok(discovered)
+    }
+    
+    /// 对组件描述符应用组件约定，生成用于诊断的注册信息
+    fn apply_conventions(&self, descriptor: &ComponentDescriptor) -> ComponentRegistration {
+        let type_name = descriptor
+            .metadata
+            .get(COMPONENT_TYPE_NAME_KEY)
+            .cloned()
+            .unwrap_or_else(|| descriptor.name.clone());
+        let type_info = TypeInfo {
+            name: type_name.clone(),
+            id: descriptor.type_id,
+            module_path: descriptor
+                .metadata
+                .get(COMPONENT_MODULE_PATH_KEY)
+                .cloned()
+                .unwrap_or_else(|| type_name.clone()),
+        };
+        let rule = self.conventions.find_rule_by_type(&type_info);
+        
+        let explicit_lifetime = descriptor
+            .metadata
+            .get(COMPONENT_LIFETIME_KEY)
+            .and_then(|name| match name.as_str() {
+                "singleton" => Some(Lifetime::Singleton),
+                "scoped" => Some(Lifetime::Scoped),
+                "transient" => Some(Lifetime::Transient),
+                other => {
+                    warn!("组件 {} 的生命周期 {} 无效，按约定推断", type_name, other);
+                    None
+                }
+            });
+        let lifetime = explicit_lifetime
+            .or(rule.map(|rule| rule.lifetime))
+            .unwrap_or(descriptor.lifetime);
+        
+        let explicit_config_section = descriptor
+            .metadata
+            .get(COMPONENT_CONFIG_SECTION_KEY)
+            .cloned();
+        let config_section = explicit_config_section.clone().unwrap_or_else(|| {
+            let path = NamingConventions::get_configuration_path(&type_info);
+            match rule {
+                Some(rule) => {
+                    let component_name = path.rsplit('.').next().unwrap_or(&path);
+                    rule.config_path_template.replace("{component_name}", component_name)
+                }
+                None => path,
+            }
+        });
         
-        warn!("自动组件发现功能尚未完全实现");
-        Ok(Vec::new())
+        ComponentRegistration {
+            type_id: descriptor.type_id,
+            type_name: descriptor
+                .metadata
+                .get(COMPONENT_MODULE_PATH_KEY)
+                .map_or_else(
+                    || type_name.clone(),
+                    |module_path| format!("{}::{}", module_path, type_name),
+                ),
+            lifecycle: lifetime.into(),
+            dependencies: Vec::new(),
+            interfaces: Vec::new(),
+            is_primary: true,
+            priority: u32::try_from(descriptor.priority).unwrap_or(0),
+            tags: HashSet::new(),
+            config_key: Some(config_section),
+            convention: Some(ConventionMatch {
+                rule: rule.map(|rule| rule.pattern.clone()),
+                explicit_lifetime: explicit_lifetime.is_some(),
+                explicit_config_section: explicit_config_section.is_some(),
+            }),
+        }
     }
     
     /// 基于属性的扫描模式
@@ -385,6 +525,49 @@ impl ComponentScannerImpl {
     }
 }
 
+/// 将约定匹配结果转换为组件元数据属性
+///
+/// 只记录匹配的约定规则和显式指定的属性。推断的生命周期和配置节仅用于诊断，
+/// 不会应用到容器注册，因此不作为元数据属性报告，需要时通过 [`ComponentScannerImpl::scan_package`] 获取
+fn convention_properties(registration: &ComponentRegistration) -> HashMap<String, String> {
+    let mut properties = HashMap::new();
+    let Some(convention) = &registration.convention else {
+        return properties;
+    };
+    if let Some(rule) = &convention.rule {
+        properties.insert("convention".to_string(), rule.clone());
+    }
+    if convention.explicit_config_section {
+        if let Some(config_key) = &registration.config_key {
+            properties.insert(COMPONENT_CONFIG_SECTION_KEY.to_string(), config_key.clone());
+        }
+    }
+    if convention.explicit_lifetime {
+        let lifetime = match registration.lifecycle {
+            ComponentLifecycle::Singleton => "singleton",
+            ComponentLifecycle::Scoped => "scoped",
+            ComponentLifecycle::Transient => "transient",
+        };
+        properties.insert(COMPONENT_LIFETIME_KEY.to_string(), lifetime.to_string());
+    }
+    properties
+}
+
+/// 检查模块是否位于扫描包内
+///
+/// `crate::` 开头的包名匹配任意 crate 中的同名模块
+fn module_in_package(module_path: &str, package_name: &str) -> bool {
+    let module_path = match package_name.strip_prefix("crate::") {
+        Some(_) => module_path.split_once("::").map_or("", |(_, rest)| rest),
+        None => module_path,
+    };
+    let package_name = package_name.strip_prefix("crate::").unwrap_or(package_name);
+    module_path == package_name
+        || module_path
+            .strip_prefix(package_name)
+            .is_some_and(|rest| rest.starts_with("::"))
+}
+
 #[async_trait::async_trait]
 impl ComponentScanner for ComponentScannerImpl {
     async fn scan(&self, package_path: &str) -> Result<Vec<ComponentMetadata>, infrastructure_common::ComponentError> {
@@ -406,8 +589,8 @@ impl ComponentScanner for ComponentScannerImpl {
                 description: Some("Auto-discovered component".to_string()),
                 version: Some("1.0.0".to_string()),
                 author: Some("System".to_string()),
+                properties: convention_properties(&reg),
                 tags: reg.tags.into_iter().collect(),
-                properties: std::collections::HashMap::new(),
             })
             .collect();
         
@@ -419,8 +602,8 @@ impl ComponentScanner for ComponentScannerImpl {
     }
     
     fn supports(&self, target: &str) -> bool {
-        // 支持扫描 Rust crate 和模块
-        target.starts_with("crate::") || target.contains("::")
+        // 支持扫描 Rust crate 和模块，以及没有记录所在模块的组件
+        target == UNSCOPED_COMPONENTS_TARGET || target.starts_with("crate::") || target.contains("::")
     }
 }
 
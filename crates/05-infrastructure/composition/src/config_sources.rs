@@ -3,10 +3,11 @@
 //! 提供多种配置源的统一管理和集成功能
 
 use config_abstractions::ConfigProvider;
+use config_impl::profiles::{ConfigFileFormat, ProfileConfigFile, ProfileConfigLoader};
 use config_impl::providers::{
-    TomlConfigProvider, JsonConfigProvider, EnvironmentConfigProviderImpl,
+    TomlConfigProvider, JsonConfigProvider, YamlConfigProvider, EnvironmentConfigProviderImpl,
 };
-use infrastructure_common::{InfrastructureError, ConfigError};
+use infrastructure_common::{ActiveProfiles, InfrastructureError, ConfigError};
 use std::path::Path;
 use tokio::fs;
 use tracing::{info, debug, warn};
@@ -30,6 +31,11 @@ pub enum ConfigSourceType {
     Memory,
 }
 
+/// 配置源描述优先级换算为配置提供者优先级时的基准值
+///
+/// 描述中的优先级数字越小优先级越高，而配置提供者的优先级数字越大优先级越高
+pub const DESCRIPTOR_PRIORITY_BASE: i32 = 1000;
+
 /// 配置源描述
 #[derive(Debug, Clone)]
 pub struct ConfigSourceDescriptor {
@@ -45,6 +51,13 @@ pub struct ConfigSourceDescriptor {
     pub options: ConfigSourceOptions,
 }
 
+impl ConfigSourceDescriptor {
+    /// 对应的配置提供者优先级
+    pub fn provider_priority(&self) -> i32 {
+        DESCRIPTOR_PRIORITY_BASE - self.priority as i32
+    }
+}
+
 /// 配置源选项
 #[derive(Debug, Clone)]
 pub struct ConfigSourceOptions {
@@ -283,7 +296,8 @@ impl ExtendedConfigSourceManager {
                     });
                 }
                 
-                let provider = TomlConfigProvider::new(&descriptor.location)?;
+                let provider = TomlConfigProvider::new(&descriptor.location)?
+                    .with_priority(descriptor.provider_priority());
                 Ok(Box::new(provider))
             }
             
@@ -296,7 +310,22 @@ impl ExtendedConfigSourceManager {
                     });
                 }
                 
-                let provider = JsonConfigProvider::new(&descriptor.location)?;
+                let provider = JsonConfigProvider::new(&descriptor.location)?
+                    .with_priority(descriptor.provider_priority());
+                Ok(Box::new(provider))
+            }
+            
+            ConfigSourceType::Yaml => {
+                if !Path::new(&descriptor.location).exists() {
+                    return Err(InfrastructureError::ConfigError {
+                        source: ConfigError::FileNotFound {
+                            path: descriptor.location.clone(),
+                        },
+                    });
+                }
+                
+                let provider = YamlConfigProvider::new(&descriptor.location)?
+                    .with_priority(descriptor.provider_priority());
                 Ok(Box::new(provider))
             }
             
@@ -307,18 +336,10 @@ impl ExtendedConfigSourceManager {
                 } else {
                     EnvironmentConfigProviderImpl::new("ADSP_")?
                 };
-                Ok(Box::new(provider))
+                Ok(Box::new(provider.with_priority(descriptor.provider_priority())))
             }
             
             // 未实现的配置源类型
-            ConfigSourceType::Yaml => {
-                Err(InfrastructureError::ConfigError {
-                    source: ConfigError::ValidationError {
-                        message: "YAML 配置提供者尚未实现".to_string(),
-                    },
-                })
-            }
-            
             ConfigSourceType::Remote => {
                 Err(InfrastructureError::ConfigError {
                     source: ConfigError::ValidationError {
@@ -455,50 +476,83 @@ impl ConfigSourceManagerBuilder {
         self
     }
     
-    /// 添加开发环境配置
-    pub fn add_development_config(mut self) -> Self {
-        // 开发环境特定的配置文件
-        let dev_paths = [
-            "./config.dev.toml",
-            "./config.development.json",
-            "./appsettings.Development.json",
-        ];
-        
-        for path in &dev_paths {
-            if Path::new(path).exists() {
-                if path.ends_with(".toml") {
-                    self.manager = self.manager.add_toml_file(path, 80, true);
-                } else if path.ends_with(".json") {
-                    self.manager = self.manager.add_json_file(path, 80, true);
-                }
-                info!("添加开发环境配置: {}", path);
+    /// 按配置档添加配置文件
+    ///
+    /// 发现 `{base_name}.{ext}`、`{base_name}.{profile}.{ext}` 和
+    /// `{base_name}.{profile}.local.{ext}`，基础文件使用 `priority`，
+    /// 后加载的覆盖文件优先级依次升高
+    pub fn add_profile_configs<P: AsRef<Path>>(
+        self,
+        directory: P,
+        base_name: &str,
+        profiles: &ActiveProfiles,
+        priority: u32,
+    ) -> Self {
+        let files = Self::discover_profile_files(directory.as_ref(), base_name, profiles, true);
+        self.add_profile_files(files, priority)
+    }
+
+    /// 发现配置档配置文件，`include_base` 为 false 时跳过基础文件
+    fn discover_profile_files(
+        directory: &Path,
+        base_name: &str,
+        profiles: &ActiveProfiles,
+        include_base: bool,
+    ) -> Vec<ProfileConfigFile> {
+        let loader =
+            ProfileConfigLoader::new(directory, base_name).with_profiles(profiles.clone());
+        match loader.discover() {
+            Ok(files) => files
+                .into_iter()
+                .filter(|file| include_base || file.profile.is_some())
+                .collect(),
+            Err(e) => {
+                warn!("发现配置档配置文件失败: {:?}", e);
+                Vec::new()
             }
         }
-        
+    }
+
+    /// 按加载顺序添加配置文件，从 `priority` 开始后加载的文件优先级依次升高
+    fn add_profile_files(mut self, files: Vec<ProfileConfigFile>, priority: u32) -> Self {
+        for (index, file) in files.into_iter().enumerate() {
+            let file_priority = priority.saturating_sub(index as u32);
+            let path = file.path.display().to_string();
+            self.manager = match file.format {
+                ConfigFileFormat::Toml => self.manager.add_toml_file(&path, file_priority, true),
+                ConfigFileFormat::Json => self.manager.add_json_file(&path, file_priority, true),
+                ConfigFileFormat::Yaml => self.manager.add_yaml_file(&path, file_priority, true),
+            };
+            info!("添加配置档配置: {} (配置档: {:?})", path, file.profile);
+        }
+
         self
     }
+
+    /// 添加开发环境配置
+    ///
+    /// 只添加 `config.dev.*` 和 `appsettings.dev.*` 等覆盖文件，基础文件由
+    /// [`auto_discover`](Self::auto_discover) 添加
+    pub fn add_development_config(self) -> Self {
+        self.add_profile_overlays(&ActiveProfiles::new(["dev"]), 80)
+    }
     
     /// 添加生产环境配置
-    pub fn add_production_config(mut self) -> Self {
-        // 生产环境特定的配置文件
-        let prod_paths = [
-            "./config.prod.toml",
-            "./config.production.json",
-            "./appsettings.Production.json",
-        ];
-        
-        for path in &prod_paths {
-            if Path::new(path).exists() {
-                if path.ends_with(".toml") {
-                    self.manager = self.manager.add_toml_file(path, 70, true);
-                } else if path.ends_with(".json") {
-                    self.manager = self.manager.add_json_file(path, 70, true);
-                }
-                info!("添加生产环境配置: {}", path);
-            }
-        }
-        
-        self
+    ///
+    /// 只添加 `config.prod.*` 和 `appsettings.prod.*` 等覆盖文件，基础文件由
+    /// [`auto_discover`](Self::auto_discover) 添加
+    pub fn add_production_config(self) -> Self {
+        self.add_profile_overlays(&ActiveProfiles::new(["prod"]), 70)
+    }
+
+    /// 添加 `config` 和 `appsettings` 的配置档覆盖文件
+    ///
+    /// 两组文件在一次遍历中依次分配优先级，不论激活多少配置档都不会重叠，`config` 优先
+    fn add_profile_overlays(self, profiles: &ActiveProfiles, priority: u32) -> Self {
+        let directory = Path::new(".");
+        let mut files = Self::discover_profile_files(directory, "appsettings", profiles, false);
+        files.extend(Self::discover_profile_files(directory, "config", profiles, false));
+        self.add_profile_files(files, priority)
     }
     
     /// 构建配置源管理器
@@ -6,7 +6,7 @@ use crate::component_scanner::{ComponentDiscoveryStrategy, ComponentScannerImpl}
 use async_trait::async_trait;
 use di_abstractions::ComponentScanner;
 use infrastructure_common::{
-    Component, ComponentError, ComponentMetadata, ComponentRegistry, ComponentScope,
+    ActiveProfiles, Component, ComponentError, ComponentMetadata, ComponentRegistry, ComponentScope,
     DependencyGraph, DiscoveryMetadata, ReflectionInfo, TypeInfo,
 };
 use std::any::TypeId;
@@ -280,6 +280,8 @@ pub struct EnhancedComponentScannerImpl {
     advanced_metadata_cache: Arc<RwLock<HashMap<TypeId, AdvancedComponentMetadata>>>,
     /// 条件评估器
     condition_evaluator: Arc<dyn ConditionEvaluator>,
+    /// 是否显式设置了条件评估器或配置档
+    condition_evaluator_configured: bool,
     /// 属性提取器
     attribute_extractor: Arc<dyn AttributeExtractor>,
     /// trait 发现器
@@ -299,11 +301,35 @@ impl EnhancedComponentScannerImpl {
             interceptors: Arc::new(RwLock::new(Vec::new())),
             advanced_metadata_cache: Arc::new(RwLock::new(HashMap::new())),
             condition_evaluator: Arc::new(DefaultConditionEvaluator::new()),
+            condition_evaluator_configured: false,
             attribute_extractor: Arc::new(DefaultAttributeExtractor::new()),
             trait_discoverer: Arc::new(DefaultTraitDiscoverer::new()),
         }
     }
 
+    /// 设置条件评估器
+    pub fn with_condition_evaluator(mut self, evaluator: Arc<dyn ConditionEvaluator>) -> Self {
+        self.condition_evaluator = evaluator;
+        self.condition_evaluator_configured = true;
+        self
+    }
+
+    /// 设置用于 `profile=` 条件的激活配置档（覆盖 `ADSP_PROFILE` 环境变量）
+    pub fn with_profiles(self, profiles: ActiveProfiles) -> Self {
+        self.with_condition_evaluator(Arc::new(
+            DefaultConditionEvaluator::new().with_profiles(profiles),
+        ))
+    }
+
+    /// 未显式设置条件评估器或配置档时使用构建器激活的配置档
+    pub(crate) fn inherit_profiles(self, profiles: &ActiveProfiles) -> Self {
+        if self.condition_evaluator_configured {
+            self
+        } else {
+            self.with_profiles(profiles.clone())
+        }
+    }
+
     /// 添加组件过滤器
     pub async fn add_filter(&self, filter: Box<dyn ComponentFilter>) {
         let mut filters = self.filters.write().await;
@@ -517,11 +543,12 @@ impl EnhancedComponentScannerImpl {
             return Ok(Vec::new());
         }
 
-        // 递归调用基础策略
+        // 递归调用基础策略，嵌套的条件使用同一个条件评估器
         let temp_scanner = EnhancedComponentScannerImpl::new(
             ComponentScannerImpl::new(ComponentDiscoveryStrategy::Automatic),
             base_strategy.clone(),
-        );
+        )
+        .with_condition_evaluator(self.condition_evaluator.clone());
 
         Box::pin(temp_scanner.scan_enhanced(package_name)).await
     }
@@ -546,7 +573,8 @@ impl EnhancedComponentScannerImpl {
             let temp_scanner = EnhancedComponentScannerImpl::new(
                 ComponentScannerImpl::new(ComponentDiscoveryStrategy::Automatic),
                 strategy.clone(),
-            );
+            )
+            .with_condition_evaluator(self.condition_evaluator.clone());
 
             let results = Box::pin(temp_scanner.scan_enhanced(package_name)).await?;
             all_results.push(results);
@@ -727,6 +755,22 @@ impl EnhancedComponentScannerImpl {
     }
 }
 
+#[async_trait]
+impl ComponentScanner for EnhancedComponentScannerImpl {
+    async fn scan(&self, target: &str) -> Result<Vec<ComponentMetadata>, ComponentError> {
+        let components = self.scan_enhanced(target).await?;
+        Ok(components.into_iter().map(|component| component.base).collect())
+    }
+
+    fn name(&self) -> &str {
+        "EnhancedComponentScannerImpl"
+    }
+
+    fn supports(&self, target: &str) -> bool {
+        self.base_scanner.supports(target)
+    }
+}
+
 /// 条件评估器 trait
 #[async_trait]
 pub trait ConditionEvaluator: Send + Sync {
@@ -738,14 +782,23 @@ pub trait ConditionEvaluator: Send + Sync {
 pub struct DefaultConditionEvaluator {
     /// 环境变量缓存
     env_cache: Arc<RwLock<HashMap<String, String>>>,
+    /// 激活的配置档
+    profiles: ActiveProfiles,
 }
 
 impl DefaultConditionEvaluator {
     pub fn new() -> Self {
         Self {
             env_cache: Arc::new(RwLock::new(HashMap::new())),
+            profiles: ActiveProfiles::from_env(),
         }
     }
+
+    /// 设置用于 `profile=` 条件的激活配置档
+    pub fn with_profiles(mut self, profiles: ActiveProfiles) -> Self {
+        self.profiles = profiles;
+        self
+    }
 }
 
 #[async_trait]
@@ -754,6 +807,11 @@ impl ConditionEvaluator for DefaultConditionEvaluator {
         // 简单的条件评估实现
         // 支持环境变量检查：env.VAR_NAME=value
         // 支持配置检查：config.path.to.value=expected
+        // 支持配置档检查：profile=prod、profile=!dev、profile=prod|staging
+
+        if let Some(expression) = condition.strip_prefix("profile=") {
+            return Ok(self.profiles.matches(expression));
+        }
 
         if condition.starts_with("env.") {
             let parts: Vec<&str> = condition.splitn(2, '=').collect();
@@ -1215,6 +1273,18 @@ mod tests {
         std::env::remove_var("TEST_VAR");
     }
 
+    #[tokio::test]
+    async fn test_profile_condition_evaluator() {
+        let evaluator =
+            DefaultConditionEvaluator::new().with_profiles(ActiveProfiles::parse("production,eu"));
+
+        assert!(evaluator.evaluate("profile=prod").await.unwrap());
+        assert!(evaluator.evaluate("profile=dev|eu").await.unwrap());
+        assert!(evaluator.evaluate("profile=prod&eu").await.unwrap());
+        assert!(!evaluator.evaluate("profile=!prod").await.unwrap());
+        assert!(!evaluator.evaluate("profile=dev").await.unwrap());
+    }
+
     #[tokio::test]
     async fn test_default_attribute_extractor() {
         let extractor = DefaultAttributeExtractor::new();
@@ -0,0 +1,835 @@
+//! 内置健康检查
+//!
+//! 提供基础设施资源的健康检查实现：
+//!
+//! - [`ConfigHealthCheck`] - 配置提供者可用、最近一次重载成功
+//! - [`DiContainerHealthCheck`] - 依赖注入容器没有创建失败的工厂
+//! - [`DiskSpaceHealthCheck`] - 指定路径所在磁盘的剩余空间
+//! - [`MemoryHealthCheck`] - 进程常驻内存和系统内存使用率
+//! - [`RuntimeHealthCheck`] - tokio 运行时调度延迟
+//! - [`TcpHealthCheck`] - `host:port` 的 TCP 连通性
+//!
+//! 每个检查都实现了 [`Configurable`]，通过
+//! [`InfrastructureBuilder::enable_health_checks`](crate::InfrastructureBuilder::enable_health_checks)
+//! 启用后，构建时从 `health.*` 配置节读取配置。
+
+use async_trait::async_trait;
+use config_abstractions::ConfigManager;
+use config_impl::manager::AdSystemConfigManager;
+use di_impl::DiContainerImpl;
+use infrastructure_common::{
+    ConfigError, Configurable, HealthCheckable, HealthProbe, HealthStatus, InfrastructureError,
+};
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use std::path::{Path, PathBuf};
+use std::sync::Arc;
+use std::time::{Duration, Instant};
+use tokio::sync::RwLock;
+
+/// 默认检查间隔（秒）
+const DEFAULT_CHECK_INTERVAL_SECS: u64 = 30;
+
+/// 配置子系统健康检查配置
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+#[serde(default)]
+pub struct ConfigHealthCheckOptions {
+    /// 是否启用
+    pub enabled: bool,
+    /// 检查间隔（秒）
+    pub interval_secs: u64,
+    /// 最近一次重载失败时是否报告降级
+    pub require_successful_reload: bool,
+}
+
+impl Default for ConfigHealthCheckOptions {
+    fn default() -> Self {
+        Self {
+            enabled: true,
+            interval_secs: DEFAULT_CHECK_INTERVAL_SECS,
+            require_successful_reload: true,
+        }
+    }
+}
+
+/// 配置子系统健康检查
+///
+/// 配置提供者不可用（例如配置文件被删除）时不健康；最近一次重载失败时
+/// 配置已回滚到上一个快照，仍可服务，报告降级
+#[derive(Debug)]
+pub struct ConfigHealthCheck {
+    /// 配置管理器
+    config_manager: Arc<AdSystemConfigManager>,
+    /// 检查配置
+    options: ConfigHealthCheckOptions,
+}
+
+impl ConfigHealthCheck {
+    /// 创建配置子系统健康检查
+    pub fn new(config_manager: Arc<AdSystemConfigManager>) -> Self {
+        Self {
+            config_manager,
+            options: ConfigHealthCheckOptions::default(),
+        }
+    }
+}
+
+impl Configurable for ConfigHealthCheck {
+    type Config = ConfigHealthCheckOptions;
+
+    fn configure(&mut self, config: Self::Config) -> Result<(), ConfigError> {
+        check_interval_secs(Self::get_config_path(), config.interval_secs)?;
+        self.options = config;
+        Ok(())
+    }
+
+    fn get_config_path() -> &'static str {
+        "health.config"
+    }
+}
+
+#[async_trait]
+impl HealthCheckable for ConfigHealthCheck {
+    async fn check_health(&self) -> HealthStatus {
+        let unavailable: HashMap<String, String> = self
+            .config_manager
+            .provider_statuses()
+            .await
+            .into_iter()
+            .filter_map(|status| status.error.map(|error| (status.name, error)))
+            .collect();
+        if !unavailable.is_empty() {
+            return HealthStatus::unhealthy_with_details(
+                format!("{}个配置提供者不可用", unavailable.len()),
+                unavailable,
+            );
+        }
+
+        if self.options.require_successful_reload {
+            if let Some(reload) = self.config_manager.last_reload_status() {
+                if let Some(error) = reload.error {
+                    return HealthStatus::degraded_with_details(
+                        format!("最近一次配置重载失败: {}", error),
+                        HashMap::from([
+                            ("source".to_string(), reload.source),
+                            ("completed_at".to_string(), reload.completed_at.to_rfc3339()),
+                        ]),
+                    );
+                }
+            }
+        }
+
+        HealthStatus::healthy()
+    }
+
+    fn name(&self) -> &str {
+        "config"
+    }
+
+    fn check_interval(&self) -> Duration {
+        Duration::from_secs(self.options.interval_secs)
+    }
+
+    fn is_enabled(&self) -> bool {
+        self.options.enabled
+    }
+
+    fn probes(&self) -> &[HealthProbe] {
+        &[HealthProbe::Startup, HealthProbe::Readiness]
+    }
+}
+
+/// 依赖注入容器健康检查配置
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+#[serde(default)]
+pub struct DiContainerHealthCheckOptions {
+    /// 是否启用
+    pub enabled: bool,
+    /// 检查间隔（秒）
+    pub interval_secs: u64,
+    /// 是否要求所有单例都已创建，未创建时报告降级
+    pub require_singletons_created: bool,
+}
+
+impl Default for DiContainerHealthCheckOptions {
+    fn default() -> Self {
+        Self {
+            enabled: true,
+            interval_secs: DEFAULT_CHECK_INTERVAL_SECS,
+            require_singletons_created: false,
+        }
+    }
+}
+
+/// 依赖注入容器健康检查
+///
+/// 存在创建失败的工厂时不健康；存在无法解析的注册或（按配置）尚未创建的单例时降级
+pub struct DiContainerHealthCheck {
+    /// 依赖注入容器
+    di_container: Arc<RwLock<DiContainerImpl>>,
+    /// 检查配置
+    options: DiContainerHealthCheckOptions,
+}
+
+impl std::fmt::Debug for DiContainerHealthCheck {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("DiContainerHealthCheck")
+            .field("options", &self.options)
+            .finish()
+    }
+}
+
+impl DiContainerHealthCheck {
+    /// 创建依赖注入容器健康检查
+    pub fn new(di_container: Arc<RwLock<DiContainerImpl>>) -> Self {
+        Self {
+            di_container,
+            options: DiContainerHealthCheckOptions::default(),
+        }
+    }
+}
+
+impl Configurable for DiContainerHealthCheck {
+    type Config = DiContainerHealthCheckOptions;
+
+    fn configure(&mut self, config: Self::Config) -> Result<(), ConfigError> {
+        check_interval_secs(Self::get_config_path(), config.interval_secs)?;
+        self.options = config;
+        Ok(())
+    }
+
+    fn get_config_path() -> &'static str {
+        "health.di"
+    }
+}
+
+#[async_trait]
+impl HealthCheckable for DiContainerHealthCheck {
+    async fn check_health(&self) -> HealthStatus {
+        let report = self.di_container.read().await.health_report().await;
+
+        if !report.factory_failures.is_empty() {
+            return HealthStatus::unhealthy_with_details(
+                format!("{}个组件创建失败", report.factory_failures.len()),
+                report.factory_failures,
+            );
+        }
+
+        let mut details: HashMap<String, String> = report
+            .unresolvable
+            .iter()
+            .map(|name| (name.clone(), "没有可用的工厂或实例".to_string()))
+            .collect();
+        if self.options.require_singletons_created {
+            details.extend(
+                report
+                    .pending_singletons
+                    .iter()
+                    .map(|name| (name.clone(), "单例尚未创建".to_string())),
+            );
+        }
+        if !details.is_empty() {
+            return HealthStatus::degraded_with_details(
+                format!("{}个组件不可用", details.len()),
+                details,
+            );
+        }
+
+        HealthStatus::healthy()
+    }
+
+    fn name(&self) -> &str {
+        "di_container"
+    }
+
+    fn check_interval(&self) -> Duration {
+        Duration::from_secs(self.options.interval_secs)
+    }
+
+    fn is_enabled(&self) -> bool {
+        self.options.enabled
+    }
+
+    fn probes(&self) -> &[HealthProbe] {
+        &[HealthProbe::Startup, HealthProbe::Readiness]
+    }
+}
+
+/// 磁盘空间健康检查配置
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+#[serde(default)]
+pub struct DiskSpaceHealthCheckOptions {
+    /// 是否启用
+    pub enabled: bool,
+    /// 检查间隔（秒）
+    pub interval_secs: u64,
+    /// 需要检查的路径，检查各路径所在的磁盘
+    pub paths: Vec<PathBuf>,
+    /// 剩余空间百分比低于该值时降级
+    pub warning_free_percent: f64,
+    /// 剩余空间百分比低于该值时不健康
+    pub critical_free_percent: f64,
+}
+
+impl Default for DiskSpaceHealthCheckOptions {
+    fn default() -> Self {
+        Self {
+            enabled: true,
+            interval_secs: DEFAULT_CHECK_INTERVAL_SECS,
+            paths: vec![PathBuf::from(".")],
+            warning_free_percent: 15.0,
+            critical_free_percent: 5.0,
+        }
+    }
+}
+
+/// 磁盘空间健康检查
+#[derive(Debug, Default)]
+pub struct DiskSpaceHealthCheck {
+    /// 检查配置
+    options: DiskSpaceHealthCheckOptions,
+}
+
+impl DiskSpaceHealthCheck {
+    /// 创建磁盘空间健康检查
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// 设置需要检查的路径
+    pub fn with_paths<I, P>(mut self, paths: I) -> Self
+    where
+        I: IntoIterator<Item = P>,
+        P: Into<PathBuf>,
+    {
+        self.options.paths = paths.into_iter().map(Into::into).collect();
+        self
+    }
+
+    /// 设置降级和不健康的剩余空间百分比阈值
+    pub fn with_thresholds(
+        mut self,
+        warning_free_percent: f64,
+        critical_free_percent: f64,
+    ) -> Self {
+        self.options.warning_free_percent = warning_free_percent;
+        self.options.critical_free_percent = critical_free_percent;
+        self
+    }
+
+    /// 查找路径所在磁盘的剩余空间百分比
+    fn free_percent(disks: &sysinfo::Disks, path: &Path) -> Result<f64, String> {
+        let path = path
+            .canonicalize()
+            .map_err(|e| format!("无法访问路径: {}", e))?;
+        let disk = disks
+            .list()
+            .iter()
+            .filter(|disk| path.starts_with(disk.mount_point()))
+            .max_by_key(|disk| disk.mount_point().as_os_str().len())
+            .ok_or_else(|| "未找到所在磁盘".to_string())?;
+
+        if disk.total_space() == 0 {
+            return Err("磁盘容量未知".to_string());
+        }
+        Ok(disk.available_space() as f64 * 100.0 / disk.total_space() as f64)
+    }
+}
+
+impl Configurable for DiskSpaceHealthCheck {
+    type Config = DiskSpaceHealthCheckOptions;
+
+    fn configure(&mut self, config: Self::Config) -> Result<(), ConfigError> {
+        check_interval_secs(Self::get_config_path(), config.interval_secs)?;
+        self.options = config;
+        Ok(())
+    }
+
+    fn get_config_path() -> &'static str {
+        "health.disk"
+    }
+}
+
+#[async_trait]
+impl HealthCheckable for DiskSpaceHealthCheck {
+    async fn check_health(&self) -> HealthStatus {
+        let options = self.options.clone();
+        let checked = tokio::task::spawn_blocking(move || {
+            let disks = sysinfo::Disks::new_with_refreshed_list();
+            options
+                .paths
+                .iter()
+                .map(|path| (path.display().to_string(), Self::free_percent(&disks, path)))
+                .collect::<Vec<_>>()
+        })
+        .await;
+        let checked = match checked {
+            Ok(checked) => checked,
+            Err(e) => return HealthStatus::unhealthy(format!("磁盘空间检查失败: {}", e)),
+        };
+
+        let mut unhealthy = false;
+        let mut degraded = false;
+        let mut details = HashMap::new();
+        for (path, free_percent) in checked {
+            match free_percent {
+                Ok(free_percent) => {
+                    unhealthy |= free_percent < self.options.critical_free_percent;
+                    degraded |= free_percent < self.options.warning_free_percent;
+                    details.insert(path, format!("剩余 {:.1}%", free_percent));
+                }
+                Err(error) => {
+                    unhealthy = true;
+                    details.insert(path, error);
+                }
+            }
+        }
+
+        if unhealthy {
+            HealthStatus::unhealthy_with_details("磁盘空间不足或不可访问", details)
+        } else if degraded {
+            HealthStatus::degraded_with_details("磁盘剩余空间偏低", details)
+        } else {
+            HealthStatus::healthy()
+        }
+    }
+
+    fn name(&self) -> &str {
+        "disk_space"
+    }
+
+    fn check_interval(&self) -> Duration {
+        Duration::from_secs(self.options.interval_secs)
+    }
+
+    fn is_enabled(&self) -> bool {
+        self.options.enabled
+    }
+}
+
+/// 内存健康检查配置
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+#[serde(default)]
+pub struct MemoryHealthCheckOptions {
+    /// 是否启用
+    pub enabled: bool,
+    /// 检查间隔（秒）
+    pub interval_secs: u64,
+    /// 进程常驻内存超过该值（字节）时降级
+    pub warning_rss_bytes: Option<u64>,
+    /// 进程常驻内存超过该值（字节）时不健康
+    pub critical_rss_bytes: Option<u64>,
+    /// 系统内存使用率超过该百分比时降级
+    pub warning_used_percent: f64,
+    /// 系统内存使用率超过该百分比时不健康
+    pub critical_used_percent: f64,
+}
+
+impl Default for MemoryHealthCheckOptions {
+    fn default() -> Self {
+        Self {
+            enabled: true,
+            interval_secs: DEFAULT_CHECK_INTERVAL_SECS,
+            warning_rss_bytes: None,
+            critical_rss_bytes: None,
+            warning_used_percent: 90.0,
+            critical_used_percent: 97.0,
+        }
+    }
+}
+
+/// 内存健康检查
+#[derive(Debug, Default)]
+pub struct MemoryHealthCheck {
+    /// 检查配置
+    options: MemoryHealthCheckOptions,
+}
+
+impl MemoryHealthCheck {
+    /// 创建内存健康检查
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// 设置进程常驻内存阈值（字节）
+    pub fn with_rss_thresholds(mut self, warning_bytes: u64, critical_bytes: u64) -> Self {
+        self.options.warning_rss_bytes = Some(warning_bytes);
+        self.options.critical_rss_bytes = Some(critical_bytes);
+        self
+    }
+}
+
+impl Configurable for MemoryHealthCheck {
+    type Config = MemoryHealthCheckOptions;
+
+    fn configure(&mut self, config: Self::Config) -> Result<(), ConfigError> {
+        check_interval_secs(Self::get_config_path(), config.interval_secs)?;
+        self.options = config;
+        Ok(())
+    }
+
+    fn get_config_path() -> &'static str {
+        "health.memory"
+    }
+}
+
+#[async_trait]
+impl HealthCheckable for MemoryHealthCheck {
+    async fn check_health(&self) -> HealthStatus {
+        let sampled = tokio::task::spawn_blocking(|| {
+            let mut system = sysinfo::System::new();
+            system.refresh_memory();
+            let rss = sysinfo::get_current_pid().ok().and_then(|pid| {
+                system.refresh_process(pid);
+                system.process(pid).map(|process| process.memory())
+            });
+            (rss, system.used_memory(), system.total_memory())
+        })
+        .await;
+        let (rss, used, total) = match sampled {
+            Ok(sampled) => sampled,
+            Err(e) => return HealthStatus::unhealthy(format!("内存检查失败: {}", e)),
+        };
+
+        let mut details = HashMap::new();
+        let mut level = 0;
+        if let Some(rss) = rss {
+            details.insert("rss_bytes".to_string(), rss.to_string());
+            if self
+                .options
+                .critical_rss_bytes
+                .is_some_and(|limit| rss > limit)
+            {
+                level = 2;
+            } else if self
+                .options
+                .warning_rss_bytes
+                .is_some_and(|limit| rss > limit)
+            {
+                level = 1;
+            }
+        }
+        if total > 0 {
+            let used_percent = used as f64 * 100.0 / total as f64;
+            details.insert(
+                "system_used_percent".to_string(),
+                format!("{:.1}", used_percent),
+            );
+            if used_percent > self.options.critical_used_percent {
+                level = 2;
+            } else if used_percent > self.options.warning_used_percent {
+                level = level.max(1);
+            }
+        }
+
+        match level {
+            2 => HealthStatus::unhealthy_with_details("内存使用超过上限", details),
+            1 => HealthStatus::degraded_with_details("内存使用偏高", details),
+            _ => HealthStatus::healthy(),
+        }
+    }
+
+    fn name(&self) -> &str {
+        "memory"
+    }
+
+    fn check_interval(&self) -> Duration {
+        Duration::from_secs(self.options.interval_secs)
+    }
+
+    fn is_enabled(&self) -> bool {
+        self.options.enabled
+    }
+}
+
+/// 运行时响应性健康检查配置
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+#[serde(default)]
+pub struct RuntimeHealthCheckOptions {
+    /// 是否启用
+    pub enabled: bool,
+    /// 检查间隔（秒）
+    pub interval_secs: u64,
+    /// 调度延迟超过该值（毫秒）时降级
+    pub warning_lag_ms: u64,
+    /// 调度延迟超过该值（毫秒）时不健康
+    pub critical_lag_ms: u64,
+}
+
+impl Default for RuntimeHealthCheckOptions {
+    fn default() -> Self {
+        Self {
+            enabled: true,
+            interval_secs: 10,
+            warning_lag_ms: 50,
+            critical_lag_ms: 500,
+        }
+    }
+}
+
+/// tokio 运行时响应性健康检查
+///
+/// 测量新任务从提交到开始执行的延迟，事件循环被阻塞时延迟显著增大
+#[derive(Debug, Default)]
+pub struct RuntimeHealthCheck {
+    /// 检查配置
+    options: RuntimeHealthCheckOptions,
+}
+
+impl RuntimeHealthCheck {
+    /// 创建运行时响应性健康检查
+    pub fn new() -> Self {
+        Self::default()
+    }
+}
+
+impl Configurable for RuntimeHealthCheck {
+    type Config = RuntimeHealthCheckOptions;
+
+    fn configure(&mut self, config: Self::Config) -> Result<(), ConfigError> {
+        check_interval_secs(Self::get_config_path(), config.interval_secs)?;
+        self.options = config;
+        Ok(())
+    }
+
+    fn get_config_path() -> &'static str {
+        "health.runtime"
+    }
+}
+
+#[async_trait]
+impl HealthCheckable for RuntimeHealthCheck {
+    async fn check_health(&self) -> HealthStatus {
+        let scheduled_at = Instant::now();
+        let lag = match tokio::spawn(async move { scheduled_at.elapsed() }).await {
+            Ok(lag) => lag,
+            Err(e) => return HealthStatus::unhealthy(format!("运行时调度失败: {}", e)),
+        };
+
+        let details = HashMap::from([("lag_ms".to_string(), lag.as_millis().to_string())]);
+        if lag > Duration::from_millis(self.options.critical_lag_ms) {
+            HealthStatus::unhealthy_with_details("运行时调度延迟过高", details)
+        } else if lag > Duration::from_millis(self.options.warning_lag_ms) {
+            HealthStatus::degraded_with_details("运行时调度延迟偏高", details)
+        } else {
+            HealthStatus::healthy()
+        }
+    }
+
+    fn name(&self) -> &str {
+        "runtime"
+    }
+
+    fn check_interval(&self) -> Duration {
+        Duration::from_secs(self.options.interval_secs)
+    }
+
+    fn is_enabled(&self) -> bool {
+        self.options.enabled
+    }
+
+    fn probes(&self) -> &[HealthProbe] {
+        &[HealthProbe::Liveness, HealthProbe::Readiness]
+    }
+}
+
+/// TCP 连通性健康检查配置
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+#[serde(default)]
+pub struct TcpHealthCheckOptions {
+    /// 是否启用
+    pub enabled: bool,
+    /// 检查间隔（秒）
+    pub interval_secs: u64,
+    /// 需要连通的地址，格式为 `host:port`
+    pub targets: Vec<String>,
+    /// 连接超时（毫秒）
+    pub connect_timeout_ms: u64,
+}
+
+impl Default for TcpHealthCheckOptions {
+    fn default() -> Self {
+        Self {
+            enabled: true,
+            interval_secs: DEFAULT_CHECK_INTERVAL_SECS,
+            targets: Vec::new(),
+            connect_timeout_ms: 2_000,
+        }
+    }
+}
+
+/// TCP 连通性健康检查
+///
+/// 任一目标无法连接时不健康
+#[derive(Debug, Default)]
+pub struct TcpHealthCheck {
+    /// 检查配置
+    options: TcpHealthCheckOptions,
+}
+
+impl TcpHealthCheck {
+    /// 创建 TCP 连通性健康检查
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// 添加需要连通的地址
+    pub fn with_target(mut self, address: impl Into<String>) -> Self {
+        self.options.targets.push(address.into());
+        self
+    }
+
+    /// 设置连接超时
+    pub fn with_connect_timeout(mut self, timeout: Duration) -> Self {
+        self.options.connect_timeout_ms = timeout.as_millis() as u64;
+        self
+    }
+
+    /// 获取需要连通的地址
+    pub fn targets(&self) -> &[String] {
+        &self.options.targets
+    }
+}
+
+impl Configurable for TcpHealthCheck {
+    type Config = TcpHealthCheckOptions;
+
+    fn configure(&mut self, config: Self::Config) -> Result<(), ConfigError> {
+        check_interval_secs(Self::get_config_path(), config.interval_secs)?;
+        self.options = config;
+        Ok(())
+    }
+
+    fn get_config_path() -> &'static str {
+        "health.tcp"
+    }
+}
+
+#[async_trait]
+impl HealthCheckable for TcpHealthCheck {
+    async fn check_health(&self) -> HealthStatus {
+        let connect_timeout = Duration::from_millis(self.options.connect_timeout_ms);
+        let mut failed = 0;
+        let mut details = HashMap::new();
+
+        for target in &self.options.targets {
+            let started = Instant::now();
+            let outcome =
+                tokio::time::timeout(connect_timeout, tokio::net::TcpStream::connect(target)).await;
+            let detail = match outcome {
+                Ok(Ok(_)) => format!("可连接 ({}ms)", started.elapsed().as_millis()),
+                Ok(Err(e)) => {
+                    failed += 1;
+                    format!("连接失败: {}", e)
+                }
+                Err(_) => {
+                    failed += 1;
+                    format!("连接超时 ({}ms)", self.options.connect_timeout_ms)
+                }
+            };
+            details.insert(target.clone(), detail);
+        }
+
+        if failed > 0 {
+            HealthStatus::unhealthy_with_details(format!("{}个地址无法连接", failed), details)
+        } else {
+            HealthStatus::healthy()
+        }
+    }
+
+    fn name(&self) -> &str {
+        "tcp"
+    }
+
+    fn timeout(&self) -> Duration {
+        Duration::from_millis(self.options.connect_timeout_ms)
+            .saturating_mul(self.options.targets.len().max(1) as u32)
+            .saturating_add(Duration::from_secs(1))
+    }
+
+    fn check_interval(&self) -> Duration {
+        Duration::from_secs(self.options.interval_secs)
+    }
+
+    fn is_enabled(&self) -> bool {
+        self.options.enabled && !self.options.targets.is_empty()
+    }
+}
+
+/// 检查间隔必须大于零
+fn check_interval_secs(config_path: &str, interval_secs: u64) -> Result<(), ConfigError> {
+    if interval_secs == 0 {
+        return Err(ConfigError::ValidationFailed {
+            errors: vec![format!("{}.interval_secs 必须大于 0", config_path)],
+        });
+    }
+    Ok(())
+}
+
+/// 从配置管理器读取健康检查的配置节，配置节不存在时保留默认配置
+async fn configure_from<T: Configurable>(
+    check: &mut T,
+    config_manager: &AdSystemConfigManager,
+) -> Result<(), InfrastructureError> {
+    match config_manager.get_configuration(T::get_config_path()).await {
+        Ok(value) => {
+            let config =
+                serde_json::from_value(value).map_err(|e| InfrastructureError::ConfigError {
+                    source: ConfigError::ParseError {
+                        source: Box::new(e),
+                    },
+                })?;
+            check
+                .configure(config)
+                .map_err(|e| InfrastructureError::ConfigError { source: e })
+        }
+        Err(ConfigError::KeyNotFound { .. }) => Ok(()),
+        Err(e) => Err(InfrastructureError::ConfigError { source: e }),
+    }
+}
+
+/// 创建内置健康检查
+///
+/// 按配置节配置各检查，只返回启用的检查。`tcp_targets` 追加到 `health.tcp.targets` 之后；
+/// `include_resources` 为 false 时只创建 TCP 连通性检查
+pub(crate) async fn create_builtin_health_checks(
+    config_manager: &Arc<AdSystemConfigManager>,
+    di_container: &Arc<RwLock<DiContainerImpl>>,
+    tcp_targets: Vec<String>,
+    include_resources: bool,
+) -> Result<Vec<Box<dyn HealthCheckable>>, InfrastructureError> {
+    let mut tcp_check = TcpHealthCheck::new();
+    configure_from(&mut tcp_check, config_manager).await?;
+    tcp_check.options.targets.extend(tcp_targets);
+    if !include_resources {
+        return Ok(if tcp_check.is_enabled() {
+            vec![Box::new(tcp_check)]
+        } else {
+            Vec::new()
+        });
+    }
+
+    let mut config_check = ConfigHealthCheck::new(config_manager.clone());
+    configure_from(&mut config_check, config_manager).await?;
+    let mut di_check = DiContainerHealthCheck::new(di_container.clone());
+    configure_from(&mut di_check, config_manager).await?;
+    let mut disk_check = DiskSpaceHealthCheck::new();
+    configure_from(&mut disk_check, config_manager).await?;
+    let mut memory_check = MemoryHealthCheck::new();
+    configure_from(&mut memory_check, config_manager).await?;
+    let mut runtime_check = RuntimeHealthCheck::new();
+    configure_from(&mut runtime_check, config_manager).await?;
+
+    let checks: Vec<Box<dyn HealthCheckable>> = vec![
+        Box::new(config_check),
+        Box::new(di_check),
+        Box::new(disk_check),
+        Box::new(memory_check),
+        Box::new(runtime_check),
+        Box::new(tcp_check),
+    ];
+    Ok(checks
+        .into_iter()
+        .filter(|check| check.is_enabled())
+        .collect())
+}
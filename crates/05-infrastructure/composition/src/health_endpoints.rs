@@ -0,0 +1,203 @@
+//! 健康检查 HTTP 端点
+//!
+//! 将聚合健康检查器暴露为可嵌入的 axum 路由，提供 Kubernetes 风格的探针：
+//!
+//! - `/health/live` - 存活探针
+//! - `/health/ready` - 就绪探针
+//! - `/health/startup` - 启动探针
+//!
+//! 降级状态返回 200 并在响应中附带警告，不健康状态返回 503。
+
+use crate::infrastructure::InfrastructureStatus;
+use axum::extract::State;
+use axum::http::StatusCode;
+use axum::response::{IntoResponse, Response};
+use axum::routing::get;
+use axum::{Json, Router};
+use infrastructure_common::{
+    AggregateHealthChecker, HealthCheckResult, HealthMonitor, HealthProbe, HealthStatus,
+};
+use serde::{Deserialize, Serialize};
+use std::sync::Arc;
+use tokio::sync::RwLock;
+
+/// 存活探针路径
+pub const LIVENESS_PATH: &str = "/health/live";
+/// 就绪探针路径
+pub const READINESS_PATH: &str = "/health/ready";
+/// 启动探针路径
+pub const STARTUP_PATH: &str = "/health/startup";
+
+/// 探针整体状态
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum HealthReportStatus {
+    /// 所有检查通过
+    Healthy,
+    /// 存在降级的检查，仍可对外服务
+    Degraded,
+    /// 存在失败的检查
+    Unhealthy,
+}
+
+/// 探针检查报告
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct HealthReport {
+    /// 探针类型
+    pub probe: HealthProbe,
+    /// 整体状态
+    pub status: HealthReportStatus,
+    /// 基础设施运行状态
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub infrastructure_status: Option<InfrastructureStatus>,
+    /// 降级检查产生的警告
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub warnings: Vec<String>,
+    /// 参与该探针的检查结果
+    pub checks: Vec<HealthCheckResult>,
+    /// 检查时间
+    pub checked_at: chrono::DateTime<chrono::Utc>,
+}
+
+impl HealthReport {
+    /// 根据检查结果和基础设施状态生成报告
+    pub fn new(
+        probe: HealthProbe,
+        checks: Vec<HealthCheckResult>,
+        infrastructure_status: Option<InfrastructureStatus>,
+    ) -> Self {
+        let mut status = HealthReportStatus::Healthy;
+        let mut warnings = Vec::new();
+
+        for check in &checks {
+            match &check.status {
+                HealthStatus::Healthy => {}
+                HealthStatus::Degraded { message, .. } => {
+                    warnings.push(format!("{}: {}", check.component_name, message));
+                    if status == HealthReportStatus::Healthy {
+                        status = HealthReportStatus::Degraded;
+                    }
+                }
+                HealthStatus::Unhealthy { .. } => status = HealthReportStatus::Unhealthy,
+            }
+        }
+
+        if let Some(infrastructure_status) = infrastructure_status {
+            if !Self::accepts(probe, infrastructure_status) {
+                status = HealthReportStatus::Unhealthy;
+            }
+        }
+
+        Self {
+            probe,
+            status,
+            infrastructure_status,
+            warnings,
+            checks,
+            checked_at: chrono::Utc::now(),
+        }
+    }
+
+    /// 对应的 HTTP 状态码
+    pub fn http_status(&self) -> StatusCode {
+        match self.status {
+            HealthReportStatus::Healthy | HealthReportStatus::Degraded => StatusCode::OK,
+            HealthReportStatus::Unhealthy => StatusCode::SERVICE_UNAVAILABLE,
+        }
+    }
+
+    /// 判断基础设施运行状态是否满足探针要求
+    ///
+    /// 存活探针只在启动失败时失败；就绪探针要求正在运行；
+    /// 启动探针要求已完成启动
+    fn accepts(probe: HealthProbe, status: InfrastructureStatus) -> bool {
+        use InfrastructureStatus::*;
+
+        match probe {
+            HealthProbe::Liveness => status != Failed,
+            HealthProbe::Readiness => status == Running,
+            HealthProbe::Startup => matches!(status, Running | Stopping | Stopped),
+        }
+    }
+}
+
+impl IntoResponse for HealthReport {
+    fn into_response(self) -> Response {
+        (self.http_status(), Json(self)).into_response()
+    }
+}
+
+/// 健康检查端点
+///
+/// 可以通过 [`AdSystemInfrastructure::health_router`](crate::AdSystemInfrastructure::health_router)
+/// 获得，也可以基于独立的聚合健康检查器创建
+#[derive(Debug, Clone)]
+pub struct HealthEndpoints {
+    /// 聚合健康检查器
+    checker: Arc<AggregateHealthChecker>,
+    /// 基础设施运行状态
+    status: Option<Arc<RwLock<InfrastructureStatus>>>,
+    /// 后台健康监控器
+    monitor: Option<Arc<HealthMonitor>>,
+}
+
+impl HealthEndpoints {
+    /// 创建健康检查端点
+    pub fn new(checker: Arc<AggregateHealthChecker>) -> Self {
+        Self {
+            checker,
+            status: None,
+            monitor: None,
+        }
+    }
+
+    /// 设置基础设施运行状态，探针结果会结合运行状态判断
+    pub fn with_status(mut self, status: Arc<RwLock<InfrastructureStatus>>) -> Self {
+        self.status = Some(status);
+        self
+    }
+
+    /// 设置后台健康监控器，监控运行时直接返回缓存的检查结果
+    pub fn with_monitor(mut self, monitor: Arc<HealthMonitor>) -> Self {
+        self.monitor = Some(monitor);
+        self
+    }
+
+    /// 执行指定探针的健康检查
+    pub async fn report(&self, probe: HealthProbe) -> HealthReport {
+        let infrastructure_status = match &self.status {
+            Some(status) => Some(*status.read().await),
+            None => None,
+        };
+        let checks = match &self.monitor {
+            Some(monitor) if monitor.is_running() => monitor.latest_for_probe(probe),
+            _ => self.checker.check_probe(probe).await,
+        };
+
+        HealthReport::new(probe, checks, infrastructure_status)
+    }
+
+    /// 创建健康检查路由
+    pub fn router(self) -> Router {
+        Router::new()
+            .route(LIVENESS_PATH, get(liveness))
+            .route(READINESS_PATH, get(readiness))
+            .route(STARTUP_PATH, get(startup))
+            .with_state(self)
+    }
+}
+
+/// 存活探针处理函数
+async fn liveness(State(endpoints): State<HealthEndpoints>) -> HealthReport {
+    endpoints.report(HealthProbe::Liveness).await
+}
+
+/// 就绪探针处理函数
+async fn readiness(State(endpoints): State<HealthEndpoints>) -> HealthReport {
+    endpoints.report(HealthProbe::Readiness).await
+}
+
+/// 启动探针处理函数
+async fn startup(State(endpoints): State<HealthEndpoints>) -> HealthReport {
+    endpoints.report(HealthProbe::Startup).await
+}
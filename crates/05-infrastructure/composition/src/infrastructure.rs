@@ -1,15 +1,24 @@
 //! 基础设施主入口
 
+use crate::admin::{AdminEndpoints, ADMIN_TOKEN_CONFIG_PATH};
+use crate::bootstrapper::BootstrapReport;
 use crate::builder::InfrastructureBuilder;
 use config_abstractions::ConfigManager;
 use config_impl::manager::AdSystemConfigManager;
 use di_abstractions::DiContainer;
 use di_impl::DiContainerImpl;
+use crate::health_endpoints::HealthEndpoints;
+use crate::log_filter::LogFilterHandle;
+use crate::metrics_exporter::PrometheusExporter;
+use crate::telemetry::Telemetry;
 use infrastructure_common::{
-    HealthCheckable, HealthStatus, InfrastructureError, Component, DependencyError,
+    ActiveProfiles, AggregateHealthChecker, DefaultLifecycleManager, HealthCheckable, HealthMonitor, HealthMonitorOptions,
+    HealthStatus, InfrastructureError, Component, DependencyError,
+    INFRASTRUCTURE_STARTUP_DURATION_SECONDS,
 };
 use serde::{Deserialize, Serialize};
 use std::sync::Arc;
+use std::time::Instant;
 use tokio::sync::RwLock;
 use tracing::{info, error};
 
@@ -21,12 +30,26 @@ pub struct AdSystemInfrastructure {
     config_manager: Arc<AdSystemConfigManager>,
     /// 依赖注入容器
     di_container: Arc<RwLock<DiContainerImpl>>,
-    /// 健康检查器集合
-    health_checkers: Vec<Box<dyn HealthCheckable>>,
+    /// 聚合健康检查器
+    health_checker: Arc<AggregateHealthChecker>,
+    /// 后台健康监控器
+    health_monitor: Option<Arc<HealthMonitor>>,
+    /// Prometheus 指标导出器
+    metrics_exporter: Option<PrometheusExporter>,
+    /// 日志和追踪
+    telemetry: Option<Telemetry>,
     /// 运行状态
     status: Arc<RwLock<InfrastructureStatus>>,
     /// 统计信息
     metrics: Arc<RwLock<InfrastructureMetrics>>,
+    /// 激活的配置档
+    active_profiles: ActiveProfiles,
+    /// 作用域生命周期管理器
+    lifecycle_manager: Arc<DefaultLifecycleManager>,
+    /// 管理令牌的配置路径
+    admin_token_path: String,
+    /// 启动流水线执行报告
+    bootstrap_report: Option<BootstrapReport>,
 }
 
 impl AdSystemInfrastructure {
@@ -38,21 +61,72 @@ impl AdSystemInfrastructure {
     /// 内部构造函数
     pub(crate) fn new(
         config_manager: Arc<AdSystemConfigManager>,
-        di_container: DiContainerImpl,
+        di_container: Arc<RwLock<DiContainerImpl>>,
         health_checkers: Vec<Box<dyn HealthCheckable>>,
+        active_profiles: ActiveProfiles,
     ) -> Self {
+        let mut health_checker = AggregateHealthChecker::new();
+        for checker in health_checkers {
+            health_checker.add_checker(checker);
+        }
+        let health_checkers_count = health_checker.checker_count();
+        
         Self {
             config_manager,
-            di_container: Arc::new(RwLock::new(di_container)),
-            health_checkers,
+            di_container,
+            health_checker: Arc::new(health_checker),
+            health_monitor: None,
+            metrics_exporter: None,
+            telemetry: None,
             status: Arc::new(RwLock::new(InfrastructureStatus::Initialized)),
-            metrics: Arc::new(RwLock::new(InfrastructureMetrics::default())),
+            metrics: Arc::new(RwLock::new(InfrastructureMetrics {
+                health_checkers_count,
+                ..InfrastructureMetrics::default()
+            })),
+            active_profiles,
+            lifecycle_manager: Arc::new(DefaultLifecycleManager::new()),
+            admin_token_path: ADMIN_TOKEN_CONFIG_PATH.to_string(),
+            bootstrap_report: None,
         }
     }
     
+    /// 启用后台健康监控，监控随基础设施启动和停止
+    pub(crate) fn with_health_monitor(mut self, options: HealthMonitorOptions) -> Self {
+        self.health_monitor = Some(Arc::new(HealthMonitor::new(
+            self.health_checker.clone(),
+            options,
+        )));
+        self
+    }
+    
+    /// 设置 Prometheus 指标导出器
+    pub(crate) fn with_metrics_exporter(mut self, exporter: PrometheusExporter) -> Self {
+        self.metrics_exporter = Some(exporter);
+        self
+    }
+    
+    /// 设置已安装的日志和追踪
+    pub(crate) fn with_telemetry(mut self, telemetry: Telemetry) -> Self {
+        self.telemetry = Some(telemetry);
+        self
+    }
+    
+    /// 设置管理令牌的配置路径
+    pub(crate) fn with_admin_token_path(mut self, path: String) -> Self {
+        self.admin_token_path = path;
+        self
+    }
+    
+    /// 设置启动流水线执行报告
+    pub(crate) fn with_bootstrap_report(mut self, report: BootstrapReport) -> Self {
+        self.bootstrap_report = Some(report);
+        self
+    }
+    
     /// 启动基础设施
     pub async fn start(&self) -> Result<(), InfrastructureError> {
         info!("启动基础设施");
+        let started = Instant::now();
         
         {
             let mut status = self.status.write().await;
@@ -73,7 +147,7 @@ impl AdSystemInfrastructure {
                 *status = InfrastructureStatus::Failed;
             }
             return Err(InfrastructureError::BootstrapFailed {
-                message: "配置验证失败".to_string(),
+                message: format!("配置验证失败: {}", e),
             });
         }
         
@@ -99,6 +173,12 @@ impl AdSystemInfrastructure {
             *status = InfrastructureStatus::Running;
         }
         
+        if let Some(monitor) = &self.health_monitor {
+            monitor.start().await;
+        }
+        
+        metrics::gauge!(INFRASTRUCTURE_STARTUP_DURATION_SECONDS)
+            .set(started.elapsed().as_secs_f64());
         info!("基础设施启动完成");
         Ok(())
     }
@@ -113,7 +193,13 @@ impl AdSystemInfrastructure {
         }
         
         // 清理资源
+        if let Some(monitor) = &self.health_monitor {
+            monitor.stop();
+        }
         info!("依赖注入容器已停止");
+        if let Some(telemetry) = &self.telemetry {
+            telemetry.flush().await;
+        }
         
         {
             let mut status = self.status.write().await;
@@ -176,62 +262,118 @@ impl AdSystemInfrastructure {
     
     /// 执行健康检查
     pub async fn check_health(&self) -> Vec<(String, HealthStatus)> {
-        let mut results = Vec::new();
+        self.metrics.write().await.health_check_count += 1;
         
-        for checker in &self.health_checkers {
-            let status = match tokio::time::timeout(
-                std::time::Duration::from_secs(30), // 默认30秒超时
-                checker.check_health()
-            ).await {
-                Ok(status) => status,
-                Err(_) => HealthStatus::unhealthy("健康检查超时"),
-            };
-            
-            results.push((checker.name().to_string(), status));
-        }
-        
-        results
+        self.health_checker
+            .check_all()
+            .await
+            .into_iter()
+            .map(|result| (result.component_name, result.status))
+            .collect()
     }
     
     /// 获取整体健康状态
     pub async fn get_overall_health(&self) -> HealthStatus {
-        let results = self.check_health().await;
-        
-        let unhealthy_count = results.iter().filter(|(_, status)| {
-            matches!(status, HealthStatus::Unhealthy { .. })
-        }).count();
-        
-        let degraded_count = results.iter().filter(|(_, status)| {
-            matches!(status, HealthStatus::Degraded { .. })
-        }).count();
-        
-        if unhealthy_count > 0 {
-            HealthStatus::unhealthy(&format!("{}个组件不健康", unhealthy_count))
-        } else if degraded_count > 0 {
-            HealthStatus::degraded(&format!("{}个组件降级", degraded_count))
-        } else {
-            HealthStatus::healthy()
+        self.health_checker.get_overall_health().await
+    }
+    
+    /// 获取聚合健康检查器
+    pub fn health_checker(&self) -> &Arc<AggregateHealthChecker> {
+        &self.health_checker
+    }
+    
+    /// 获取后台健康监控器，未启用时返回 None
+    pub fn health_monitor(&self) -> Option<&Arc<HealthMonitor>> {
+        self.health_monitor.as_ref()
+    }
+    
+    /// 创建健康检查 HTTP 端点
+    ///
+    /// 探针结果会结合基础设施的运行状态，例如启动完成前就绪探针返回失败
+    pub fn health_endpoints(&self) -> HealthEndpoints {
+        let endpoints =
+            HealthEndpoints::new(self.health_checker.clone()).with_status(self.status.clone());
+        match &self.health_monitor {
+            Some(monitor) => endpoints.with_monitor(monitor.clone()),
+            None => endpoints,
         }
     }
     
+    /// 创建可嵌入的健康检查路由
+    ///
+    /// 提供 `/health/live`、`/health/ready` 和 `/health/startup` 三个端点
+    pub fn health_router(&self) -> axum::Router {
+        self.health_endpoints().router()
+    }
+    
+    /// 获取 Prometheus 指标导出器，未启用时返回 None
+    pub fn metrics_exporter(&self) -> Option<&PrometheusExporter> {
+        self.metrics_exporter.as_ref()
+    }
+    
+    /// 创建可嵌入的指标路由，提供 `/metrics` 端点
+    ///
+    /// 未通过 [`InfrastructureBuilder::with_prometheus_metrics`] 启用时返回 None
+    pub fn metrics_router(&self) -> Option<axum::Router> {
+        self.metrics_exporter.clone().map(PrometheusExporter::router)
+    }
+    
+    /// 获取日志和追踪，未通过构建器配置时返回 None
+    pub fn telemetry(&self) -> Option<&Telemetry> {
+        self.telemetry.as_ref()
+    }
+    
+    /// 获取日志过滤句柄，未通过构建器配置日志时返回 None
+    pub fn log_filter(&self) -> Option<&LogFilterHandle> {
+        self.telemetry.as_ref().map(Telemetry::log_filter)
+    }
+    
+    /// 创建可嵌入的日志级别管理路由
+    ///
+    /// 提供 `/admin/log-level` 端点，未通过构建器配置日志时返回 None
+    pub fn log_level_router(&self) -> Option<axum::Router> {
+        self.log_filter().cloned().map(LogFilterHandle::router)
+    }
+    
+    /// 创建管理和诊断 HTTP 端点
+    pub fn admin_endpoints(self: &Arc<Self>) -> AdminEndpoints {
+        AdminEndpoints::new(self.clone()).with_token_path(self.admin_token_path.clone())
+    }
+    
+    /// 创建可嵌入的管理路由
+    ///
+    /// 提供状态、组件、作用域、配置查看与回滚等 `/admin` 端点，
+    /// 请求需携带配置中的管理令牌，配置了日志时同时包含日志级别管理端点
+    pub fn admin_router(self: &Arc<Self>) -> axum::Router {
+        self.admin_endpoints().router()
+    }
+    
     /// 获取运行状态
     pub async fn get_status(&self) -> InfrastructureStatus {
         *self.status.read().await
     }
     
     /// 获取统计信息
+    ///
+    /// 配置重载和组件解析次数取自配置管理器和依赖注入容器的计数，
+    /// 包括文件监听触发的重载和直接在容器上进行的解析
     pub async fn get_metrics(&self) -> InfrastructureMetrics {
-        self.metrics.read().await.clone()
+        let mut metrics = self.metrics.read().await.clone();
+        metrics.config_reload_count = self.config_manager.reload_count();
+        metrics.component_resolution_count = self.di_container.read().await.resolution_count();
+        metrics
     }
     
     /// 重新加载配置
     pub async fn reload_configuration(&self) -> Result<(), InfrastructureError> {
         info!("重新加载配置");
         
-        // 这里需要获取可变引用，但当前架构中 config_manager 是不可变的
-        // 实际实现中可能需要使用内部可变性或重新设计架构
+        let events = self.config_manager
+            .reload_and_notify("AdSystemInfrastructure")
+            .await
+            .map_err(|e| InfrastructureError::ConfigError { source: e })?;
         
-        info!("配置重新加载完成");
+        info!("配置重新加载完成，{}个配置键发生变化", events.len());
         Ok(())
     }
     
@@ -241,6 +383,21 @@ impl AdSystemInfrastructure {
         container.get_registered_components()
     }
     
+    /// 获取启动流水线执行报告，记录各阶段和步骤的耗时
+    pub fn bootstrap_report(&self) -> Option<&BootstrapReport> {
+        self.bootstrap_report.as_ref()
+    }
+    
+    /// 获取激活的配置档
+    pub fn active_profiles(&self) -> &ActiveProfiles {
+        &self.active_profiles
+    }
+    
+    /// 获取作用域生命周期管理器
+    pub fn lifecycle_manager(&self) -> &Arc<DefaultLifecycleManager> {
+        &self.lifecycle_manager
+    }
+    
     /// 获取配置管理器引用
     pub fn config_manager(&self) -> &Arc<AdSystemConfigManager> {
         &self.config_manager
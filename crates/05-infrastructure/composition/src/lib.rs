@@ -7,8 +7,16 @@
 //!
 //! - **基础设施构建器**: 使用构建者模式组装基础设施组件
 //! - **配置源管理**: 统一管理多种类型的配置源
-//! - **组件扫描发现**: 自动化组件发现和注册
+//! - **组件扫描发现**: 自动化组件发现和注册，按类型名称约定推断生命周期和配置节
 //! - **生命周期管理**: 管理整个基础设施的启动和关闭
+//! - **启动流水线**: 按配置、验证、发现、注册、预热、生命周期启动和健康检查阶段启动，可插入自定义步骤并记录各阶段耗时
+//! - **启动报告**: 记录配置源、发现和注册的组件、配置验证问题、阶段耗时和启动结束时的健康状态，启动失败时附加在错误上
+//! - **内置健康检查**: 检查配置、依赖注入容器、磁盘、内存、运行时和 TCP 连通性
+//! - **健康检查端点**: 提供存活、就绪和启动探针的 HTTP 路由
+//! - **日志和追踪**: 分层日志订阅者，通过 OTLP 或 Jaeger 导出追踪并以 W3C Trace Context 传播
+//! - **运行时日志级别**: 日志过滤指令随配置热重载，可临时提高模块日志级别
+//! - **指标导出**: 记录依赖注入、配置和健康检查指标，通过 `/metrics` 端点导出给 Prometheus
+//! - **管理端点**: 受令牌保护的 `/admin` 路由，查看状态、组件、作用域和脱敏配置，触发配置重载和回滚
 //!
 //! ## 基本使用
 //!
@@ -36,17 +44,31 @@
 //! }
 //! ```
 
+pub mod admin;
+pub mod bootstrapper;
 pub mod builder;
 pub mod component_scanner;
 pub mod config_sources;
 pub mod enhanced_component_scanner;
+pub mod health_checks;
+pub mod health_endpoints;
 pub mod infrastructure;
+pub mod log_filter;
+pub mod metrics_exporter;
+pub mod telemetry;
 
 // 重新导出主要类型
+pub use admin::{AdminEndpoints, AdminStatus, ComponentState, ComponentStatus};
+pub use bootstrapper::{
+    BootstrapContext, BootstrapReport, BootstrapStage, BootstrapStep, ConfigSourceReport,
+    DiscoveredComponentReport, InfrastructureBootstrapper, RegistrationReport,
+    ValidationIssueReport,
+};
 pub use builder::InfrastructureBuilder;
 pub use component_scanner::{
     AdvancedComponentManager, ComponentDiscoveryStrategy, ComponentLifecycle,
-    ComponentRegistration, ComponentScannerBuilder, ComponentScannerImpl,
+    ComponentRegistration, ComponentScannerBuilder, ComponentScannerImpl, ConventionMatch,
+    UNSCOPED_COMPONENTS_TARGET,
 };
 
 #[cfg(test)]
@@ -64,7 +86,18 @@ pub use enhanced_component_scanner::{
     EnhancedComponentScannerImpl, ImplementorInfo, LoggingInterceptor, NameFilter, ScopeFilter,
     TraitDiscoverer,
 };
+pub use health_checks::{
+    ConfigHealthCheck, DiContainerHealthCheck, DiskSpaceHealthCheck, MemoryHealthCheck,
+    RuntimeHealthCheck, TcpHealthCheck,
+};
+pub use health_endpoints::{HealthEndpoints, HealthReport, HealthReportStatus};
 pub use infrastructure::{AdSystemInfrastructure, InfrastructureMetrics, InfrastructureStatus};
+pub use log_filter::{LogFilterHandle, LogFilterStatus, LogLevelOverride};
+pub use metrics_exporter::PrometheusExporter;
+pub use telemetry::{
+    extract_trace_context, inject_context, inject_trace_context, LoggingConfig, Telemetry,
+    TelemetryConfig, TraceExporter,
+};
 
 // 重新导出错误类型
 pub use infrastructure_common::InfrastructureError;
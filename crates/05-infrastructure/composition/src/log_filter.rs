@@ -0,0 +1,444 @@
+//! 运行时日志过滤
+//!
+//! 日志过滤器通过可重载的过滤层安装，[`LogFilterHandle`] 可以在运行时替换过滤
+//! 指令，或临时提高某个模块的日志级别并在到期后自动恢复。过滤指令可以绑定到
+//! 配置节（默认 `logging.filter`，如 `"info,ad_engine=debug"`），配置重载后
+//! 自动生效，无法解析的新指令会被拒绝并保留原过滤器。
+//!
+//! [`LogFilterHandle::router`] 提供管理端点：
+//!
+//! - `GET /admin/log-level` - 查看当前过滤指令和临时级别
+//! - `PUT /admin/log-level` - 替换过滤指令
+//! - `POST /admin/log-level/overrides` - 临时设置模块日志级别
+//! - `DELETE /admin/log-level/overrides/{target}` - 提前恢复模块日志级别
+
+use async_trait::async_trait;
+use axum::extract::{Path, State};
+use axum::http::StatusCode;
+use axum::response::{IntoResponse, Response};
+use axum::routing::{delete, get};
+use axum::{Json, Router};
+use config_abstractions::ConfigValidator;
+use config_impl::manager::AdSystemConfigManager;
+use config_impl::options::OptionsMonitor;
+use infrastructure_common::{ConfigError, InfrastructureError, ValidationError};
+use serde::{Deserialize, Serialize};
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::{Arc, Weak};
+use std::time::Duration;
+use tokio::task::JoinHandle;
+use tracing::level_filters::LevelFilter;
+use tracing_subscriber::{reload, EnvFilter, Registry};
+
+/// 默认绑定的日志过滤配置路径
+pub const LOG_FILTER_CONFIG_PATH: &str = "logging.filter";
+/// 日志级别管理端点路径
+pub const LOG_LEVEL_PATH: &str = "/admin/log-level";
+/// 临时日志级别管理端点路径
+pub const LOG_LEVEL_OVERRIDES_PATH: &str = "/admin/log-level/overrides";
+
+/// 解析过滤指令，语法同 `RUST_LOG`，空指令表示 `info`
+pub fn parse_log_filter(filter: &str) -> Result<EnvFilter, InfrastructureError> {
+    EnvFilter::builder()
+        .with_default_directive(LevelFilter::INFO.into())
+        .parse(filter)
+        .map_err(|e| invalid_value("filter", filter, e.to_string()))
+}
+
+/// 参数无效错误
+fn invalid_value(field: &str, value: &str, reason: impl Into<String>) -> InfrastructureError {
+    InfrastructureError::ValidationError {
+        source: ValidationError::invalid_field_value(field, value, reason),
+    }
+}
+
+/// 临时日志级别
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct LogLevelOverride {
+    /// 模块路径，如 `ad_engine::ranking`
+    pub target: String,
+    /// 日志级别
+    pub level: String,
+    /// 自动恢复时间
+    pub expires_at: chrono::DateTime<chrono::Utc>,
+}
+
+/// 日志过滤状态
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct LogFilterStatus {
+    /// 配置的过滤指令
+    pub filter: String,
+    /// 叠加临时级别后生效的过滤指令
+    pub effective_filter: String,
+    /// 生效中的临时级别
+    pub overrides: Vec<LogLevelOverride>,
+}
+
+/// 生效中的临时级别及其恢复任务
+struct ActiveOverride {
+    /// 标识，同一模块被重新设置后旧的恢复任务不再生效
+    id: u64,
+    /// 临时级别
+    value: LogLevelOverride,
+    /// 自动恢复任务
+    revert: JoinHandle<()>,
+}
+
+/// 日志过滤共享状态
+struct LogFilterState {
+    /// 过滤层重载句柄
+    reload: reload::Handle<EnvFilter, Registry>,
+    /// 配置的过滤指令
+    filter: parking_lot::Mutex<String>,
+    /// 生效中的临时级别
+    overrides: parking_lot::Mutex<Vec<ActiveOverride>>,
+    /// 下一个临时级别标识
+    next_override_id: AtomicU64,
+    /// 绑定的配置
+    binding: parking_lot::Mutex<Option<OptionsMonitor<String>>>,
+}
+
+impl LogFilterState {
+    /// 叠加临时级别后的过滤指令
+    fn effective_filter(filter: &str, overrides: &[ActiveOverride]) -> String {
+        std::iter::once(filter)
+            .filter(|filter| !filter.is_empty())
+            .map(str::to_string)
+            .chain(
+                overrides
+                    .iter()
+                    .map(|active| format!("{}={}", active.value.target, active.value.level)),
+            )
+            .collect::<Vec<_>>()
+            .join(",")
+    }
+
+    /// 按当前过滤指令和临时级别重新安装过滤器
+    fn apply(&self, filter: &str, overrides: &[ActiveOverride]) -> Result<(), InfrastructureError> {
+        let effective = Self::effective_filter(filter, overrides);
+        let env_filter = parse_log_filter(&effective)?;
+        self.reload
+            .reload(env_filter)
+            .map_err(|e| InfrastructureError::BootstrapFailed {
+                message: format!("重新安装日志过滤器失败: {}", e),
+            })
+    }
+
+    /// 移除指定标识的临时级别
+    fn expire(&self, id: u64) {
+        let mut overrides = self.overrides.lock();
+        let Some(position) = overrides.iter().position(|active| active.id == id) else {
+            return;
+        };
+        let expired = overrides.remove(position);
+        if let Err(e) = self.apply(&self.filter.lock(), &overrides) {
+            tracing::warn!("恢复日志级别失败: {}", e);
+        }
+        tracing::info!("模块 {} 的临时日志级别已到期", expired.value.target);
+    }
+}
+
+impl Drop for LogFilterState {
+    fn drop(&mut self) {
+        for active in self.overrides.get_mut().drain(..) {
+            active.revert.abort();
+        }
+    }
+}
+
+/// 日志过滤句柄
+///
+/// 克隆的句柄共享同一个过滤器
+#[derive(Clone)]
+pub struct LogFilterHandle {
+    /// 共享状态
+    state: Arc<LogFilterState>,
+}
+
+impl std::fmt::Debug for LogFilterHandle {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("LogFilterHandle")
+            .field("filter", &self.filter())
+            .field("overrides", &self.overrides())
+            .finish()
+    }
+}
+
+impl LogFilterHandle {
+    /// 创建可重载的过滤层及其句柄
+    pub(crate) fn new(
+        filter: impl Into<String>,
+    ) -> Result<(reload::Layer<EnvFilter, Registry>, Self), InfrastructureError> {
+        let filter = filter.into();
+        let (layer, reload) = reload::Layer::new(parse_log_filter(&filter)?);
+        let handle = Self {
+            state: Arc::new(LogFilterState {
+                reload,
+                filter: parking_lot::Mutex::new(filter),
+                overrides: parking_lot::Mutex::new(Vec::new()),
+                next_override_id: AtomicU64::new(1),
+                binding: parking_lot::Mutex::new(None),
+            }),
+        };
+        Ok((layer, handle))
+    }
+
+    /// 获取配置的过滤指令
+    pub fn filter(&self) -> String {
+        self.state.filter.lock().clone()
+    }
+
+    /// 获取叠加临时级别后生效的过滤指令
+    pub fn effective_filter(&self) -> String {
+        let overrides = self.state.overrides.lock();
+        LogFilterState::effective_filter(&self.state.filter.lock(), &overrides)
+    }
+
+    /// 获取生效中的临时级别
+    pub fn overrides(&self) -> Vec<LogLevelOverride> {
+        self.state
+            .overrides
+            .lock()
+            .iter()
+            .map(|active| active.value.clone())
+            .collect()
+    }
+
+    /// 获取过滤状态
+    pub fn status(&self) -> LogFilterStatus {
+        LogFilterStatus {
+            filter: self.filter(),
+            effective_filter: self.effective_filter(),
+            overrides: self.overrides(),
+        }
+    }
+
+    /// 替换过滤指令，临时级别继续生效
+    ///
+    /// 指令无法解析时保留原过滤器
+    pub fn set_filter(&self, filter: &str) -> Result<(), InfrastructureError> {
+        let overrides = self.state.overrides.lock();
+        let mut current = self.state.filter.lock();
+        self.state.apply(filter, &overrides)?;
+        if *current != filter {
+            tracing::info!("日志过滤指令已更新: {:?} -> {:?}", *current, filter);
+            *current = filter.to_string();
+        }
+        Ok(())
+    }
+
+    /// 临时设置模块的日志级别，到期后自动恢复
+    ///
+    /// 同一模块重复设置时替换原有的临时级别并重新计时。需要在 tokio 运行时中调用
+    pub fn set_override(
+        &self,
+        target: &str,
+        level: &str,
+        duration: Duration,
+    ) -> Result<LogLevelOverride, InfrastructureError> {
+        if target.is_empty() || target.contains([',', '=', '[', ']', ' ']) {
+            return Err(invalid_value("target", target, "模块路径无效"));
+        }
+        let level: LevelFilter = level
+            .parse()
+            .map_err(|_| invalid_value("level", level, "日志级别无效"))?;
+        let expires_in = chrono::Duration::from_std(duration)
+            .map_err(|e| invalid_value("duration", &format!("{:?}", duration), e.to_string()))?;
+
+        let value = LogLevelOverride {
+            target: target.to_string(),
+            level: level.to_string(),
+            expires_at: chrono::Utc::now() + expires_in,
+        };
+        let id = self.state.next_override_id.fetch_add(1, Ordering::Relaxed);
+        let state: Weak<LogFilterState> = Arc::downgrade(&self.state);
+        let revert = tokio::spawn(async move {
+            tokio::time::sleep(duration).await;
+            if let Some(state) = state.upgrade() {
+                state.expire(id);
+            }
+        });
+
+        let mut overrides = self.state.overrides.lock();
+        let replaced = overrides
+            .iter()
+            .position(|active| active.value.target == target)
+            .map(|position| overrides.remove(position));
+        overrides.push(ActiveOverride {
+            id,
+            value: value.clone(),
+            revert,
+        });
+
+        if let Err(e) = self.state.apply(&self.state.filter.lock(), &overrides) {
+            if let Some(active) = overrides.pop() {
+                active.revert.abort();
+            }
+            overrides.extend(replaced);
+            return Err(e);
+        }
+        if let Some(replaced) = replaced {
+            replaced.revert.abort();
+        }
+
+        tracing::info!(
+            "模块 {} 的日志级别临时设置为 {}，{:?} 后恢复",
+            value.target,
+            value.level,
+            duration
+        );
+        Ok(value)
+    }
+
+    /// 提前恢复模块的日志级别，没有临时级别时返回 false
+    pub fn clear_override(&self, target: &str) -> Result<bool, InfrastructureError> {
+        let mut overrides = self.state.overrides.lock();
+        let Some(position) = overrides
+            .iter()
+            .position(|active| active.value.target == target)
+        else {
+            return Ok(false);
+        };
+
+        let cleared = overrides.remove(position);
+        cleared.revert.abort();
+        self.state.apply(&self.state.filter.lock(), &overrides)?;
+        Ok(true)
+    }
+
+    /// 将过滤指令绑定到配置路径，配置重载后自动更新
+    ///
+    /// 配置中没有该路径时不绑定并返回 false；重载后的指令无法解析时保留原过滤器
+    pub async fn bind_config(
+        &self,
+        config_manager: &AdSystemConfigManager,
+        path: &str,
+    ) -> Result<bool, InfrastructureError> {
+        let monitor = match config_manager
+            .options_monitor_with_validator::<String>(
+                path,
+                Arc::new(LogFilterValidator {
+                    path: path.to_string(),
+                }),
+            )
+            .await
+        {
+            Ok(monitor) => monitor,
+            Err(ConfigError::KeyNotFound { .. }) => return Ok(false),
+            Err(e) => return Err(InfrastructureError::ConfigError { source: e }),
+        };
+
+        self.set_filter(&monitor.current())?;
+        let state = Arc::downgrade(&self.state);
+        monitor.on_change(move |filter: &String| {
+            if let Some(state) = state.upgrade() {
+                if let Err(e) = (LogFilterHandle { state }).set_filter(filter) {
+                    tracing::warn!("应用配置的日志过滤指令失败: {}", e);
+                }
+            }
+        });
+        *self.state.binding.lock() = Some(monitor);
+        Ok(true)
+    }
+
+    /// 创建日志级别管理路由
+    pub fn router(self) -> Router {
+        Router::new()
+            .route(LOG_LEVEL_PATH, get(get_status).put(put_filter))
+            .route(LOG_LEVEL_OVERRIDES_PATH, axum::routing::post(post_override))
+            .route(
+                &format!("{}/:target", LOG_LEVEL_OVERRIDES_PATH),
+                delete(delete_override),
+            )
+            .with_state(self)
+    }
+}
+
+/// 过滤指令配置验证器
+struct LogFilterValidator {
+    /// 配置路径
+    path: String,
+}
+
+#[async_trait]
+impl ConfigValidator<String> for LogFilterValidator {
+    async fn validate(&self, config: &String) -> Result<(), ValidationError> {
+        parse_log_filter(config).map(|_| ()).map_err(|e| {
+            ValidationError::invalid_field_value(&self.path, config.clone(), e.to_string())
+        })
+    }
+
+    fn name(&self) -> &str {
+        "LogFilterValidator"
+    }
+}
+
+/// 替换过滤指令请求
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SetLogFilterRequest {
+    /// 过滤指令
+    pub filter: String,
+}
+
+/// 临时日志级别请求
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SetLogLevelOverrideRequest {
+    /// 模块路径
+    pub target: String,
+    /// 日志级别
+    pub level: String,
+    /// 持续秒数
+    pub duration_secs: u64,
+}
+
+/// 请求参数错误响应
+fn bad_request(error: InfrastructureError) -> Response {
+    (
+        StatusCode::BAD_REQUEST,
+        Json(serde_json::json!({ "error": error.to_string() })),
+    )
+        .into_response()
+}
+
+/// 查看日志过滤状态
+async fn get_status(State(handle): State<LogFilterHandle>) -> Json<LogFilterStatus> {
+    Json(handle.status())
+}
+
+/// 替换过滤指令
+async fn put_filter(
+    State(handle): State<LogFilterHandle>,
+    Json(request): Json<SetLogFilterRequest>,
+) -> Response {
+    match handle.set_filter(&request.filter) {
+        Ok(()) => Json(handle.status()).into_response(),
+        Err(e) => bad_request(e),
+    }
+}
+
+/// 临时设置模块日志级别
+async fn post_override(
+    State(handle): State<LogFilterHandle>,
+    Json(request): Json<SetLogLevelOverrideRequest>,
+) -> Response {
+    match handle.set_override(
+        &request.target,
+        &request.level,
+        Duration::from_secs(request.duration_secs),
+    ) {
+        Ok(value) => Json(value).into_response(),
+        Err(e) => bad_request(e),
+    }
+}
+
+/// 提前恢复模块日志级别
+async fn delete_override(
+    State(handle): State<LogFilterHandle>,
+    Path(target): Path<String>,
+) -> Response {
+    match handle.clear_override(&target) {
+        Ok(true) => StatusCode::NO_CONTENT.into_response(),
+        Ok(false) => StatusCode::NOT_FOUND.into_response(),
+        Err(e) => bad_request(e),
+    }
+}
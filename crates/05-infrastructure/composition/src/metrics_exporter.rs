@@ -0,0 +1,83 @@
+//! Prometheus 指标导出
+//!
+//! 将 `metrics` 门面的全局记录器安装为 Prometheus 记录器，并通过 `/metrics`
+//! 端点以 Prometheus 文本格式暴露基础设施指标，指标定义见
+//! [`infrastructure_common::instrumentation`]。
+
+use axum::extract::State;
+use axum::http::header;
+use axum::response::IntoResponse;
+use axum::routing::get;
+use axum::Router;
+use infrastructure_common::{describe_infrastructure_metrics, InfrastructureError};
+use metrics_exporter_prometheus::{Matcher, PrometheusBuilder, PrometheusHandle};
+use once_cell::sync::OnceCell;
+
+/// 指标端点路径
+pub const METRICS_PATH: &str = "/metrics";
+
+/// Prometheus 文本格式的内容类型
+const CONTENT_TYPE: &str = "text/plain; version=0.0.4";
+
+/// 耗时类直方图的桶边界（秒）
+const DURATION_BUCKETS: &[f64] = &[
+    0.0001, 0.0005, 0.001, 0.005, 0.01, 0.025, 0.05, 0.1, 0.25, 0.5, 1.0, 2.5, 5.0, 10.0,
+];
+
+/// 进程内唯一的 Prometheus 记录器句柄
+static HANDLE: OnceCell<PrometheusHandle> = OnceCell::new();
+
+/// Prometheus 指标导出器
+///
+/// 全局记录器在进程内只能安装一次，重复调用 [`install`](Self::install)
+/// 返回同一个记录器的导出器
+#[derive(Clone)]
+pub struct PrometheusExporter {
+    /// 记录器句柄
+    handle: PrometheusHandle,
+}
+
+impl std::fmt::Debug for PrometheusExporter {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("PrometheusExporter").finish_non_exhaustive()
+    }
+}
+
+impl PrometheusExporter {
+    /// 安装全局 Prometheus 记录器
+    ///
+    /// 已安装其他记录器时返回错误
+    pub fn install() -> Result<Self, InfrastructureError> {
+        let handle = HANDLE.get_or_try_init(|| {
+            let handle = PrometheusBuilder::new()
+                .set_buckets_for_metric(Matcher::Suffix("_seconds".to_string()), DURATION_BUCKETS)
+                .and_then(PrometheusBuilder::install_recorder)
+                .map_err(|e| InfrastructureError::BootstrapFailed {
+                    message: format!("安装 Prometheus 指标记录器失败: {}", e),
+                })?;
+            describe_infrastructure_metrics();
+            Ok::<_, InfrastructureError>(handle)
+        })?;
+
+        Ok(Self {
+            handle: handle.clone(),
+        })
+    }
+
+    /// 以 Prometheus 文本格式输出当前指标
+    pub fn render(&self) -> String {
+        self.handle.render()
+    }
+
+    /// 创建指标路由，提供 `/metrics` 端点
+    pub fn router(self) -> Router {
+        Router::new()
+            .route(METRICS_PATH, get(metrics))
+            .with_state(self)
+    }
+}
+
+/// 指标端点处理函数
+async fn metrics(State(exporter): State<PrometheusExporter>) -> impl IntoResponse {
+    ([(header::CONTENT_TYPE, CONTENT_TYPE)], exporter.render())
+}
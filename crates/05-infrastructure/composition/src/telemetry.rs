@@ -0,0 +1,416 @@
+//! 日志和分布式追踪
+//!
+//! [`TelemetryConfig`] 描述日志输出格式、按模块过滤的日志指令、追踪采样率、
+//! 服务资源属性和追踪导出端点，用于构建分层的 `tracing` 订阅者：
+//!
+//! - `EnvFilter` - 按日志指令过滤，可在运行时重载，见 [`crate::log_filter`]
+//! - `fmt` - 文本或 JSON 格式的日志输出
+//! - `tracing-opentelemetry` - 将 span 导出到 OTLP 或 Jaeger
+//!
+//! 跨服务调用通过 [`inject_trace_context`] 和 [`extract_trace_context`] 以
+//! W3C Trace Context（`traceparent`/`tracestate` 请求头）传播追踪上下文。
+
+use crate::log_filter::{parse_log_filter, LogFilterHandle};
+use axum::http::{HeaderMap, HeaderName, HeaderValue};
+use infrastructure_common::InfrastructureError;
+use opentelemetry::propagation::{Extractor, Injector, TextMapPropagator};
+use opentelemetry::trace::TracerProvider as _;
+use opentelemetry::{global, Context, KeyValue};
+use opentelemetry_otlp::WithExportConfig;
+use opentelemetry_sdk::export::trace::SpanExporter;
+use opentelemetry_sdk::propagation::TraceContextPropagator;
+use opentelemetry_sdk::trace::{Sampler, TracerProvider};
+use opentelemetry_sdk::{runtime, Resource};
+use std::collections::BTreeMap;
+use std::time::Duration;
+use tracing::level_filters::LevelFilter;
+use tracing::Subscriber;
+use tracing_opentelemetry::OpenTelemetrySpanExt;
+use tracing_subscriber::layer::SubscriberExt;
+use tracing_subscriber::{EnvFilter, Layer};
+
+/// 默认服务名称
+pub const DEFAULT_SERVICE_NAME: &str = "lorn-adsp";
+
+/// 追踪导出方式
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub enum TraceExporter {
+    /// 不导出追踪数据，只输出日志
+    #[default]
+    None,
+    /// 通过 gRPC 导出到 OTLP 收集器，如 `http://localhost:4317`
+    Otlp {
+        /// 收集器地址
+        endpoint: String,
+    },
+    /// 通过 UDP 导出到 Jaeger agent，如 `localhost:6831`
+    Jaeger {
+        /// agent 地址
+        endpoint: String,
+    },
+}
+
+/// 日志和追踪配置
+#[derive(Debug, Clone)]
+pub struct TelemetryConfig {
+    /// 日志级别，未被日志指令覆盖的模块使用此级别
+    pub level: tracing::Level,
+    /// 按模块过滤的日志指令，语法同 `RUST_LOG`，如 `config_impl=debug`
+    pub directives: Vec<String>,
+    /// 是否显示目标
+    pub show_target: bool,
+    /// 是否显示线程ID
+    pub show_thread_ids: bool,
+    /// 是否显示文件名
+    pub show_file: bool,
+    /// 是否显示行号
+    pub show_line_number: bool,
+    /// 是否使用 JSON 格式
+    pub json_format: bool,
+    /// 服务名称，作为 `service.name` 资源属性
+    pub service_name: String,
+    /// 服务版本，作为 `service.version` 资源属性
+    pub service_version: String,
+    /// 附加的资源属性，如 `deployment.environment`
+    pub resource_attributes: BTreeMap<String, String>,
+    /// 根 span 的采样率，取值 0.0 到 1.0，子 span 跟随父 span 的采样结果
+    pub sampling_ratio: f64,
+    /// 追踪导出方式
+    pub exporter: TraceExporter,
+    /// 单次导出的超时时间
+    pub export_timeout: Duration,
+}
+
+/// 日志配置，即不导出追踪数据的 [`TelemetryConfig`]
+pub type LoggingConfig = TelemetryConfig;
+
+impl Default for TelemetryConfig {
+    fn default() -> Self {
+        Self {
+            level: tracing::Level::INFO,
+            directives: Vec::new(),
+            show_target: true,
+            show_thread_ids: false,
+            show_file: false,
+            show_line_number: false,
+            json_format: false,
+            service_name: DEFAULT_SERVICE_NAME.to_string(),
+            service_version: env!("CARGO_PKG_VERSION").to_string(),
+            resource_attributes: BTreeMap::new(),
+            sampling_ratio: 1.0,
+            exporter: TraceExporter::None,
+            export_timeout: Duration::from_secs(10),
+        }
+    }
+}
+
+impl TelemetryConfig {
+    /// 创建开发环境日志配置
+    pub fn development() -> Self {
+        Self {
+            level: tracing::Level::DEBUG,
+            show_target: true,
+            show_thread_ids: true,
+            show_file: true,
+            show_line_number: true,
+            json_format: false,
+            ..Self::default()
+        }
+    }
+
+    /// 创建生产环境日志配置
+    pub fn production() -> Self {
+        Self {
+            level: tracing::Level::INFO,
+            show_target: false,
+            show_thread_ids: false,
+            show_file: false,
+            show_line_number: false,
+            json_format: true,
+            ..Self::default()
+        }
+    }
+
+    /// 设置日志级别
+    pub fn with_level(mut self, level: tracing::Level) -> Self {
+        self.level = level;
+        self
+    }
+
+    /// 添加日志指令，如 `di_impl=trace`
+    pub fn with_directive<S: Into<String>>(mut self, directive: S) -> Self {
+        self.directives.push(directive.into());
+        self
+    }
+
+    /// 设置服务名称和版本
+    pub fn with_service<N: Into<String>, V: Into<String>>(mut self, name: N, version: V) -> Self {
+        self.service_name = name.into();
+        self.service_version = version.into();
+        self
+    }
+
+    /// 添加资源属性
+    pub fn with_resource_attribute<K: Into<String>, V: Into<String>>(
+        mut self,
+        key: K,
+        value: V,
+    ) -> Self {
+        self.resource_attributes.insert(key.into(), value.into());
+        self
+    }
+
+    /// 设置根 span 的采样率，超出 0.0 到 1.0 的值会被截断
+    pub fn with_sampling_ratio(mut self, ratio: f64) -> Self {
+        self.sampling_ratio = ratio.clamp(0.0, 1.0);
+        self
+    }
+
+    /// 通过 OTLP 导出追踪数据
+    pub fn with_otlp_exporter<S: Into<String>>(mut self, endpoint: S) -> Self {
+        self.exporter = TraceExporter::Otlp {
+            endpoint: endpoint.into(),
+        };
+        self
+    }
+
+    /// 通过 Jaeger agent 导出追踪数据
+    pub fn with_jaeger_exporter<S: Into<String>>(mut self, endpoint: S) -> Self {
+        self.exporter = TraceExporter::Jaeger {
+            endpoint: endpoint.into(),
+        };
+        self
+    }
+
+    /// 设置导出超时时间
+    pub fn with_export_timeout(mut self, timeout: Duration) -> Self {
+        self.export_timeout = timeout;
+        self
+    }
+
+    /// 日志级别和日志指令组成的过滤指令，如 `info,config_impl=debug`
+    pub fn filter_directives(&self) -> String {
+        std::iter::once(LevelFilter::from_level(self.level).to_string())
+            .chain(self.directives.iter().cloned())
+            .collect::<Vec<_>>()
+            .join(",")
+    }
+
+    /// 根据日志级别和日志指令创建过滤器
+    pub fn env_filter(&self) -> Result<EnvFilter, InfrastructureError> {
+        parse_log_filter(&self.filter_directives())
+    }
+
+    /// 服务资源，包含服务名称、版本和附加的资源属性
+    pub fn resource(&self) -> Resource {
+        let mut attributes = vec![
+            KeyValue::new("service.name", self.service_name.clone()),
+            KeyValue::new("service.version", self.service_version.clone()),
+        ];
+        attributes.extend(
+            self.resource_attributes
+                .iter()
+                .map(|(key, value)| KeyValue::new(key.clone(), value.clone())),
+        );
+        Resource::new(attributes)
+    }
+
+    /// 采样器，根 span 按采样率采样，子 span 跟随父 span
+    pub fn sampler(&self) -> Sampler {
+        Sampler::ParentBased(Box::new(Sampler::TraceIdRatioBased(
+            self.sampling_ratio.clamp(0.0, 1.0),
+        )))
+    }
+
+    /// 按配置的导出方式创建追踪提供者，未配置导出时返回 None
+    ///
+    /// 需要在 tokio 运行时中调用，导出在后台批量进行
+    pub fn tracer_provider(&self) -> Result<Option<TracerProvider>, InfrastructureError> {
+        let map_err = |e: opentelemetry::trace::TraceError| InfrastructureError::BootstrapFailed {
+            message: format!("创建追踪导出器失败: {}", e),
+        };
+
+        let builder = match &self.exporter {
+            TraceExporter::None => return Ok(None),
+            TraceExporter::Otlp { endpoint } => {
+                let exporter = opentelemetry_otlp::new_exporter()
+                    .tonic()
+                    .with_endpoint(endpoint.clone())
+                    .with_timeout(self.export_timeout)
+                    .build_span_exporter()
+                    .map_err(map_err)?;
+                TracerProvider::builder().with_batch_exporter(exporter, runtime::Tokio)
+            }
+            TraceExporter::Jaeger { endpoint } => {
+                let exporter = opentelemetry_jaeger::new_agent_pipeline()
+                    .with_endpoint(endpoint.clone())
+                    .with_service_name(self.service_name.clone())
+                    .with_trace_config(
+                        opentelemetry_sdk::trace::config().with_resource(self.resource()),
+                    )
+                    .build_async_agent_exporter(runtime::Tokio)
+                    .map_err(map_err)?;
+                TracerProvider::builder().with_batch_exporter(exporter, runtime::Tokio)
+            }
+        };
+
+        Ok(Some(builder.with_config(self.trace_config()).build()))
+    }
+
+    /// 使用指定的导出器创建追踪提供者，每个 span 结束时同步导出
+    ///
+    /// 用于测试或自定义导出，如 `opentelemetry_sdk` 的内存导出器
+    pub fn tracer_provider_with_exporter<E>(&self, exporter: E) -> TracerProvider
+    where
+        E: SpanExporter + 'static,
+    {
+        TracerProvider::builder()
+            .with_simple_exporter(exporter)
+            .with_config(self.trace_config())
+            .build()
+    }
+
+    /// 创建分层的订阅者，指定追踪提供者时同时将 span 导出
+    ///
+    /// 返回的订阅者可以安装为全局订阅者，也可以通过
+    /// [`tracing::subscriber::with_default`] 在局部使用；过滤层可以通过
+    /// 同时返回的 [`LogFilterHandle`] 在运行时替换
+    pub fn subscriber(
+        &self,
+        tracer_provider: Option<&TracerProvider>,
+    ) -> Result<(impl Subscriber + Send + Sync, LogFilterHandle), InfrastructureError> {
+        let (filter_layer, log_filter) = LogFilterHandle::new(self.filter_directives())?;
+        let fmt_layer = tracing_subscriber::fmt::layer()
+            .with_target(self.show_target)
+            .with_thread_ids(self.show_thread_ids)
+            .with_file(self.show_file)
+            .with_line_number(self.show_line_number);
+        let fmt_layer = if self.json_format {
+            fmt_layer.json().boxed()
+        } else {
+            fmt_layer.boxed()
+        };
+        let otel_layer = tracer_provider.map(|provider| {
+            tracing_opentelemetry::layer().with_tracer(provider.tracer(self.service_name.clone()))
+        });
+
+        let subscriber = tracing_subscriber::registry()
+            .with(filter_layer)
+            .with(fmt_layer)
+            .with(otel_layer);
+        Ok((subscriber, log_filter))
+    }
+
+    /// 安装全局订阅者、追踪提供者和 W3C Trace Context 传播器
+    ///
+    /// 全局订阅者只能安装一次，重复安装返回错误
+    pub fn init(&self) -> Result<Telemetry, InfrastructureError> {
+        let tracer_provider = self.tracer_provider()?;
+        let (subscriber, log_filter) = self.subscriber(tracer_provider.as_ref())?;
+        tracing::subscriber::set_global_default(subscriber).map_err(|e| {
+            InfrastructureError::BootstrapFailed {
+                message: format!("日志初始化失败: {}", e),
+            }
+        })?;
+
+        global::set_text_map_propagator(TraceContextPropagator::new());
+        if let Some(provider) = &tracer_provider {
+            global::set_tracer_provider(provider.clone());
+        }
+
+        Ok(Telemetry {
+            tracer_provider,
+            log_filter,
+        })
+    }
+
+    /// SDK 追踪配置
+    fn trace_config(&self) -> opentelemetry_sdk::trace::Config {
+        opentelemetry_sdk::trace::config()
+            .with_sampler(self.sampler())
+            .with_resource(self.resource())
+    }
+}
+
+/// 已安装的日志和追踪
+///
+/// 持有追踪提供者和日志过滤句柄，停止时刷新尚未导出的 span
+#[derive(Debug, Clone)]
+pub struct Telemetry {
+    /// 追踪提供者，未配置导出时为 None
+    tracer_provider: Option<TracerProvider>,
+    /// 日志过滤句柄
+    log_filter: LogFilterHandle,
+}
+
+impl Telemetry {
+    /// 获取追踪提供者
+    pub fn tracer_provider(&self) -> Option<&TracerProvider> {
+        self.tracer_provider.as_ref()
+    }
+
+    /// 获取日志过滤句柄
+    pub fn log_filter(&self) -> &LogFilterHandle {
+        &self.log_filter
+    }
+
+    /// 导出所有已结束但尚未导出的 span
+    pub async fn flush(&self) {
+        let Some(provider) = self.tracer_provider.clone() else {
+            return;
+        };
+
+        // 批量导出器在刷新时阻塞等待后台任务，不能占用当前的运行时线程
+        let results = tokio::task::spawn_blocking(move || provider.force_flush()).await;
+        for result in results.into_iter().flatten() {
+            if let Err(e) = result {
+                tracing::warn!("导出追踪数据失败: {}", e);
+            }
+        }
+    }
+}
+
+/// 将当前 span 的追踪上下文以 W3C Trace Context 格式写入请求头
+pub fn inject_trace_context(headers: &mut HeaderMap) {
+    inject_context(&tracing::Span::current().context(), headers);
+}
+
+/// 将指定的追踪上下文以 W3C Trace Context 格式写入请求头
+pub fn inject_context(context: &Context, headers: &mut HeaderMap) {
+    TraceContextPropagator::new().inject_context(context, &mut HeaderInjector(headers));
+}
+
+/// 从请求头中读取 W3C Trace Context 追踪上下文
+///
+/// 通过 [`OpenTelemetrySpanExt::set_parent`] 将其设置为 span 的父上下文，
+/// 请求头中没有追踪上下文时返回空上下文
+pub fn extract_trace_context(headers: &HeaderMap) -> Context {
+    TraceContextPropagator::new().extract(&HeaderExtractor(headers))
+}
+
+/// 请求头写入适配器
+struct HeaderInjector<'a>(&'a mut HeaderMap);
+
+impl Injector for HeaderInjector<'_> {
+    fn set(&mut self, key: &str, value: String) {
+        if let (Ok(name), Ok(value)) = (
+            HeaderName::from_bytes(key.as_bytes()),
+            HeaderValue::from_str(&value),
+        ) {
+            self.0.insert(name, value);
+        }
+    }
+}
+
+/// 请求头读取适配器
+struct HeaderExtractor<'a>(&'a HeaderMap);
+
+impl Extractor for HeaderExtractor<'_> {
+    fn get(&self, key: &str) -> Option<&str> {
+        self.0.get(key).and_then(|value| value.to_str().ok())
+    }
+
+    fn keys(&self) -> Vec<&str> {
+        self.0.keys().map(HeaderName::as_str).collect()
+    }
+}
@@ -258,6 +258,1743 @@ async fn test_infrastructure_config_access() {
     }
 }
 
+/// 测试通过依赖注入获取类型化实时配置
+#[tokio::test]
+async fn test_options_monitor_resolved_from_container() {
+    #[derive(Debug, serde::Deserialize)]
+    struct AppOptions {
+        name: String,
+    }
+
+    let temp_file = NamedTempFile::new().unwrap();
+    let config_path = temp_file.path();
+    fs::write(config_path, json!({"app": {"name": "before"}}).to_string())
+        .await
+        .unwrap();
+
+    let infrastructure = InfrastructureBuilder::new()
+        .add_config_json(config_path)
+        .expect("添加配置文件应该成功")
+        .add_options::<AppOptions>("app")
+        .build()
+        .await
+        .expect("构建基础设施应该成功");
+
+    let monitor = infrastructure
+        .resolve::<config_impl::options::OptionsMonitor<AppOptions>>()
+        .await
+        .expect("应该能解析实时配置");
+    assert_eq!(monitor.current().name, "before");
+
+    let mut receiver = monitor.subscribe();
+    fs::write(config_path, json!({"app": {"name": "after"}}).to_string())
+        .await
+        .unwrap();
+    infrastructure.reload_configuration().await.unwrap();
+
+    tokio::time::timeout(std::time::Duration::from_secs(5), receiver.changed())
+        .await
+        .expect("等待配置更新超时")
+        .unwrap();
+    assert_eq!(monitor.current().name, "after");
+}
+
+/// 测试命令行参数覆盖配置文件并参与来源追踪
+#[tokio::test]
+async fn test_command_line_overrides_config_file() {
+    let temp_file = NamedTempFile::new().unwrap();
+    let config_path = temp_file.path();
+    fs::write(
+        config_path,
+        json!({"server": {"port": 8080, "host": "0.0.0.0"}}).to_string(),
+    )
+    .await
+    .unwrap();
+
+    let infrastructure = InfrastructureBuilder::new()
+        .add_config_json(config_path)
+        .expect("添加配置文件应该成功")
+        .add_config_args(["--verbose", "--server.port=9090", "--set", "server.workers=4"])
+        .expect("添加命令行参数应该成功")
+        .build()
+        .await
+        .expect("构建基础设施应该成功");
+
+    assert_eq!(infrastructure.get_config::<u16>("server.port").await.unwrap(), 9090);
+    assert_eq!(infrastructure.get_config::<u32>("server.workers").await.unwrap(), 4);
+    assert_eq!(
+        infrastructure.get_config::<String>("server.host").await.unwrap(),
+        "0.0.0.0"
+    );
+
+    let explanation = infrastructure
+        .config_manager()
+        .explain_configuration("server.port")
+        .await
+        .unwrap();
+    assert_eq!(explanation.winner.unwrap().source, "cli:--server.port");
+}
+
+/// 测试通过依赖注入获取功能开关
+#[tokio::test]
+async fn test_feature_flags_resolved_from_container() {
+    let temp_file = NamedTempFile::new().unwrap();
+    let config_path = temp_file.path();
+    fs::write(
+        config_path,
+        json!({"feature_flags": {"new_bidder": {"countries": ["US"]}}}).to_string(),
+    )
+    .await
+    .unwrap();
+
+    let infrastructure = InfrastructureBuilder::new()
+        .add_config_json(config_path)
+        .expect("添加配置文件应该成功")
+        .with_feature_flags("feature_flags")
+        .build()
+        .await
+        .expect("构建基础设施应该成功");
+
+    let flags = infrastructure
+        .resolve::<config_impl::feature_flags::FeatureFlags>()
+        .await
+        .expect("应该能解析功能开关");
+    let context = config_impl::feature_flags::FeatureContext::new("user-1");
+    assert!(flags.is_enabled("new_bidder", &context.clone().with_country("US")));
+    assert!(!flags.is_enabled("new_bidder", &context.with_country("DE")));
+    assert_eq!(flags.exposures()["new_bidder"].enabled, 1);
+}
+
+/// 测试用健康检查器，返回固定状态
+struct StaticHealthCheck {
+    name: &'static str,
+    status: infrastructure_common::HealthStatus,
+    probes: Vec<infrastructure_common::HealthProbe>,
+}
+
+#[async_trait::async_trait]
+impl infrastructure_common::HealthCheckable for StaticHealthCheck {
+    async fn check_health(&self) -> infrastructure_common::HealthStatus {
+        self.status.clone()
+    }
+
+    fn name(&self) -> &str {
+        self.name
+    }
+
+    fn probes(&self) -> &[infrastructure_common::HealthProbe] {
+        &self.probes
+    }
+}
+
+/// 辅助函数：请求健康检查路由并解析响应
+async fn request_probe(router: &axum::Router, path: &str) -> (u16, serde_json::Value) {
+    use tower::ServiceExt;
+
+    let response = router
+        .clone()
+        .oneshot(
+            axum::http::Request::get(path)
+                .body(axum::body::Body::empty())
+                .unwrap(),
+        )
+        .await
+        .unwrap();
+    let status = response.status().as_u16();
+    let body = axum::body::to_bytes(response.into_body(), usize::MAX)
+        .await
+        .unwrap();
+    (status, serde_json::from_slice(&body).unwrap())
+}
+
+/// 测试健康检查端点按探针筛选检查，降级返回 200 并附带警告，不健康返回 503
+#[tokio::test]
+async fn test_health_probe_endpoints() {
+    use infrastructure_common::{HealthProbe, HealthStatus};
+
+    let infrastructure = InfrastructureBuilder::new()
+        .auto_configure_development()
+        .add_health_check(StaticHealthCheck {
+            name: "bidder_pool",
+            status: HealthStatus::degraded("可用竞价方不足"),
+            probes: vec![HealthProbe::Readiness],
+        })
+        .add_health_check(StaticHealthCheck {
+            name: "event_loop",
+            status: HealthStatus::unhealthy("事件循环阻塞"),
+            probes: vec![HealthProbe::Liveness],
+        })
+        .add_health_check(StaticHealthCheck {
+            name: "warmup",
+            status: HealthStatus::healthy(),
+            probes: vec![HealthProbe::Startup, HealthProbe::Readiness],
+        })
+        .build()
+        .await
+        .expect("构建基础设施应该成功");
+    let router = infrastructure.health_router();
+
+    // 启动前就绪和启动探针失败
+    let (status, body) = request_probe(&router, "/health/ready").await;
+    assert_eq!(status, 503);
+    assert_eq!(body["infrastructure_status"], json!("Initialized"));
+    let (status, _) = request_probe(&router, "/health/startup").await;
+    assert_eq!(status, 503);
+
+    infrastructure.start().await.expect("启动基础设施应该成功");
+
+    let (status, body) = request_probe(&router, "/health/ready").await;
+    assert_eq!(status, 200);
+    assert_eq!(body["probe"], json!("readiness"));
+    assert_eq!(body["status"], json!("degraded"));
+    assert_eq!(body["warnings"], json!(["bidder_pool: 可用竞价方不足"]));
+    let names: Vec<&str> = body["checks"]
+        .as_array()
+        .unwrap()
+        .iter()
+        .map(|check| check["component_name"].as_str().unwrap())
+        .collect();
+    assert_eq!(names, vec!["bidder_pool", "warmup"]);
+
+    let (status, body) = request_probe(&router, "/health/startup").await;
+    assert_eq!(status, 200);
+    assert_eq!(body["status"], json!("healthy"));
+    assert_eq!(body["checks"].as_array().unwrap().len(), 1);
+
+    let (status, body) = request_probe(&router, "/health/live").await;
+    assert_eq!(status, 503);
+    assert_eq!(body["status"], json!("unhealthy"));
+    assert_eq!(body["checks"][0]["status"]["data"]["error"], json!("事件循环阻塞"));
+
+    infrastructure.stop().await.expect("停止基础设施应该成功");
+    let (status, _) = request_probe(&router, "/health/ready").await;
+    assert_eq!(status, 503);
+}
+
+/// 测试用健康检查器，状态可在测试中修改
+#[derive(Clone)]
+struct SwitchableHealthCheck {
+    status: std::sync::Arc<parking_lot::Mutex<infrastructure_common::HealthStatus>>,
+    calls: std::sync::Arc<std::sync::atomic::AtomicUsize>,
+}
+
+impl SwitchableHealthCheck {
+    fn new() -> Self {
+        Self {
+            status: std::sync::Arc::new(parking_lot::Mutex::new(
+                infrastructure_common::HealthStatus::healthy(),
+            )),
+            calls: Default::default(),
+        }
+    }
+
+    fn set(&self, status: infrastructure_common::HealthStatus) {
+        *self.status.lock() = status;
+    }
+}
+
+#[async_trait::async_trait]
+impl infrastructure_common::HealthCheckable for SwitchableHealthCheck {
+    async fn check_health(&self) -> infrastructure_common::HealthStatus {
+        self.calls.fetch_add(1, std::sync::atomic::Ordering::SeqCst);
+        self.status.lock().clone()
+    }
+
+    fn name(&self) -> &str {
+        "ad_index"
+    }
+
+    fn check_interval(&self) -> std::time::Duration {
+        std::time::Duration::from_millis(20)
+    }
+}
+
+/// 测试健康监控的抖动抑制、历史记录和状态变化事件
+#[tokio::test]
+async fn test_health_monitor_damping_and_history() {
+    use infrastructure_common::{
+        AggregateHealthChecker, HealthMonitor, HealthMonitorOptions, HealthStatus,
+    };
+
+    let check = SwitchableHealthCheck::new();
+    let mut checker = AggregateHealthChecker::new();
+    checker.add_checker(Box::new(check.clone()));
+    let monitor = HealthMonitor::new(
+        std::sync::Arc::new(checker),
+        HealthMonitorOptions::default()
+            .with_failure_threshold(2)
+            .with_recovery_threshold(2)
+            .with_history_size(3),
+    );
+    let mut transitions = monitor.subscribe();
+
+    assert!(monitor.check_now().await[0].status.is_healthy());
+    assert!(transitions.try_recv().is_err());
+
+    // 第一次失败只降级
+    check.set(HealthStatus::unhealthy("索引加载失败"));
+    let status = monitor.check_now().await.remove(0).status;
+    assert_eq!(status, HealthStatus::degraded("连续失败 1/2 次: 索引加载失败"));
+    let transition = transitions.try_recv().unwrap();
+    assert!(transition.previous.is_healthy() && transition.current.is_degraded());
+
+    // 连续失败达到阈值后不健康
+    monitor.check_now().await;
+    assert!(monitor.latest("ad_index").unwrap().status.is_unhealthy());
+    assert!(transitions.try_recv().unwrap().current.is_unhealthy());
+
+    // 需要连续两次成功才恢复
+    check.set(HealthStatus::healthy());
+    monitor.check_now().await;
+    assert!(monitor.latest("ad_index").unwrap().status.is_unhealthy());
+    assert!(transitions.try_recv().is_err());
+    monitor.check_now().await;
+    assert!(monitor.latest("ad_index").unwrap().status.is_healthy());
+    assert!(transitions.try_recv().unwrap().current.is_healthy());
+
+    // 历史记录保留最近的原始结果
+    let history = monitor.history("ad_index");
+    assert_eq!(history.len(), 3);
+    assert!(history[0].status.is_unhealthy());
+    assert!(history[1].status.is_healthy() && history[2].status.is_healthy());
+}
+
+/// 检查间隔为零的测试健康检查器
+struct ZeroIntervalHealthCheck {
+    calls: std::sync::Arc<std::sync::atomic::AtomicUsize>,
+}
+
+#[async_trait::async_trait]
+impl infrastructure_common::HealthCheckable for ZeroIntervalHealthCheck {
+    async fn check_health(&self) -> infrastructure_common::HealthStatus {
+        self.calls.fetch_add(1, std::sync::atomic::Ordering::SeqCst);
+        infrastructure_common::HealthStatus::healthy()
+    }
+
+    fn name(&self) -> &str {
+        "zero_interval"
+    }
+
+    fn check_interval(&self) -> std::time::Duration {
+        std::time::Duration::ZERO
+    }
+}
+
+/// 测试检查间隔为零时后台监控使用最小间隔而不是崩溃
+#[tokio::test]
+async fn test_health_monitor_clamps_zero_interval() {
+    use infrastructure_common::{AggregateHealthChecker, HealthMonitor, HealthMonitorOptions};
+
+    let calls = std::sync::Arc::new(std::sync::atomic::AtomicUsize::new(0));
+    let mut checker = AggregateHealthChecker::new();
+    checker.add_checker(Box::new(ZeroIntervalHealthCheck {
+        calls: calls.clone(),
+    }));
+    let monitor = HealthMonitor::new(
+        std::sync::Arc::new(checker),
+        HealthMonitorOptions::default(),
+    );
+
+    monitor.start().await;
+    tokio::time::sleep(std::time::Duration::from_millis(60)).await;
+    let observed = calls.load(std::sync::atomic::Ordering::SeqCst);
+    assert!(observed >= 2, "后台检查应继续执行，实际执行 {} 次", observed);
+    assert!(observed < 20, "检查间隔应不小于最小间隔，实际执行 {} 次", observed);
+    monitor.stop();
+}
+
+/// 测试基础设施启动后台健康监控，探针读取缓存结果
+#[tokio::test]
+async fn test_health_monitor_runs_in_background() {
+    use infrastructure_common::{HealthMonitorOptions, HealthStatus};
+    use std::sync::atomic::Ordering;
+
+    let check = SwitchableHealthCheck::new();
+    let infrastructure = InfrastructureBuilder::new()
+        .auto_configure_development()
+        .add_health_check(check.clone())
+        .with_health_monitor(HealthMonitorOptions::default().with_failure_threshold(1))
+        .build()
+        .await
+        .expect("构建基础设施应该成功");
+    let monitor = infrastructure.health_monitor().expect("应该启用健康监控").clone();
+    let mut transitions = monitor.subscribe();
+    infrastructure.start().await.expect("启动基础设施应该成功");
+    assert!(monitor.is_running());
+
+    check.set(HealthStatus::unhealthy("索引加载失败"));
+    let transition = tokio::time::timeout(std::time::Duration::from_secs(5), transitions.recv())
+        .await
+        .expect("等待状态变化超时")
+        .unwrap();
+    assert_eq!(transition.component_name, "ad_index");
+    assert!(transition.current.is_unhealthy());
+
+    let router = infrastructure.health_router();
+    let (status, _) = request_probe(&router, "/health/ready").await;
+    assert_eq!(status, 503);
+
+    // 停止后不再执行检查
+    infrastructure.stop().await.expect("停止基础设施应该成功");
+    assert!(!monitor.is_running());
+    let calls = check.calls.load(Ordering::SeqCst);
+    tokio::time::sleep(std::time::Duration::from_millis(100)).await;
+    assert_eq!(check.calls.load(Ordering::SeqCst), calls);
+}
+
+/// 测试内置健康检查按配置节注册和配置
+#[tokio::test]
+async fn test_builtin_health_checks() {
+    use infrastructure_common::HealthStatus;
+
+    let data_dir = tempfile::tempdir().unwrap();
+    let listener = tokio::net::TcpListener::bind("127.0.0.1:0").await.unwrap();
+    let reachable = listener.local_addr().unwrap().to_string();
+    let closed = {
+        let listener = std::net::TcpListener::bind("127.0.0.1:0").unwrap();
+        listener.local_addr().unwrap().to_string()
+    };
+
+    let temp_file = NamedTempFile::new().unwrap();
+    let config_path = temp_file.path().to_path_buf();
+    fs::write(
+        &config_path,
+        json!({
+            "health": {
+                // 任何磁盘的剩余空间都低于 100%，因此报告降级
+                "disk": {"paths": [data_dir.path()], "warning_free_percent": 100.0},
+                "memory": {"enabled": false},
+                "tcp": {"targets": [reachable], "connect_timeout_ms": 500}
+            }
+        })
+        .to_string(),
+    )
+    .await
+    .unwrap();
+
+    let infrastructure = InfrastructureBuilder::new()
+        .add_config_json(&config_path)
+        .expect("添加配置文件应该成功")
+        .enable_health_checks(true)
+        .add_tcp_health_check(closed.clone())
+        .build()
+        .await
+        .expect("构建基础设施应该成功");
+
+    let results: std::collections::HashMap<String, HealthStatus> =
+        infrastructure.check_health().await.into_iter().collect();
+    let mut names: Vec<&str> = results.keys().map(String::as_str).collect();
+    names.sort();
+    assert_eq!(names, vec!["config", "di_container", "disk_space", "runtime", "tcp"]);
+    assert!(results["config"].is_healthy());
+    assert!(results["di_container"].is_healthy());
+    assert!(results["disk_space"].is_degraded());
+    assert!(results["runtime"].is_healthy());
+    match &results["tcp"] {
+        HealthStatus::Unhealthy { details: Some(details), .. } => {
+            assert!(details[&reachable].starts_with("可连接"));
+            assert!(!details[&closed].starts_with("可连接"));
+        }
+        other => panic!("TCP 检查应该不健康: {:?}", other),
+    }
+
+    // 配置文件被删除后配置检查不健康
+    fs::remove_file(&config_path).await.unwrap();
+    let results: std::collections::HashMap<String, HealthStatus> =
+        infrastructure.check_health().await.into_iter().collect();
+    assert!(results["config"].is_unhealthy());
+}
+
+/// 测试用组件，工厂总是创建失败
+#[derive(Debug)]
+struct BrokenBidderClient;
+
+impl infrastructure_common::Component for BrokenBidderClient {
+    fn name(&self) -> &'static str {
+        "broken_bidder_client"
+    }
+}
+
+/// 测试依赖注入容器检查报告创建失败的工厂
+#[tokio::test]
+async fn test_builtin_health_checks_report_failures() {
+    use di_abstractions::ComponentRegistry;
+    use infrastructure_common::{DependencyError, HealthStatus, Lifetime};
+
+    let infrastructure = InfrastructureBuilder::new()
+        .enable_health_checks(true)
+        .build()
+        .await
+        .expect("构建基础设施应该成功");
+
+    {
+        let mut container = infrastructure.di_container().write().await;
+        container
+            .register_factory::<BrokenBidderClient, _>(
+                || {
+                    Err(DependencyError::DependencyResolutionFailed {
+                        type_name: "BrokenBidderClient".to_string(),
+                        message: "连接池耗尽".to_string(),
+                    })
+                },
+                Lifetime::Singleton,
+            )
+            .await
+            .unwrap();
+    }
+    assert!(infrastructure.resolve::<BrokenBidderClient>().await.is_err());
+
+    let results: std::collections::HashMap<String, HealthStatus> =
+        infrastructure.check_health().await.into_iter().collect();
+    match &results["di_container"] {
+        HealthStatus::Unhealthy { details: Some(details), .. } => {
+            assert_eq!(details.len(), 1);
+            assert!(details.values().all(|error| error.contains("连接池耗尽")));
+        }
+        other => panic!("依赖注入容器检查应该不健康: {:?}", other),
+    }
+}
+
+/// 测试内置健康检查拒绝零检查间隔
+#[tokio::test]
+async fn test_builtin_health_checks_reject_zero_interval() {
+    let result = InfrastructureBuilder::new()
+        .add_config_args(["--health.disk.interval_secs=0"])
+        .expect("添加命令行参数应该成功")
+        .enable_health_checks(true)
+        .build()
+        .await;
+
+    match result.as_ref().map_err(|e| e.root_cause()) {
+        Err(crate::InfrastructureError::ConfigError { source }) => {
+            assert!(source.to_string().contains("health.disk.interval_secs"), "{}", source);
+        }
+        other => panic!("零检查间隔应该被拒绝: {:?}", other.map(|_| ())),
+    }
+}
+
+/// 辅助函数：请求指标路由，返回响应的内容类型和正文
+async fn scrape_metrics(router: &axum::Router) -> (String, String) {
+    use tower::ServiceExt;
+
+    let response = router
+        .clone()
+        .oneshot(
+            axum::http::Request::get(crate::metrics_exporter::METRICS_PATH)
+                .body(axum::body::Body::empty())
+                .unwrap(),
+        )
+        .await
+        .unwrap();
+    assert_eq!(response.status().as_u16(), 200);
+    let content_type = response.headers()[axum::http::header::CONTENT_TYPE]
+        .to_str()
+        .unwrap()
+        .to_string();
+    let body = axum::body::to_bytes(response.into_body(), usize::MAX)
+        .await
+        .unwrap();
+    (content_type, String::from_utf8(body.to_vec()).unwrap())
+}
+
+/// 辅助函数：读取名称和所有标签都匹配的指标样本值
+fn metric_value(body: &str, name: &str, labels: &[&str]) -> Option<f64> {
+    body.lines()
+        .filter(|line| line.split(['{', ' ']).next() == Some(name))
+        .find(|line| labels.iter().all(|label| line.contains(label)))
+        .and_then(|line| line.rsplit(' ').next())
+        .and_then(|value| value.parse().ok())
+}
+
+/// 测试 Prometheus 指标记录依赖注入、配置重载和健康检查，并通过 `/metrics` 端点导出
+#[tokio::test]
+async fn test_prometheus_metrics_endpoint() {
+    use infrastructure_common::{ActiveProfiles, HealthProbe, HealthStatus};
+
+    let temp_file = NamedTempFile::new().unwrap();
+    fs::write(temp_file.path(), json!({"app": {"name": "metrics"}}).to_string())
+        .await
+        .unwrap();
+
+    let infrastructure = InfrastructureBuilder::new()
+        .add_config_json(temp_file.path())
+        .expect("添加配置文件应该成功")
+        .add_health_check(StaticHealthCheck {
+            name: "metrics_probe",
+            status: HealthStatus::degraded("缓存命中率低"),
+            probes: vec![HealthProbe::Readiness],
+        })
+        .with_prometheus_metrics()
+        .build()
+        .await
+        .expect("构建基础设施应该成功");
+    let router = infrastructure.metrics_router().expect("应该启用指标端点");
+
+    let (_, before) = scrape_metrics(&router).await;
+    let resolutions = |body: &str| {
+        metric_value(
+            body,
+            "adsp_di_resolutions_total",
+            &["ActiveProfiles", "result=\"success\""],
+        )
+        .unwrap_or(0.0)
+    };
+    let reloads = |body: &str| {
+        metric_value(body, "adsp_config_reloads_total", &["result=\"success\""]).unwrap_or(0.0)
+    };
+
+    infrastructure.resolve::<ActiveProfiles>().await.unwrap();
+    infrastructure.resolve::<ActiveProfiles>().await.unwrap();
+    infrastructure.reload_configuration().await.unwrap();
+    infrastructure.check_health().await;
+
+    let (content_type, after) = scrape_metrics(&router).await;
+    assert!(content_type.starts_with("text/plain"));
+    assert!(resolutions(&after) >= resolutions(&before) + 2.0);
+    assert!(reloads(&after) >= reloads(&before) + 1.0);
+    assert!(after.contains("adsp_di_resolve_duration_seconds_bucket"));
+    assert!(after.contains("adsp_config_reload_duration_seconds_bucket"));
+    assert!(after.contains("adsp_infrastructure_build_duration_seconds"));
+    assert!(after.contains("# HELP adsp_di_resolutions_total"));
+    assert_eq!(
+        metric_value(&after, "adsp_health_status", &["component=\"metrics_probe\""]),
+        Some(0.5)
+    );
+    assert!(metric_value(
+        &after,
+        "adsp_health_check_duration_seconds_count",
+        &["component=\"metrics_probe\""]
+    )
+    .is_some());
+
+    let metrics = infrastructure.get_metrics().await;
+    assert_eq!(metrics.component_resolution_count, 2);
+    assert_eq!(metrics.config_reload_count, 1);
+
+    // 直接在容器上的解析和文件监听触发的重载同样计入统计
+    {
+        use di_abstractions::DiContainer;
+        let container = infrastructure.di_container().read().await;
+        container.resolve::<ActiveProfiles>().await.unwrap();
+    }
+    infrastructure
+        .config_manager()
+        .reload_and_notify("/etc/ad-engine/config.toml")
+        .await
+        .unwrap();
+    let metrics = infrastructure.get_metrics().await;
+    assert_eq!(metrics.component_resolution_count, 3);
+    assert_eq!(metrics.config_reload_count, 2);
+    assert_eq!(metrics.health_check_count, 1);
+
+    // 重复安装返回同一个记录器
+    let exporter = crate::PrometheusExporter::install().unwrap();
+    assert!(exporter.render().contains("adsp_di_resolutions_total"));
+}
+
+/// 测试追踪数据导出到内存导出器，并通过 W3C Trace Context 请求头跨服务传播
+#[tokio::test]
+async fn test_telemetry_exports_spans_with_trace_context() {
+    use crate::telemetry::{extract_trace_context, inject_trace_context, TelemetryConfig};
+    use opentelemetry::trace::TraceContextExt;
+    use opentelemetry::Key;
+    use opentelemetry_sdk::testing::trace::InMemorySpanExporterBuilder;
+    use tracing_opentelemetry::OpenTelemetrySpanExt;
+
+    let config = TelemetryConfig::default()
+        .with_service("bidder", "1.2.3")
+        .with_resource_attribute("deployment.environment", "test")
+        .with_directive("hyper=off");
+    let exporter = InMemorySpanExporterBuilder::new().build();
+    let provider = config.tracer_provider_with_exporter(exporter.clone());
+    let (subscriber, _) = config.subscriber(Some(&provider)).unwrap();
+
+    let mut headers = axum::http::HeaderMap::new();
+    tracing::subscriber::with_default(subscriber, || {
+        let upstream = tracing::info_span!("bid_request");
+        upstream.in_scope(|| inject_trace_context(&mut headers));
+
+        // 下游服务从请求头恢复追踪上下文
+        let downstream = tracing::info_span!("auction");
+        downstream.set_parent(extract_trace_context(&headers));
+        let remote = extract_trace_context(&headers);
+        assert_eq!(
+            downstream.context().span().span_context().trace_id(),
+            remote.span().span_context().trace_id()
+        );
+    });
+    provider.force_flush();
+
+    let traceparent = headers["traceparent"].to_str().unwrap();
+    let parts: Vec<&str> = traceparent.split('-').collect();
+    assert_eq!(parts.len(), 4);
+    assert_eq!((parts[0], parts[1].len(), parts[2].len(), parts[3]), ("00", 32, 16, "01"));
+
+    let spans = exporter.get_finished_spans().unwrap();
+    let span = |name: &str| spans.iter().find(|span| span.name == name).unwrap();
+    let (upstream, downstream) = (span("bid_request"), span("auction"));
+    assert_eq!(
+        downstream.span_context.trace_id(),
+        upstream.span_context.trace_id()
+    );
+    assert_eq!(downstream.parent_span_id, upstream.span_context.span_id());
+    assert_eq!(
+        upstream.resource.get(Key::from_static_str("service.name")),
+        Some("bidder".into())
+    );
+    assert_eq!(
+        upstream.resource.get(Key::from_static_str("service.version")),
+        Some("1.2.3".into())
+    );
+    assert_eq!(
+        upstream
+            .resource
+            .get(Key::from_static_str("deployment.environment")),
+        Some("test".into())
+    );
+}
+
+/// 测试采样率为 0 时不导出根 span，但跟随已采样的上游调用
+#[tokio::test]
+async fn test_telemetry_sampling_follows_parent() {
+    use crate::telemetry::{extract_trace_context, TelemetryConfig};
+    use opentelemetry_sdk::testing::trace::InMemorySpanExporterBuilder;
+    use tracing_opentelemetry::OpenTelemetrySpanExt;
+
+    let config = TelemetryConfig::default().with_sampling_ratio(0.0);
+    let exporter = InMemorySpanExporterBuilder::new().build();
+    let provider = config.tracer_provider_with_exporter(exporter.clone());
+    let (subscriber, _) = config.subscriber(Some(&provider)).unwrap();
+
+    let mut headers = axum::http::HeaderMap::new();
+    headers.insert(
+        "traceparent",
+        "00-4bf92f3577b34da6a3ce929d0e0e4736-00f067aa0ba902b7-01"
+            .parse()
+            .unwrap(),
+    );
+    tracing::subscriber::with_default(subscriber, || {
+        tracing::info_span!("unsampled_root").in_scope(|| {});
+
+        let span = tracing::info_span!("sampled_child");
+        span.set_parent(extract_trace_context(&headers));
+        span.in_scope(|| {});
+    });
+    provider.force_flush();
+
+    let spans = exporter.get_finished_spans().unwrap();
+    assert_eq!(spans.len(), 1);
+    assert_eq!(spans[0].name, "sampled_child");
+    assert_eq!(
+        spans[0].span_context.trace_id().to_string(),
+        "4bf92f3577b34da6a3ce929d0e0e4736"
+    );
+}
+
+/// 测试日志指令解析
+#[test]
+fn test_telemetry_filter_directives() {
+    use crate::telemetry::TelemetryConfig;
+
+    let config = TelemetryConfig::default()
+        .with_directive("config_impl=debug")
+        .with_directive("di_impl::container=trace");
+    let filter = config.env_filter().unwrap().to_string();
+    assert!(filter.contains("config_impl=debug"));
+    assert!(filter.contains("di_impl::container=trace"));
+    assert!(filter.contains("info"));
+
+    let invalid = TelemetryConfig::default().with_directive("config_impl=loud");
+    assert!(invalid.env_filter().is_err());
+}
+
+/// 辅助函数：结束一个指定目标的 debug span 并返回已导出的 span 数量
+fn export_debug_span(
+    provider: &opentelemetry_sdk::trace::TracerProvider,
+    exporter: &opentelemetry_sdk::testing::trace::InMemorySpanExporter,
+) -> usize {
+    tracing::debug_span!(target: "ad_engine::ranking", "rank").in_scope(|| {});
+    provider.force_flush();
+    exporter.get_finished_spans().unwrap().len()
+}
+
+/// 测试临时提高模块日志级别并在到期后自动恢复
+#[tokio::test]
+async fn test_log_filter_override_reverts() {
+    use crate::telemetry::TelemetryConfig;
+    use opentelemetry_sdk::testing::trace::InMemorySpanExporterBuilder;
+
+    let config = TelemetryConfig::default().with_directive("hyper=warn");
+    let exporter = InMemorySpanExporterBuilder::new().build();
+    let provider = config.tracer_provider_with_exporter(exporter.clone());
+    let (subscriber, log_filter) = config.subscriber(Some(&provider)).unwrap();
+    let _guard = tracing::subscriber::set_default(subscriber);
+
+    assert_eq!(log_filter.filter(), "info,hyper=warn");
+    assert_eq!(export_debug_span(&provider, &exporter), 0);
+
+    log_filter
+        .set_override("ad_engine", "debug", std::time::Duration::from_millis(100))
+        .unwrap();
+    assert_eq!(log_filter.effective_filter(), "info,hyper=warn,ad_engine=debug");
+    assert_eq!(export_debug_span(&provider, &exporter), 1);
+
+    // 无效的过滤指令和日志级别被拒绝，原过滤器保持不变
+    assert!(log_filter.set_filter("info,ad_engine=loud").is_err());
+    assert!(log_filter
+        .set_override("ad_engine", "loud", std::time::Duration::from_secs(1))
+        .is_err());
+    assert_eq!(log_filter.filter(), "info,hyper=warn");
+    assert_eq!(log_filter.overrides().len(), 1);
+
+    tokio::time::sleep(std::time::Duration::from_millis(300)).await;
+    assert!(log_filter.overrides().is_empty());
+    assert_eq!(log_filter.effective_filter(), "info,hyper=warn");
+    assert_eq!(export_debug_span(&provider, &exporter), 1);
+
+    // 替换过滤指令后同样生效
+    log_filter.set_filter("info,ad_engine=trace").unwrap();
+    assert_eq!(export_debug_span(&provider, &exporter), 2);
+}
+
+/// 测试日志过滤指令绑定到配置，配置重载后自动更新
+#[tokio::test]
+async fn test_log_filter_follows_config_reload() {
+    use crate::log_filter::LOG_FILTER_CONFIG_PATH;
+    use crate::telemetry::TelemetryConfig;
+
+    let temp_file = NamedTempFile::new().unwrap();
+    let write_filter = |filter: &str| {
+        std::fs::write(
+            temp_file.path(),
+            json!({"logging": {"filter": filter}}).to_string(),
+        )
+        .unwrap()
+    };
+    write_filter("warn,ad_engine=debug");
+
+    let infrastructure = InfrastructureBuilder::new()
+        .add_config_json(temp_file.path())
+        .expect("添加配置文件应该成功")
+        .build()
+        .await
+        .expect("构建基础设施应该成功");
+    let (_subscriber, log_filter) = TelemetryConfig::default().subscriber(None).unwrap();
+
+    let bound = log_filter
+        .bind_config(infrastructure.config_manager(), LOG_FILTER_CONFIG_PATH)
+        .await
+        .unwrap();
+    assert!(bound);
+    assert_eq!(log_filter.filter(), "warn,ad_engine=debug");
+    assert!(!log_filter
+        .bind_config(infrastructure.config_manager(), "logging.missing")
+        .await
+        .unwrap());
+
+    /// 等待过滤指令变为期望值
+    async fn wait_for_filter(log_filter: &crate::LogFilterHandle, expected: &str) {
+        for _ in 0..50 {
+            if log_filter.filter() == expected {
+                return;
+            }
+            tokio::time::sleep(std::time::Duration::from_millis(20)).await;
+        }
+        panic!("日志过滤指令没有更新为 {}: {}", expected, log_filter.filter());
+    }
+
+    write_filter("info,ad_engine=trace");
+    infrastructure.reload_configuration().await.unwrap();
+    wait_for_filter(&log_filter, "info,ad_engine=trace").await;
+
+    // 无法解析的过滤指令不会生效
+    write_filter("info,ad_engine=loud");
+    infrastructure.reload_configuration().await.unwrap();
+    tokio::time::sleep(std::time::Duration::from_millis(100)).await;
+    assert_eq!(log_filter.filter(), "info,ad_engine=trace");
+}
+
+/// 测试日志级别管理端点
+#[tokio::test]
+async fn test_log_level_endpoints() {
+    use crate::telemetry::TelemetryConfig;
+    use tower::ServiceExt;
+
+    let (_subscriber, log_filter) = TelemetryConfig::default().subscriber(None).unwrap();
+    let router = log_filter.clone().router();
+    let send = |method: &str, path: &str, body: Option<serde_json::Value>| {
+        let request = axum::http::Request::builder()
+            .method(method)
+            .uri(path)
+            .header("content-type", "application/json")
+            .body(match body {
+                Some(body) => axum::body::Body::from(body.to_string()),
+                None => axum::body::Body::empty(),
+            })
+            .unwrap();
+        let router = router.clone();
+        async move {
+            let response = router.oneshot(request).await.unwrap();
+            let status = response.status().as_u16();
+            let body = axum::body::to_bytes(response.into_body(), usize::MAX)
+                .await
+                .unwrap();
+            (status, serde_json::from_slice(&body).unwrap_or(serde_json::Value::Null))
+        }
+    };
+
+    let (status, body) = send(
+        "POST",
+        "/admin/log-level/overrides",
+        Some(json!({"target": "ad_engine", "level": "DEBUG", "duration_secs": 60})),
+    )
+    .await;
+    assert_eq!(status, 200);
+    assert_eq!(body["level"], "debug");
+
+    let (status, body) = send("GET", "/admin/log-level", None).await;
+    assert_eq!(status, 200);
+    assert_eq!(body["filter"], "info");
+    assert_eq!(body["effective_filter"], "info,ad_engine=debug");
+    assert_eq!(body["overrides"][0]["target"], "ad_engine");
+
+    let (status, body) = send(
+        "PUT",
+        "/admin/log-level",
+        Some(json!({"filter": "warn,bidder=debug"})),
+    )
+    .await;
+    assert_eq!(status, 200);
+    assert_eq!(body["effective_filter"], "warn,bidder=debug,ad_engine=debug");
+
+    let (status, body) = send(
+        "PUT",
+        "/admin/log-level",
+        Some(json!({"filter": "warn,bidder=loud"})),
+    )
+    .await;
+    assert_eq!(status, 400);
+    assert!(body["error"].as_str().unwrap().contains("bidder=loud"));
+
+    let (status, _) = send("DELETE", "/admin/log-level/overrides/ad_engine", None).await;
+    assert_eq!(status, 204);
+    let (status, _) = send("DELETE", "/admin/log-level/overrides/ad_engine", None).await;
+    assert_eq!(status, 404);
+    assert_eq!(log_filter.effective_filter(), "warn,bidder=debug");
+}
+
+/// 辅助函数：以管理令牌请求管理路由，返回状态码和 JSON 正文
+async fn admin_request(
+    router: &axum::Router,
+    method: &str,
+    path: &str,
+    token: Option<&str>,
+    body: &str,
+) -> (u16, serde_json::Value) {
+    use tower::ServiceExt;
+
+    let mut request = axum::http::Request::builder().method(method).uri(path);
+    if let Some(token) = token {
+        request = request.header("authorization", format!("Bearer {}", token));
+    }
+    let response = router
+        .clone()
+        .oneshot(request.body(axum::body::Body::from(body.to_string())).unwrap())
+        .await
+        .unwrap();
+    let status = response.status().as_u16();
+    let body = axum::body::to_bytes(response.into_body(), usize::MAX)
+        .await
+        .unwrap();
+    (status, serde_json::from_slice(&body).unwrap_or(serde_json::Value::Null))
+}
+
+/// 测试管理端点的令牌校验、脱敏配置、重载和回滚
+#[tokio::test]
+async fn test_admin_endpoints() {
+    use di_abstractions::ComponentRegistry;
+    use infrastructure_common::{DependencyError, Lifetime, LifecycleManager};
+    use std::sync::Arc;
+
+    let temp_file = NamedTempFile::new().unwrap();
+    let write_config = |name: &str| {
+        std::fs::write(
+            temp_file.path(),
+            json!({
+                "app": {"name": name},
+                "admin": {"token": "s3cret"},
+                "database": {"password": "hunter2"}
+            })
+            .to_string(),
+        )
+        .unwrap()
+    };
+    write_config("ad-engine");
+
+    let infrastructure = Arc::new(
+        InfrastructureBuilder::new()
+            .add_config_json(temp_file.path())
+            .expect("添加配置文件应该成功")
+            .build()
+            .await
+            .expect("构建基础设施应该成功"),
+    );
+    {
+        let mut container = infrastructure.di_container().write().await;
+        container
+            .register_factory::<BrokenBidderClient, _>(
+                || {
+                    Err(DependencyError::DependencyResolutionFailed {
+                        type_name: "BrokenBidderClient".to_string(),
+                        message: "连接池耗尽".to_string(),
+                    })
+                },
+                Lifetime::Singleton,
+            )
+            .await
+            .unwrap();
+    }
+    assert!(infrastructure.resolve::<BrokenBidderClient>().await.is_err());
+    let scope = infrastructure
+        .lifecycle_manager()
+        .create_scope("request")
+        .await
+        .unwrap();
+    let router = infrastructure.admin_router();
+    let token = Some("s3cret");
+
+    // 缺少或错误的令牌被拒绝
+    let (status, _) = admin_request(&router, "GET", "/admin/status", None, "").await;
+    assert_eq!(status, 401);
+    let (status, _) = admin_request(&router, "GET", "/admin/status", Some("guess"), "").await;
+    assert_eq!(status, 401);
+
+    // 查看状态不写入配置历史
+    let history_len = infrastructure.config_manager().get_config_history().await.len();
+    let (status, body) = admin_request(&router, "GET", "/admin/status", token, "").await;
+    assert_eq!(status, 200);
+    assert_eq!(body["status"], "Initialized");
+    assert_eq!(
+        infrastructure.config_manager().get_config_history().await.len(),
+        history_len
+    );
+    assert_eq!(
+        body["config_version"],
+        json!(infrastructure.config_manager().latest_version().await.unwrap())
+    );
+
+    let (status, body) = admin_request(&router, "GET", "/admin/metrics", token, "").await;
+    assert_eq!(status, 200);
+    assert_eq!(body["component_resolution_count"], 1);
+
+    let (_, body) = admin_request(&router, "GET", "/admin/components", token, "").await;
+    let component = body
+        .as_array()
+        .unwrap()
+        .iter()
+        .find(|component| component["type_name"].as_str().unwrap().contains("BrokenBidderClient"))
+        .expect("应该列出已注册的组件");
+    assert_eq!(component["lifetime"], "singleton");
+    assert_eq!(component["state"], "failed");
+    assert!(component["error"].as_str().unwrap().contains("连接池耗尽"));
+
+    let (_, body) = admin_request(&router, "GET", "/admin/scopes", token, "").await;
+    assert_eq!(body[0]["id"], scope.id.to_string());
+    assert_eq!(body[0]["name"], "request");
+
+    // 生效配置中的敏感配置已脱敏
+    let (status, body) = admin_request(&router, "GET", "/admin/config", token, "").await;
+    assert_eq!(status, 200);
+    let value_of = |body: &serde_json::Value, key: &str| {
+        body["entries"]
+            .as_array()
+            .unwrap()
+            .iter()
+            .find(|entry| entry["key"] == key)
+            .map(|entry| entry["value"].clone())
+    };
+    assert_eq!(value_of(&body, "app.name"), Some(json!("ad-engine")));
+    assert_eq!(
+        value_of(&body, "database.password"),
+        Some(json!(config_abstractions::REDACTED_VALUE))
+    );
+    assert_eq!(
+        value_of(&body, "admin.token"),
+        Some(json!(config_abstractions::REDACTED_VALUE))
+    );
+
+    // 重载配置后回滚到上一个版本
+    write_config("ad-engine-v2");
+    let (status, _) = admin_request(&router, "POST", "/admin/config/reload", token, "").await;
+    assert_eq!(status, 200);
+    assert_eq!(infrastructure.get_metrics().await.config_reload_count, 1);
+    let name: String = infrastructure.get_config("app.name").await.unwrap();
+    assert_eq!(name, "ad-engine-v2");
+
+    let (_, history) = admin_request(&router, "GET", "/admin/config/history", token, "").await;
+    assert!(history.as_array().unwrap().len() >= 2);
+    assert!(!history.to_string().contains("hunter2"));
+
+    let (status, _) =
+        admin_request(&router, "POST", "/admin/config/rollback", token, "{not json").await;
+    assert_eq!(status, 400);
+    let (status, _) = admin_request(
+        &router,
+        "POST",
+        "/admin/config/rollback",
+        token,
+        r#"{"version": 9999}"#,
+    )
+    .await;
+    assert_eq!(status, 404);
+
+    let (status, body) = admin_request(
+        &router,
+        "POST",
+        "/admin/config/rollback",
+        token,
+        r#"{"actor": "oncall", "reason": "bad deploy"}"#,
+    )
+    .await;
+    assert_eq!(status, 200);
+    assert_eq!(body["changes"][0]["path"], "app.name");
+    let name: String = infrastructure.get_config("app.name").await.unwrap();
+    assert_eq!(name, "ad-engine");
+    let latest = infrastructure.config_manager().get_config_history().await;
+    assert_eq!(latest.last().unwrap().audit.actor, "oncall");
+}
+
+/// 测试未配置管理令牌时拒绝所有管理请求
+#[tokio::test]
+async fn test_admin_endpoints_require_configured_token() {
+    let infrastructure = std::sync::Arc::new(
+        InfrastructureBuilder::new()
+            .build()
+            .await
+            .expect("构建基础设施应该成功"),
+    );
+    let router = infrastructure.admin_router();
+
+    let (status, body) =
+        admin_request(&router, "GET", "/admin/status", Some(""), "").await;
+    assert_eq!(status, 403);
+    assert!(body["error"].is_string());
+}
+
+/// 测试用启动步骤，记录执行顺序，可配置为失败
+struct RecordingStep {
+    name: &'static str,
+    stage: crate::BootstrapStage,
+    log: std::sync::Arc<std::sync::Mutex<Vec<String>>>,
+    fail: bool,
+}
+
+impl RecordingStep {
+    fn new(
+        name: &'static str,
+        stage: crate::BootstrapStage,
+        log: &std::sync::Arc<std::sync::Mutex<Vec<String>>>,
+    ) -> Self {
+        Self { name, stage, log: log.clone(), fail: false }
+    }
+}
+
+#[async_trait::async_trait]
+impl crate::BootstrapStep for RecordingStep {
+    fn name(&self) -> &str {
+        self.name
+    }
+
+    fn stage(&self) -> crate::BootstrapStage {
+        self.stage
+    }
+
+    async fn run(
+        &mut self,
+        context: &mut crate::BootstrapContext,
+    ) -> Result<(), crate::InfrastructureError> {
+        // 构建阶段尚未组装基础设施
+        assert_eq!(context.infrastructure().is_ok(), !self.stage.is_build_stage());
+        self.log.lock().unwrap().push(self.name.to_string());
+        if self.fail {
+            return Err(crate::InfrastructureError::HealthCheckFailed {
+                component_name: self.name.to_string(),
+                message: "依赖的服务不可用".to_string(),
+            });
+        }
+        Ok(())
+    }
+}
+
+/// 测试用启动步骤，注册一个创建失败的单例工厂
+struct RegisterBrokenClientStep;
+
+#[async_trait::async_trait]
+impl crate::BootstrapStep for RegisterBrokenClientStep {
+    fn name(&self) -> &str {
+        "broken_bidder_client"
+    }
+
+    fn stage(&self) -> crate::BootstrapStage {
+        crate::BootstrapStage::Registration
+    }
+
+    async fn run(
+        &mut self,
+        context: &mut crate::BootstrapContext,
+    ) -> Result<(), crate::InfrastructureError> {
+        use di_abstractions::ComponentRegistry;
+        use infrastructure_common::{DependencyError, Lifetime};
+
+        context
+            .di_container()
+            .write()
+            .await
+            .register_factory::<BrokenBidderClient, _>(
+                || {
+                    Err(DependencyError::DependencyResolutionFailed {
+                        type_name: "BrokenBidderClient".to_string(),
+                        message: "连接池耗尽".to_string(),
+                    })
+                },
+                Lifetime::Singleton,
+            )
+            .await
+            .map_err(|e| crate::InfrastructureError::DependencyError { source: e })
+    }
+}
+
+/// 测试 build 只执行构建阶段，自定义步骤按阶段插入并记录在启动报告中
+#[tokio::test]
+async fn test_bootstrap_pipeline_build_stages() {
+    use crate::BootstrapStage;
+
+    let log = std::sync::Arc::new(std::sync::Mutex::new(Vec::new()));
+    let infrastructure = InfrastructureBuilder::new()
+        .add_bootstrap_step(RecordingStep::new("late_health", BootstrapStage::Health, &log))
+        .add_bootstrap_step(RecordingStep::new("custom_discovery", BootstrapStage::Discovery, &log))
+        .add_bootstrap_step(RecordingStep::new(
+            "custom_config",
+            BootstrapStage::Configuration,
+            &log,
+        ))
+        .add_health_check(StaticHealthCheck {
+            name: "custom",
+            status: infrastructure_common::HealthStatus::Healthy,
+            probes: vec![],
+        })
+        .build()
+        .await
+        .expect("构建基础设施应该成功");
+
+    // 生命周期启动和健康检查阶段不在 build 中执行
+    assert_eq!(*log.lock().unwrap(), vec!["custom_config", "custom_discovery"]);
+    assert_eq!(
+        infrastructure.get_status().await,
+        crate::InfrastructureStatus::Initialized
+    );
+
+    let report = infrastructure.bootstrap_report().expect("应该记录启动报告");
+    assert!(report.is_success());
+    let stages: Vec<BootstrapStage> = report.stages.iter().map(|stage| stage.stage).collect();
+    assert_eq!(
+        stages,
+        vec![
+            BootstrapStage::Configuration,
+            BootstrapStage::Validation,
+            BootstrapStage::Discovery,
+            BootstrapStage::Registration,
+            BootstrapStage::WarmUp,
+        ]
+    );
+    let step_names = |stage| -> Vec<String> {
+        report.stage(stage).unwrap().steps.iter().map(|step| step.name.clone()).collect()
+    };
+    assert_eq!(step_names(BootstrapStage::Configuration)[0], "config_providers");
+    assert!(step_names(BootstrapStage::Configuration).contains(&"custom_config".to_string()));
+    assert_eq!(
+        step_names(BootstrapStage::Discovery),
+        vec!["component_scanning", "custom_discovery"]
+    );
+    assert!(step_names(BootstrapStage::Registration).contains(&"active_profiles".to_string()));
+    assert!(step_names(BootstrapStage::Registration).contains(&"options".to_string()));
+    assert!(step_names(BootstrapStage::WarmUp).is_empty());
+
+    let results: std::collections::HashMap<String, infrastructure_common::HealthStatus> =
+        infrastructure.check_health().await.into_iter().collect();
+    assert!(results["custom"].is_healthy());
+}
+
+/// 测试 bootstrap 执行全部阶段并启动基础设施，启动后健康检查失败时返回健康检查错误
+#[tokio::test]
+async fn test_bootstrap_pipeline_full_run() {
+    use crate::BootstrapStage;
+
+    let log = std::sync::Arc::new(std::sync::Mutex::new(Vec::new()));
+    let infrastructure = InfrastructureBuilder::new()
+        .add_bootstrap_step(RecordingStep::new("after_start", BootstrapStage::Health, &log))
+        .bootstrap()
+        .await
+        .expect("启动基础设施应该成功");
+
+    assert_eq!(*log.lock().unwrap(), vec!["after_start"]);
+    assert_eq!(infrastructure.get_status().await, crate::InfrastructureStatus::Running);
+    let report = infrastructure.bootstrap_report().unwrap();
+    assert_eq!(report.stages.len(), BootstrapStage::ALL.len());
+    assert_eq!(
+        report.stage(BootstrapStage::LifecycleStart).unwrap().steps[0].name,
+        "infrastructure_start"
+    );
+    assert_eq!(report.stage(BootstrapStage::Health).unwrap().steps[0].name, "startup_health");
+    assert!(report.total_duration_ms >= 0.0);
+
+    let result = InfrastructureBuilder::new()
+        .add_health_check(StaticHealthCheck {
+            name: "bidder_pool",
+            status: infrastructure_common::HealthStatus::Unhealthy {
+                error: "没有可用的竞价连接".to_string(),
+                details: None,
+            },
+            probes: vec![],
+        })
+        .bootstrap()
+        .await;
+    let error = result.err().expect("启动后健康检查应该失败");
+    match error.root_cause() {
+        crate::InfrastructureError::HealthCheckFailed { component_name, message } => {
+            assert_eq!(component_name, "bidder_pool");
+            assert_eq!(message, "没有可用的竞价连接");
+        }
+        other => panic!("应该返回健康检查错误: {:?}", other),
+    }
+
+    // 失败的启动报告包含启动结束时的健康状态
+    let report = crate::BootstrapReport::from_error(&error).expect("错误应该附带启动报告");
+    assert_eq!(report.health.len(), 1);
+    assert!(report.health["bidder_pool"].is_unhealthy());
+    assert_eq!(report.failed_step().unwrap().1.name, "startup_health");
+}
+
+/// 测试预热阶段创建单例失败，以及失败步骤保留原始错误并阻止后续阶段
+#[tokio::test]
+async fn test_bootstrap_pipeline_failures() {
+    use crate::BootstrapStage;
+
+    let result = InfrastructureBuilder::new()
+        .enable_warm_up(true)
+        .add_bootstrap_step(RegisterBrokenClientStep)
+        .build()
+        .await;
+    match result.as_ref().map_err(|e| e.root_cause()) {
+        Err(crate::InfrastructureError::BootstrapFailed { message }) => {
+            assert!(message.contains("BrokenBidderClient"), "{}", message);
+            assert!(message.contains("连接池耗尽"), "{}", message);
+        }
+        other => panic!("预热单例应该失败: {:?}", other.map(|_| ())),
+    }
+
+    let log = std::sync::Arc::new(std::sync::Mutex::new(Vec::new()));
+    let mut failing = RecordingStep::new("custom_validation", BootstrapStage::Validation, &log);
+    failing.fail = true;
+    let result = InfrastructureBuilder::new()
+        .add_bootstrap_step(failing)
+        .add_bootstrap_step(RecordingStep::new(
+            "custom_registration",
+            BootstrapStage::Registration,
+            &log,
+        ))
+        .build()
+        .await;
+    match result.as_ref().map_err(|e| e.root_cause()) {
+        Err(crate::InfrastructureError::HealthCheckFailed { component_name, .. }) => {
+            assert_eq!(component_name, "custom_validation");
+        }
+        other => panic!("验证阶段的步骤应该失败: {:?}", other.map(|_| ())),
+    }
+    assert_eq!(*log.lock().unwrap(), vec!["custom_validation"]);
+}
+
+/// 测试启动报告记录配置源、组件注册和阶段耗时，可输出摘要和 JSON
+#[tokio::test]
+async fn test_bootstrap_report_contents() {
+    let temp_file = NamedTempFile::new().unwrap();
+    let config_path = temp_file.path();
+    fs::write(config_path, json!({"app": {"name": "bidder"}}).to_string())
+        .await
+        .unwrap();
+
+    let infrastructure = InfrastructureBuilder::new()
+        .add_config_json(config_path)
+        .expect("添加配置文件应该成功")
+        .add_config_env_vars("ADSP_REPORT_TEST")
+        .expect("添加环境变量配置应该成功")
+        .enable_health_checks(true)
+        .bootstrap()
+        .await
+        .expect("启动基础设施应该成功");
+    let report = infrastructure.bootstrap_report().unwrap();
+
+    assert!(report.error.is_none());
+    assert_eq!(report.config_sources.len(), 2);
+    let file_source = &report.config_sources[0];
+    assert_eq!(file_source.source, config_path.display().to_string());
+    assert!(file_source.error.is_none());
+    assert!(report.config_sources[1].source.starts_with("env:ADSP_REPORT_TEST"));
+    assert!(report
+        .registrations
+        .iter()
+        .any(|registration| registration.name.contains("ActiveProfiles")));
+    assert!(report.validation_errors.is_empty());
+    assert!(report.health["config"].is_healthy());
+
+    let summary = report.to_string();
+    assert!(summary.starts_with("基础设施启动完成"), "{}", summary);
+    assert!(summary.contains(&config_path.display().to_string()), "{}", summary);
+    assert!(summary.contains("config_providers"), "{}", summary);
+    assert!(summary.contains("infrastructure_start"), "{}", summary);
+
+    let json = infrastructure_common::StartupDiagnostics::to_json(report);
+    assert_eq!(json["config_sources"][0]["priority"], file_source.priority);
+    assert_eq!(json["stages"][0]["stage"], "configuration");
+    assert_eq!(json["health"]["config"]["status"], "Healthy");
+    assert!(json.get("error").is_none());
+}
+
+/// 测试用启动步骤，为配置路径注册 Schema
+struct RegisterTimeoutSchemaStep;
+
+#[async_trait::async_trait]
+impl crate::BootstrapStep for RegisterTimeoutSchemaStep {
+    fn name(&self) -> &str {
+        "timeout_schema"
+    }
+
+    fn stage(&self) -> crate::BootstrapStage {
+        crate::BootstrapStage::Configuration
+    }
+
+    async fn run(
+        &mut self,
+        context: &mut crate::BootstrapContext,
+    ) -> Result<(), crate::InfrastructureError> {
+        context
+            .config_manager()
+            .register_config_schema::<u64>("bidder.timeout_ms");
+        Ok(())
+    }
+}
+
+/// 测试配置验证失败时错误附带包含验证错误路径的启动报告
+#[tokio::test]
+async fn test_bootstrap_report_attached_to_validation_failure() {
+    let temp_file = NamedTempFile::new().unwrap();
+    let config_path = temp_file.path();
+    fs::write(
+        config_path,
+        json!({"bidder": {"endpoint": "http://dsp", "timeout_ms": "fast"}}).to_string(),
+    )
+    .await
+    .unwrap();
+
+    let error = InfrastructureBuilder::new()
+        .add_config_json(config_path)
+        .expect("添加配置文件应该成功")
+        .add_bootstrap_step(RegisterTimeoutSchemaStep)
+        .build()
+        .await
+        .err()
+        .expect("配置验证应该失败");
+    assert!(matches!(
+        error.root_cause(),
+        crate::InfrastructureError::BootstrapFailed { .. }
+    ));
+
+    let report = crate::BootstrapReport::from_error(&error).expect("错误应该附带启动报告");
+    assert!(!report.is_success());
+    assert!(
+        report.error.as_deref().unwrap().contains("配置验证失败"),
+        "{}",
+        report
+    );
+    assert_eq!(report.validation_errors.len(), 1, "{}", report);
+    assert_eq!(report.validation_errors[0].path, "/bidder/timeout_ms");
+    let (stage, step) = report.failed_step().unwrap();
+    assert_eq!(stage, crate::BootstrapStage::Validation);
+    assert_eq!(step.name, "config_validation");
+    // 失败之后的阶段不会执行
+    assert!(report.stage(crate::BootstrapStage::Discovery).is_none());
+    assert_eq!(report.config_sources.len(), 1);
+
+    let summary = error.startup_report().unwrap().to_string();
+    assert!(summary.starts_with("基础设施启动失败"), "{}", summary);
+    assert!(summary.contains("配置验证错误"), "{}", summary);
+    let json = error.startup_report().unwrap().to_json();
+    assert_eq!(json["validation_errors"][0]["path"], report.validation_errors[0].path);
+}
+
+/// 测试用全局组件注册表
+#[derive(Default)]
+struct TestComponentRegistry {
+    descriptors: std::sync::Mutex<Vec<infrastructure_common::ComponentDescriptor>>,
+}
+
+impl infrastructure_common::GlobalComponentRegistry for TestComponentRegistry {
+    fn register_component_descriptor(
+        &self,
+        descriptor: infrastructure_common::ComponentDescriptor,
+    ) -> Result<(), crate::InfrastructureError> {
+        self.descriptors.lock().unwrap().push(descriptor);
+        Ok(())
+    }
+
+    fn get_all_descriptors(&self) -> Vec<infrastructure_common::ComponentDescriptor> {
+        self.descriptors.lock().unwrap().clone()
+    }
+}
+
+/// 辅助函数：创建与 `#[component]` 生成代码一致的组件描述符
+fn component_descriptor<T: 'static>(
+    type_name: &str,
+    module_path: &str,
+    explicit: &[(&str, &str)],
+) -> infrastructure_common::ComponentDescriptor {
+    use infrastructure_common::{COMPONENT_MODULE_PATH_KEY, COMPONENT_TYPE_NAME_KEY};
+
+    let mut metadata: std::collections::HashMap<String, String> = explicit
+        .iter()
+        .map(|(key, value)| (key.to_string(), value.to_string()))
+        .collect();
+    metadata.insert(COMPONENT_TYPE_NAME_KEY.to_string(), type_name.to_string());
+    metadata.insert(COMPONENT_MODULE_PATH_KEY.to_string(), module_path.to_string());
+    infrastructure_common::ComponentDescriptor {
+        name: type_name.to_string(),
+        type_id: std::any::TypeId::of::<T>(),
+        lifetime: infrastructure_common::Lifetime::Singleton,
+        priority: 0,
+        enabled: true,
+        metadata,
+    }
+}
+
+/// 测试自动发现按类型名称约定推断生命周期和配置节，显式参数优先并报告匹配的规则
+#[tokio::test]
+async fn test_automatic_scan_applies_component_conventions() {
+    use crate::{
+        ComponentDiscoveryStrategy, ComponentLifecycle, ComponentScannerImpl,
+        UNSCOPED_COMPONENTS_TARGET,
+    };
+    use infrastructure_common::{
+        GlobalComponentRegistry, COMPONENT_CONFIG_SECTION_KEY, COMPONENT_LIFETIME_KEY,
+    };
+
+    struct BidPriceStrategy;
+    struct BudgetService;
+    struct PacingHelper;
+    struct AudienceMatcher;
+    struct ReportingService;
+    struct ManualComponent;
+
+    let registry = std::sync::Arc::new(TestComponentRegistry::default());
+    let descriptors = [
+        component_descriptor::<BidPriceStrategy>("BidPriceStrategy", "ad_engine::bidding", &[]),
+        component_descriptor::<BudgetService>(
+            "BudgetService",
+            "ad_engine::bidding::budget",
+            &[(COMPONENT_LIFETIME_KEY, "scoped")],
+        ),
+        component_descriptor::<PacingHelper>("PacingHelper", "ad_engine::bidding", &[]),
+        component_descriptor::<AudienceMatcher>(
+            "AudienceMatcher",
+            "ad_engine::bidding",
+            &[(COMPONENT_CONFIG_SECTION_KEY, "targeting.audience")],
+        ),
+        // 不在扫描包内
+        component_descriptor::<ReportingService>("ReportingService", "ad_engine::reporting", &[]),
+    ];
+    for descriptor in descriptors {
+        registry.register_component_descriptor(descriptor).unwrap();
+    }
+    // 没有记录所在模块的手工注册描述符
+    registry
+        .register_component_descriptor(infrastructure_common::ComponentDescriptor {
+            name: "ManualComponent".to_string(),
+            type_id: std::any::TypeId::of::<ManualComponent>(),
+            lifetime: infrastructure_common::Lifetime::Singleton,
+            priority: 0,
+            enabled: true,
+            metadata: std::collections::HashMap::new(),
+        })
+        .unwrap();
+    infrastructure_common::set_global_component_registry(registry);
+
+    let scanner = ComponentScannerImpl::new(ComponentDiscoveryStrategy::Automatic);
+    let registrations = scanner.scan_package("crate::bidding").await.unwrap();
+    let by_name: std::collections::HashMap<&str, &crate::ComponentRegistration> = registrations
+        .iter()
+        .map(|registration| {
+            (registration.type_name.rsplit("::").next().unwrap(), registration)
+        })
+        .collect();
+    assert_eq!(by_name.len(), 4);
+    assert!(!by_name.contains_key("ReportingService"));
+
+    // 没有记录所在模块的描述符不归入任何包，只在扫描单独的目标时返回
+    let reporting = scanner.scan_package("crate::reporting").await.unwrap();
+    assert_eq!(reporting.len(), 1);
+    assert_eq!(reporting[0].type_name, "ad_engine::reporting::ReportingService");
+    let unscoped = scanner.scan_package(UNSCOPED_COMPONENTS_TARGET).await.unwrap();
+    assert_eq!(unscoped.len(), 1);
+    assert_eq!(unscoped[0].type_name, "ManualComponent");
+
+    // 按约定推断
+    let strategy = by_name["BidPriceStrategy"];
+    assert_eq!(strategy.type_name, "ad_engine::bidding::BidPriceStrategy");
+    assert_eq!(strategy.lifecycle, ComponentLifecycle::Transient);
+    assert_eq!(strategy.config_key.as_deref(), Some("strategies.bid_price"));
+    let convention = strategy.convention.as_ref().unwrap();
+    assert_eq!(convention.rule.as_deref(), Some("*Strategy"));
+    assert!(!convention.explicit_lifetime);
+
+    // 显式生命周期优先，配置节仍按约定推断
+    let service = by_name["BudgetService"];
+    assert_eq!(service.lifecycle, ComponentLifecycle::Scoped);
+    assert_eq!(service.config_key.as_deref(), Some("services.budget"));
+    let convention = service.convention.as_ref().unwrap();
+    assert_eq!(convention.rule.as_deref(), Some("*Service"));
+    assert!(convention.explicit_lifetime);
+
+    // 没有匹配的规则时保留描述符的生命周期，配置节使用 components
+    let helper = by_name["PacingHelper"];
+    assert_eq!(helper.lifecycle, ComponentLifecycle::Singleton);
+    assert_eq!(helper.config_key.as_deref(), Some("components.pacing_helper"));
+    assert_eq!(helper.convention.as_ref().unwrap().rule, None);
+
+    // 显式配置节优先
+    let matcher = by_name["AudienceMatcher"];
+    assert_eq!(matcher.lifecycle, ComponentLifecycle::Transient);
+    assert_eq!(matcher.config_key.as_deref(), Some("targeting.audience"));
+    assert!(matcher.convention.as_ref().unwrap().explicit_config_section);
+
+    // 启动报告中按扫描器列出发现的组件，元数据属性记录匹配的规则
+    let infrastructure = InfrastructureBuilder::new()
+        .add_component_scanner(ComponentScannerImpl::new(ComponentDiscoveryStrategy::Automatic))
+        .scan_crate("ad_engine::bidding")
+        .unwrap()
+        .scan_crate("ad_engine::reporting")
+        .unwrap()
+        .add_bootstrap_step(ConventionAssertionStep)
+        .build()
+        .await
+        .expect("构建基础设施应该成功");
+    let report = infrastructure.bootstrap_report().unwrap();
+    let discovered = &report.discovered_components["ComponentScannerImpl"];
+    assert_eq!(discovered.len(), 6);
+    let manual: Vec<_> = discovered
+        .iter()
+        .filter(|component| component.name == "ManualComponent")
+        .collect();
+    assert_eq!(manual.len(), 1);
+    assert_eq!(manual[0].target, UNSCOPED_COMPONENTS_TARGET);
+    assert_eq!(
+        discovered
+            .iter()
+            .filter(|component| component.target == "ad_engine::reporting")
+            .count(),
+        1
+    );
+
+    // 描述符不包含工厂，发现的组件不会自动注册，启动报告标记为未注册
+    assert!(discovered.iter().all(|component| !component.registered));
+    assert!(report.to_string().contains("BidPriceStrategy (ad_engine::bidding) 未注册"));
+}
+
+/// 测试用启动步骤，检查发现的组件元数据记录匹配的规则和显式指定的属性
+struct ConventionAssertionStep;
+
+#[async_trait::async_trait]
+impl crate::BootstrapStep for ConventionAssertionStep {
+    fn name(&self) -> &str {
+        "convention_assertions"
+    }
+
+    fn stage(&self) -> crate::BootstrapStage {
+        crate::BootstrapStage::Discovery
+    }
+
+    async fn run(
+        &mut self,
+        context: &mut crate::BootstrapContext,
+    ) -> Result<(), crate::InfrastructureError> {
+        let properties_of = |name: &str| {
+            context
+                .discovered_components()
+                .iter()
+                .find(|component| component.metadata.name == name)
+                .map(|component| component.metadata.properties.clone())
+                .unwrap_or_else(|| panic!("应该发现 {}", name))
+        };
+
+        // 推断的生命周期和配置节不作为元数据属性报告
+        let strategy = properties_of("BidPriceStrategy");
+        assert_eq!(strategy["convention"], "*Strategy");
+        assert!(!strategy.contains_key("lifetime"));
+        assert!(!strategy.contains_key("config_section"));
+
+        let service = properties_of("BudgetService");
+        assert_eq!(service["lifetime"], "scoped");
+        assert!(!service.contains_key("config_section"));
+
+        let matcher = properties_of("AudienceMatcher");
+        assert_eq!(matcher["config_section"], "targeting.audience");
+        assert!(!matcher.contains_key("lifetime"));
+        Ok(())
+    }
+}
+
+/// 测试多组配置档配置文件中所有基础配置都低于配置档覆盖，重复添加同一组时只加载一次
+#[tokio::test]
+async fn test_profile_config_groups_order_base_files_below_overlays() {
+    let dir = tempfile::tempdir().unwrap();
+    for (name, value) in [
+        ("config.toml", "base"),
+        ("config.dev.toml", "dev"),
+        ("appsettings.toml", "appsettings"),
+    ] {
+        fs::write(dir.path().join(name), format!("[app]\nname = \"{}\"\n", value))
+            .await
+            .unwrap();
+    }
+
+    let infrastructure = InfrastructureBuilder::new()
+        .activate_profile("dev")
+        .add_profile_config(dir.path(), "config")
+        .add_profile_config(dir.path(), "appsettings")
+        .add_profile_config(dir.path(), "config")
+        .build()
+        .await
+        .expect("构建基础设施应该成功");
+
+    let report = infrastructure.bootstrap_report().unwrap();
+    let priorities: Vec<(String, i32)> = report
+        .config_sources
+        .iter()
+        .map(|source| {
+            let file_name = std::path::Path::new(&source.source).file_name().unwrap();
+            (file_name.to_string_lossy().into_owned(), source.priority)
+        })
+        .collect();
+    assert_eq!(
+        priorities,
+        vec![
+            ("config.toml".to_string(), 100),
+            ("appsettings.toml".to_string(), 101),
+            ("config.dev.toml".to_string(), 102),
+        ]
+    );
+    let name: String = infrastructure.get_config("app.name").await.unwrap();
+    assert_eq!(name, "dev");
+}
+
 /// 测试基础设施销毁和清理
 #[tokio::test]
 async fn test_infrastructure_cleanup() {
@@ -284,3 +2021,61 @@ async fn test_infrastructure_cleanup() {
         Err(e) => println!("基础设施停止返回错误（可能是正常的）: {}", e),
     }
 }
+
+/// 测试增强组件扫描器的 `profile=` 条件按构建器激活的配置档评估
+#[tokio::test]
+async fn test_enhanced_scanner_conditions_use_builder_profiles() {
+    use crate::{
+        ComponentDiscoveryStrategy, ComponentScannerImpl, EnhancedComponentDiscoveryStrategy,
+        EnhancedComponentScannerImpl,
+    };
+    use infrastructure_common::ActiveProfiles;
+
+    let scanner = || {
+        EnhancedComponentScannerImpl::new(
+            ComponentScannerImpl::new(ComponentDiscoveryStrategy::Automatic),
+            EnhancedComponentDiscoveryStrategy::Conditional {
+                conditions: vec!["profile=canary".to_string()],
+                base_strategy: Box::new(EnhancedComponentDiscoveryStrategy::AttributeBased {
+                    attributes: vec!["component".to_string()],
+                    recursive: false,
+                }),
+            },
+        )
+    };
+    let discovered_count = |infrastructure: &crate::AdSystemInfrastructure| {
+        infrastructure
+            .bootstrap_report()
+            .unwrap()
+            .discovered_components
+            .get("EnhancedComponentScannerImpl")
+            .map_or(0, |components| {
+                components
+                    .iter()
+                    .filter(|component| component.target == "ad_engine::canary")
+                    .count()
+            })
+    };
+
+    // 不设置 ADSP_PROFILE，通过构建器激活配置档
+    let infrastructure = InfrastructureBuilder::new()
+        .with_profiles(ActiveProfiles::default())
+        .activate_profile("canary")
+        .add_enhanced_component_scanner(scanner())
+        .scan_crate("ad_engine::canary")
+        .unwrap()
+        .build()
+        .await
+        .expect("构建基础设施应该成功");
+    assert_eq!(discovered_count(&infrastructure), 1);
+
+    let infrastructure = InfrastructureBuilder::new()
+        .with_profiles(ActiveProfiles::new(["prod"]))
+        .add_enhanced_component_scanner(scanner())
+        .scan_crate("ad_engine::canary")
+        .unwrap()
+        .build()
+        .await
+        .expect("构建基础设施应该成功");
+    assert_eq!(discovered_count(&infrastructure), 0);
+}
@@ -0,0 +1,88 @@
+//! 配置差异计算
+//!
+//! 比较两棵配置树的叶子节点，得到逐键的变更列表
+
+use crate::events::ConfigChangeEvent;
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use std::collections::BTreeMap;
+
+/// 单个配置键的变更
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ConfigKeyChange {
+    /// 配置键（点分路径）
+    pub key: String,
+    /// 旧值（新增时为 `None`）
+    pub old_value: Option<Value>,
+    /// 新值（删除时为 `None`）
+    pub new_value: Option<Value>,
+}
+
+impl ConfigKeyChange {
+    /// 转换为配置变更事件
+    pub fn to_event(&self, source: impl Into<String>) -> ConfigChangeEvent {
+        match (&self.old_value, &self.new_value) {
+            (Some(old_value), Some(new_value)) => {
+                ConfigChangeEvent::updated(&self.key, old_value.clone(), new_value.clone(), source)
+            }
+            (None, Some(new_value)) => ConfigChangeEvent::created(&self.key, new_value.clone(), source),
+            (Some(old_value), None) => ConfigChangeEvent::deleted(&self.key, old_value.clone(), source),
+            (None, None) => ConfigChangeEvent::reloaded(&self.key, source),
+        }
+    }
+}
+
+/// 计算两棵配置树之间的逐键差异
+///
+/// 对象按键展开到叶子，数组和标量作为整体比较；结果按键排序
+pub fn diff_configuration(old: &Value, new: &Value) -> Vec<ConfigKeyChange> {
+    let old_leaves = flatten_leaves(old);
+    let mut new_leaves = flatten_leaves(new);
+    let mut changes = Vec::new();
+
+    for (key, old_value) in old_leaves {
+        match new_leaves.remove(&key) {
+            Some(new_value) if new_value == old_value => {}
+            new_value => changes.push(ConfigKeyChange {
+                key,
+                old_value: Some(old_value),
+                new_value,
+            }),
+        }
+    }
+    changes.extend(new_leaves.into_iter().map(|(key, new_value)| ConfigKeyChange {
+        key,
+        old_value: None,
+        new_value: Some(new_value),
+    }));
+
+    changes.sort_by(|a, b| a.key.cmp(&b.key));
+    changes
+}
+
+/// 将配置树展开为叶子节点（非对象值以及空对象）
+pub fn flatten_leaves(value: &Value) -> BTreeMap<String, Value> {
+    let mut leaves = BTreeMap::new();
+    collect_leaves(value, String::new(), &mut leaves);
+    leaves
+}
+
+/// 递归收集叶子节点
+fn collect_leaves(value: &Value, prefix: String, leaves: &mut BTreeMap<String, Value>) {
+    match value {
+        Value::Object(map) if !map.is_empty() => {
+            for (key, child) in map {
+                let path = if prefix.is_empty() {
+                    key.clone()
+                } else {
+                    format!("{}.{}", prefix, key)
+                };
+                collect_leaves(child, path, leaves);
+            }
+        }
+        _ if prefix.is_empty() => {}
+        other => {
+            leaves.insert(prefix, other.clone());
+        }
+    }
+}
@@ -92,6 +92,29 @@ impl ConfigChangeEvent {
         }
     }
     
+    /// 创建配置验证失败事件
+    ///
+    /// 验证错误以 `error.<序号>` 的形式记录在元数据中
+    pub fn validation_failed(
+        path: impl Into<String>,
+        errors: &[String],
+        source: impl Into<String>,
+    ) -> Self {
+        Self {
+            event_type: ConfigChangeEventType::ValidationFailed,
+            path: path.into(),
+            old_value: None,
+            new_value: None,
+            timestamp: chrono::Utc::now(),
+            source: source.into(),
+            metadata: errors
+                .iter()
+                .enumerate()
+                .map(|(index, error)| (format!("error.{}", index), error.clone()))
+                .collect(),
+        }
+    }
+
     /// 添加元数据
     pub fn with_metadata(mut self, key: impl Into<String>, value: impl Into<String>) -> Self {
         self.metadata.insert(key.into(), value.into());
@@ -0,0 +1,192 @@
+//! 配置历史抽象
+//!
+//! 每次成功应用的配置变更都保存为一个 [`ConfigSnapshot`]，附带记录操作者、原因和来源的
+//! [`ConfigAuditRecord`]。快照由 [`ConfigHistoryStore`] 保存，版本号由存储分配并单调递增，
+//! 裁剪或清除历史后也不会重复
+
+use crate::diff::{diff_configuration, ConfigKeyChange};
+use async_trait::async_trait;
+use infrastructure_common::ConfigError;
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use std::collections::HashMap;
+
+/// 未指定操作者时使用的默认值
+pub const SYSTEM_ACTOR: &str = "system";
+
+/// 配置变更审计记录
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ConfigAuditRecord {
+    /// 操作者（用户名、服务账号等）
+    pub actor: String,
+    /// 变更原因
+    pub reason: Option<String>,
+    /// 变更来源（如文件监控、管理接口、回滚）
+    pub source: String,
+}
+
+impl ConfigAuditRecord {
+    /// 创建由系统发起的审计记录
+    pub fn new(source: impl Into<String>) -> Self {
+        Self {
+            actor: SYSTEM_ACTOR.to_string(),
+            reason: None,
+            source: source.into(),
+        }
+    }
+
+    /// 设置操作者
+    pub fn with_actor(mut self, actor: impl Into<String>) -> Self {
+        self.actor = actor.into();
+        self
+    }
+
+    /// 设置变更原因
+    pub fn with_reason(mut self, reason: impl Into<String>) -> Self {
+        self.reason = Some(reason.into());
+        self
+    }
+}
+
+impl Default for ConfigAuditRecord {
+    fn default() -> Self {
+        Self::new(SYSTEM_ACTOR)
+    }
+}
+
+/// 配置快照，用于配置回滚和审计
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ConfigSnapshot {
+    /// 快照时间
+    pub timestamp: chrono::DateTime<chrono::Utc>,
+    /// 配置数据（合并后的生效配置，按顶层配置节存放）
+    pub config_data: HashMap<String, Value>,
+    /// 快照版本号（由历史存储分配）
+    pub version: u64,
+    /// 快照描述
+    pub description: String,
+    /// 审计记录
+    #[serde(default)]
+    pub audit: ConfigAuditRecord,
+    /// 插值前的合并配置（按顶层配置节存放），回滚时用于写回配置提供者；
+    /// 旧版本保存的快照中为空
+    #[serde(default)]
+    pub raw_config_data: HashMap<String, Value>,
+}
+
+impl ConfigSnapshot {
+    /// 从完整的配置树创建尚未分配版本号的快照
+    pub fn new(config: &Value, description: impl Into<String>, audit: ConfigAuditRecord) -> Self {
+        Self {
+            timestamp: chrono::Utc::now(),
+            config_data: top_level_sections(config),
+            version: 0,
+            description: description.into(),
+            audit,
+            raw_config_data: HashMap::new(),
+        }
+    }
+
+    /// 记录插值前的合并配置
+    pub fn with_raw_config(mut self, raw_config: &Value) -> Self {
+        self.raw_config_data = top_level_sections(raw_config);
+        self
+    }
+
+    /// 还原插值前的合并配置树，快照未记录时返回 `None`
+    pub fn raw_value(&self) -> Option<Value> {
+        if self.raw_config_data.is_empty() && !self.config_data.is_empty() {
+            return None;
+        }
+        Some(Value::Object(
+            self.raw_config_data
+                .iter()
+                .map(|(key, value)| (key.clone(), value.clone()))
+                .collect(),
+        ))
+    }
+
+    /// 还原为完整的配置树
+    pub fn to_value(&self) -> Value {
+        Value::Object(
+            self.config_data
+                .iter()
+                .map(|(key, value)| (key.clone(), value.clone()))
+                .collect(),
+        )
+    }
+}
+
+/// 按顶层配置节拆分配置树
+fn top_level_sections(config: &Value) -> HashMap<String, Value> {
+    match config {
+        Value::Object(map) => map
+            .iter()
+            .map(|(key, value)| (key.clone(), value.clone()))
+            .collect(),
+        _ => HashMap::new(),
+    }
+}
+
+/// 两个配置版本之间的结构化差异
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ConfigHistoryDiff {
+    /// 起始版本
+    pub from_version: u64,
+    /// 目标版本
+    pub to_version: u64,
+    /// 逐键变更（`old_value` 来自起始版本，`new_value` 来自目标版本）
+    pub changes: Vec<ConfigKeyChange>,
+}
+
+impl ConfigHistoryDiff {
+    /// 比较两个快照
+    pub fn between(from: &ConfigSnapshot, to: &ConfigSnapshot) -> Self {
+        Self {
+            from_version: from.version,
+            to_version: to.version,
+            changes: diff_configuration(&from.to_value(), &to.to_value()),
+        }
+    }
+
+    /// 两个版本是否相同
+    pub fn is_empty(&self) -> bool {
+        self.changes.is_empty()
+    }
+}
+
+/// 配置历史存储接口
+///
+/// 快照按版本号升序保存。实现必须保证版本号单调递增，
+/// 即使历史被裁剪、清除或进程重启也不能重复使用已分配的版本号
+#[async_trait]
+pub trait ConfigHistoryStore: Send + Sync {
+    /// 获取存储名称
+    fn name(&self) -> &str;
+
+    /// 追加快照，忽略快照中的版本号，返回分配的新版本号
+    async fn append(&self, snapshot: ConfigSnapshot) -> Result<u64, ConfigError>;
+
+    /// 按版本号升序列出所有保留的快照
+    async fn list(&self) -> Result<Vec<ConfigSnapshot>, ConfigError>;
+
+    /// 获取指定版本的快照
+    async fn get(&self, version: u64) -> Result<Option<ConfigSnapshot>, ConfigError> {
+        Ok(self
+            .list()
+            .await?
+            .into_iter()
+            .find(|snapshot| snapshot.version == version))
+    }
+
+    /// 获取最新的快照
+    async fn latest(&self) -> Result<Option<ConfigSnapshot>, ConfigError> {
+        Ok(self.list().await?.pop())
+    }
+
+    /// 只保留最新的 `keep` 个快照
+    async fn prune(&self, keep: usize) -> Result<(), ConfigError>;
+
+    /// 删除所有快照（版本计数不重置）
+    async fn clear(&self) -> Result<(), ConfigError>;
+}
@@ -8,15 +8,33 @@
 //! - [`ConfigManager`] - 配置管理器接口
 //! - [`ConfigWatcher`] - 配置监控接口
 //! - [`ConfigValidator`] - 配置验证接口
+//! - [`ConfigProvenance`] - 配置来源追踪
+//! - [`SecretProvider`] - 密钥提供者接口
+//! - [`ConfigKeyChange`] - 配置逐键差异
+//! - [`SchemaValidator`] - 基于 JSON Schema 的配置验证
+//! - [`ConfigHistoryStore`] - 配置历史与审计存储
+//! - [`ConfigWriteOptions`] - 配置写入与乐观并发控制
 
 pub mod provider;
 pub mod manager;
 pub mod watcher;
 pub mod validator;
 pub mod events;
+pub mod diff;
+pub mod provenance;
+pub mod secret;
+pub mod schema;
+pub mod history;
+pub mod write;
 
 pub use provider::*;
 pub use manager::*; 
 pub use watcher::*;
 pub use validator::*;
 pub use events::*;
+pub use diff::*;
+pub use provenance::*;
+pub use secret::*;
+pub use schema::*;
+pub use history::*;
+pub use write::*;
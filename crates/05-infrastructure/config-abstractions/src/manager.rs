@@ -45,12 +45,14 @@ pub trait ConfigManager: Send + Sync {
     async fn validate_configuration(&self) -> Result<ValidationResult, ConfigError>;
 
     /// 注册配置验证器
+    ///
+    /// 同一配置类型已有验证器时，新验证器会与已有验证器串联执行
     async fn register_validator<T>(
         &mut self,
         validator: Box<dyn ConfigValidator<T>>,
     ) -> Result<(), ConfigError>
     where
-        T: 'static;
+        T: Send + Sync + 'static;
 
     /// 注册所有组件的配置选项
     async fn register_all_options(&mut self) -> Result<(), ConfigError>;
@@ -216,6 +218,23 @@ impl ValidationError {
     }
 }
 
+/// 只输出配置路径、错误消息和期望值，不输出实际值，避免泄露解析后的敏感配置
+impl std::fmt::Display for ValidationError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        if self.path.is_empty() {
+            write!(f, "{}", self.message)?;
+        } else {
+            write!(f, "{}: {}", self.path, self.message)?;
+        }
+        match &self.expected {
+            Some(expected) if !matches!(self.error_type, ValidationErrorType::FormatError) => {
+                write!(f, "（期望 {}）", expected)
+            }
+            _ => Ok(()),
+        }
+    }
+}
+
 /// 验证警告
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct ValidationWarning {
@@ -285,4 +304,6 @@ pub struct ConfigOptionDescriptor {
     pub required: bool,
     /// 验证规则
     pub validation_rules: Vec<String>,
+    /// 配置 JSON Schema
+    pub schema: Option<Value>,
 }
@@ -0,0 +1,133 @@
+//! 配置来源追踪定义
+//!
+//! 描述合并后的配置值来自哪个配置源，以及被覆盖的值
+
+use crate::provider::insert_nested_value;
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use std::fmt;
+
+/// 数组合并策略
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+pub enum ArrayMergeStrategy {
+    /// 高优先级的数组整体替换低优先级的数组
+    #[default]
+    Replace,
+    /// 高优先级的数组追加到低优先级数组之后
+    Append,
+}
+
+/// 配置来源条目
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ProvenanceEntry {
+    /// 配置源描述（文件路径、环境变量名等）
+    pub source: String,
+    /// 配置源优先级
+    pub priority: i32,
+    /// 该配置源提供的原始值
+    pub value: Value,
+}
+
+/// 配置键的来源信息
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ConfigProvenance {
+    /// 配置键
+    pub key: String,
+    /// 合并后的生效值
+    pub effective_value: Option<Value>,
+    /// 胜出的配置源
+    pub winner: Option<ProvenanceEntry>,
+    /// 被覆盖的配置源（按优先级从高到低）
+    pub shadowed: Vec<ProvenanceEntry>,
+    /// 数组合并策略（仅当生效值为数组时有效）
+    pub array_strategy: Option<ArrayMergeStrategy>,
+}
+
+impl ConfigProvenance {
+    /// 配置键是否在任何配置源中定义
+    pub fn is_defined(&self) -> bool {
+        self.winner.is_some()
+    }
+}
+
+impl fmt::Display for ConfigProvenance {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        writeln!(f, "{}", self.key)?;
+        match (&self.winner, &self.effective_value) {
+            (Some(winner), Some(value)) => {
+                writeln!(f, "  生效值: {}", value)?;
+                writeln!(f, "  来源:   {} (优先级 {})", winner.source, winner.priority)?;
+            }
+            _ => {
+                writeln!(f, "  未在任何配置源中定义")?;
+                return Ok(());
+            }
+        }
+        if let Some(strategy) = self.array_strategy {
+            writeln!(f, "  数组合并策略: {:?}", strategy)?;
+        }
+        for entry in &self.shadowed {
+            writeln!(
+                f,
+                "  被覆盖: {} (优先级 {}) = {}",
+                entry.source, entry.priority, entry.value
+            )?;
+        }
+        Ok(())
+    }
+}
+
+/// 合并配置导出条目
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ConfigDumpEntry {
+    /// 叶子配置键
+    pub key: String,
+    /// 生效值
+    pub value: Value,
+    /// 提供该值的配置源（追加合并的数组可能有多个）
+    pub sources: Vec<String>,
+}
+
+/// 合并后生效配置的导出结果
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct ConfigDump {
+    /// 叶子配置条目（按键排序）
+    pub entries: Vec<ConfigDumpEntry>,
+}
+
+impl ConfigDump {
+    /// 查找指定键的条目
+    pub fn get(&self, key: &str) -> Option<&ConfigDumpEntry> {
+        self.entries.iter().find(|entry| entry.key == key)
+    }
+
+    /// 转换为带来源标注的嵌套 JSON
+    ///
+    /// 每个叶子节点形如 `{"value": ..., "source": "..."}`
+    pub fn to_annotated_value(&self) -> Value {
+        let mut root = Value::Object(serde_json::Map::new());
+        for entry in &self.entries {
+            let annotated = serde_json::json!({
+                "value": entry.value,
+                "source": entry.sources.join(", "),
+            });
+            insert_nested_value(&mut root, &entry.key, annotated);
+        }
+        root
+    }
+}
+
+impl fmt::Display for ConfigDump {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for entry in &self.entries {
+            writeln!(
+                f,
+                "{} = {}  # {}",
+                entry.key,
+                entry.value,
+                entry.sources.join(", ")
+            )?;
+        }
+        Ok(())
+    }
+}
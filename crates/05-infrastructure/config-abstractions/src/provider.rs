@@ -1,5 +1,6 @@
 //! 配置提供者抽象接口
 
+use crate::diff::ConfigKeyChange;
 use async_trait::async_trait;
 use infrastructure_common::{ConfigError, ConfigSection};
 use serde_json::Value;
@@ -37,6 +38,125 @@ pub trait ConfigProvider: Send + Sync {
     fn supports_hot_reload(&self) -> bool {
         false
     }
+
+    /// 获取完整的配置树
+    ///
+    /// 默认实现根据 [`get_all_keys`](Self::get_all_keys) 逐个读取叶子值并组装成嵌套对象，
+    /// 能够直接提供整棵配置树的提供者应当覆盖此方法
+    async fn get_all_configuration(&self) -> Result<Value, ConfigError> {
+        let mut root = Value::Object(serde_json::Map::new());
+        for key in self.get_all_keys().await? {
+            let value = match self.get_configuration(&key).await {
+                Ok(value) => value,
+                Err(ConfigError::KeyNotFound { .. }) => continue,
+                Err(e) => return Err(e),
+            };
+            if value.is_object() {
+                continue;
+            }
+            insert_nested_value(&mut root, &key, value);
+        }
+        Ok(root)
+    }
+
+    /// 获取配置源描述（如文件路径），用于配置来源追踪
+    fn source_description(&self) -> String {
+        self.name().to_string()
+    }
+
+    /// 获取指定配置键的具体来源（如环境变量名）
+    fn key_source(&self, _key: &str) -> String {
+        self.source_description()
+    }
+
+    /// 获取热重载时需要监控的文件
+    fn watch_paths(&self) -> Vec<std::path::PathBuf> {
+        Vec::new()
+    }
+
+    /// 是否支持写入配置
+    fn is_writable(&self) -> bool {
+        false
+    }
+
+    /// 写入一组配置变更，`new_value` 为 `None` 的键将被删除
+    ///
+    /// 只有 [`is_writable`](Self::is_writable) 返回 `true` 的提供者需要实现；
+    /// 写入成功后提供者返回的配置应立即反映这些变更
+    async fn apply_changes(&mut self, _changes: &[ConfigKeyChange]) -> Result<(), ConfigError> {
+        Err(ConfigError::ReadOnlyProvider {
+            provider: self.name().to_string(),
+        })
+    }
+}
+
+/// 按点分路径向 JSON 对象插入值，自动创建中间对象
+pub fn insert_nested_value(root: &mut Value, path: &str, value: Value) {
+    let mut current = root;
+    let mut parts = path.split('.').peekable();
+    while let Some(part) = parts.next() {
+        if !current.is_object() {
+            *current = Value::Object(serde_json::Map::new());
+        }
+        let map = current.as_object_mut().expect("已确保为对象");
+        if parts.peek().is_none() {
+            map.insert(part.to_string(), value);
+            return;
+        }
+        current = map
+            .entry(part.to_string())
+            .or_insert_with(|| Value::Object(serde_json::Map::new()));
+    }
+}
+
+/// 按点分路径删除 JSON 值，返回被删除的值
+///
+/// 删除后变为空的中间对象一并删除
+pub fn remove_nested_value(root: &mut Value, path: &str) -> Option<Value> {
+    let (parent, last) = match path.rsplit_once('.') {
+        Some((parent, last)) => (Some(parent), last),
+        None => (None, path),
+    };
+    let removed = match parent {
+        Some(parent) => get_nested_value_mut(root, parent)?
+            .as_object_mut()?
+            .remove(last)?,
+        None => root.as_object_mut()?.remove(last)?,
+    };
+    if let Some(parent) = parent {
+        let emptied = get_nested_value(root, parent)
+            .and_then(Value::as_object)
+            .is_some_and(|map| map.is_empty());
+        if emptied {
+            remove_nested_value(root, parent);
+        }
+    }
+    Some(removed)
+}
+
+/// 按点分路径获取 JSON 值的可变引用
+fn get_nested_value_mut<'a>(root: &'a mut Value, path: &str) -> Option<&'a mut Value> {
+    let mut current = root;
+    for part in path.split('.') {
+        current = current.as_object_mut()?.get_mut(part)?;
+    }
+    Some(current)
+}
+
+/// 按点分路径读取 JSON 值，空路径返回根节点
+pub fn get_nested_value<'a>(root: &'a Value, path: &str) -> Option<&'a Value> {
+    if path.is_empty() {
+        return Some(root);
+    }
+    let mut current = root;
+    for part in path.split('.') {
+        current = match current {
+            Value::Object(map) => map.get(part)?,
+            Value::Array(items) => items.get(part.parse::<usize>().ok()?)?,
+            _ => return None,
+        };
+    }
+    Some(current)
 }
 
 /// 文件配置提供者 trait
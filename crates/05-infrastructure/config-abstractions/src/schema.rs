@@ -0,0 +1,619 @@
+//! 配置 JSON Schema 的组装与验证
+//!
+//! [`ApplicationSchemaBuilder`] 把各配置选项的 Schema 按配置路径组装成整个应用的 Schema，
+//! [`SchemaValidator`] 按 Schema 验证合并后的配置，错误路径使用 JSON Pointer 表示。
+//! 被验证的是解析密钥后的配置，因此验证错误不记录配置的实际值
+
+use crate::manager::{ValidationError, ValidationErrorType};
+use dashmap::DashMap;
+use infrastructure_common::JSON_SCHEMA_DIALECT;
+use regex::Regex;
+use serde_json::{json, Map, Value};
+
+/// 应用配置 Schema 构建器
+#[derive(Debug, Clone)]
+pub struct ApplicationSchemaBuilder {
+    /// 根 Schema
+    root: Value,
+}
+
+impl ApplicationSchemaBuilder {
+    /// 创建新的应用配置 Schema 构建器
+    pub fn new(title: impl Into<String>) -> Self {
+        Self {
+            root: json!({
+                "$schema": JSON_SCHEMA_DIALECT,
+                "title": title.into(),
+                "type": "object",
+                "properties": {}
+            }),
+        }
+    }
+
+    /// 在点分配置路径处挂载 Schema
+    ///
+    /// 路径上的中间节点自动创建为对象；`required` 为 `true` 时路径上的每一级都标记为必需
+    pub fn option(mut self, path: &str, schema: Value, required: bool) -> Self {
+        let segments: Vec<&str> = path.split('.').filter(|s| !s.is_empty()).collect();
+        let Some((last, parents)) = segments.split_last() else {
+            return self;
+        };
+
+        let mut node = &mut self.root;
+        for segment in parents {
+            if required {
+                mark_required(node, segment);
+            }
+            node = property_entry(node, segment)
+                .or_insert_with(|| json!({"type": "object", "properties": {}}));
+        }
+        if required {
+            mark_required(node, last);
+        }
+        let entry = property_entry(node, last);
+        match entry {
+            serde_json::map::Entry::Occupied(mut existing) => {
+                merge_schema(existing.get_mut(), schema);
+            }
+            serde_json::map::Entry::Vacant(vacant) => {
+                vacant.insert(schema);
+            }
+        }
+        self
+    }
+
+    /// 生成应用配置 Schema
+    pub fn build(self) -> Value {
+        self.root
+    }
+}
+
+/// 获取对象 Schema 的属性入口
+fn property_entry<'a>(node: &'a mut Value, name: &str) -> serde_json::map::Entry<'a> {
+    let map = ensure_object(node);
+    map.entry("properties")
+        .or_insert_with(|| Value::Object(Map::new()));
+    let properties = map
+        .get_mut("properties")
+        .map(ensure_object)
+        .expect("properties 已存在");
+    properties.entry(name.to_string())
+}
+
+/// 将 Schema 展开为对象形式
+fn ensure_object(node: &mut Value) -> &mut Map<String, Value> {
+    if !node.is_object() {
+        *node = Value::Object(Map::new());
+    }
+    node.as_object_mut().expect("已转换为对象")
+}
+
+/// 将属性标记为必需
+fn mark_required(node: &mut Value, name: &str) {
+    let map = ensure_object(node);
+    let required = map
+        .entry("required")
+        .or_insert_with(|| Value::Array(Vec::new()));
+    if let Value::Array(required) = required {
+        if !required.iter().any(|r| r.as_str() == Some(name)) {
+            required.push(Value::String(name.to_string()));
+        }
+    }
+}
+
+/// 合并同一路径上的两个 Schema（例如父路径的占位对象与具体选项的 Schema）
+fn merge_schema(existing: &mut Value, schema: Value) {
+    let Value::Object(incoming) = schema else {
+        *existing = schema;
+        return;
+    };
+    let map = ensure_object(existing);
+    for (key, value) in incoming {
+        match (key.as_str(), map.get_mut(&key)) {
+            ("properties", Some(Value::Object(properties))) => {
+                if let Value::Object(value) = value {
+                    for (name, property) in value {
+                        match properties.get_mut(&name) {
+                            Some(current) => merge_schema(current, property),
+                            None => {
+                                properties.insert(name, property);
+                            }
+                        }
+                    }
+                }
+            }
+            ("required", Some(Value::Array(required))) => {
+                if let Value::Array(value) = value {
+                    for name in value {
+                        if !required.contains(&name) {
+                            required.push(name);
+                        }
+                    }
+                }
+            }
+            _ => {
+                map.insert(key, value);
+            }
+        }
+    }
+}
+
+/// 将点分配置路径转换为 JSON Pointer
+pub fn config_path_to_pointer(path: &str) -> String {
+    path.split('.')
+        .filter(|s| !s.is_empty())
+        .map(|segment| format!("/{}", escape_pointer_segment(segment)))
+        .collect()
+}
+
+/// 转义 JSON Pointer 片段（RFC 6901）
+fn escape_pointer_segment(segment: &str) -> String {
+    segment.replace('~', "~0").replace('/', "~1")
+}
+
+/// 基于 JSON Schema 的配置验证器
+///
+/// 支持 2020-12 草案中常用于配置的关键字：`$ref`（文档内引用）、`type`、`enum`、`const`、
+/// `properties`、`required`、`additionalProperties`、`items`、`minItems`、`maxItems`、
+/// `uniqueItems`、`minimum`、`maximum`、`exclusiveMinimum`、`exclusiveMaximum`、`multipleOf`、
+/// `minLength`、`maxLength`、`pattern`、`allOf`、`anyOf`、`oneOf` 和 `not`
+#[derive(Debug, Clone)]
+pub struct SchemaValidator {
+    /// 根 Schema
+    schema: Value,
+}
+
+impl SchemaValidator {
+    /// 创建新的 Schema 验证器
+    pub fn new(schema: Value) -> Self {
+        Self { schema }
+    }
+
+    /// 获取根 Schema
+    pub fn schema(&self) -> &Value {
+        &self.schema
+    }
+
+    /// 验证配置值，返回所有验证错误
+    ///
+    /// 错误路径为 JSON Pointer，根节点为空字符串
+    pub fn validate(&self, instance: &Value) -> Vec<ValidationError> {
+        let mut errors = Vec::new();
+        self.validate_node(&self.schema, instance, "", &mut errors, 0);
+        errors
+    }
+
+    /// 验证配置值是否满足 Schema
+    pub fn is_valid(&self, instance: &Value) -> bool {
+        self.validate(instance).is_empty()
+    }
+
+    /// 递归验证节点
+    fn validate_node(
+        &self,
+        schema: &Value,
+        instance: &Value,
+        pointer: &str,
+        errors: &mut Vec<ValidationError>,
+        depth: usize,
+    ) {
+        // 防止循环引用导致无限递归
+        if depth > 64 {
+            return;
+        }
+
+        let schema = match schema {
+            Value::Bool(true) => return,
+            Value::Bool(false) => {
+                errors.push(ValidationError::new(
+                    pointer,
+                    "Schema 不允许此值",
+                    ValidationErrorType::Custom,
+                ));
+                return;
+            }
+            Value::Object(map) => map,
+            _ => return,
+        };
+
+        if let Some(reference) = schema.get("$ref").and_then(Value::as_str) {
+            match self.resolve_ref(reference) {
+                Some(target) => self.validate_node(target, instance, pointer, errors, depth + 1),
+                None => errors.push(ValidationError::new(
+                    pointer,
+                    format!("无法解析 Schema 引用: {}", reference),
+                    ValidationErrorType::Custom,
+                )),
+            }
+        }
+
+        if let Some(expected) = schema.get("type") {
+            if !matches_type(expected, instance) {
+                let mut error = ValidationError::new(
+                    pointer,
+                    "类型不匹配",
+                    ValidationErrorType::TypeMismatch,
+                );
+                error.expected = Some(type_description(expected));
+                error.actual = Some(instance_type(instance).to_string());
+                errors.push(error);
+                // 类型不匹配时其余关键字的错误没有意义
+                return;
+            }
+        }
+
+        if let Some(Value::Array(allowed)) = schema.get("enum") {
+            if !allowed.iter().any(|candidate| json_equal(candidate, instance)) {
+                let mut error = ValidationError::new(
+                    pointer,
+                    "值不在允许的范围内",
+                    ValidationErrorType::ValueOutOfRange,
+                );
+                error.expected = Some(Value::Array(allowed.clone()).to_string());
+                errors.push(error);
+            }
+        }
+
+        if let Some(expected) = schema.get("const") {
+            if !json_equal(expected, instance) {
+                let mut error = ValidationError::new(
+                    pointer,
+                    "值与常量不一致",
+                    ValidationErrorType::ValueOutOfRange,
+                );
+                error.expected = Some(expected.to_string());
+                errors.push(error);
+            }
+        }
+
+        match instance {
+            Value::Object(object) => self.validate_object(schema, object, pointer, errors, depth),
+            Value::Array(items) => self.validate_array(schema, items, pointer, errors, depth),
+            Value::Number(_) => validate_number(schema, instance, pointer, errors),
+            Value::String(text) => validate_string(schema, text, pointer, errors),
+            _ => {}
+        }
+
+        self.validate_combinators(schema, instance, pointer, errors, depth);
+    }
+
+    /// 验证对象关键字
+    fn validate_object(
+        &self,
+        schema: &Map<String, Value>,
+        object: &Map<String, Value>,
+        pointer: &str,
+        errors: &mut Vec<ValidationError>,
+        depth: usize,
+    ) {
+        if let Some(Value::Array(required)) = schema.get("required") {
+            for name in required.iter().filter_map(Value::as_str) {
+                if !object.contains_key(name) {
+                    errors.push(ValidationError::required_field_missing(child_pointer(
+                        pointer, name,
+                    )));
+                }
+            }
+        }
+
+        let properties = schema.get("properties").and_then(Value::as_object);
+        for (name, value) in object {
+            let child = child_pointer(pointer, name);
+            match properties.and_then(|properties| properties.get(name)) {
+                Some(property) => self.validate_node(property, value, &child, errors, depth + 1),
+                None => match schema.get("additionalProperties") {
+                    Some(Value::Bool(false)) => errors.push(ValidationError::new(
+                        child,
+                        format!("不允许的配置项: {}", name),
+                        ValidationErrorType::Custom,
+                    )),
+                    Some(additional) => {
+                        self.validate_node(additional, value, &child, errors, depth + 1)
+                    }
+                    None => {}
+                },
+            }
+        }
+    }
+
+    /// 验证数组关键字
+    fn validate_array(
+        &self,
+        schema: &Map<String, Value>,
+        items: &[Value],
+        pointer: &str,
+        errors: &mut Vec<ValidationError>,
+        depth: usize,
+    ) {
+        if let Some(min) = schema.get("minItems").and_then(Value::as_u64) {
+            if (items.len() as u64) < min {
+                errors.push(ValidationError::value_out_of_range(
+                    pointer,
+                    format!("{} 项", items.len()),
+                    format!("至少 {} 项", min),
+                ));
+            }
+        }
+        if let Some(max) = schema.get("maxItems").and_then(Value::as_u64) {
+            if (items.len() as u64) > max {
+                errors.push(ValidationError::value_out_of_range(
+                    pointer,
+                    format!("{} 项", items.len()),
+                    format!("至多 {} 项", max),
+                ));
+            }
+        }
+        if schema.get("uniqueItems").and_then(Value::as_bool) == Some(true) {
+            let duplicated = items
+                .iter()
+                .enumerate()
+                .any(|(i, a)| items[i + 1..].iter().any(|b| json_equal(a, b)));
+            if duplicated {
+                errors.push(ValidationError::new(
+                    pointer,
+                    "数组元素必须唯一",
+                    ValidationErrorType::Custom,
+                ));
+            }
+        }
+        if let Some(item_schema) = schema.get("items") {
+            for (index, item) in items.iter().enumerate() {
+                let child = format!("{}/{}", pointer, index);
+                self.validate_node(item_schema, item, &child, errors, depth + 1);
+            }
+        }
+    }
+
+    /// 验证组合关键字
+    fn validate_combinators(
+        &self,
+        schema: &Map<String, Value>,
+        instance: &Value,
+        pointer: &str,
+        errors: &mut Vec<ValidationError>,
+        depth: usize,
+    ) {
+        if let Some(Value::Array(all_of)) = schema.get("allOf") {
+            for sub in all_of {
+                self.validate_node(sub, instance, pointer, errors, depth + 1);
+            }
+        }
+
+        if let Some(Value::Array(any_of)) = schema.get("anyOf") {
+            let matched = any_of
+                .iter()
+                .any(|sub| self.matches(sub, instance, pointer, depth));
+            if !matched {
+                errors.push(ValidationError::new(
+                    pointer,
+                    "值不满足 anyOf 中的任何一个 Schema",
+                    ValidationErrorType::Custom,
+                ));
+            }
+        }
+
+        if let Some(Value::Array(one_of)) = schema.get("oneOf") {
+            let matched = one_of
+                .iter()
+                .filter(|sub| self.matches(sub, instance, pointer, depth))
+                .count();
+            if matched != 1 {
+                errors.push(ValidationError::new(
+                    pointer,
+                    format!("值必须恰好满足 oneOf 中的一个 Schema，实际满足 {} 个", matched),
+                    ValidationErrorType::Custom,
+                ));
+            }
+        }
+
+        if let Some(not) = schema.get("not") {
+            if self.matches(not, instance, pointer, depth) {
+                errors.push(ValidationError::new(
+                    pointer,
+                    "值不能满足 not 中的 Schema",
+                    ValidationErrorType::Custom,
+                ));
+            }
+        }
+    }
+
+    /// 子 Schema 是否匹配
+    fn matches(&self, schema: &Value, instance: &Value, pointer: &str, depth: usize) -> bool {
+        let mut errors = Vec::new();
+        self.validate_node(schema, instance, pointer, &mut errors, depth + 1);
+        errors.is_empty()
+    }
+
+    /// 解析文档内引用（`#` 或 `#/...`）
+    fn resolve_ref(&self, reference: &str) -> Option<&Value> {
+        let pointer = reference.strip_prefix('#')?;
+        if pointer.is_empty() {
+            return Some(&self.schema);
+        }
+        self.schema.pointer(pointer)
+    }
+}
+
+/// 拼接子节点的 JSON Pointer
+fn child_pointer(pointer: &str, name: &str) -> String {
+    format!("{}/{}", pointer, escape_pointer_segment(name))
+}
+
+/// 验证数值关键字
+fn validate_number(
+    schema: &Map<String, Value>,
+    instance: &Value,
+    pointer: &str,
+    errors: &mut Vec<ValidationError>,
+) {
+    let Some(value) = instance.as_f64() else {
+        return;
+    };
+    let bound = |keyword: &str| schema.get(keyword).and_then(Value::as_f64);
+
+    let mut out_of_range = |expected: String| {
+        let mut error =
+            ValidationError::new(pointer, "值超出范围", ValidationErrorType::ValueOutOfRange);
+        error.expected = Some(expected);
+        errors.push(error);
+    };
+    if let Some(minimum) = bound("minimum") {
+        if value < minimum {
+            out_of_range(format!(">= {}", minimum));
+        }
+    }
+    if let Some(maximum) = bound("maximum") {
+        if value > maximum {
+            out_of_range(format!("<= {}", maximum));
+        }
+    }
+    if let Some(minimum) = bound("exclusiveMinimum") {
+        if value <= minimum {
+            out_of_range(format!("> {}", minimum));
+        }
+    }
+    if let Some(maximum) = bound("exclusiveMaximum") {
+        if value >= maximum {
+            out_of_range(format!("< {}", maximum));
+        }
+    }
+    if let Some(divisor) = bound("multipleOf") {
+        if divisor > 0.0 {
+            let quotient = value / divisor;
+            if (quotient - quotient.round()).abs() > 1e-9 {
+                out_of_range(format!("{} 的倍数", divisor));
+            }
+        }
+    }
+}
+
+/// 验证字符串关键字
+fn validate_string(
+    schema: &Map<String, Value>,
+    text: &str,
+    pointer: &str,
+    errors: &mut Vec<ValidationError>,
+) {
+    let length = text.chars().count() as u64;
+    if let Some(min) = schema.get("minLength").and_then(Value::as_u64) {
+        if length < min {
+            errors.push(ValidationError::value_out_of_range(
+                pointer,
+                format!("长度 {}", length),
+                format!("长度至少为 {}", min),
+            ));
+        }
+    }
+    if let Some(max) = schema.get("maxLength").and_then(Value::as_u64) {
+        if length > max {
+            errors.push(ValidationError::value_out_of_range(
+                pointer,
+                format!("长度 {}", length),
+                format!("长度至多为 {}", max),
+            ));
+        }
+    }
+    if let Some(pattern) = schema.get("pattern").and_then(Value::as_str) {
+        match compiled_pattern(pattern).value() {
+            Ok(regex) if !regex.is_match(text) => {
+                errors.push(ValidationError::format_error(pointer, pattern));
+            }
+            Ok(_) => {}
+            Err(e) => errors.push(ValidationError::new(
+                pointer,
+                format!("无效的正则表达式 {}: {}", pattern, e),
+                ValidationErrorType::Custom,
+            )),
+        }
+    }
+}
+
+/// 获取按模式缓存的编译后正则表达式，避免每次验证都重新编译
+fn compiled_pattern(
+    pattern: &str,
+) -> dashmap::mapref::one::Ref<'static, String, Result<Regex, String>> {
+    static PATTERNS: once_cell::sync::Lazy<DashMap<String, Result<Regex, String>>> =
+        once_cell::sync::Lazy::new(DashMap::new);
+
+    if let Some(compiled) = PATTERNS.get(pattern) {
+        return compiled;
+    }
+    PATTERNS
+        .entry(pattern.to_string())
+        .or_insert_with(|| Regex::new(pattern).map_err(|e| e.to_string()))
+        .downgrade()
+}
+
+/// 值是否满足 `type` 关键字
+fn matches_type(expected: &Value, instance: &Value) -> bool {
+    match expected {
+        Value::String(kind) => matches_single_type(kind, instance),
+        Value::Array(kinds) => kinds
+            .iter()
+            .filter_map(Value::as_str)
+            .any(|kind| matches_single_type(kind, instance)),
+        _ => true,
+    }
+}
+
+/// 值是否为指定的 JSON 类型
+fn matches_single_type(kind: &str, instance: &Value) -> bool {
+    match kind {
+        "null" => instance.is_null(),
+        "boolean" => instance.is_boolean(),
+        "object" => instance.is_object(),
+        "array" => instance.is_array(),
+        "string" => instance.is_string(),
+        "number" => instance.is_number(),
+        "integer" => match instance {
+            Value::Number(n) => {
+                n.is_i64() || n.is_u64() || n.as_f64().is_some_and(|f| f.fract() == 0.0)
+            }
+            _ => false,
+        },
+        _ => true,
+    }
+}
+
+/// `type` 关键字的描述
+fn type_description(expected: &Value) -> String {
+    match expected {
+        Value::String(kind) => kind.clone(),
+        Value::Array(kinds) => kinds
+            .iter()
+            .filter_map(Value::as_str)
+            .collect::<Vec<_>>()
+            .join(" | "),
+        other => other.to_string(),
+    }
+}
+
+/// 值的 JSON 类型名称
+fn instance_type(instance: &Value) -> &'static str {
+    match instance {
+        Value::Null => "null",
+        Value::Bool(_) => "boolean",
+        Value::Number(n) if n.is_i64() || n.is_u64() => "integer",
+        Value::Number(_) => "number",
+        Value::String(_) => "string",
+        Value::Array(_) => "array",
+        Value::Object(_) => "object",
+    }
+}
+
+/// 按 JSON Schema 语义比较两个值（`1` 与 `1.0` 相等）
+fn json_equal(a: &Value, b: &Value) -> bool {
+    match (a, b) {
+        (Value::Number(x), Value::Number(y)) => x.as_f64() == y.as_f64(),
+        (Value::Array(x), Value::Array(y)) => {
+            x.len() == y.len() && x.iter().zip(y).all(|(a, b)| json_equal(a, b))
+        }
+        (Value::Object(x), Value::Object(y)) => {
+            x.len() == y.len()
+                && x
+                    .iter()
+                    .all(|(key, value)| y.get(key).is_some_and(|other| json_equal(value, other)))
+        }
+        _ => a == b,
+    }
+}
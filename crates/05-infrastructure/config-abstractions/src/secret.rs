@@ -0,0 +1,246 @@
+//! 密钥管理抽象
+//!
+//! 配置值中的 `${secret:name}` 引用通过 [`SecretProvider`] 解析，
+//! 敏感配置在日志、变更事件、快照和配置导出中通过 [`ConfigRedactor`] 脱敏
+
+use crate::events::ConfigChangeEvent;
+use crate::provenance::{ConfigDump, ConfigProvenance};
+use async_trait::async_trait;
+use infrastructure_common::ConfigError;
+use serde_json::Value;
+use std::collections::HashSet;
+
+pub use secrecy::{ExposeSecret, Secret, SecretString};
+
+/// 密钥引用前缀
+pub const SECRET_REFERENCE_PREFIX: &str = "${secret:";
+
+/// 脱敏后的占位值
+pub const REDACTED_VALUE: &str = "******";
+
+/// 默认视为敏感的配置键片段
+pub const DEFAULT_SENSITIVE_KEY_PATTERNS: &[&str] = &[
+    "password",
+    "passwd",
+    "secret",
+    "token",
+    "api_key",
+    "apikey",
+    "private_key",
+    "credential",
+];
+
+/// 密钥提供者接口
+///
+/// 按名称提供密钥值，如 Docker/K8s 挂载的密钥文件、加密的本地文件或外部密钥服务
+#[async_trait]
+pub trait SecretProvider: Send + Sync {
+    /// 获取提供者名称
+    fn name(&self) -> &str;
+
+    /// 获取密钥，不存在时返回 `None`
+    async fn get_secret(&self, name: &str) -> Result<Option<SecretString>, ConfigError>;
+
+    /// 重新加载密钥
+    async fn reload(&self) -> Result<(), ConfigError> {
+        Ok(())
+    }
+}
+
+/// 字符串中的密钥引用
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SecretReference {
+    /// 引用在字符串中的起始字节位置
+    pub start: usize,
+    /// 引用在字符串中的结束字节位置（不含）
+    pub end: usize,
+    /// 密钥名称
+    pub name: String,
+}
+
+/// 解析字符串中的所有 `${secret:name}` 引用
+pub fn parse_secret_references(value: &str) -> Vec<SecretReference> {
+    let mut references = Vec::new();
+    let mut offset = 0;
+
+    while let Some(found) = value[offset..].find(SECRET_REFERENCE_PREFIX) {
+        let start = offset + found;
+        let name_start = start + SECRET_REFERENCE_PREFIX.len();
+        let Some(close) = value[name_start..].find('}') else {
+            break;
+        };
+        let end = name_start + close + 1;
+        let name = value[name_start..end - 1].trim();
+        if !name.is_empty() {
+            references.push(SecretReference {
+                start,
+                end,
+                name: name.to_string(),
+            });
+        }
+        offset = end;
+    }
+
+    references
+}
+
+/// 配置值中是否包含密钥引用
+pub fn contains_secret_reference(value: &Value) -> bool {
+    match value {
+        Value::String(s) => s.contains(SECRET_REFERENCE_PREFIX),
+        Value::Array(items) => items.iter().any(contains_secret_reference),
+        Value::Object(map) => map.values().any(contains_secret_reference),
+        _ => false,
+    }
+}
+
+/// 配置脱敏器
+///
+/// 键名匹配敏感片段（不区分大小写）或被显式标记的配置路径，
+/// 其下所有叶子值都会替换为 [`REDACTED_VALUE`]
+#[derive(Debug, Clone)]
+pub struct ConfigRedactor {
+    /// 敏感键名片段
+    patterns: Vec<String>,
+    /// 显式标记的敏感配置路径
+    sensitive_paths: HashSet<String>,
+}
+
+impl ConfigRedactor {
+    /// 使用默认敏感键名片段创建脱敏器
+    pub fn new() -> Self {
+        Self {
+            patterns: DEFAULT_SENSITIVE_KEY_PATTERNS
+                .iter()
+                .map(|pattern| pattern.to_string())
+                .collect(),
+            sensitive_paths: HashSet::new(),
+        }
+    }
+
+    /// 添加敏感键名片段
+    pub fn with_pattern(mut self, pattern: impl Into<String>) -> Self {
+        self.patterns.push(pattern.into().to_lowercase());
+        self
+    }
+
+    /// 标记敏感配置路径
+    pub fn mark_sensitive(&mut self, path: impl Into<String>) {
+        self.sensitive_paths.insert(path.into());
+    }
+
+    /// 判断配置路径是否敏感
+    pub fn is_sensitive(&self, path: &str) -> bool {
+        let mut prefix = String::new();
+        for segment in path.split('.') {
+            if !prefix.is_empty() {
+                prefix.push('.');
+            }
+            prefix.push_str(segment);
+
+            if self.sensitive_paths.contains(&prefix) {
+                return true;
+            }
+            let segment = segment.to_lowercase();
+            if self
+                .patterns
+                .iter()
+                .any(|pattern| segment.contains(pattern.as_str()))
+            {
+                return true;
+            }
+        }
+        false
+    }
+
+    /// 对指定路径下的配置值脱敏
+    pub fn redact_value(&self, path: &str, value: &Value) -> Value {
+        if self.is_sensitive(path) {
+            return redact_leaves(value);
+        }
+
+        match value {
+            Value::Object(map) => Value::Object(
+                map.iter()
+                    .map(|(key, child)| {
+                        let child_path = if path.is_empty() {
+                            key.clone()
+                        } else {
+                            format!("{}.{}", path, key)
+                        };
+                        (key.clone(), self.redact_value(&child_path, child))
+                    })
+                    .collect(),
+            ),
+            Value::Array(items) => Value::Array(
+                items
+                    .iter()
+                    .enumerate()
+                    .map(|(index, child)| self.redact_value(&format!("{}[{}]", path, index), child))
+                    .collect(),
+            ),
+            other => other.clone(),
+        }
+    }
+
+    /// 对配置变更事件脱敏
+    pub fn redact_event(&self, event: &ConfigChangeEvent) -> ConfigChangeEvent {
+        let mut redacted = event.clone();
+        redacted.old_value = event
+            .old_value
+            .as_ref()
+            .map(|value| self.redact_value(&event.path, value));
+        redacted.new_value = event
+            .new_value
+            .as_ref()
+            .map(|value| self.redact_value(&event.path, value));
+        redacted
+    }
+
+    /// 对配置导出脱敏
+    pub fn redact_dump(&self, dump: &ConfigDump) -> ConfigDump {
+        let mut redacted = dump.clone();
+        for entry in &mut redacted.entries {
+            entry.value = self.redact_value(&entry.key, &entry.value);
+        }
+        redacted
+    }
+
+    /// 对配置来源信息脱敏
+    pub fn redact_provenance(&self, provenance: &ConfigProvenance) -> ConfigProvenance {
+        let key = provenance.key.as_str();
+        let mut redacted = provenance.clone();
+        redacted.effective_value = provenance
+            .effective_value
+            .as_ref()
+            .map(|value| self.redact_value(key, value));
+        for entry in redacted
+            .winner
+            .iter_mut()
+            .chain(redacted.shadowed.iter_mut())
+        {
+            entry.value = self.redact_value(key, &entry.value);
+        }
+        redacted
+    }
+}
+
+impl Default for ConfigRedactor {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// 将所有非空叶子值替换为占位值
+fn redact_leaves(value: &Value) -> Value {
+    match value {
+        Value::Null => Value::Null,
+        Value::Object(map) => Value::Object(
+            map.iter()
+                .map(|(key, child)| (key.clone(), redact_leaves(child)))
+                .collect(),
+        ),
+        Value::Array(items) => Value::Array(items.iter().map(redact_leaves).collect()),
+        _ => Value::String(REDACTED_VALUE.to_string()),
+    }
+}
@@ -32,13 +32,102 @@ pub trait ConfigValidator<T>: Send + Sync {
     }
 }
 
+/// 同步配置验证器适配器
+///
+/// 将 `infrastructure_common::ConfigValidator`（如 `#[derive(Validate)]` 生成的验证器）
+/// 适配为 [`ConfigValidator`]，以便注册到配置管理器
+pub struct SyncValidatorAdapter<T> {
+    /// 被适配的同步验证器
+    inner: Box<dyn infrastructure_common::ConfigValidator<T>>,
+}
+
+impl<T> SyncValidatorAdapter<T> {
+    /// 创建新的同步验证器适配器
+    pub fn new(inner: Box<dyn infrastructure_common::ConfigValidator<T>>) -> Self {
+        Self { inner }
+    }
+}
+
+impl<T> std::fmt::Debug for SyncValidatorAdapter<T> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("SyncValidatorAdapter")
+            .field("name", &self.inner.name())
+            .finish()
+    }
+}
+
+#[async_trait]
+impl<T> ConfigValidator<T> for SyncValidatorAdapter<T>
+where
+    T: Send + Sync,
+{
+    async fn validate(&self, config: &T) -> Result<(), ValidationError> {
+        self.inner.validate(config)
+    }
+
+    fn name(&self) -> &str {
+        self.inner.name()
+    }
+}
+
+/// 串联配置验证器
+///
+/// 按注册顺序依次执行多个验证器，返回第一个验证错误
+pub struct ChainedConfigValidator<T> {
+    /// 验证器名称（各验证器名称以 ` + ` 连接）
+    name: String,
+    /// 被串联的验证器
+    validators: Vec<Box<dyn ConfigValidator<T>>>,
+}
+
+impl<T> ChainedConfigValidator<T> {
+    /// 串联两个验证器
+    pub fn new(first: Box<dyn ConfigValidator<T>>, second: Box<dyn ConfigValidator<T>>) -> Self {
+        Self {
+            name: format!("{} + {}", first.name(), second.name()),
+            validators: vec![first, second],
+        }
+    }
+}
+
+impl<T> std::fmt::Debug for ChainedConfigValidator<T> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("ChainedConfigValidator")
+            .field("name", &self.name)
+            .finish()
+    }
+}
+
+#[async_trait]
+impl<T> ConfigValidator<T> for ChainedConfigValidator<T>
+where
+    T: Send + Sync,
+{
+    async fn validate(&self, config: &T) -> Result<(), ValidationError> {
+        for validator in &self.validators {
+            validator.validate(config).await?;
+        }
+        Ok(())
+    }
+
+    fn name(&self) -> &str {
+        &self.name
+    }
+
+    fn is_async(&self) -> bool {
+        self.validators.iter().any(|validator| validator.is_async())
+    }
+}
+
 /// 配置验证管理器 trait
 #[async_trait]
 pub trait ConfigValidationManager: Send + Sync {
     /// 注册验证器
+    ///
+    /// 同一配置类型已有验证器时，新验证器会与已有验证器串联执行
     async fn register_validator<T>(&mut self, validator: Box<dyn ConfigValidator<T>>) -> Result<(), ConfigError>
     where
-        T: 'static;
+        T: Send + Sync + 'static;
     
     /// 移除验证器
     async fn unregister_validator<T>(&mut self) -> Result<(), ConfigError>
@@ -48,7 +137,7 @@ pub trait ConfigValidationManager: Send + Sync {
     /// 验证特定类型的配置
     async fn validate_options_type<T>(&self, config: &T) -> Result<ValidationResult, ConfigError>
     where
-        T: 'static;
+        T: Send + Sync + 'static;
     
     /// 验证所有已注册的配置类型
     async fn validate_all(&self) -> Result<ValidationResult, ConfigError>;
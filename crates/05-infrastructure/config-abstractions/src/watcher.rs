@@ -23,8 +23,10 @@ pub trait ConfigWatcher: Send + Sync {
     /// 移除监控路径
     async fn remove_watch_path(&mut self, path: &Path) -> Result<(), ConfigError>;
     
-    /// 获取变更事件接收器
-    fn get_change_receiver(&self) -> mpsc::Receiver<ConfigChangeEvent>;
+    /// 取出变更事件接收器
+    ///
+    /// 接收器只能被取出一次，之后返回 `None`
+    fn take_change_receiver(&mut self) -> Option<mpsc::Receiver<ConfigChangeEvent>>;
     
     /// 是否正在监控
     fn is_watching(&self) -> bool;
@@ -0,0 +1,56 @@
+//! 配置写入抽象
+//!
+//! 运行时修改配置（如管理后台调整参数）时，写入请求路由到可写的配置提供者，
+//! 通过 [`ConfigWriteOptions::expected_version`] 进行乐观并发控制（比较并设置）
+
+use crate::events::ConfigChangeEvent;
+use crate::history::ConfigAuditRecord;
+
+/// 配置写入选项
+#[derive(Debug, Clone, Default)]
+pub struct ConfigWriteOptions {
+    /// 期望的当前配置版本，与实际版本不一致时拒绝写入
+    pub expected_version: Option<u64>,
+    /// 目标配置提供者名称，缺省时写入优先级最高的可写提供者
+    pub provider: Option<String>,
+    /// 审计记录
+    pub audit: ConfigAuditRecord,
+}
+
+impl ConfigWriteOptions {
+    /// 创建指定来源的写入选项
+    pub fn new(source: impl Into<String>) -> Self {
+        Self {
+            expected_version: None,
+            provider: None,
+            audit: ConfigAuditRecord::new(source),
+        }
+    }
+
+    /// 设置期望的当前配置版本
+    pub fn with_expected_version(mut self, version: u64) -> Self {
+        self.expected_version = Some(version);
+        self
+    }
+
+    /// 设置目标配置提供者
+    pub fn with_provider(mut self, provider: impl Into<String>) -> Self {
+        self.provider = Some(provider.into());
+        self
+    }
+
+    /// 设置审计记录
+    pub fn with_audit(mut self, audit: ConfigAuditRecord) -> Self {
+        self.audit = audit;
+        self
+    }
+}
+
+/// 配置写入结果
+#[derive(Debug, Clone)]
+pub struct ConfigWriteResult {
+    /// 写入后的配置版本
+    pub version: u64,
+    /// 写入产生的逐键变更事件
+    pub events: Vec<ConfigChangeEvent>,
+}
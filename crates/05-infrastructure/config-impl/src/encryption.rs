@@ -0,0 +1,476 @@
+//! 配置值静态加密
+//!
+//! 配置文件中的敏感值可以保存为 `ENC[...]` 形式的密文，括号内为
+//! [`SecretEncryptionKey::encrypt`] 加密后的值的 JSON 编码，因此数字、布尔值和配置节
+//! 解密后保持原有类型。TOML/JSON/YAML 配置提供者设置解密密钥后透明解密，
+//! [`ConfigFileEncryptor`] 用于原地加密配置项和轮换密钥。
+//!
+//! 解密失败的错误只包含配置键路径，不包含明文
+
+use crate::profiles::ConfigFileFormat;
+use crate::secrets::SecretEncryptionKey;
+use config_abstractions::{get_nested_value, ConfigKeyChange};
+use infrastructure_common::ConfigError;
+use serde_json::Value;
+use std::path::Path;
+use tracing::info;
+
+/// 加密值前缀
+pub const ENCRYPTED_VALUE_PREFIX: &str = "ENC[";
+
+/// 加密值后缀
+pub const ENCRYPTED_VALUE_SUFFIX: &str = "]";
+
+/// 默认保存配置加密密钥（Base64）的环境变量
+pub const DEFAULT_CONFIG_KEY_ENV: &str = "ADSP_CONFIG_KEY";
+
+/// 判断字符串是否为 `ENC[...]` 形式的加密值
+pub fn is_encrypted_value(value: &str) -> bool {
+    value.starts_with(ENCRYPTED_VALUE_PREFIX)
+        && value.ends_with(ENCRYPTED_VALUE_SUFFIX)
+        && value.len() > ENCRYPTED_VALUE_PREFIX.len() + ENCRYPTED_VALUE_SUFFIX.len()
+}
+
+/// 加密配置值，返回 `ENC[...]` 形式的字符串
+pub fn encrypt_config_value(
+    key: &SecretEncryptionKey,
+    value: &Value,
+) -> Result<String, ConfigError> {
+    let plaintext = serde_json::to_vec(value)?;
+    Ok(format!(
+        "{}{}{}",
+        ENCRYPTED_VALUE_PREFIX,
+        key.encrypt(&plaintext)?,
+        ENCRYPTED_VALUE_SUFFIX
+    ))
+}
+
+/// 解密 `ENC[...]` 形式的配置值
+///
+/// `path` 仅用于错误信息
+pub fn decrypt_config_value(
+    key: &SecretEncryptionKey,
+    path: &str,
+    encrypted: &str,
+) -> Result<Value, ConfigError> {
+    let payload = encrypted
+        .strip_prefix(ENCRYPTED_VALUE_PREFIX)
+        .and_then(|rest| rest.strip_suffix(ENCRYPTED_VALUE_SUFFIX))
+        .ok_or_else(|| decryption_error(path, "不是 ENC[...] 形式的加密值"))?;
+    let plaintext = key.decrypt(payload).map_err(|e| match e {
+        ConfigError::SecretError { message } => decryption_error(path, message),
+        other => decryption_error(path, other.to_string()),
+    })?;
+    // 不能使用 serde_json 的错误信息，其中可能包含部分明文
+    serde_json::from_slice(&plaintext)
+        .map_err(|_| decryption_error(path, "解密结果不是有效的 JSON 值"))
+}
+
+/// 解密配置树中所有的加密值
+///
+/// 没有解密密钥但配置中存在加密值时返回错误，避免把密文当作配置值使用
+pub fn decrypt_config_tree(
+    root: &mut Value,
+    key: Option<&SecretEncryptionKey>,
+) -> Result<(), ConfigError> {
+    decrypt_json_value(root, "", key)
+}
+
+/// 解密 TOML 配置树中所有的加密值
+pub(crate) fn decrypt_toml_tree(
+    root: &mut toml::Value,
+    key: Option<&SecretEncryptionKey>,
+) -> Result<(), ConfigError> {
+    decrypt_toml_value(root, "", key)
+}
+
+/// 配置树中是否存在加密值
+pub fn contains_encrypted_values(root: &Value) -> bool {
+    match root {
+        Value::String(value) => is_encrypted_value(value),
+        Value::Array(items) => items.iter().any(contains_encrypted_values),
+        Value::Object(map) => map.values().any(contains_encrypted_values),
+        _ => false,
+    }
+}
+
+/// 加密写回的变更中原本已加密的配置项
+///
+/// `raw` 为配置文件中未解密的配置树，文件中以密文保存的键写回时仍保存为密文
+pub(crate) fn seal_changes(
+    raw: &Value,
+    changes: &[ConfigKeyChange],
+    key: Option<&SecretEncryptionKey>,
+) -> Result<Vec<ConfigKeyChange>, ConfigError> {
+    let Some(key) = key else {
+        return Ok(changes.to_vec());
+    };
+
+    changes
+        .iter()
+        .map(|change| {
+            let encrypted = matches!(
+                get_nested_value(raw, &change.key),
+                Some(Value::String(value)) if is_encrypted_value(value)
+            );
+            let mut change = change.clone();
+            if let (true, Some(value)) = (encrypted, &change.new_value) {
+                change.new_value = Some(Value::String(encrypt_config_value(key, value)?));
+            }
+            Ok(change)
+        })
+        .collect()
+}
+
+/// 递归解密 JSON 配置值
+fn decrypt_json_value(
+    value: &mut Value,
+    path: &str,
+    key: Option<&SecretEncryptionKey>,
+) -> Result<(), ConfigError> {
+    match value {
+        Value::String(encrypted) if is_encrypted_value(encrypted) => {
+            let key = key.ok_or_else(|| missing_key_error(path))?;
+            *value = decrypt_config_value(key, path, encrypted)?;
+        }
+        Value::Array(items) => {
+            for (index, item) in items.iter_mut().enumerate() {
+                decrypt_json_value(item, &child_path(path, &index.to_string()), key)?;
+            }
+        }
+        Value::Object(map) => {
+            for (name, item) in map.iter_mut() {
+                decrypt_json_value(item, &child_path(path, name), key)?;
+            }
+        }
+        _ => {}
+    }
+    Ok(())
+}
+
+/// 递归解密 TOML 配置值
+fn decrypt_toml_value(
+    value: &mut toml::Value,
+    path: &str,
+    key: Option<&SecretEncryptionKey>,
+) -> Result<(), ConfigError> {
+    match value {
+        toml::Value::String(encrypted) if is_encrypted_value(encrypted) => {
+            let key = key.ok_or_else(|| missing_key_error(path))?;
+            let decrypted = decrypt_config_value(key, path, encrypted)?;
+            *value = toml::Value::try_from(decrypted)
+                .map_err(|_| decryption_error(path, "解密结果无法表示为 TOML 值"))?;
+        }
+        toml::Value::Array(items) => {
+            for (index, item) in items.iter_mut().enumerate() {
+                decrypt_toml_value(item, &child_path(path, &index.to_string()), key)?;
+            }
+        }
+        toml::Value::Table(table) => {
+            for (name, item) in table.iter_mut() {
+                decrypt_toml_value(item, &child_path(path, name), key)?;
+            }
+        }
+        _ => {}
+    }
+    Ok(())
+}
+
+/// 配置文件加密工具
+///
+/// 原地加密配置文件中的指定配置项，或使用新密钥重新加密所有加密值。
+/// TOML 文件保留注释和格式，JSON 文件重新格式化，YAML 文件不保留注释
+#[derive(Debug, Clone)]
+pub struct ConfigFileEncryptor {
+    /// 加密密钥
+    key: SecretEncryptionKey,
+}
+
+impl ConfigFileEncryptor {
+    /// 创建使用指定密钥的加密工具
+    pub fn new(key: SecretEncryptionKey) -> Self {
+        Self { key }
+    }
+
+    /// 加密配置文件中的指定配置项，返回新加密的配置项数量
+    ///
+    /// 已经加密的配置项保持不变；配置项不存在时返回错误且不修改文件
+    pub fn encrypt_keys(&self, path: impl AsRef<Path>, keys: &[&str]) -> Result<usize, ConfigError> {
+        let path = path.as_ref();
+        let mut document = ConfigDocument::load(path)?;
+        let mut encrypted = 0;
+        for key in keys {
+            let value = document
+                .get(key)?
+                .ok_or_else(|| ConfigError::KeyNotFound {
+                    key: key.to_string(),
+                })?;
+            if matches!(&value, Value::String(value) if is_encrypted_value(value)) {
+                continue;
+            }
+            let sealed = encrypt_config_value(&self.key, &value)?;
+            document.set(key, sealed)?;
+            encrypted += 1;
+        }
+
+        if encrypted > 0 {
+            document.save(path)?;
+        }
+        info!("加密配置文件 {} 中的 {} 个配置项", path.display(), encrypted);
+        Ok(encrypted)
+    }
+
+    /// 使用新密钥重新加密配置文件中的所有加密值，返回重新加密的配置项数量
+    ///
+    /// 任何一个值无法用当前密钥解密时返回错误且不修改文件
+    pub fn rotate(
+        &self,
+        path: impl AsRef<Path>,
+        new_key: &SecretEncryptionKey,
+    ) -> Result<usize, ConfigError> {
+        let path = path.as_ref();
+        let mut document = ConfigDocument::load(path)?;
+        let rotated = document.replace_encrypted(&mut |key, encrypted| {
+            let value = decrypt_config_value(&self.key, key, encrypted)?;
+            encrypt_config_value(new_key, &value)
+        })?;
+
+        if rotated > 0 {
+            document.save(path)?;
+        }
+        info!(
+            "使用新密钥重新加密配置文件 {} 中的 {} 个配置项",
+            path.display(),
+            rotated
+        );
+        Ok(rotated)
+    }
+}
+
+/// 可原地修改的配置文件
+enum ConfigDocument {
+    /// TOML 文档（保留注释和格式）
+    Toml(toml_edit::DocumentMut),
+    /// JSON 配置树
+    Json(Value),
+    /// YAML 配置树
+    Yaml(Value),
+}
+
+impl ConfigDocument {
+    /// 按扩展名读取配置文件
+    fn load(path: &Path) -> Result<Self, ConfigError> {
+        let format = path
+            .extension()
+            .and_then(|extension| extension.to_str())
+            .and_then(ConfigFileFormat::from_extension)
+            .ok_or_else(|| ConfigError::ValidationError {
+                message: format!("无法识别配置文件格式: {}", path.display()),
+            })?;
+        let content = std::fs::read_to_string(path)?;
+        Ok(match format {
+            ConfigFileFormat::Toml => Self::Toml(content.parse().map_err(|e| {
+                ConfigError::ParseError {
+                    source: Box::new(e),
+                }
+            })?),
+            ConfigFileFormat::Json => Self::Json(serde_json::from_str(&content)?),
+            ConfigFileFormat::Yaml => {
+                Self::Yaml(serde_yaml::from_str(&content).map_err(|e| ConfigError::ParseError {
+                    source: Box::new(e),
+                })?)
+            }
+        })
+    }
+
+    /// 转换为 JSON 配置树
+    fn to_value(&self) -> Result<Value, ConfigError> {
+        match self {
+            Self::Toml(document) => {
+                let value: toml::Value =
+                    toml::from_str(&document.to_string()).map_err(|e| ConfigError::ParseError {
+                        source: Box::new(e),
+                    })?;
+                Ok(serde_json::to_value(value)?)
+            }
+            Self::Json(value) | Self::Yaml(value) => Ok(value.clone()),
+        }
+    }
+
+    /// 读取配置项
+    fn get(&self, key: &str) -> Result<Option<Value>, ConfigError> {
+        Ok(get_nested_value(&self.to_value()?, key).cloned())
+    }
+
+    /// 把配置项替换为加密值
+    fn set(&mut self, key: &str, encrypted: String) -> Result<(), ConfigError> {
+        match self {
+            Self::Toml(document) => crate::providers::apply_toml_change(
+                document.as_table_mut(),
+                key,
+                Some(&Value::String(encrypted)),
+            ),
+            Self::Json(value) | Self::Yaml(value) => {
+                config_abstractions::insert_nested_value(value, key, Value::String(encrypted));
+                Ok(())
+            }
+        }
+    }
+
+    /// 替换所有加密值（包括数组元素中的加密值），返回替换的数量
+    fn replace_encrypted(
+        &mut self,
+        replace: &mut ReplaceEncrypted<'_>,
+    ) -> Result<usize, ConfigError> {
+        match self {
+            Self::Toml(document) => {
+                replace_encrypted_toml_item(document.as_item_mut(), "", replace)
+            }
+            Self::Json(value) | Self::Yaml(value) => replace_encrypted_json(value, "", replace),
+        }
+    }
+
+    /// 写回配置文件
+    fn save(&self, path: &Path) -> Result<(), ConfigError> {
+        let content = match self {
+            Self::Toml(document) => document.to_string(),
+            Self::Json(value) => {
+                let mut content = serde_json::to_string_pretty(value)?;
+                content.push('\n');
+                content
+            }
+            Self::Yaml(value) => serde_yaml::to_string(value).map_err(|e| {
+                ConfigError::ParseError {
+                    source: Box::new(e),
+                }
+            })?,
+        };
+        crate::providers::write_file_atomically(path, &content)
+    }
+}
+
+/// 加密值替换函数，参数为配置键和原加密值，返回新的加密值
+type ReplaceEncrypted<'a> = dyn FnMut(&str, &str) -> Result<String, ConfigError> + 'a;
+
+/// 递归替换 JSON 配置树中的加密值
+fn replace_encrypted_json(
+    value: &mut Value,
+    path: &str,
+    replace: &mut ReplaceEncrypted<'_>,
+) -> Result<usize, ConfigError> {
+    match value {
+        Value::String(encrypted) if is_encrypted_value(encrypted) => {
+            *encrypted = replace(path, encrypted)?;
+            Ok(1)
+        }
+        Value::Array(items) => {
+            let mut replaced = 0;
+            for (index, item) in items.iter_mut().enumerate() {
+                replaced +=
+                    replace_encrypted_json(item, &child_path(path, &index.to_string()), replace)?;
+            }
+            Ok(replaced)
+        }
+        Value::Object(map) => {
+            let mut replaced = 0;
+            for (name, item) in map.iter_mut() {
+                replaced += replace_encrypted_json(item, &child_path(path, name), replace)?;
+            }
+            Ok(replaced)
+        }
+        _ => Ok(0),
+    }
+}
+
+/// 递归替换 TOML 文档中的加密值，保留注释和格式
+fn replace_encrypted_toml_item(
+    item: &mut toml_edit::Item,
+    path: &str,
+    replace: &mut ReplaceEncrypted<'_>,
+) -> Result<usize, ConfigError> {
+    match item {
+        toml_edit::Item::Value(value) => replace_encrypted_toml_value(value, path, replace),
+        toml_edit::Item::Table(table) => {
+            let mut replaced = 0;
+            for (name, item) in table.iter_mut() {
+                replaced +=
+                    replace_encrypted_toml_item(item, &child_path(path, name.get()), replace)?;
+            }
+            Ok(replaced)
+        }
+        toml_edit::Item::ArrayOfTables(tables) => {
+            let mut replaced = 0;
+            for (index, table) in tables.iter_mut().enumerate() {
+                let table_path = child_path(path, &index.to_string());
+                for (name, item) in table.iter_mut() {
+                    replaced += replace_encrypted_toml_item(
+                        item,
+                        &child_path(&table_path, name.get()),
+                        replace,
+                    )?;
+                }
+            }
+            Ok(replaced)
+        }
+        toml_edit::Item::None => Ok(0),
+    }
+}
+
+/// 递归替换 TOML 值中的加密值
+fn replace_encrypted_toml_value(
+    value: &mut toml_edit::Value,
+    path: &str,
+    replace: &mut ReplaceEncrypted<'_>,
+) -> Result<usize, ConfigError> {
+    match value {
+        toml_edit::Value::String(encrypted) if is_encrypted_value(encrypted.value()) => {
+            let decor = encrypted.decor().clone();
+            let mut replaced = toml_edit::Formatted::new(replace(path, encrypted.value())?);
+            *replaced.decor_mut() = decor;
+            *encrypted = replaced;
+            Ok(1)
+        }
+        toml_edit::Value::Array(items) => {
+            let mut replaced = 0;
+            for (index, item) in items.iter_mut().enumerate() {
+                replaced += replace_encrypted_toml_value(
+                    item,
+                    &child_path(path, &index.to_string()),
+                    replace,
+                )?;
+            }
+            Ok(replaced)
+        }
+        toml_edit::Value::InlineTable(table) => {
+            let mut replaced = 0;
+            for (name, item) in table.iter_mut() {
+                replaced +=
+                    replace_encrypted_toml_value(item, &child_path(path, name.get()), replace)?;
+            }
+            Ok(replaced)
+        }
+        _ => Ok(0),
+    }
+}
+
+/// 拼接子配置键路径
+fn child_path(path: &str, name: &str) -> String {
+    if path.is_empty() {
+        name.to_string()
+    } else {
+        format!("{}.{}", path, name)
+    }
+}
+
+/// 创建解密失败错误
+fn decryption_error(path: &str, message: impl Into<String>) -> ConfigError {
+    ConfigError::DecryptionFailed {
+        key: path.to_string(),
+        message: message.into(),
+    }
+}
+
+/// 创建缺少解密密钥错误
+fn missing_key_error(path: &str) -> ConfigError {
+    decryption_error(path, "配置值已加密，但未设置解密密钥")
+}
@@ -2,6 +2,7 @@
 
 use async_trait::async_trait;
 use config_abstractions::events::{ConfigChangeEvent, ConfigChangeEventType, ConfigEventListener};
+use config_abstractions::ConfigRedactor;
 use infrastructure_common::ConfigError;
 use std::collections::HashMap;
 use std::sync::Arc;
@@ -182,6 +183,7 @@ pub struct LoggingConfigEventListener {
     name: String,
     enabled: bool,
     interested_events: Vec<ConfigChangeEventType>,
+    redactor: ConfigRedactor,
 }
 
 impl LoggingConfigEventListener {
@@ -199,9 +201,16 @@ impl LoggingConfigEventListener {
                 ConfigChangeEventType::SourceConnectionFailed,
                 ConfigChangeEventType::SourceConnectionRestored,
             ],
+            redactor: ConfigRedactor::new(),
         }
     }
 
+    /// 设置记录配置值时使用的脱敏器
+    pub fn with_redactor(mut self, redactor: ConfigRedactor) -> Self {
+        self.redactor = redactor;
+        self
+    }
+
     /// 设置是否启用
     pub fn set_enabled(&mut self, enabled: bool) {
         self.enabled = enabled;
@@ -245,6 +254,14 @@ impl ConfigEventListener for LoggingConfigEventListener {
             }
         }
 
+        if event.old_value.is_some() || event.new_value.is_some() {
+            let redacted = self.redactor.redact_event(event);
+            debug!(
+                "配置值变更: {} {:?} -> {:?}",
+                event.path, redacted.old_value, redacted.new_value
+            );
+        }
+
         if !event.metadata.is_empty() {
             debug!("事件元数据: {:?}", event.metadata);
         }
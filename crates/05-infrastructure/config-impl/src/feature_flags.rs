@@ -0,0 +1,520 @@
+//! 功能开关与灰度发布
+//!
+//! 功能开关定义在配置中（默认位于 `feature_flags` 配置节），支持布尔开关、
+//! 按百分比灰度以及按发布商、国家和设备定向：
+//!
+//! ```toml
+//! [feature_flags]
+//! simple_toggle = true
+//!
+//! [feature_flags.new_bidder]
+//! rollout = 25.0
+//! countries = ["US", "CA"]
+//! devices = ["mobile"]
+//! ```
+//!
+//! 灰度分桶对上下文键（如用户或请求标识）做稳定哈希，同一个键在进程重启和
+//! 配置重载后得到相同的结果。配置变更后开关定义自动更新
+
+use crate::manager::AdSystemConfigManager;
+use arc_swap::ArcSwap;
+use config_abstractions::events::{ConfigChangeEvent, ConfigChangeEventType};
+use config_abstractions::ConfigManager;
+use dashmap::DashMap;
+use infrastructure_common::{Component, ConfigError};
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use std::collections::HashMap;
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::Arc;
+use tokio::sync::{broadcast, Mutex};
+use tracing::{debug, warn};
+
+/// 功能开关的默认配置路径
+pub const DEFAULT_FEATURE_FLAGS_PATH: &str = "feature_flags";
+
+/// 灰度分桶数量，百分比精确到 0.01%
+const ROLLOUT_BUCKETS: u64 = 10_000;
+
+/// 功能开关定义
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct FeatureFlag {
+    /// 总开关，关闭时忽略其他规则
+    #[serde(default = "default_enabled")]
+    pub enabled: bool,
+    /// 灰度百分比（0-100）
+    #[serde(default = "default_rollout")]
+    pub rollout: f64,
+    /// 定向的发布商，为空时不限制
+    #[serde(default)]
+    pub publishers: Vec<String>,
+    /// 定向的国家代码（不区分大小写），为空时不限制
+    #[serde(default)]
+    pub countries: Vec<String>,
+    /// 定向的设备类型（不区分大小写），为空时不限制
+    #[serde(default)]
+    pub devices: Vec<String>,
+    /// 分桶哈希盐，缺省使用开关名称；修改后重新分配灰度人群
+    #[serde(default)]
+    pub salt: Option<String>,
+}
+
+fn default_enabled() -> bool {
+    true
+}
+
+fn default_rollout() -> f64 {
+    100.0
+}
+
+impl FeatureFlag {
+    /// 创建全量开启或关闭的开关
+    pub fn toggle(enabled: bool) -> Self {
+        Self {
+            enabled,
+            rollout: default_rollout(),
+            publishers: Vec::new(),
+            countries: Vec::new(),
+            devices: Vec::new(),
+            salt: None,
+        }
+    }
+
+    /// 检查开关定义是否有效
+    fn validate(&self, name: &str) -> Result<(), String> {
+        if !(0.0..=100.0).contains(&self.rollout) {
+            return Err(format!(
+                "功能开关 {} 的灰度百分比必须在 0 到 100 之间: {}",
+                name, self.rollout
+            ));
+        }
+        Ok(())
+    }
+}
+
+/// 配置中的开关可以是布尔值或完整定义
+#[derive(Deserialize)]
+#[serde(untagged)]
+enum FeatureFlagConfig {
+    Toggle(bool),
+    Rule(FeatureFlag),
+}
+
+/// 功能开关求值上下文
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct FeatureContext {
+    /// 灰度分桶使用的键（如用户或设备标识）
+    pub key: Option<String>,
+    /// 发布商标识
+    pub publisher_id: Option<String>,
+    /// 国家代码
+    pub country: Option<String>,
+    /// 设备类型
+    pub device: Option<String>,
+}
+
+impl FeatureContext {
+    /// 创建指定分桶键的上下文
+    pub fn new(key: impl Into<String>) -> Self {
+        Self {
+            key: Some(key.into()),
+            ..Self::default()
+        }
+    }
+
+    /// 设置发布商
+    pub fn with_publisher(mut self, publisher_id: impl Into<String>) -> Self {
+        self.publisher_id = Some(publisher_id.into());
+        self
+    }
+
+    /// 设置国家代码
+    pub fn with_country(mut self, country: impl Into<String>) -> Self {
+        self.country = Some(country.into());
+        self
+    }
+
+    /// 设置设备类型
+    pub fn with_device(mut self, device: impl Into<String>) -> Self {
+        self.device = Some(device.into());
+        self
+    }
+}
+
+/// 功能开关求值原因
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum FeatureFlagReason {
+    /// 开关未定义
+    NotFound,
+    /// 开关已关闭
+    Disabled,
+    /// 上下文不满足定向条件
+    TargetingMismatch,
+    /// 需要灰度分桶但上下文没有分桶键
+    MissingContextKey,
+    /// 分桶不在灰度范围内
+    OutOfRollout,
+    /// 开关生效
+    Enabled,
+}
+
+/// 功能开关求值结果
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct FeatureEvaluation {
+    /// 开关名称
+    pub flag: String,
+    /// 是否开启
+    pub enabled: bool,
+    /// 求值原因
+    pub reason: FeatureFlagReason,
+}
+
+/// 功能开关曝光计数
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct FeatureExposure {
+    /// 求值为开启的次数
+    pub enabled: u64,
+    /// 求值为关闭的次数
+    pub disabled: u64,
+}
+
+/// 曝光计数器
+#[derive(Default)]
+struct ExposureCounters {
+    enabled: AtomicU64,
+    disabled: AtomicU64,
+}
+
+/// 功能开关求值器
+///
+/// 克隆的句柄共享开关定义和曝光计数，最后一个句柄释放时停止后台更新任务。
+/// 开关配置重载后无法解析时保留原有定义
+pub struct FeatureFlags {
+    /// 共享状态
+    state: Arc<FeatureFlagsState>,
+    /// 后台更新任务
+    task: Arc<FeatureFlagsTask>,
+}
+
+/// 功能开关的共享状态
+struct FeatureFlagsState {
+    /// 配置路径
+    path: String,
+    /// 配置管理器
+    manager: Arc<AdSystemConfigManager>,
+    /// 当前开关定义
+    flags: ArcSwap<HashMap<String, FeatureFlag>>,
+    /// 当前开关定义对应的原始配置
+    current_raw: parking_lot::Mutex<Option<Value>>,
+    /// 按开关名称统计的曝光计数
+    exposures: DashMap<String, ExposureCounters>,
+    /// 串行化重新加载，避免较早读取的配置覆盖较新的配置
+    refresh_lock: Mutex<()>,
+}
+
+/// 后台更新任务句柄，释放时终止任务
+struct FeatureFlagsTask(tokio::task::JoinHandle<()>);
+
+impl Drop for FeatureFlagsTask {
+    fn drop(&mut self) {
+        self.0.abort();
+    }
+}
+
+impl FeatureFlags {
+    /// 创建功能开关求值器
+    ///
+    /// 配置路径不存在时视为没有定义任何开关；开关定义无效时返回错误
+    pub(crate) async fn start(
+        manager: Arc<AdSystemConfigManager>,
+        path: impl Into<String>,
+    ) -> Result<Self, ConfigError> {
+        let path = path.into();
+        // 先订阅再加载，避免错过两者之间发生的变更
+        let changes = manager.subscribe_changes();
+
+        let state = Arc::new(FeatureFlagsState {
+            path,
+            manager,
+            flags: ArcSwap::from_pointee(HashMap::new()),
+            current_raw: parking_lot::Mutex::new(None),
+            exposures: DashMap::new(),
+            refresh_lock: Mutex::new(()),
+        });
+        state.refresh().await?;
+        let task = tokio::spawn(Self::watch_changes(Arc::downgrade(&state), changes));
+
+        Ok(Self {
+            state,
+            task: Arc::new(FeatureFlagsTask(task)),
+        })
+    }
+
+    /// 判断开关是否对上下文开启
+    pub fn is_enabled(&self, flag: &str, context: &FeatureContext) -> bool {
+        self.evaluate(flag, context).enabled
+    }
+
+    /// 对上下文求值开关并记录曝光
+    pub fn evaluate(&self, flag: &str, context: &FeatureContext) -> FeatureEvaluation {
+        let flags = self.state.flags.load();
+        let reason = match flags.get(flag) {
+            Some(definition) => evaluate_flag(flag, definition, context),
+            None => FeatureFlagReason::NotFound,
+        };
+        let enabled = reason == FeatureFlagReason::Enabled;
+
+        self.record_exposure(flag, enabled);
+
+        FeatureEvaluation {
+            flag: flag.to_string(),
+            enabled,
+            reason,
+        }
+    }
+
+    /// 记录一次曝光，已统计过的开关不分配新的键
+    fn record_exposure(&self, flag: &str, enabled: bool) {
+        let record = |counters: &ExposureCounters| {
+            let counter = if enabled {
+                &counters.enabled
+            } else {
+                &counters.disabled
+            };
+            counter.fetch_add(1, Ordering::Relaxed);
+        };
+        match self.state.exposures.get(flag) {
+            Some(counters) => record(&counters),
+            None => record(&self.state.exposures.entry(flag.to_string()).or_default()),
+        }
+    }
+
+    /// 获取当前的开关定义
+    pub fn flags(&self) -> Arc<HashMap<String, FeatureFlag>> {
+        self.state.flags.load_full()
+    }
+
+    /// 获取开关配置路径
+    pub fn path(&self) -> &str {
+        &self.state.path
+    }
+
+    /// 获取按开关名称统计的曝光计数
+    pub fn exposures(&self) -> HashMap<String, FeatureExposure> {
+        self.state
+            .exposures
+            .iter()
+            .map(|entry| {
+                (
+                    entry.key().clone(),
+                    FeatureExposure {
+                        enabled: entry.enabled.load(Ordering::Relaxed),
+                        disabled: entry.disabled.load(Ordering::Relaxed),
+                    },
+                )
+            })
+            .collect()
+    }
+
+    /// 清空曝光计数
+    pub fn reset_exposures(&self) {
+        self.state.exposures.clear();
+    }
+
+    /// 立即从配置管理器重新加载开关定义
+    ///
+    /// 开关定义发生变化时返回 `true`；定义无效时保留原有定义并返回错误
+    pub async fn refresh(&self) -> Result<bool, ConfigError> {
+        self.state.refresh().await
+    }
+
+    /// 监听配置变更并更新开关定义
+    async fn watch_changes(
+        state: std::sync::Weak<FeatureFlagsState>,
+        mut changes: broadcast::Receiver<ConfigChangeEvent>,
+    ) {
+        loop {
+            match changes.recv().await {
+                Ok(event) => {
+                    let Some(state) = state.upgrade() else {
+                        return;
+                    };
+                    if !state.is_affected_by(&event) {
+                        continue;
+                    }
+                }
+                // 错过了部分事件，直接重新加载
+                Err(broadcast::error::RecvError::Lagged(_)) => {}
+                Err(broadcast::error::RecvError::Closed) => return,
+            }
+
+            let Some(state) = state.upgrade() else {
+                return;
+            };
+            if let Err(e) = state.refresh().await {
+                warn!("功能开关 {} 重新加载失败，保留原有定义: {}", state.path, e);
+            }
+        }
+    }
+}
+
+impl FeatureFlagsState {
+    /// 配置变更事件是否影响开关配置路径
+    fn is_affected_by(&self, event: &ConfigChangeEvent) -> bool {
+        if !matches!(
+            event.event_type,
+            ConfigChangeEventType::Created
+                | ConfigChangeEventType::Updated
+                | ConfigChangeEventType::Deleted
+                | ConfigChangeEventType::Reloaded
+        ) {
+            return false;
+        }
+
+        let path = self.path.as_str();
+        let changed = event.path.as_str();
+        changed == path
+            || changed
+                .strip_prefix(path)
+                .is_some_and(|rest| rest.starts_with('.'))
+            || path
+                .strip_prefix(changed)
+                .is_some_and(|rest| rest.starts_with('.'))
+    }
+
+    /// 重新加载开关定义
+    async fn refresh(&self) -> Result<bool, ConfigError> {
+        let _refresh_guard = self.refresh_lock.lock().await;
+        let raw = match self.manager.get_configuration(&self.path).await {
+            Ok(raw) => Some(raw),
+            Err(ConfigError::KeyNotFound { .. }) => None,
+            Err(e) => return Err(e),
+        };
+        if *self.current_raw.lock() == raw {
+            return Ok(false);
+        }
+
+        let flags = match &raw {
+            Some(raw) => parse_flags(raw)?,
+            None => HashMap::new(),
+        };
+        debug!("功能开关 {} 已更新，共 {} 个开关", self.path, flags.len());
+        self.flags.store(Arc::new(flags));
+        *self.current_raw.lock() = raw;
+        Ok(true)
+    }
+}
+
+/// 解析开关配置节
+fn parse_flags(raw: &Value) -> Result<HashMap<String, FeatureFlag>, ConfigError> {
+    let configs: HashMap<String, FeatureFlagConfig> =
+        serde_json::from_value(raw.clone()).map_err(|e| ConfigError::ParseError {
+            source: Box::new(e),
+        })?;
+
+    let mut flags = HashMap::with_capacity(configs.len());
+    let mut errors = Vec::new();
+    for (name, config) in configs {
+        let flag = match config {
+            FeatureFlagConfig::Toggle(enabled) => FeatureFlag::toggle(enabled),
+            FeatureFlagConfig::Rule(flag) => flag,
+        };
+        if let Err(message) = flag.validate(&name) {
+            errors.push(message);
+        }
+        flags.insert(name, flag);
+    }
+
+    if errors.is_empty() {
+        Ok(flags)
+    } else {
+        errors.sort();
+        Err(ConfigError::ValidationFailed { errors })
+    }
+}
+
+/// 对单个开关定义求值
+fn evaluate_flag(name: &str, flag: &FeatureFlag, context: &FeatureContext) -> FeatureFlagReason {
+    if !flag.enabled {
+        return FeatureFlagReason::Disabled;
+    }
+
+    let targeted = matches_target(&flag.publishers, context.publisher_id.as_deref(), false)
+        && matches_target(&flag.countries, context.country.as_deref(), true)
+        && matches_target(&flag.devices, context.device.as_deref(), true);
+    if !targeted {
+        return FeatureFlagReason::TargetingMismatch;
+    }
+
+    if flag.rollout >= 100.0 {
+        return FeatureFlagReason::Enabled;
+    }
+    let Some(key) = context.key.as_deref() else {
+        return FeatureFlagReason::MissingContextKey;
+    };
+    let salt = flag.salt.as_deref().unwrap_or(name);
+    let threshold = (flag.rollout * (ROLLOUT_BUCKETS as f64 / 100.0)).round() as u64;
+    if rollout_bucket(salt, key) < threshold {
+        FeatureFlagReason::Enabled
+    } else {
+        FeatureFlagReason::OutOfRollout
+    }
+}
+
+/// 上下文属性是否满足定向列表，列表为空时不限制
+fn matches_target(targets: &[String], value: Option<&str>, ignore_case: bool) -> bool {
+    if targets.is_empty() {
+        return true;
+    }
+    let Some(value) = value else {
+        return false;
+    };
+    targets.iter().any(|target| {
+        if ignore_case {
+            target.eq_ignore_ascii_case(value)
+        } else {
+            target == value
+        }
+    })
+}
+
+/// 计算上下文键的灰度分桶
+///
+/// 使用 FNV-1a 哈希，结果不依赖进程、平台或 Rust 版本
+fn rollout_bucket(salt: &str, key: &str) -> u64 {
+    const FNV_OFFSET_BASIS: u64 = 0xcbf2_9ce4_8422_2325;
+    const FNV_PRIME: u64 = 0x0000_0100_0000_01b3;
+
+    let mut hash = FNV_OFFSET_BASIS;
+    for byte in salt.bytes().chain(std::iter::once(b':')).chain(key.bytes()) {
+        hash ^= u64::from(byte);
+        hash = hash.wrapping_mul(FNV_PRIME);
+    }
+    hash % ROLLOUT_BUCKETS
+}
+
+impl Clone for FeatureFlags {
+    fn clone(&self) -> Self {
+        Self {
+            state: self.state.clone(),
+            task: self.task.clone(),
+        }
+    }
+}
+
+impl std::fmt::Debug for FeatureFlags {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("FeatureFlags")
+            .field("path", &self.state.path)
+            .field("flags", &self.state.flags.load().len())
+            .finish()
+    }
+}
+
+impl Component for FeatureFlags {
+    fn name(&self) -> &'static str {
+        "FeatureFlags"
+    }
+}
@@ -0,0 +1,249 @@
+//! 配置历史存储实现
+//!
+//! 提供进程内的 [`InMemoryConfigHistoryStore`] 和保存到本地目录的
+//! [`FileConfigHistoryStore`]，后者在重启后保留历史和版本计数
+
+use async_trait::async_trait;
+use config_abstractions::{ConfigHistoryStore, ConfigSnapshot};
+use infrastructure_common::ConfigError;
+use std::path::{Path, PathBuf};
+use tokio::sync::Mutex;
+use tracing::{debug, warn};
+
+/// 快照文件名前缀
+const SNAPSHOT_FILE_PREFIX: &str = "snapshot-";
+
+/// 快照文件扩展名
+const SNAPSHOT_FILE_EXTENSION: &str = "json";
+
+/// 保存最后分配版本号的文件
+const SEQUENCE_FILE_NAME: &str = "sequence";
+
+/// 内存配置历史存储
+///
+/// 历史随进程结束丢失，但在进程内版本号不会因裁剪或清除而重复
+#[derive(Debug, Default)]
+pub struct InMemoryConfigHistoryStore {
+    state: parking_lot::Mutex<InMemoryHistory>,
+}
+
+/// 内存历史状态
+#[derive(Debug, Default)]
+struct InMemoryHistory {
+    snapshots: Vec<ConfigSnapshot>,
+    last_version: u64,
+}
+
+impl InMemoryConfigHistoryStore {
+    /// 创建空的内存历史存储
+    pub fn new() -> Self {
+        Self::default()
+    }
+}
+
+#[async_trait]
+impl ConfigHistoryStore for InMemoryConfigHistoryStore {
+    fn name(&self) -> &str {
+        "memory"
+    }
+
+    async fn append(&self, mut snapshot: ConfigSnapshot) -> Result<u64, ConfigError> {
+        let mut state = self.state.lock();
+        state.last_version += 1;
+        snapshot.version = state.last_version;
+        state.snapshots.push(snapshot);
+        Ok(state.last_version)
+    }
+
+    async fn list(&self) -> Result<Vec<ConfigSnapshot>, ConfigError> {
+        Ok(self.state.lock().snapshots.clone())
+    }
+
+    async fn latest(&self) -> Result<Option<ConfigSnapshot>, ConfigError> {
+        Ok(self.state.lock().snapshots.last().cloned())
+    }
+
+    async fn prune(&self, keep: usize) -> Result<(), ConfigError> {
+        let mut state = self.state.lock();
+        let excess = state.snapshots.len().saturating_sub(keep);
+        state.snapshots.drain(..excess);
+        Ok(())
+    }
+
+    async fn clear(&self) -> Result<(), ConfigError> {
+        self.state.lock().snapshots.clear();
+        Ok(())
+    }
+}
+
+/// 本地目录配置历史存储
+///
+/// 每个快照保存为一个 JSON 文件（`snapshot-<版本号>.json`），最后分配的版本号单独保存，
+/// 因此即使所有快照都被删除，重启后也会继续递增。快照包含未脱敏的配置，
+/// 在 Unix 上文件权限设置为仅所有者可读写
+#[derive(Debug)]
+pub struct FileConfigHistoryStore {
+    directory: PathBuf,
+    /// 最后分配的版本号，同时串行化写入
+    last_version: Mutex<u64>,
+}
+
+impl FileConfigHistoryStore {
+    /// 打开（必要时创建）历史目录
+    pub async fn open(directory: impl AsRef<Path>) -> Result<Self, ConfigError> {
+        let directory = directory.as_ref().to_path_buf();
+        tokio::fs::create_dir_all(&directory).await?;
+
+        let recorded = match tokio::fs::read_to_string(directory.join(SEQUENCE_FILE_NAME)).await {
+            Ok(content) => content.trim().parse::<u64>().map_err(|e| ConfigError::ParseError {
+                source: Box::new(e),
+            })?,
+            Err(e) if e.kind() == std::io::ErrorKind::NotFound => 0,
+            Err(e) => return Err(e.into()),
+        };
+        let stored = snapshot_versions(&directory).await?.last().copied().unwrap_or(0);
+        let last_version = recorded.max(stored);
+        debug!(
+            "打开配置历史目录 {:?}，最后版本号: {}",
+            directory, last_version
+        );
+
+        Ok(Self {
+            directory,
+            last_version: Mutex::new(last_version),
+        })
+    }
+
+    /// 获取历史目录
+    pub fn directory(&self) -> &Path {
+        &self.directory
+    }
+
+    /// 快照文件路径
+    fn snapshot_path(&self, version: u64) -> PathBuf {
+        self.directory.join(format!(
+            "{}{:020}.{}",
+            SNAPSHOT_FILE_PREFIX, version, SNAPSHOT_FILE_EXTENSION
+        ))
+    }
+
+    /// 读取快照文件
+    async fn read_snapshot(&self, version: u64) -> Result<Option<ConfigSnapshot>, ConfigError> {
+        match tokio::fs::read(self.snapshot_path(version)).await {
+            Ok(content) => Ok(Some(serde_json::from_slice(&content)?)),
+            Err(e) if e.kind() == std::io::ErrorKind::NotFound => Ok(None),
+            Err(e) => Err(e.into()),
+        }
+    }
+
+    /// 删除快照文件
+    async fn remove_snapshots(&self, versions: &[u64]) -> Result<(), ConfigError> {
+        for version in versions {
+            match tokio::fs::remove_file(self.snapshot_path(*version)).await {
+                Ok(()) => {}
+                Err(e) if e.kind() == std::io::ErrorKind::NotFound => {}
+                Err(e) => return Err(e.into()),
+            }
+        }
+        Ok(())
+    }
+}
+
+#[async_trait]
+impl ConfigHistoryStore for FileConfigHistoryStore {
+    fn name(&self) -> &str {
+        "file"
+    }
+
+    async fn append(&self, mut snapshot: ConfigSnapshot) -> Result<u64, ConfigError> {
+        let mut last_version = self.last_version.lock().await;
+        let version = *last_version + 1;
+        snapshot.version = version;
+
+        // 先持久化版本号，写快照失败时也不会重复使用该版本号
+        write_private_file(
+            &self.directory.join(SEQUENCE_FILE_NAME),
+            version.to_string().as_bytes(),
+        )
+        .await?;
+        *last_version = version;
+        write_private_file(
+            &self.snapshot_path(version),
+            &serde_json::to_vec_pretty(&snapshot)?,
+        )
+        .await?;
+
+        Ok(version)
+    }
+
+    async fn list(&self) -> Result<Vec<ConfigSnapshot>, ConfigError> {
+        let mut snapshots = Vec::new();
+        for version in snapshot_versions(&self.directory).await? {
+            match self.read_snapshot(version).await {
+                Ok(Some(snapshot)) => snapshots.push(snapshot),
+                Ok(None) => {}
+                Err(e) => warn!("跳过无法读取的配置快照 {}: {}", version, e),
+            }
+        }
+        Ok(snapshots)
+    }
+
+    async fn get(&self, version: u64) -> Result<Option<ConfigSnapshot>, ConfigError> {
+        self.read_snapshot(version).await
+    }
+
+    async fn latest(&self) -> Result<Option<ConfigSnapshot>, ConfigError> {
+        for version in snapshot_versions(&self.directory).await?.into_iter().rev() {
+            if let Some(snapshot) = self.read_snapshot(version).await? {
+                return Ok(Some(snapshot));
+            }
+        }
+        Ok(None)
+    }
+
+    async fn prune(&self, keep: usize) -> Result<(), ConfigError> {
+        let _guard = self.last_version.lock().await;
+        let versions = snapshot_versions(&self.directory).await?;
+        let excess = versions.len().saturating_sub(keep);
+        self.remove_snapshots(&versions[..excess]).await
+    }
+
+    async fn clear(&self) -> Result<(), ConfigError> {
+        let _guard = self.last_version.lock().await;
+        let versions = snapshot_versions(&self.directory).await?;
+        self.remove_snapshots(&versions).await
+    }
+}
+
+/// 按升序列出目录中的快照版本号
+async fn snapshot_versions(directory: &Path) -> Result<Vec<u64>, ConfigError> {
+    let mut versions = Vec::new();
+    let mut entries = tokio::fs::read_dir(directory).await?;
+    while let Some(entry) = entries.next_entry().await? {
+        let file_name = entry.file_name();
+        let version = file_name
+            .to_str()
+            .and_then(|name| name.strip_prefix(SNAPSHOT_FILE_PREFIX))
+            .and_then(|name| name.strip_suffix(SNAPSHOT_FILE_EXTENSION))
+            .and_then(|name| name.strip_suffix('.'))
+            .and_then(|version| version.parse::<u64>().ok());
+        if let Some(version) = version {
+            versions.push(version);
+        }
+    }
+    versions.sort_unstable();
+    Ok(versions)
+}
+
+/// 先写入临时文件再重命名，保证文件内容完整，并限制为仅所有者可读写
+async fn write_private_file(path: &Path, content: &[u8]) -> Result<(), ConfigError> {
+    let temp_path = path.with_extension("tmp");
+    tokio::fs::write(&temp_path, content).await?;
+    #[cfg(unix)]
+    {
+        use std::os::unix::fs::PermissionsExt;
+        tokio::fs::set_permissions(&temp_path, std::fs::Permissions::from_mode(0o600)).await?;
+    }
+    tokio::fs::rename(&temp_path, path).await?;
+    Ok(())
+}
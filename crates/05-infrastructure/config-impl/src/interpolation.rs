@@ -0,0 +1,421 @@
+//! 配置值插值
+//!
+//! 在所有配置源合并之后解析配置值中的占位符：
+//!
+//! - `${other.key}` - 引用合并后配置中的其他键
+//! - `${env:VAR}` / `${env:VAR:-default}` - 引用环境变量，可指定缺省值
+//! - `${file:path}` - 引用文件内容（去除末尾换行）
+//! - `$${...}` - 转义，输出字面量 `${...}`
+//!
+//! `${secret:name}` 密钥引用保持原样，由 [`SecretResolver`](crate::secrets::SecretResolver)
+//! 在读取配置时解析。若字符串仅包含一个键引用，则保留被引用值的类型
+//!
+//! [`ConfigInterpolator::interpolate_lenient`] 按键降级：无法解析的配置值保持原样，
+//! 错误只记录在引用了缺失来源的配置键上，其余配置键不受影响
+
+use config_abstractions::get_nested_value;
+use infrastructure_common::ConfigError;
+use serde_json::{Map, Value};
+use std::collections::{BTreeMap, HashMap};
+
+/// 占位符起始标记
+const PLACEHOLDER_START: &str = "${";
+
+/// 配置值插值器
+#[derive(Debug, Clone, Default)]
+pub struct ConfigInterpolator;
+
+impl ConfigInterpolator {
+    /// 创建新的插值器
+    pub fn new() -> Self {
+        Self
+    }
+
+    /// 解析配置树中的所有占位符
+    pub fn interpolate(&self, root: &Value) -> Result<Value, ConfigError> {
+        InterpolationContext::new(root, false).resolve_node("", root)
+    }
+
+    /// 按键降级地解析配置树中的占位符
+    ///
+    /// 解析失败的配置值保持原样，错误按配置键记录在返回结果中
+    pub fn interpolate_lenient(&self, root: &Value) -> InterpolatedConfig {
+        let mut context = InterpolationContext::new(root, true);
+        let value = context
+            .resolve_node("", root)
+            .unwrap_or_else(|_| root.clone());
+        InterpolatedConfig {
+            value,
+            errors: context.errors,
+        }
+    }
+
+    /// 配置值中是否包含占位符
+    pub fn contains_placeholder(value: &Value) -> bool {
+        match value {
+            Value::String(s) => s.contains(PLACEHOLDER_START),
+            Value::Array(items) => items.iter().any(Self::contains_placeholder),
+            Value::Object(map) => map.values().any(Self::contains_placeholder),
+            _ => false,
+        }
+    }
+}
+
+/// 按键降级的插值结果
+#[derive(Debug, Default)]
+pub struct InterpolatedConfig {
+    /// 插值后的配置树，解析失败的配置值保持原样
+    pub value: Value,
+    /// 解析失败的配置键及其错误
+    pub errors: BTreeMap<String, ConfigError>,
+}
+
+impl InterpolatedConfig {
+    /// 读取配置键时应返回的插值错误
+    ///
+    /// 配置键本身或其下任一子键解析失败时返回错误
+    pub fn error_for(&self, key: &str) -> Option<ConfigError> {
+        self.errors
+            .iter()
+            .find(|(path, _)| {
+                key.is_empty()
+                    || path.as_str() == key
+                    || path
+                        .strip_prefix(key)
+                        .is_some_and(|rest| rest.starts_with('.'))
+            })
+            .map(|(_, error)| copy_error(error))
+    }
+}
+
+/// 复制插值错误（[`ConfigError`] 未实现 `Clone`）
+fn copy_error(error: &ConfigError) -> ConfigError {
+    match error {
+        ConfigError::CircularReference { chain } => ConfigError::CircularReference {
+            chain: chain.clone(),
+        },
+        ConfigError::InterpolationError { key, message } => ConfigError::InterpolationError {
+            key: key.clone(),
+            message: message.clone(),
+        },
+        other => ConfigError::InterpolationError {
+            key: String::new(),
+            message: other.to_string(),
+        },
+    }
+}
+
+/// 占位符类型
+#[derive(Debug, Clone, PartialEq, Eq)]
+enum Placeholder<'a> {
+    /// 配置键引用
+    Key(&'a str),
+    /// 环境变量引用
+    Env {
+        name: &'a str,
+        default: Option<&'a str>,
+    },
+    /// 文件内容引用
+    File(&'a str),
+    /// 保持原样输出的文本（密钥引用、转义）
+    Literal(&'a str),
+}
+
+/// 字符串片段
+#[derive(Debug, Clone, PartialEq, Eq)]
+enum Segment<'a> {
+    /// 普通文本
+    Text(&'a str),
+    /// 占位符
+    Placeholder(Placeholder<'a>),
+}
+
+/// 将字符串拆分为文本和占位符片段
+fn parse_segments(value: &str) -> Vec<Segment<'_>> {
+    let mut segments = Vec::new();
+    let mut rest = value;
+
+    while let Some(found) = rest.find(PLACEHOLDER_START) {
+        // `$${` 转义为字面量 `${`
+        if found > 0 && rest.as_bytes()[found - 1] == b'$' {
+            segments.push(Segment::Text(&rest[..found - 1]));
+            segments.push(Segment::Placeholder(Placeholder::Literal(
+                PLACEHOLDER_START,
+            )));
+            rest = &rest[found + PLACEHOLDER_START.len()..];
+            continue;
+        }
+
+        let Some(close) = rest[found..].find('}') else {
+            break;
+        };
+        let end = found + close + 1;
+        if found > 0 {
+            segments.push(Segment::Text(&rest[..found]));
+        }
+
+        let body = rest[found + PLACEHOLDER_START.len()..end - 1].trim();
+        let placeholder = if let Some(env) = body.strip_prefix("env:") {
+            match env.split_once(":-") {
+                Some((name, default)) => Placeholder::Env {
+                    name: name.trim(),
+                    default: Some(default),
+                },
+                None => Placeholder::Env {
+                    name: env.trim(),
+                    default: None,
+                },
+            }
+        } else if let Some(path) = body.strip_prefix("file:") {
+            Placeholder::File(path.trim())
+        } else if body.starts_with("secret:") {
+            Placeholder::Literal(&rest[found..end])
+        } else {
+            Placeholder::Key(body)
+        };
+        segments.push(Segment::Placeholder(placeholder));
+        rest = &rest[end..];
+    }
+
+    if !rest.is_empty() {
+        segments.push(Segment::Text(rest));
+    }
+    segments
+}
+
+/// 单次插值过程的状态
+struct InterpolationContext<'a> {
+    /// 合并后的配置树
+    root: &'a Value,
+    /// 已解析的配置键
+    resolved: HashMap<String, Value>,
+    /// 正在解析的配置键（用于循环检测）
+    stack: Vec<String>,
+    /// 已读取的文件内容，同一次插值中每个文件只读取一次
+    files: HashMap<String, String>,
+    /// 是否按键降级
+    lenient: bool,
+    /// 按键降级时记录的解析错误
+    errors: BTreeMap<String, ConfigError>,
+}
+
+impl<'a> InterpolationContext<'a> {
+    /// 创建插值上下文
+    fn new(root: &'a Value, lenient: bool) -> Self {
+        Self {
+            root,
+            resolved: HashMap::new(),
+            stack: Vec::new(),
+            files: HashMap::new(),
+            lenient,
+            errors: BTreeMap::new(),
+        }
+    }
+
+    /// 解析配置节点
+    fn resolve_node(&mut self, path: &str, value: &Value) -> Result<Value, ConfigError> {
+        match value {
+            // 按键降级时只在遍历配置树的叶子上吞下错误，
+            // 解析被引用的配置键时错误继续向引用方传播
+            Value::String(s) => match self.resolve_string(path, s) {
+                Err(error) if self.lenient && self.stack.is_empty() => {
+                    self.errors.insert(path.to_string(), error);
+                    Ok(value.clone())
+                }
+                result => result,
+            },
+            Value::Array(items) => items
+                .iter()
+                .enumerate()
+                .map(|(index, item)| self.resolve_node(&join_path(path, &index.to_string()), item))
+                .collect::<Result<Vec<_>, _>>()
+                .map(Value::Array),
+            Value::Object(map) => {
+                let mut resolved = Map::with_capacity(map.len());
+                for (key, child) in map {
+                    resolved.insert(
+                        key.clone(),
+                        self.resolve_node(&join_path(path, key), child)?,
+                    );
+                }
+                Ok(Value::Object(resolved))
+            }
+            other => Ok(other.clone()),
+        }
+    }
+
+    /// 解析字符串中的占位符
+    fn resolve_string(&mut self, path: &str, value: &str) -> Result<Value, ConfigError> {
+        if !value.contains(PLACEHOLDER_START) {
+            return Ok(Value::String(value.to_string()));
+        }
+
+        let segments = parse_segments(value);
+
+        // 仅包含一个键引用时保留被引用值的类型
+        if let [Segment::Placeholder(Placeholder::Key(key))] = segments.as_slice() {
+            return self.resolve_key(path, key);
+        }
+
+        let mut output = String::with_capacity(value.len());
+        for segment in segments {
+            match segment {
+                Segment::Text(text) => output.push_str(text),
+                Segment::Placeholder(Placeholder::Literal(text)) => output.push_str(text),
+                Segment::Placeholder(Placeholder::Key(key)) => {
+                    match self.resolve_key(path, key)? {
+                        Value::String(s) => output.push_str(&s),
+                        other => output.push_str(&other.to_string()),
+                    }
+                }
+                Segment::Placeholder(Placeholder::Env { name, default }) => {
+                    match (std::env::var(name), default) {
+                        (Ok(value), _) => output.push_str(&value),
+                        (Err(_), Some(default)) => output.push_str(default),
+                        (Err(_), None) => {
+                            return Err(ConfigError::InterpolationError {
+                                key: path.to_string(),
+                                message: format!("环境变量未设置: {}", name),
+                            })
+                        }
+                    }
+                }
+                Segment::Placeholder(Placeholder::File(file)) => {
+                    output.push_str(self.read_file(path, file)?);
+                }
+            }
+        }
+        Ok(Value::String(output))
+    }
+
+    /// 读取被引用的文件内容（去除末尾换行）
+    fn read_file(&mut self, path: &str, file: &str) -> Result<&str, ConfigError> {
+        if !self.files.contains_key(file) {
+            let content =
+                std::fs::read_to_string(file).map_err(|e| ConfigError::InterpolationError {
+                    key: path.to_string(),
+                    message: format!("读取文件失败: {}: {}", file, e),
+                })?;
+            let content = content.trim_end_matches(['\r', '\n']).to_string();
+            self.files.insert(file.to_string(), content);
+        }
+        Ok(&self.files[file])
+    }
+
+    /// 解析被引用的配置键
+    fn resolve_key(&mut self, path: &str, key: &str) -> Result<Value, ConfigError> {
+        if let Some(value) = self.resolved.get(key) {
+            return Ok(value.clone());
+        }
+
+        if let Some(position) = self.stack.iter().position(|entry| entry == key) {
+            let mut chain = self.stack[position..].to_vec();
+            chain.push(key.to_string());
+            return Err(ConfigError::CircularReference {
+                chain: chain.join(" -> "),
+            });
+        }
+
+        let raw =
+            get_nested_value(self.root, key).ok_or_else(|| ConfigError::InterpolationError {
+                key: path.to_string(),
+                message: format!("引用的配置键不存在: {}", key),
+            })?;
+
+        self.stack.push(key.to_string());
+        let resolved = self.resolve_node(key, raw);
+        self.stack.pop();
+
+        let resolved = resolved?;
+        self.resolved.insert(key.to_string(), resolved.clone());
+        Ok(resolved)
+    }
+}
+
+/// 拼接点分路径
+fn join_path(prefix: &str, key: &str) -> String {
+    if prefix.is_empty() {
+        key.to_string()
+    } else {
+        format!("{}.{}", prefix, key)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    #[test]
+    fn test_key_references_keep_type() {
+        let config = json!({
+            "server": {"host": "ads.internal", "port": 8080},
+            "dsp": {
+                "endpoint": "http://${server.host}:${server.port}/bid",
+                "port": "${server.port}"
+            }
+        });
+        let resolved = ConfigInterpolator::new().interpolate(&config).unwrap();
+        assert_eq!(
+            resolved["dsp"]["endpoint"],
+            json!("http://ads.internal:8080/bid")
+        );
+        assert_eq!(resolved["dsp"]["port"], json!(8080));
+    }
+
+    #[test]
+    fn test_env_default_and_escape() {
+        let config = json!({
+            "region": "${env:ADSP_INTERPOLATION_TEST_UNSET:-cn-north}",
+            "template": "$${server.host}",
+            "password": "${secret:db_password}"
+        });
+        let resolved = ConfigInterpolator::new().interpolate(&config).unwrap();
+        assert_eq!(resolved["region"], json!("cn-north"));
+        assert_eq!(resolved["template"], json!("${server.host}"));
+        assert_eq!(resolved["password"], json!("${secret:db_password}"));
+    }
+
+    #[test]
+    fn test_cycles_are_reported() {
+        let config = json!({"a": "${b}", "b": "${c.d}", "c": {"d": "x-${a}"}});
+        let error = ConfigInterpolator::new().interpolate(&config).unwrap_err();
+        match error {
+            ConfigError::CircularReference { chain } => assert_eq!(chain, "b -> c.d -> a -> b"),
+            other => panic!("unexpected error: {other}"),
+        }
+    }
+
+    #[test]
+    fn test_lenient_interpolation_degrades_per_key() {
+        let config = json!({
+            "server": {"host": "ads.internal"},
+            "db": {
+                "password": "${env:ADSP_INTERPOLATION_TEST_UNSET}",
+                "url": "postgres://${db.password}@db"
+            },
+            "dsp": {"endpoint": "http://${server.host}/bid"}
+        });
+        let resolved = ConfigInterpolator::new().interpolate_lenient(&config);
+        assert_eq!(
+            resolved.value["dsp"]["endpoint"],
+            json!("http://ads.internal/bid")
+        );
+        assert_eq!(
+            resolved.value["db"]["password"],
+            json!("${env:ADSP_INTERPOLATION_TEST_UNSET}")
+        );
+        assert!(resolved.error_for("dsp").is_none());
+        assert!(resolved.error_for("db.password").is_some());
+        assert!(resolved.error_for("db.url").is_some());
+        assert!(resolved.error_for("db").is_some());
+        assert!(resolved.error_for("db.pass").is_none());
+    }
+
+    #[test]
+    fn test_missing_reference_is_reported() {
+        let config = json!({"a": "${missing.key}"});
+        let error = ConfigInterpolator::new().interpolate(&config).unwrap_err();
+        assert!(matches!(error, ConfigError::InterpolationError { key, .. } if key == "a"));
+    }
+}
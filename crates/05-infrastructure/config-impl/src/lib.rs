@@ -7,28 +7,70 @@
 //! - [`AdSystemConfigManager`] - 主配置管理器
 //! - [`TomlConfigProvider`] - TOML 配置提供者
 //! - [`JsonConfigProvider`] - JSON 配置提供者
+//! - [`YamlConfigProvider`] - YAML 配置提供者
 //! - [`EnvironmentConfigProvider`] - 环境变量配置提供者
+//! - [`MemoryConfigProvider`] - 可写的内存配置提供者
+//! - [`CommandLineConfigProvider`] - 命令行参数配置提供者
 //! - [`ConfigValidationManager`] - 配置验证管理器
 //! - [`TypedConfigBinder`] - 类型化配置绑定器
 //! - [`ConfigEventHandler`] - 配置事件处理器
 //! - [`AdvancedConfigValidator`] - 高级配置验证器
+//! - [`ConfigMerger`] - 分层配置合并器
+//! - [`ConfigInterpolator`] - 配置值插值器
+//! - [`ProfileConfigLoader`] - 配置档配置文件加载器
+//! - [`ProfileConfigGroupLoader`] - 多组配置档配置文件加载器
+//! - [`SecretResolver`] - 密钥引用解析器
+//! - [`ConfigFileEncryptor`] - 配置值加密与密钥轮换工具
+//! - [`OptionsMonitor`] - 类型化实时配置句柄
+//! - [`FileConfigHistoryStore`] - 持久化配置历史存储
+//! - [`FeatureFlags`] - 功能开关与灰度发布
 
 pub mod advanced_validator;
 pub mod binder;
+pub mod encryption;
 pub mod event_handler;
+pub mod feature_flags;
+pub mod history;
+pub mod interpolation;
 pub mod manager;
+pub mod merge;
+pub mod options;
+pub mod profiles;
 pub mod providers;
+pub mod secrets;
 pub mod validation;
 pub mod watcher;
 
 pub use advanced_validator::*;
+pub use encryption::*;
 pub use event_handler::*;
+pub use feature_flags::*;
+pub use history::*;
+pub use interpolation::*;
 pub use manager::*;
+pub use merge::*;
+pub use options::*;
+pub use profiles::*;
 pub use providers::*;
+pub use secrets::*;
 pub use validation::*;
 pub use watcher::*;
 
 #[cfg(test)]
 mod tests {
+    pub mod command_line_config_tests;
+    pub mod config_history_tests;
+    pub mod config_reload_tests;
+    pub mod config_schema_tests;
+    pub mod config_validation_tests;
+    pub mod config_write_tests;
+    pub mod encryption_tests;
+    pub mod env_config_tests;
+    pub mod feature_flag_tests;
     pub mod hot_reload_tests;
+    pub mod interpolation_tests;
+    pub mod layered_config_tests;
+    pub mod options_monitor_tests;
+    pub mod profile_config_tests;
+    pub mod secret_config_tests;
 }
@@ -1,77 +1,162 @@
 //! 配置管理器实现
 
 use crate::event_handler::ConfigEventHandler;
+use crate::feature_flags::FeatureFlags;
+use crate::history::InMemoryConfigHistoryStore;
+use crate::interpolation::{ConfigInterpolator, InterpolatedConfig};
+use crate::merge::{ConfigLayer, ConfigMerger};
+use crate::options::OptionsMonitor;
+use crate::secrets::SecretResolver;
+use crate::validation::{
+    insert_chained_validator, run_options_validators, OptionsValidationEntry,
+    OptionsValidationTarget, TypedOptionsTarget, DEFAULT_ASYNC_VALIDATION_TIMEOUT,
+};
 use async_trait::async_trait;
+use config_abstractions::events::ConfigChangeEventType;
 use config_abstractions::manager::ValidationResult;
 use config_abstractions::{
-    events::ConfigChangeEvent, ConfigManager, ConfigOptionDescriptor, ConfigProvider,
-    ConfigValidator, ConfigWatcher, TypedConfigBinder,
+    contains_secret_reference, diff_configuration, events::ConfigChangeEvent, get_nested_value,
+    insert_nested_value, remove_nested_value,
+    ApplicationSchemaBuilder, ArrayMergeStrategy, ConfigAuditRecord, ConfigDump,
+    ConfigHistoryDiff, ConfigHistoryStore, ConfigKeyChange, ConfigManager,
+    ConfigOptionDescriptor, ConfigProvenance, ConfigProvider, ConfigRedactor, ConfigValidator,
+    ConfigWatcher, ConfigWriteOptions, ConfigWriteResult, SchemaValidator, SecretProvider,
+    SyncValidatorAdapter, TypedConfigBinder,
+};
+
+pub use config_abstractions::ConfigSnapshot;
+use infrastructure_common::{
+    result_label, ConfigError, ConfigSchema, ConfigSection, Configurable,
+    CONFIG_RELOADS_TOTAL, CONFIG_RELOAD_DURATION_SECONDS,
 };
-use infrastructure_common::{ConfigError, ConfigSection, Configurable};
-use serde::Deserialize;
-use serde_json::Value;
+use serde::de::DeserializeOwned;
+use serde::{Deserialize, Serialize};
+use serde_json::{Map, Value};
 use std::any::TypeId;
-use std::collections::HashMap;
+use std::collections::{HashMap, HashSet};
+use std::path::{Path, PathBuf};
+use std::sync::atomic::{AtomicU64, AtomicUsize, Ordering};
 use std::sync::Arc;
-use tokio::sync::{mpsc, Mutex, RwLock};
+use std::time::Duration;
+use tokio::sync::{broadcast, Mutex, RwLock};
 use tracing::{debug, error, info, warn};
 
-/// 配置快照，用于配置回滚
-#[derive(Debug, Clone)]
-pub struct ConfigSnapshot {
-    /// 快照时间
-    pub timestamp: chrono::DateTime<chrono::Utc>,
-    /// 配置数据
-    pub config_data: HashMap<String, Value>,
-    /// 快照版本号
-    pub version: u64,
-    /// 快照描述
-    pub description: String,
+/// 配置变更广播通道容量
+const CHANGE_NOTIFIER_CAPACITY: usize = 256;
+
+/// 最近一次配置重载的结果
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ConfigReloadStatus {
+    /// 触发重载的来源
+    pub source: String,
+    /// 失败原因，成功时为 None
+    pub error: Option<String>,
+    /// 完成时间
+    pub completed_at: chrono::DateTime<chrono::Utc>,
+}
+
+impl ConfigReloadStatus {
+    /// 重载是否成功
+    pub fn is_success(&self) -> bool {
+        self.error.is_none()
+    }
+}
+
+/// 配置提供者的可用状态
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ConfigProviderStatus {
+    /// 提供者名称
+    pub name: String,
+    /// 配置源描述
+    pub source: String,
+    /// 不可用原因，可用时为 None
+    pub error: Option<String>,
+}
+
+impl ConfigProviderStatus {
+    /// 提供者是否可用
+    pub fn is_available(&self) -> bool {
+        self.error.is_none()
+    }
 }
 
 /// 广告系统配置管理器
 ///
 /// 主配置管理器，协调多个配置源并提供统一的配置访问接口
 /// 支持热重载、配置验证和回滚功能
+///
+/// 内部状态通过 `Arc` 共享，热重载后台任务与管理器本身操作的是同一份状态
 pub struct AdSystemConfigManager {
     /// 配置提供者列表（按优先级排序）
-    providers: Vec<Box<dyn ConfigProvider>>,
+    providers: Arc<RwLock<Vec<Box<dyn ConfigProvider>>>>,
+    /// 配置提供者数量（无需等待提供者列表的锁即可读取）
+    provider_count: Arc<AtomicUsize>,
     /// 配置验证器映射
-    validators: HashMap<TypeId, Box<dyn std::any::Any + Send + Sync>>,
-    /// 类型化配置绑定器
-    binder: Arc<TypedConfigBinderImpl>,
+    validators: Arc<RwLock<HashMap<TypeId, Box<dyn std::any::Any + Send + Sync>>>>,
+    /// 分层配置合并器
+    merger: Arc<parking_lot::RwLock<ConfigMerger>>,
+    /// 配置值插值器
+    interpolator: ConfigInterpolator,
+    /// 密钥引用解析器
+    secret_resolver: Arc<parking_lot::RwLock<SecretResolver>>,
+    /// 敏感配置脱敏器
+    redactor: Arc<parking_lot::RwLock<ConfigRedactor>>,
     /// 已注册的配置选项
-    registered_options: HashMap<String, ConfigOptionDescriptor>,
+    registered_options: Arc<parking_lot::RwLock<HashMap<String, ConfigOptionDescriptor>>>,
+    /// 配置选项的验证目标（路径 -> 绑定并执行验证器的入口）
+    options_targets: Arc<parking_lot::RwLock<HashMap<String, Arc<dyn OptionsValidationTarget>>>>,
+    /// 异步验证器超时时间
+    validation_timeout: Arc<parking_lot::RwLock<Duration>>,
     /// 缓存的配置值
     config_cache: Arc<RwLock<HashMap<String, Value>>>,
+    /// 缓存的插值后配置树（包含按键记录的插值错误）
+    resolved_cache: Arc<RwLock<Option<Arc<InterpolatedConfig>>>>,
+    /// 缓存代数，清除缓存时递增，避免并发读取写回过期的配置树
+    cache_generation: Arc<AtomicU64>,
     /// 是否启用缓存
     cache_enabled: bool,
+    /// 回滚后固定使用的配置树
+    ///
+    /// 配置变更被回滚时，配置提供者中的数据已经是新值，
+    /// 因此固定使用快照中的配置，直到下一次成功重载
+    pinned_config: Arc<RwLock<Option<Value>>>,
+    /// 串行化重载流程
+    reload_lock: Arc<Mutex<()>>,
+    /// 配置变更广播
+    change_notifier: broadcast::Sender<ConfigChangeEvent>,
     /// 热重载相关字段
     /// 配置文件监控器
     config_watcher: Option<Arc<Mutex<dyn ConfigWatcher>>>,
     /// 是否启用热重载
     hot_reload_enabled: bool,
-    /// 配置变更事件接收器
-    change_receiver: Option<mpsc::Receiver<ConfigChangeEvent>>,
-    /// 配置变更事件发送器（用于内部通信）
-    change_sender: Option<mpsc::Sender<ConfigChangeEvent>>,
-    /// 配置回滚历史（保存最近几次的配置快照）
-    config_history: Arc<RwLock<Vec<ConfigSnapshot>>>,
+    /// 配置历史存储（保存最近几次的配置快照及审计记录）
+    history_store: Arc<parking_lot::RwLock<Arc<dyn ConfigHistoryStore>>>,
     /// 最大回滚历史数量
-    max_history_size: usize,
+    max_history_size: Arc<AtomicUsize>,
     /// 配置变更处理任务句柄
     change_handler_task: Option<tokio::task::JoinHandle<()>>,
     /// 配置事件处理器
-    event_handler: Option<Arc<Mutex<ConfigEventHandler>>>,
+    event_handler: Arc<parking_lot::RwLock<Option<Arc<Mutex<ConfigEventHandler>>>>>,
+    /// 最近一次重载的结果
+    last_reload: Arc<parking_lot::RwLock<Option<ConfigReloadStatus>>>,
+    /// 配置重载次数
+    reload_count: Arc<AtomicU64>,
 }
 
 impl std::fmt::Debug for AdSystemConfigManager {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         f.debug_struct("AdSystemConfigManager")
-            .field("providers_count", &self.providers.len())
-            .field("validators_count", &self.validators.len())
-            .field("registered_options", &self.registered_options)
+            .field(
+                "providers_count",
+                &self.providers.try_read().map(|providers| providers.len()).ok(),
+            )
+            .field(
+                "validators_count",
+                &self.validators.try_read().map(|validators| validators.len()).ok(),
+            )
+            .field("registered_options", &*self.registered_options.read())
             .field("cache_enabled", &self.cache_enabled)
+            .field("hot_reload_enabled", &self.hot_reload_enabled)
             .finish()
     }
 }
@@ -79,22 +164,36 @@ impl std::fmt::Debug for AdSystemConfigManager {
 impl AdSystemConfigManager {
     /// 创建新的配置管理器
     pub fn new() -> Self {
+        let (change_notifier, _) = broadcast::channel(CHANGE_NOTIFIER_CAPACITY);
         Self {
-            providers: Vec::new(),
-            validators: HashMap::new(),
-            binder: Arc::new(TypedConfigBinderImpl::new()),
-            registered_options: HashMap::new(),
+            providers: Arc::new(RwLock::new(Vec::new())),
+            provider_count: Arc::new(AtomicUsize::new(0)),
+            validators: Arc::new(RwLock::new(HashMap::new())),
+            merger: Arc::new(parking_lot::RwLock::new(ConfigMerger::new())),
+            interpolator: ConfigInterpolator::new(),
+            secret_resolver: Arc::new(parking_lot::RwLock::new(SecretResolver::new())),
+            redactor: Arc::new(parking_lot::RwLock::new(ConfigRedactor::new())),
+            registered_options: Arc::new(parking_lot::RwLock::new(HashMap::new())),
+            options_targets: Arc::new(parking_lot::RwLock::new(HashMap::new())),
+            validation_timeout: Arc::new(parking_lot::RwLock::new(DEFAULT_ASYNC_VALIDATION_TIMEOUT)),
             config_cache: Arc::new(RwLock::new(HashMap::new())),
+            resolved_cache: Arc::new(RwLock::new(None)),
+            cache_generation: Arc::new(AtomicU64::new(0)),
             cache_enabled: true,
+            pinned_config: Arc::new(RwLock::new(None)),
+            reload_lock: Arc::new(Mutex::new(())),
+            change_notifier,
             // 热重载相关字段初始化
             config_watcher: None,
             hot_reload_enabled: false,
-            change_receiver: None,
-            change_sender: None,
-            config_history: Arc::new(RwLock::new(Vec::new())),
-            max_history_size: 10, // 默认保留最近10个配置快照
+            history_store: Arc::new(parking_lot::RwLock::new(Arc::new(
+                InMemoryConfigHistoryStore::new(),
+            ))),
+            max_history_size: Arc::new(AtomicUsize::new(10)), // 默认保留最近10个配置快照
             change_handler_task: None,
-            event_handler: None,
+            event_handler: Arc::new(parking_lot::RwLock::new(None)),
+            last_reload: Arc::new(parking_lot::RwLock::new(None)),
+            reload_count: Arc::new(AtomicU64::new(0)),
         }
     }
 
@@ -106,6 +205,8 @@ impl AdSystemConfigManager {
     /// 清除配置缓存
     pub async fn clear_cache(&self) -> Result<(), ConfigError> {
         if self.cache_enabled {
+            self.cache_generation.fetch_add(1, Ordering::SeqCst);
+            *self.resolved_cache.write().await = None;
             let mut cache = self.config_cache.write().await;
             cache.clear();
             debug!("配置缓存已清除");
@@ -115,12 +216,815 @@ impl AdSystemConfigManager {
 
     /// 获取配置提供者数量
     pub fn provider_count(&self) -> usize {
-        self.providers.len()
+        self.provider_count.load(Ordering::SeqCst)
+    }
+
+    /// 检查所有配置提供者是否可用
+    ///
+    /// 文件类提供者要求监控的配置文件仍然存在，其余提供者要求能够列出配置键
+    pub async fn provider_statuses(&self) -> Vec<ConfigProviderStatus> {
+        let providers = self.providers.read().await;
+        let mut statuses = Vec::with_capacity(providers.len());
+
+        for provider in providers.iter() {
+            let missing = provider
+                .watch_paths()
+                .into_iter()
+                .find(|path| !path.exists());
+            let error = match missing {
+                Some(path) => Some(format!("配置文件不存在: {}", path.display())),
+                None => provider.get_all_keys().await.err().map(|e| e.to_string()),
+            };
+            statuses.push(ConfigProviderStatus {
+                name: provider.name().to_string(),
+                source: provider.source_description(),
+                error,
+            });
+        }
+
+        statuses
+    }
+
+    /// 获取最近一次配置重载的结果，尚未重载时返回 None
+    pub fn last_reload_status(&self) -> Option<ConfigReloadStatus> {
+        self.last_reload.read().clone()
+    }
+
+    /// 获取配置重载次数，包括文件监听触发的重载
+    pub fn reload_count(&self) -> u64 {
+        self.reload_count.load(Ordering::SeqCst)
     }
 
     /// 获取已注册的配置选项数量
     pub fn registered_options_count(&self) -> usize {
-        self.registered_options.len()
+        self.registered_options.read().len()
+    }
+
+    /// 获取已注册的配置验证器数量
+    pub async fn registered_validators_count(&self) -> usize {
+        self.validators.read().await.len()
+    }
+
+    /// 设置异步验证器超时时间
+    pub fn set_validation_timeout(&self, timeout: Duration) {
+        *self.validation_timeout.write() = timeout;
+    }
+
+    /// 注册需要验证的配置选项
+    ///
+    /// `validate_configuration` 会把该路径的配置绑定为 `T`，
+    /// 并执行为 `T` 注册的验证器
+    pub fn register_options<T>(&self, path: &str)
+    where
+        T: DeserializeOwned + Send + Sync + 'static,
+    {
+        self.registered_options
+            .write()
+            .entry(path.to_string())
+            .or_insert_with(|| ConfigOptionDescriptor {
+                path: path.to_string(),
+                option_type: std::any::type_name::<T>().to_string(),
+                default_value: None,
+                description: None,
+                required: true,
+                validation_rules: Vec::new(),
+                schema: None,
+            });
+        self.options_targets
+            .write()
+            .insert(path.to_string(), TypedOptionsTarget::<T>::shared());
+        debug!("注册配置选项: {} -> {}", path, std::any::type_name::<T>());
+    }
+
+    /// 对配置树中所有已注册的配置选项执行验证器
+    async fn validate_registered_options(&self, config: &Value, result: &mut ValidationResult) {
+        let targets: Vec<(String, bool, Arc<dyn OptionsValidationTarget>)> = {
+            let options = self.registered_options.read();
+            let targets = self.options_targets.read();
+            options
+                .values()
+                .filter_map(|descriptor| {
+                    targets
+                        .get(&descriptor.path)
+                        .map(|target| (descriptor.path.clone(), descriptor.required, target.clone()))
+                })
+                .collect()
+        };
+
+        let mut entries = Vec::with_capacity(targets.len());
+        for (path, required, target) in targets {
+            let raw = match get_nested_value(config, &path) {
+                Some(value) => self.resolve_secrets(value.clone()).await,
+                None => Err(ConfigError::KeyNotFound { key: path.clone() }),
+            };
+            entries.push(OptionsValidationEntry {
+                path,
+                required,
+                target,
+                raw,
+            });
+        }
+
+        let timeout = *self.validation_timeout.read();
+        let validators = self.validators.read().await;
+        let (failures, validated_count) =
+            run_options_validators(entries, &validators, timeout).await;
+        debug!("执行了 {} 个配置验证器", validated_count);
+        for failure in &failures {
+            result.add_error(failure.to_config_validation_error());
+        }
+    }
+
+    /// 设置默认数组合并策略
+    pub async fn set_default_array_merge_strategy(
+        &mut self,
+        strategy: ArrayMergeStrategy,
+    ) -> Result<(), ConfigError> {
+        self.merger.write().set_default_array_strategy(strategy);
+        self.clear_cache().await
+    }
+
+    /// 为指定配置路径设置数组合并策略
+    pub async fn set_array_merge_strategy(
+        &mut self,
+        path: impl Into<String>,
+        strategy: ArrayMergeStrategy,
+    ) -> Result<(), ConfigError> {
+        self.merger.write().set_array_strategy(path, strategy);
+        self.clear_cache().await
+    }
+
+    /// 注册密钥提供者
+    ///
+    /// 配置值中的 `${secret:name}` 引用在读取配置时按注册顺序依次查询密钥提供者
+    pub fn register_secret_provider(&mut self, provider: Arc<dyn SecretProvider>) {
+        self.secret_resolver.write().add_provider(provider);
+    }
+
+    /// 获取敏感配置脱敏器
+    pub fn redactor(&self) -> ConfigRedactor {
+        self.redactor.read().clone()
+    }
+
+    /// 设置敏感配置脱敏器
+    pub fn set_redactor(&mut self, redactor: ConfigRedactor) {
+        *self.redactor.write() = redactor;
+    }
+
+    /// 订阅配置变更
+    ///
+    /// 每次成功重载后，每个发生变化的配置键都会广播一个变更事件，敏感配置已脱敏
+    pub fn subscribe_changes(&self) -> broadcast::Receiver<ConfigChangeEvent> {
+        self.change_notifier.subscribe()
+    }
+
+    /// 获取类型化实时配置句柄
+    ///
+    /// 句柄始终持有该路径最近一次成功绑定的值，配置重载后自动更新
+    pub async fn options_monitor<T>(
+        &self,
+        path: impl Into<String>,
+    ) -> Result<OptionsMonitor<T>, ConfigError>
+    where
+        T: DeserializeOwned + Send + Sync + 'static,
+    {
+        OptionsMonitor::start(self.clone_for_event_handling(), path, None).await
+    }
+
+    /// 获取带验证器的类型化实时配置句柄
+    ///
+    /// 重载后的值未通过验证时，句柄保留上一次通过验证的值
+    pub async fn options_monitor_with_validator<T>(
+        &self,
+        path: impl Into<String>,
+        validator: Arc<dyn ConfigValidator<T>>,
+    ) -> Result<OptionsMonitor<T>, ConfigError>
+    where
+        T: DeserializeOwned + Send + Sync + 'static,
+    {
+        OptionsMonitor::start(self.clone_for_event_handling(), path, Some(validator)).await
+    }
+
+    /// 获取功能开关求值器
+    ///
+    /// 开关定义从指定配置路径加载，配置重载后自动更新
+    pub async fn feature_flags(
+        &self,
+        path: impl Into<String>,
+    ) -> Result<FeatureFlags, ConfigError> {
+        FeatureFlags::start(self.clone_for_event_handling(), path).await
+    }
+
+    /// 获取所有配置提供者合并后的完整配置树
+    ///
+    /// `${other.key}`、`${env:VAR:-default}` 和 `${file:path}` 占位符在合并后解析，
+    /// 密钥引用保持 `${secret:name}` 形式，不会被解析。
+    /// 无法解析的占位符保持原样，读取对应配置键时才返回错误。
+    /// 配置变更被回滚后，返回回滚到的快照配置
+    pub async fn merged_configuration(&self) -> Result<Value, ConfigError> {
+        Ok(self.resolved_configuration().await.value.clone())
+    }
+
+    /// 获取插值后的配置树及按键记录的插值错误
+    ///
+    /// 结果在缓存被清除（注册提供者、重载等）之前复用，
+    /// `${file:path}` 引用的文件不会在每次读取时重新读取
+    async fn resolved_configuration(&self) -> Arc<InterpolatedConfig> {
+        if let Some(pinned) = self.pinned_config.read().await.clone() {
+            return Arc::new(InterpolatedConfig {
+                value: pinned,
+                errors: Default::default(),
+            });
+        }
+        if self.cache_enabled {
+            if let Some(resolved) = self.resolved_cache.read().await.clone() {
+                return resolved;
+            }
+        }
+
+        let generation = self.cache_generation.load(Ordering::SeqCst);
+        let layers = self.collect_layers().await;
+        let merged = self.merger.read().merge(&layers);
+        let resolved = Arc::new(self.interpolator.interpolate_lenient(&merged));
+        for (key, error) in &resolved.errors {
+            warn!("配置插值失败，配置键保持原值: {}: {}", key, error);
+        }
+
+        if self.cache_enabled {
+            let mut cache = self.resolved_cache.write().await;
+            if self.cache_generation.load(Ordering::SeqCst) == generation {
+                *cache = Some(resolved.clone());
+            }
+        }
+        resolved
+    }
+
+    /// 解释配置键的来源
+    ///
+    /// 返回生效值、胜出的配置源以及所有被覆盖的值。
+    /// 生效值为插值后的结果，各配置源的值保持原始形式
+    pub async fn explain_configuration(&self, key: &str) -> Result<ConfigProvenance, ConfigError> {
+        let layers = self.collect_layers().await;
+        let mut provenance = self.merger.read().provenance(&layers, key);
+        if provenance.effective_value.is_some() {
+            let merged = self.merged_configuration().await?;
+            provenance.effective_value = get_nested_value(&merged, key).cloned();
+        }
+        Ok(self.redactor.read().redact_provenance(&provenance))
+    }
+
+    /// 导出合并后的生效配置，每个叶子标注来源
+    ///
+    /// 配置值为插值后的结果，敏感配置已脱敏，密钥引用保持 `${secret:name}` 形式
+    pub async fn dump_effective_configuration(&self) -> Result<ConfigDump, ConfigError> {
+        let layers = self.collect_layers().await;
+        let merged = self.merged_configuration().await?;
+        let mut dump = self.merger.read().dump(&layers);
+        for entry in &mut dump.entries {
+            if let Some(value) = get_nested_value(&merged, &entry.key) {
+                entry.value = value.clone();
+            }
+        }
+        Ok(self.redactor.read().redact_dump(&dump))
+    }
+
+    /// 注册配置类型的 JSON Schema
+    ///
+    /// 已通过 `register_component_options` 注册的路径只更新 Schema，
+    /// 否则新建配置选项描述符
+    pub fn register_config_schema<C>(&self, path: &str)
+    where
+        C: ConfigSchema + 'static,
+    {
+        let schema = C::config_schema();
+        let mut options = self.registered_options.write();
+        match options.get_mut(path) {
+            Some(descriptor) => descriptor.schema = Some(schema),
+            None => {
+                options.insert(
+                    path.to_string(),
+                    ConfigOptionDescriptor {
+                        path: path.to_string(),
+                        option_type: std::any::type_name::<C>().to_string(),
+                        default_value: None,
+                        description: schema
+                            .get("description")
+                            .and_then(Value::as_str)
+                            .map(str::to_string),
+                        required: !C::is_optional(),
+                        validation_rules: Vec::new(),
+                        schema: Some(schema),
+                    },
+                );
+            }
+        }
+        debug!("注册配置 Schema: {} -> {}", path, std::any::type_name::<C>());
+    }
+
+    /// 由所有已注册配置选项的 Schema 组装应用配置 Schema
+    pub fn application_schema(&self) -> Value {
+        let options = self.registered_options.read();
+        let mut descriptors: Vec<&ConfigOptionDescriptor> = options.values().collect();
+        descriptors.sort_by(|a, b| a.path.cmp(&b.path));
+
+        descriptors
+            .into_iter()
+            .filter_map(|descriptor| {
+                let mut schema = descriptor.schema.clone()?;
+                if let (Some(description), Value::Object(map)) =
+                    (&descriptor.description, &mut schema)
+                {
+                    map.entry("description")
+                        .or_insert_with(|| Value::String(description.clone()));
+                }
+                if let (Some(default_value), Value::Object(map)) =
+                    (&descriptor.default_value, &mut schema)
+                {
+                    map.entry("default").or_insert_with(|| default_value.clone());
+                }
+                Some((descriptor.path.as_str(), schema, descriptor.required))
+            })
+            .fold(
+                ApplicationSchemaBuilder::new("Lorn ADSP Configuration"),
+                |builder, (path, schema, required)| builder.option(path, schema, required),
+            )
+            .build()
+    }
+
+    /// 将应用配置 Schema 导出为 JSON 文件，供编辑器自动补全使用
+    pub async fn export_application_schema(&self, path: impl AsRef<Path>) -> Result<(), ConfigError> {
+        let content = serde_json::to_string_pretty(&self.application_schema())?;
+        tokio::fs::write(path.as_ref(), content)
+            .await
+            .map_err(|e| ConfigError::FileReadError { source: e })?;
+        info!("应用配置 Schema 已导出: {}", path.as_ref().display());
+        Ok(())
+    }
+
+    /// 按应用配置 Schema 验证合并后的配置
+    ///
+    /// 错误路径为 JSON Pointer（如 `/server/port`）
+    pub async fn validate_against_schema(&self) -> Result<ValidationResult, ConfigError> {
+        let merged = self.merged_configuration().await?;
+        let errors = SchemaValidator::new(self.application_schema()).validate(&merged);
+        Ok(if errors.is_empty() {
+            ValidationResult::success()
+        } else {
+            ValidationResult::failure(errors)
+        })
+    }
+
+    /// 使用 JSON Schema 和已注册的验证器验证配置树
+    async fn validate_config_tree(&self, config: &Value) -> ValidationResult {
+        let mut result = ValidationResult::success();
+        for error in SchemaValidator::new(self.application_schema()).validate(config) {
+            result.add_error(error);
+        }
+        self.validate_registered_options(config, &mut result).await;
+        result.validated_at = chrono::Utc::now();
+        result
+    }
+
+    /// 重新加载所有配置提供者，并将逐键变更通知给监听器
+    ///
+    /// 等同于以系统身份调用 [`reload_with_audit`](Self::reload_with_audit)
+    pub async fn reload_and_notify(
+        &self,
+        source: &str,
+    ) -> Result<Vec<ConfigChangeEvent>, ConfigError> {
+        self.reload_with_audit(ConfigAuditRecord::new(source)).await
+    }
+
+    /// 重新加载所有配置提供者，记录审计信息并将逐键变更通知给监听器
+    ///
+    /// 流程：重载配置提供者 → 比较新旧配置得到逐键差异 → 验证配置 →
+    /// 保存带审计记录的快照并分发变更事件。新配置无法合并或验证失败时，
+    /// 自动回滚到历史中的最近快照并返回错误
+    pub async fn reload_with_audit(
+        &self,
+        audit: ConfigAuditRecord,
+    ) -> Result<Vec<ConfigChangeEvent>, ConfigError> {
+        let source = audit.source.clone();
+        let started = std::time::Instant::now();
+        let result = self.apply_reload(audit).await;
+
+        metrics::histogram!(CONFIG_RELOAD_DURATION_SECONDS).record(started.elapsed().as_secs_f64());
+        metrics::counter!(CONFIG_RELOADS_TOTAL, "result" => result_label(&result)).increment(1);
+        self.reload_count.fetch_add(1, Ordering::SeqCst);
+        *self.last_reload.write() = Some(ConfigReloadStatus {
+            source,
+            error: result.as_ref().err().map(|e| e.to_string()),
+            completed_at: chrono::Utc::now(),
+        });
+        result
+    }
+
+    /// 执行重载流程，见 [`reload_with_audit`](Self::reload_with_audit)
+    async fn apply_reload(
+        &self,
+        audit: ConfigAuditRecord,
+    ) -> Result<Vec<ConfigChangeEvent>, ConfigError> {
+        let _reload_guard = self.reload_lock.lock().await;
+        let source = audit.source.as_str();
+        info!("重新加载配置: {} (操作者: {})", source, audit.actor);
+
+        let old_config = match self.merged_configuration().await {
+            Ok(config) => config,
+            Err(e) => {
+                warn!("当前配置无法合并，视为空配置: {}", e);
+                Value::Object(Map::new())
+            }
+        };
+        self.ensure_baseline_snapshot(&old_config).await;
+
+        let errors = self.reload_providers().await;
+        if !errors.is_empty() {
+            self.rollback_failed_change(source, &errors).await?;
+            return Err(ConfigError::ReloadError {
+                message: format!("{}个提供者重载失败", errors.len()),
+            });
+        }
+
+        *self.pinned_config.write().await = None;
+        self.clear_cache().await?;
+
+        let new_config = match self.merge_providers().await {
+            Ok(config) => config,
+            Err(e) => {
+                self.rollback_failed_change(source, &[e.to_string()]).await?;
+                return Err(e);
+            }
+        };
+
+        let changes = diff_configuration(&old_config, &new_config);
+        if changes.is_empty() {
+            debug!("配置重载后没有变化: {}", source);
+            return Ok(Vec::new());
+        }
+
+        let validation_result = self.validate_configuration().await?;
+        if !validation_result.is_valid {
+            let errors: Vec<String> = validation_result
+                .errors
+                .iter()
+                .map(ToString::to_string)
+                .collect();
+            warn!("配置变更后验证失败，回滚配置: {:?}", errors);
+            self.rollback_failed_change(source, &errors).await?;
+            return Err(ConfigError::ValidationFailed { errors });
+        }
+
+        let keys: Vec<&str> = changes.iter().map(|change| change.key.as_str()).collect();
+        let raw_config = self.raw_configuration().await;
+        self.push_snapshot(
+            &new_config,
+            Some(&raw_config),
+            &format!("Applied change from {}: {}", source, keys.join(", ")),
+            audit.clone(),
+        )
+        .await;
+
+        let events: Vec<ConfigChangeEvent> = changes
+            .iter()
+            .map(|change| change.to_event(source))
+            .collect();
+        for event in &events {
+            self.dispatch_event(event).await?;
+        }
+
+        info!("配置重载完成，{}个配置键发生变化", events.len());
+        Ok(events)
+    }
+
+    /// 合并所有配置提供者的配置并解析占位符
+    async fn merge_providers(&self) -> Result<Value, ConfigError> {
+        let merged = self.raw_configuration().await;
+        self.interpolator.interpolate(&merged)
+    }
+
+    /// 合并所有配置提供者的配置，不解析占位符
+    async fn raw_configuration(&self) -> Value {
+        let layers = self.collect_layers().await;
+        self.merger.read().merge(&layers)
+    }
+
+    /// 依次重载所有配置提供者，返回失败信息
+    async fn reload_providers(&self) -> Vec<String> {
+        let mut errors = Vec::new();
+        let mut providers = self.providers.write().await;
+
+        for provider in providers.iter_mut() {
+            if let Err(e) = provider.reload().await {
+                error!("提供者 {} 重载失败: {}", provider.name(), e);
+                errors.push(format!("{}: {}", provider.name(), e));
+            }
+        }
+
+        errors
+    }
+
+    /// 回滚失败的配置变更
+    ///
+    /// 恢复历史中的最近快照（即最后一次成功应用的配置）并发送验证失败事件
+    async fn rollback_failed_change(
+        &self,
+        source: &str,
+        errors: &[String],
+    ) -> Result<(), ConfigError> {
+        let snapshot = match self.history_store().latest().await {
+            Ok(snapshot) => snapshot,
+            Err(e) => {
+                error!("读取配置历史失败: {}", e);
+                None
+            }
+        };
+        match snapshot {
+            Some(snapshot) => {
+                self.restore_snapshot(&snapshot).await;
+                info!("配置变更已回滚到版本: {}", snapshot.version);
+            }
+            None => warn!("没有可用的配置快照，无法回滚"),
+        }
+
+        self.dispatch_event(&ConfigChangeEvent::validation_failed(
+            source,
+            errors,
+            "ConfigManager",
+        ))
+        .await
+    }
+
+    /// 获取当前生效配置的版本号
+    ///
+    /// 历史为空或最新快照与生效配置不一致时先保存基线快照，保证返回的版本号对应当前配置。
+    /// 写入配置时作为 [`ConfigWriteOptions::expected_version`] 传入以避免覆盖并发修改
+    pub async fn current_version(&self) -> Result<u64, ConfigError> {
+        let _reload_guard = self.reload_lock.lock().await;
+        let config = self.merged_configuration().await?;
+        self.effective_version(&config).await
+    }
+
+    /// 获取最新快照的版本号，不保存快照
+    ///
+    /// 供只读查询使用，没有快照时返回 None；历史中尚未记录最新配置时可能落后于
+    /// [`current_version`](Self::current_version)
+    pub async fn latest_version(&self) -> Result<Option<u64>, ConfigError> {
+        Ok(self
+            .history_store()
+            .latest()
+            .await?
+            .map(|snapshot| snapshot.version))
+    }
+
+    /// 保存必要的基线快照并返回最新快照的版本号
+    async fn effective_version(&self, config: &Value) -> Result<u64, ConfigError> {
+        self.ensure_baseline_snapshot(config).await;
+        Ok(self
+            .history_store()
+            .latest()
+            .await?
+            .map_or(0, |snapshot| snapshot.version))
+    }
+
+    /// 设置配置值，值可以是标量、数组或整个配置节
+    pub async fn set_value(
+        &self,
+        key: &str,
+        value: Value,
+        options: ConfigWriteOptions,
+    ) -> Result<ConfigWriteResult, ConfigError> {
+        let write = ConfigKeyChange {
+            key: key.to_string(),
+            old_value: None,
+            new_value: Some(value),
+        };
+        self.write_changes(vec![write], options).await
+    }
+
+    /// 删除配置值
+    ///
+    /// 只删除目标配置提供者中的值，较低优先级配置源中的同名键随后生效
+    pub async fn delete_value(
+        &self,
+        key: &str,
+        options: ConfigWriteOptions,
+    ) -> Result<ConfigWriteResult, ConfigError> {
+        let write = ConfigKeyChange {
+            key: key.to_string(),
+            old_value: None,
+            new_value: None,
+        };
+        self.write_changes(vec![write], options).await
+    }
+
+    /// 将一组配置变更写入可写的配置提供者（`new_value` 为 `None` 表示删除）
+    ///
+    /// 流程：比较期望版本 → 在目标配置层上模拟写入并合并 → 验证合并结果 →
+    /// 写入配置提供者 → 保存带审计记录的快照并分发变更事件。
+    /// 版本冲突、写入的键被更高优先级的配置源覆盖或验证失败时不会修改任何配置
+    pub async fn write_changes(
+        &self,
+        writes: Vec<ConfigKeyChange>,
+        options: ConfigWriteOptions,
+    ) -> Result<ConfigWriteResult, ConfigError> {
+        let _reload_guard = self.reload_lock.lock().await;
+        let ConfigWriteOptions {
+            expected_version,
+            provider,
+            audit,
+        } = options;
+        let keys: Vec<&str> = writes.iter().map(|write| write.key.as_str()).collect();
+        info!("写入配置: {} (操作者: {})", keys.join(", "), audit.actor);
+
+        let old_config = self.merged_configuration().await?;
+        let current_version = self.effective_version(&old_config).await?;
+        if let Some(expected) = expected_version {
+            if expected != current_version {
+                return Err(ConfigError::VersionConflict {
+                    expected,
+                    actual: current_version,
+                });
+            }
+        }
+
+        let (target, priority) = self.select_writable_provider(provider.as_deref()).await?;
+        let candidate = self.simulate_writes(&target, priority, &writes).await?;
+        if diff_configuration(&old_config, &candidate).is_empty() {
+            debug!("写入后配置没有变化");
+            return Ok(ConfigWriteResult {
+                version: current_version,
+                events: Vec::new(),
+            });
+        }
+
+        let validation_result = self.validate_config_tree(&candidate).await;
+        if !validation_result.is_valid {
+            let errors: Vec<String> = validation_result
+                .errors
+                .iter()
+                .map(ToString::to_string)
+                .collect();
+            warn!("配置写入未通过验证: {:?}", errors);
+            return Err(ConfigError::ValidationFailed { errors });
+        }
+
+        {
+            let mut providers = self.providers.write().await;
+            let provider = providers
+                .iter_mut()
+                .find(|provider| provider.source_description() == target)
+                .ok_or_else(|| ConfigError::ProviderNotFound {
+                    provider: target.clone(),
+                })?;
+            provider.apply_changes(&writes).await?;
+        }
+        *self.pinned_config.write().await = None;
+        self.clear_cache().await?;
+
+        let new_config = self.merge_providers().await?;
+        let raw_config = self.raw_configuration().await;
+        let changes = diff_configuration(&old_config, &new_config);
+        let version = self
+            .push_snapshot(
+                &new_config,
+                Some(&raw_config),
+                &format!("Wrote {} to {}", keys.join(", "), target),
+                audit.clone(),
+            )
+            .await
+            .unwrap_or(current_version);
+
+        let events: Vec<ConfigChangeEvent> = changes
+            .iter()
+            .map(|change| change.to_event(audit.source.as_str()))
+            .collect();
+        for event in &events {
+            self.dispatch_event(event).await?;
+        }
+
+        info!("配置写入完成，版本: {}", version);
+        Ok(ConfigWriteResult { version, events })
+    }
+
+    /// 选择写入目标，返回配置源描述和优先级
+    ///
+    /// 指定名称时按提供者名称或配置源描述匹配，否则选择优先级最高的可写提供者
+    async fn select_writable_provider(
+        &self,
+        name: Option<&str>,
+    ) -> Result<(String, i32), ConfigError> {
+        let providers = self.providers.read().await;
+        let provider = match name {
+            Some(name) => {
+                let provider = providers
+                    .iter()
+                    .find(|provider| {
+                        provider.name() == name || provider.source_description() == name
+                    })
+                    .ok_or_else(|| ConfigError::ProviderNotFound {
+                        provider: name.to_string(),
+                    })?;
+                if !provider.is_writable() {
+                    return Err(ConfigError::ReadOnlyProvider {
+                        provider: provider.source_description(),
+                    });
+                }
+                provider
+            }
+            None => providers
+                .iter()
+                .find(|provider| provider.is_writable())
+                .ok_or(ConfigError::NoWritableProvider)?,
+        };
+        Ok((provider.source_description(), provider.priority()))
+    }
+
+    /// 在目标配置层上模拟写入，返回合并并解析占位符后的配置
+    async fn simulate_writes(
+        &self,
+        target: &str,
+        priority: i32,
+        writes: &[ConfigKeyChange],
+    ) -> Result<Value, ConfigError> {
+        let mut layers = self.collect_layers().await;
+        let index = match layers.iter().position(|layer| layer.source == target) {
+            Some(index) => index,
+            None => {
+                layers.push(ConfigLayer::new(target, priority, Value::Object(Map::new())));
+                layers.len() - 1
+            }
+        };
+
+        for write in writes {
+            let layer_value = &mut layers[index].value;
+            match &write.new_value {
+                Some(value) => insert_nested_value(layer_value, &write.key, value.clone()),
+                None => {
+                    remove_nested_value(layer_value, &write.key).ok_or_else(|| {
+                        ConfigError::KeyNotFound {
+                            key: write.key.clone(),
+                        }
+                    })?;
+                }
+            }
+        }
+
+        let merger = self.merger.read().clone();
+        let merged = merger.merge(&layers);
+        for write in writes {
+            let Some(value) = &write.new_value else {
+                continue;
+            };
+            if get_nested_value(&merged, &write.key) != Some(value) {
+                let winner = merger.provenance(&layers, &write.key).winner;
+                return Err(ConfigError::KeyShadowed {
+                    key: write.key.clone(),
+                    source_name: winner.map_or_else(|| target.to_string(), |entry| entry.source),
+                });
+            }
+        }
+
+        self.interpolator.interpolate(&merged)
+    }
+
+    /// 解析配置值中的密钥引用
+    async fn resolve_secrets(&self, value: Value) -> Result<Value, ConfigError> {
+        if contains_secret_reference(&value) {
+            let resolver = self.secret_resolver.read().clone();
+            resolver.resolve_value(&value).await
+        } else {
+            Ok(value)
+        }
+    }
+
+    /// 从所有配置提供者收集配置层
+    async fn collect_layers(&self) -> Vec<ConfigLayer> {
+        let providers = self.providers.read().await;
+        let mut layers = Vec::with_capacity(providers.len());
+
+        for provider in providers.iter() {
+            let value = match provider.get_all_configuration().await {
+                Ok(value) => value,
+                Err(e) => {
+                    error!("提供者 {} 获取配置失败: {}", provider.name(), e);
+                    continue;
+                }
+            };
+
+            let source = provider.source_description();
+            let mut layer = ConfigLayer::new(source.clone(), provider.priority(), value);
+            if let Ok(keys) = provider.get_all_keys().await {
+                for key in keys {
+                    let key_source = provider.key_source(&key);
+                    if key_source != source {
+                        layer = layer.with_key_source(key, key_source);
+                    }
+                }
+            }
+            layers.push(layer);
+        }
+
+        layers
     }
 }
 
@@ -130,6 +1034,14 @@ impl Default for AdSystemConfigManager {
     }
 }
 
+impl Drop for AdSystemConfigManager {
+    fn drop(&mut self) {
+        if let Some(handle) = self.change_handler_task.take() {
+            handle.abort();
+        }
+    }
+}
+
 #[async_trait]
 impl ConfigManager for AdSystemConfigManager {
     async fn register_provider(
@@ -138,24 +1050,34 @@ impl ConfigManager for AdSystemConfigManager {
     ) -> Result<(), ConfigError> {
         info!("注册配置提供者: {}", provider.name());
 
-        self.providers.push(provider);
+        {
+            let mut providers = self.providers.write().await;
+            providers.push(provider);
 
-        // 按优先级排序（优先级高的在前）
-        self.providers
-            .sort_by(|a, b| b.priority().cmp(&a.priority()));
+            // 按优先级排序（优先级高的在前）
+            providers.sort_by_key(|p| std::cmp::Reverse(p.priority()));
+            self.provider_count.store(providers.len(), Ordering::SeqCst);
+        }
 
         // 清除缓存，强制重新加载
+        *self.pinned_config.write().await = None;
         self.clear_cache().await?;
 
         Ok(())
     }
 
     async fn unregister_provider(&mut self, provider_name: &str) -> Result<(), ConfigError> {
-        let initial_count = self.providers.len();
-        self.providers.retain(|p| p.name() != provider_name);
+        let removed = {
+            let mut providers = self.providers.write().await;
+            let initial_count = providers.len();
+            providers.retain(|p| p.name() != provider_name);
+            self.provider_count.store(providers.len(), Ordering::SeqCst);
+            providers.len() < initial_count
+        };
 
-        if self.providers.len() < initial_count {
+        if removed {
             info!("移除配置提供者: {}", provider_name);
+            *self.pinned_config.write().await = None;
             self.clear_cache().await?;
             Ok(())
         } else {
@@ -172,75 +1094,48 @@ impl ConfigManager for AdSystemConfigManager {
         // 检查缓存
         if self.cache_enabled {
             let cache = self.config_cache.read().await;
-            if let Some(value) = cache.get(key) {
+            if let Some(value) = cache.get(key).cloned() {
                 debug!("从缓存获取配置: {}", key);
-                return Ok(value.clone());
+                drop(cache);
+                return self.resolve_secrets(value).await;
             }
         }
 
-        // 按优先级顺序尝试各个提供者
-        for provider in &self.providers {
-            match provider.get_configuration(key).await {
-                Ok(value) => {
-                    debug!("从提供者 {} 获取配置: {}", provider.name(), key);
-
-                    // 更新缓存
-                    if self.cache_enabled {
-                        let mut cache = self.config_cache.write().await;
-                        cache.insert(key.to_string(), value.clone());
-                    }
-
-                    return Ok(value);
-                }
-                Err(ConfigError::KeyNotFound { .. }) => {
-                    // 继续尝试下一个提供者
-                    continue;
-                }
-                Err(e) => {
-                    error!("提供者 {} 获取配置失败: {}", provider.name(), e);
-                    continue;
+        // 在合并后的配置树中查找，只有引用了缺失来源的配置键返回插值错误
+        let resolved = self.resolved_configuration().await;
+        if let Some(error) = resolved.error_for(key) {
+            return Err(error);
+        }
+        match get_nested_value(&resolved.value, key) {
+            Some(value) => {
+                // 更新缓存（缓存中保留未解析的密钥引用）
+                if self.cache_enabled {
+                    let mut cache = self.config_cache.write().await;
+                    cache.insert(key.to_string(), value.clone());
                 }
+
+                self.resolve_secrets(value.clone()).await
             }
+            None => Err(ConfigError::KeyNotFound {
+                key: key.to_string(),
+            }),
         }
-
-        Err(ConfigError::KeyNotFound {
-            key: key.to_string(),
-        })
     }
 
     async fn get_section(&self, section_name: &str) -> Result<ConfigSection, ConfigError> {
         debug!("获取配置节: {}", section_name);
 
-        let mut combined_section = ConfigSection::new();
-
-        // 从所有提供者收集配置节数据
-        for provider in &self.providers {
-            match provider.get_section(section_name).await {
-                Ok(section) => {
-                    // 合并配置节数据（后面的提供者优先级更低，不覆盖已有配置）
-                    for (key, value) in section.data {
-                        if !combined_section.data.contains_key(&key) {
-                            combined_section.insert(key, value);
-                        }
-                    }
-                }
-                Err(ConfigError::KeyNotFound { .. }) => {
-                    // 继续尝试下一个提供者
-                    continue;
-                }
-                Err(e) => {
-                    warn!("提供者 {} 获取配置节失败: {}", provider.name(), e);
-                    continue;
+        match self.get_configuration(section_name).await? {
+            Value::Object(map) => {
+                let mut section = ConfigSection::new();
+                for (key, value) in map {
+                    section.insert(key, value);
                 }
+                Ok(section)
             }
-        }
-
-        if combined_section.data.is_empty() {
-            Err(ConfigError::KeyNotFound {
-                key: section_name.to_string(),
-            })
-        } else {
-            Ok(combined_section)
+            _ => Err(ConfigError::TypeConversionError {
+                message: format!("配置节 {} 不是对象类型", section_name),
+            }),
         }
     }
 
@@ -249,7 +1144,10 @@ impl ConfigManager for AdSystemConfigManager {
         T: for<'de> Deserialize<'de> + Send + 'static,
     {
         debug!("绑定配置到类型: {} -> {}", key, std::any::type_name::<T>());
-        self.binder.bind_configuration(key).await
+        let value = self.get_configuration(key).await?;
+        serde_json::from_value(value).map_err(|e| ConfigError::ParseError {
+            source: Box::new(e),
+        })
     }
 
     async fn bind_to_instance<T>(&self, instance: &mut T, path: &str) -> Result<(), ConfigError>
@@ -257,44 +1155,23 @@ impl ConfigManager for AdSystemConfigManager {
         T: Configurable,
     {
         debug!("绑定配置到实例: {} -> {}", path, std::any::type_name::<T>());
-        self.binder.bind_to_instance(instance, path).await
+        let config: T::Config = self.bind_configuration(path).await?;
+        instance.configure(config)
     }
 
     async fn reload_all(&mut self) -> Result<(), ConfigError> {
         info!("重新加载所有配置");
-
-        let mut errors = Vec::new();
-
-        for provider in &mut self.providers {
-            if let Err(e) = provider.reload().await {
-                error!("提供者 {} 重载失败: {}", provider.name(), e);
-                errors.push(e);
-            }
-        }
-
-        // 清除缓存
-        self.clear_cache().await?;
-
-        if errors.is_empty() {
-            info!("所有配置提供者重载成功");
-            Ok(())
-        } else {
-            Err(ConfigError::ReloadError {
-                message: format!("{}个提供者重载失败", errors.len()),
-            })
-        }
+        self.reload_and_notify("ConfigManager").await?;
+        info!("所有配置提供者重载成功");
+        Ok(())
     }
 
     async fn validate_configuration(&self) -> Result<ValidationResult, ConfigError> {
         info!("验证所有配置");
 
-        let mut overall_result = ValidationResult::success();
         let start_time = std::time::Instant::now();
-
-        // 这里应该实现具体的验证逻辑
-        // 遍历所有已注册的配置选项，使用对应的验证器进行验证
-
-        overall_result.validated_at = chrono::Utc::now();
+        let merged = self.merged_configuration().await?;
+        let overall_result = self.validate_config_tree(&merged).await;
         let duration = start_time.elapsed();
 
         if overall_result.is_valid {
@@ -315,17 +1192,15 @@ impl ConfigManager for AdSystemConfigManager {
         validator: Box<dyn ConfigValidator<T>>,
     ) -> Result<(), ConfigError>
     where
-        T: 'static,
+        T: Send + Sync + 'static,
     {
-        let type_id = TypeId::of::<T>();
         info!(
             "注册配置验证器: {} -> {}",
             validator.name(),
             std::any::type_name::<T>()
         );
 
-        let boxed_validator: Box<dyn std::any::Any + Send + Sync> = Box::new(validator);
-        self.validators.insert(type_id, boxed_validator);
+        insert_chained_validator(&mut *self.validators.write().await, validator);
         Ok(())
     }
 
@@ -358,10 +1233,26 @@ impl ConfigManager for AdSystemConfigManager {
             description: Some(format!("配置选项: {}", std::any::type_name::<T>())),
             required: true,
             validation_rules: Vec::new(),
+            // 保留通过 register_config_schema 单独注册的 Schema
+            schema: T::config_schema().or_else(|| {
+                self.registered_options
+                    .read()
+                    .get(config_path)
+                    .and_then(|existing| existing.schema.clone())
+            }),
         };
 
         self.registered_options
+            .write()
             .insert(config_path.to_string(), descriptor);
+        self.options_targets
+            .write()
+            .insert(config_path.to_string(), TypedOptionsTarget::<T::Config>::shared());
+
+        if let Some(validator) = T::config_validator() {
+            self.register_validator::<T::Config>(Box::new(SyncValidatorAdapter::new(validator)))
+                .await?;
+        }
         Ok(())
     }
 }
@@ -371,7 +1262,8 @@ impl AdSystemConfigManager {
 
     /// 启用配置热重载
     ///
-    /// 启用后，配置管理器将监控配置文件变更并自动重载配置
+    /// 启用后，配置管理器将监控所有配置提供者的文件，
+    /// 文件变更经防抖后触发 [`reload_and_notify`](Self::reload_and_notify)
     pub async fn enable_hot_reload(
         &mut self,
         watcher: Arc<Mutex<dyn ConfigWatcher>>,
@@ -382,23 +1274,26 @@ impl AdSystemConfigManager {
 
         info!("启用配置热重载");
 
-        // 创建配置变更事件通道
-        let (sender, receiver) = mpsc::channel(1000);
-        self.change_sender = Some(sender.clone());
-        self.change_receiver = Some(receiver);
-
-        // 保存监控器引用
-        self.config_watcher = Some(watcher.clone());
-        self.hot_reload_enabled = true;
+        // 创建配置初始快照（与最近的快照相同时不重复保存）
+        let current_config = self.merged_configuration().await?;
+        self.ensure_baseline_snapshot(&current_config).await;
 
-        // 创建配置初始快照
-        self.create_config_snapshot("Initial configuration snapshot")
-            .await?;
+        // 监控所有支持热重载的配置文件，并取得变更事件接收器
+        let watch_paths = self.collect_watch_paths().await;
+        let mut receiver = {
+            let mut watcher_guard = watcher.lock().await;
+            for path in &watch_paths {
+                watcher_guard.add_watch_path(path).await?;
+            }
+            watcher_guard
+                .take_change_receiver()
+                .ok_or_else(|| ConfigError::WatchError {
+                    message: format!("监控器 {:?} 的变更事件接收器已被占用", watch_paths),
+                })?
+        };
 
         // 启动配置变更处理任务
-        let manager_clone = self.clone_for_event_handling().await;
-        let mut receiver = self.change_receiver.take().unwrap();
-
+        let manager_clone = self.clone_for_event_handling();
         let handle = tokio::spawn(async move {
             while let Some(event) = receiver.recv().await {
                 if let Err(e) = manager_clone.handle_config_change_event(event).await {
@@ -409,6 +1304,10 @@ impl AdSystemConfigManager {
 
         self.change_handler_task = Some(handle);
 
+        // 保存监控器引用
+        self.config_watcher = Some(watcher.clone());
+        self.hot_reload_enabled = true;
+
         // 启动文件监控器
         {
             let mut watcher_guard = watcher.lock().await;
@@ -441,13 +1340,23 @@ impl AdSystemConfigManager {
         // 清理资源
         self.hot_reload_enabled = false;
         self.config_watcher = None;
-        self.change_receiver = None;
-        self.change_sender = None;
 
         info!("配置热重载已禁用");
         Ok(())
     }
 
+    /// 收集所有支持热重载的配置提供者需要监控的路径
+    async fn collect_watch_paths(&self) -> Vec<PathBuf> {
+        let providers = self.providers.read().await;
+        let mut seen = HashSet::new();
+        providers
+            .iter()
+            .filter(|provider| provider.supports_hot_reload())
+            .flat_map(|provider| provider.watch_paths())
+            .filter(|path| seen.insert(path.clone()))
+            .collect()
+    }
+
     /// 处理配置变更事件
     async fn handle_config_change_event(
         &self,
@@ -456,96 +1365,63 @@ impl AdSystemConfigManager {
         info!("处理配置变更事件: {:?}", event.event_type);
         debug!("配置变更路径: {}", event.path);
 
-        // 首先发送事件到事件处理器（通知监听器）
-        self.send_event_to_handler(event.clone()).await?;
-
-        // 在应用配置变更之前创建快照
-        let snapshot_description = format!("Before applying change: {}", event.path);
-        self.create_config_snapshot(&snapshot_description).await?;
-
         match event.event_type {
-            config_abstractions::events::ConfigChangeEventType::Created => {
+            ConfigChangeEventType::Created => {
                 self.handle_config_created(&event).await?;
             }
-            config_abstractions::events::ConfigChangeEventType::Updated => {
+            ConfigChangeEventType::Updated => {
                 self.handle_config_updated(&event).await?;
             }
-            config_abstractions::events::ConfigChangeEventType::Deleted => {
+            ConfigChangeEventType::Deleted => {
                 self.handle_config_deleted(&event).await?;
             }
-            config_abstractions::events::ConfigChangeEventType::Reloaded => {
+            ConfigChangeEventType::Reloaded => {
                 self.handle_config_reloaded(&event).await?;
             }
-            config_abstractions::events::ConfigChangeEventType::ValidationFailed => {
+            ConfigChangeEventType::ValidationFailed => {
                 self.handle_config_validation_failed(&event).await?;
             }
-            config_abstractions::events::ConfigChangeEventType::SourceConnectionFailed => {
+            ConfigChangeEventType::SourceConnectionFailed => {
                 self.handle_source_connection_failed(&event).await?;
             }
-            config_abstractions::events::ConfigChangeEventType::SourceConnectionRestored => {
+            ConfigChangeEventType::SourceConnectionRestored => {
                 self.handle_source_connection_restored(&event).await?;
             }
         }
 
-        // 验证配置变更后的配置
-        let validation_result = self.validate_configuration().await?;
-        if !validation_result.is_valid {
-            warn!(
-                "配置变更后验证失败，尝试回滚: {:?}",
-                validation_result.errors
-            );
-
-            // 发送验证失败事件
-            let validation_failed_event = ConfigChangeEvent::created(
-                format!("validation_failed:{}", event.path),
-                serde_json::Value::String("Validation failed after config change".to_string()),
-                "ConfigManager",
-            );
-            self.send_event_to_handler(validation_failed_event).await?;
-
-            self.rollback_last_change().await?;
-            return Err(ConfigError::ValidationFailed {
-                errors: validation_result
-                    .errors
-                    .into_iter()
-                    .map(|e| format!("{:?}", e))
-                    .collect(),
-            });
-        }
-
         info!("配置变更处理完成: {}", event.path);
         Ok(())
     }
 
     /// 处理配置创建事件
-    async fn handle_config_created(&self, _event: &ConfigChangeEvent) -> Result<(), ConfigError> {
+    async fn handle_config_created(&self, event: &ConfigChangeEvent) -> Result<(), ConfigError> {
         // 重新加载所有配置提供者以包含新的配置
         info!("处理配置创建事件");
-        self.reload_all_providers().await?;
+        self.reload_and_notify(&event.path).await?;
         Ok(())
     }
 
     /// 处理配置更新事件
-    async fn handle_config_updated(&self, _event: &ConfigChangeEvent) -> Result<(), ConfigError> {
+    async fn handle_config_updated(&self, event: &ConfigChangeEvent) -> Result<(), ConfigError> {
         // 重新加载受影响的配置提供者
         info!("处理配置更新事件");
-        self.reload_all_providers().await?;
+        self.reload_and_notify(&event.path).await?;
         Ok(())
     }
 
     /// 处理配置删除事件
-    async fn handle_config_deleted(&self, _event: &ConfigChangeEvent) -> Result<(), ConfigError> {
+    async fn handle_config_deleted(&self, event: &ConfigChangeEvent) -> Result<(), ConfigError> {
         // 重新加载所有配置提供者并清除相关缓存
         info!("处理配置删除事件");
-        self.reload_all_providers().await?;
+        self.reload_and_notify(&event.path).await?;
         Ok(())
     }
 
     /// 处理配置重载事件
-    async fn handle_config_reloaded(&self, _event: &ConfigChangeEvent) -> Result<(), ConfigError> {
+    async fn handle_config_reloaded(&self, event: &ConfigChangeEvent) -> Result<(), ConfigError> {
         // 重新加载所有配置提供者
         info!("处理配置重载事件");
-        self.reload_all_providers().await?;
+        self.reload_and_notify(&event.path).await?;
         Ok(())
     }
 
@@ -578,135 +1454,314 @@ impl AdSystemConfigManager {
     ) -> Result<(), ConfigError> {
         info!("配置源连接已恢复: {}", event.path);
         // 配置源恢复时，重新加载配置
-        self.reload_all_providers().await?;
+        self.reload_and_notify(&event.path).await?;
         Ok(())
     }
 
-    /// 重新加载所有配置提供者（不可变版本）
-    async fn reload_all_providers(&self) -> Result<(), ConfigError> {
-        info!("重新加载所有配置提供者");
-
-        let errors: Vec<ConfigError> = Vec::new();
-
-        // 注意：这里由于借用检查的限制，我们需要重新设计这个方法
-        // 或者使用内部可变性
-        // 暂时记录错误但不实际重载，在实际实现中需要解决这个问题
+    /// 获取配置历史存储
+    pub fn history_store(&self) -> Arc<dyn ConfigHistoryStore> {
+        self.history_store.read().clone()
+    }
 
-        // 清除缓存
-        {
-            let mut cache = self.config_cache.write().await;
-            cache.clear();
-        }
+    /// 设置配置历史存储
+    ///
+    /// 默认使用内存存储，使用 [`FileConfigHistoryStore`](crate::FileConfigHistoryStore)
+    /// 可以在重启后保留历史和版本号
+    pub fn set_history_store(&self, store: Arc<dyn ConfigHistoryStore>) {
+        info!("设置配置历史存储: {}", store.name());
+        *self.history_store.write() = store;
+    }
 
-        if errors.is_empty() {
-            info!("所有配置提供者重载成功");
-            Ok(())
-        } else {
-            Err(ConfigError::ReloadError {
-                message: format!("{}个提供者重载失败", errors.len()),
-            })
+    /// 当历史为空或最近的快照与当前配置不同时，保存当前配置作为基线快照
+    ///
+    /// 持久化的历史在重启后可能与当前配置不一致（如离线修改了配置文件），
+    /// 基线快照保证回滚和差异比较总是从实际生效的配置开始
+    async fn ensure_baseline_snapshot(&self, config: &Value) {
+        let latest = match self.history_store().latest().await {
+            Ok(latest) => latest,
+            Err(e) => {
+                error!("读取配置历史失败: {}", e);
+                return;
+            }
+        };
+        if latest.map_or(true, |snapshot| snapshot.to_value() != *config) {
+            // 固定使用快照配置时提供者中的原始配置与生效配置不一致，不记录原始配置
+            let raw_config = if self.pinned_config.read().await.is_some() {
+                None
+            } else {
+                Some(self.raw_configuration().await)
+            };
+            self.push_snapshot(
+                config,
+                raw_config.as_ref(),
+                "Initial configuration snapshot",
+                ConfigAuditRecord::new("ConfigManager"),
+            )
+            .await;
         }
     }
 
-    /// 创建配置快照
-    async fn create_config_snapshot(&self, description: &str) -> Result<(), ConfigError> {
+    /// 将配置树保存为快照，并按最大历史数量裁剪
+    ///
+    /// `raw_config` 为插值前的合并配置，回滚时用于写回配置提供者。
+    /// 历史存储失败不影响配置变更本身，只记录错误日志
+    async fn push_snapshot(
+        &self,
+        config: &Value,
+        raw_config: Option<&Value>,
+        description: &str,
+        audit: ConfigAuditRecord,
+    ) -> Option<u64> {
         debug!("创建配置快照: {}", description);
 
-        // 获取当前所有配置数据
-        let current_config = self.get_all_config_data().await?;
-
-        let snapshot = ConfigSnapshot {
-            timestamp: chrono::Utc::now(),
-            config_data: current_config,
-            version: self.get_next_snapshot_version().await,
-            description: description.to_string(),
+        let mut snapshot = ConfigSnapshot::new(config, description, audit);
+        if let Some(raw_config) = raw_config {
+            snapshot = snapshot.with_raw_config(raw_config);
+        }
+        let store = self.history_store();
+        let version = match store.append(snapshot).await {
+            Ok(version) => version,
+            Err(e) => {
+                error!("保存配置快照失败: {}", e);
+                return None;
+            }
         };
+        debug!("配置快照创建完成，版本: {}", version);
 
-        // 添加到历史记录
-        {
-            let mut history = self.config_history.write().await;
-            history.push(snapshot);
-
-            // 限制历史记录大小
-            if history.len() > self.max_history_size {
-                history.remove(0);
-            }
+        let max_history_size = self.max_history_size.load(Ordering::Relaxed);
+        if let Err(e) = store.prune(max_history_size.max(1)).await {
+            warn!("裁剪配置历史失败: {}", e);
         }
-
-        debug!("配置快照创建完成");
-        Ok(())
-    }
-
-    /// 获取所有配置数据
-    async fn get_all_config_data(&self) -> Result<HashMap<String, Value>, ConfigError> {
-        let cache = self.config_cache.read().await;
-        Ok(cache.clone())
+        Some(version)
     }
 
-    /// 获取下一个快照版本号
-    async fn get_next_snapshot_version(&self) -> u64 {
-        let history = self.config_history.read().await;
-        history.len() as u64 + 1
+    /// 恢复配置快照
+    ///
+    /// 固定使用快照配置直到下一次成功重载或写入
+    async fn restore_snapshot(&self, snapshot: &ConfigSnapshot) {
+        *self.pinned_config.write().await = Some(snapshot.to_value());
+        self.cache_generation.fetch_add(1, Ordering::SeqCst);
+        *self.resolved_cache.write().await = None;
+        self.config_cache.write().await.clear();
     }
 
     /// 回滚到上一个配置快照
-    pub async fn rollback_last_change(&self) -> Result<(), ConfigError> {
+    pub async fn rollback_last_change(&self) -> Result<Vec<ConfigChangeEvent>, ConfigError> {
         info!("回滚到上一个配置");
 
-        let snapshot = {
-            let history = self.config_history.read().await;
-            if history.len() < 2 {
-                return Err(ConfigError::NoRollbackAvailable);
-            }
-            // 获取倒数第二个快照（最后一个是当前的）
-            history[history.len() - 2].clone()
-        };
-
-        // 恢复配置数据到缓存
-        {
-            let mut cache = self.config_cache.write().await;
-            *cache = snapshot.config_data;
+        let history = self.history_store().list().await?;
+        if history.len() < 2 {
+            return Err(ConfigError::NoRollbackAvailable);
         }
+        // 获取倒数第二个快照（最后一个是当前的）
+        let version = history[history.len() - 2].version;
 
-        info!("配置已回滚到版本: {}", snapshot.version);
-        Ok(())
+        self.rollback_with_audit(version, ConfigAuditRecord::new("rollback"))
+            .await
     }
 
     /// 回滚到指定版本的配置快照
-    pub async fn rollback_to_version(&self, version: u64) -> Result<(), ConfigError> {
-        info!("回滚到配置版本: {}", version);
+    pub async fn rollback_to_version(
+        &self,
+        version: u64,
+    ) -> Result<Vec<ConfigChangeEvent>, ConfigError> {
+        self.rollback_with_audit(version, ConfigAuditRecord::new("rollback"))
+            .await
+    }
+
+    /// 回滚到指定版本的配置快照并记录审计信息
+    ///
+    /// 快照配置先经过 JSON Schema 和已注册验证器的验证，验证失败时不产生任何副作用。
+    /// 所有差异键都由优先级最高的可写配置提供者持有、且模拟写入后能得到快照配置时，
+    /// 将快照中未插值的原始值写回该提供者；否则不修改任何提供者，固定使用快照配置
+    /// 直到下一次成功重载。回滚作为一次新的配置变更保存到历史，并将逐键变更通知给监听器
+    pub async fn rollback_with_audit(
+        &self,
+        version: u64,
+        audit: ConfigAuditRecord,
+    ) -> Result<Vec<ConfigChangeEvent>, ConfigError> {
+        let _reload_guard = self.reload_lock.lock().await;
+        info!("回滚到配置版本: {} (操作者: {})", version, audit.actor);
+
+        let snapshot = self
+            .history_store()
+            .get(version)
+            .await?
+            .ok_or(ConfigError::VersionNotFound { version })?;
+        let current_config = self.merged_configuration().await?;
+        let target_config = snapshot.to_value();
+
+        let changes = diff_configuration(&current_config, &target_config);
+        if changes.is_empty() {
+            info!("当前配置与版本 {} 相同，无需回滚", version);
+            return Ok(Vec::new());
+        }
 
-        let snapshot = {
-            let history = self.config_history.read().await;
-            history
+        let validation_result = self.validate_config_tree(&target_config).await;
+        if !validation_result.is_valid {
+            let errors: Vec<String> = validation_result
+                .errors
                 .iter()
-                .find(|s| s.version == version)
-                .cloned()
-                .ok_or_else(|| ConfigError::VersionNotFound { version })?
+                .map(ToString::to_string)
+                .collect();
+            warn!("回滚目标版本 {} 未通过验证: {:?}", version, errors);
+            return Err(ConfigError::ValidationFailed { errors });
+        }
+
+        let written = match self.plan_rollback_write_back(&snapshot, &target_config).await {
+            Some((target, writes)) => self.write_back_changes(&target, &writes).await,
+            None => false,
         };
+        if written {
+            *self.pinned_config.write().await = None;
+            self.clear_cache().await?;
+        } else {
+            debug!("配置提供者无法还原版本 {}，固定使用快照配置", version);
+            self.restore_snapshot(&snapshot).await;
+        }
 
-        // 恢复配置数据到缓存
-        {
-            let mut cache = self.config_cache.write().await;
-            *cache = snapshot.config_data;
+        let raw_config = snapshot.raw_value();
+        self.push_snapshot(
+            &target_config,
+            raw_config.as_ref(),
+            &format!("Rolled back to version {}", version),
+            audit.clone(),
+        )
+        .await;
+
+        let events: Vec<ConfigChangeEvent> = changes
+            .iter()
+            .map(|change| change.to_event(audit.source.as_str()))
+            .collect();
+        for event in &events {
+            self.dispatch_event(event).await?;
         }
 
         info!("配置已回滚到版本: {}", version);
-        Ok(())
+        Ok(events)
+    }
+
+    /// 规划回滚时写回可写配置提供者的原始值变更
+    ///
+    /// 只有快照记录了原始配置、每个差异键当前都由优先级最高的可写提供者持有（或尚不存在），
+    /// 且模拟写入后的生效配置与快照一致时返回写入目标和变更，否则返回 `None`
+    async fn plan_rollback_write_back(
+        &self,
+        snapshot: &ConfigSnapshot,
+        target_config: &Value,
+    ) -> Option<(String, Vec<ConfigKeyChange>)> {
+        let target_raw = snapshot.raw_value()?;
+        let (target, priority) = self.select_writable_provider(None).await.ok()?;
+
+        let layers = self.collect_layers().await;
+        let merger = self.merger.read().clone();
+        let writes = diff_configuration(&merger.merge(&layers), &target_raw);
+        for write in &writes {
+            if let Some(winner) = merger.provenance(&layers, &write.key).winner {
+                if winner.source != target {
+                    debug!("配置键 {} 来自 {}，不写回 {}", write.key, winner.source, target);
+                    return None;
+                }
+            }
+        }
+
+        match self.simulate_writes(&target, priority, &writes).await {
+            Ok(candidate) if candidate == *target_config => Some((target, writes)),
+            Ok(_) => None,
+            Err(e) => {
+                debug!("模拟写回 {} 失败: {}", target, e);
+                None
+            }
+        }
+    }
+
+    /// 将配置变更写入指定的配置提供者，返回是否写入成功
+    async fn write_back_changes(&self, target: &str, changes: &[ConfigKeyChange]) -> bool {
+        let mut providers = self.providers.write().await;
+        let Some(provider) = providers
+            .iter_mut()
+            .find(|provider| provider.source_description() == target)
+        else {
+            return false;
+        };
+        match provider.apply_changes(changes).await {
+            Ok(()) => true,
+            Err(e) => {
+                warn!("写回配置提供者 {} 失败: {}", provider.name(), e);
+                false
+            }
+        }
+    }
+
+    /// 比较两个历史版本之间的逐键差异
+    ///
+    /// 返回的差异中敏感配置已脱敏
+    pub async fn diff_versions(
+        &self,
+        from_version: u64,
+        to_version: u64,
+    ) -> Result<ConfigHistoryDiff, ConfigError> {
+        let store = self.history_store();
+        let from = store
+            .get(from_version)
+            .await?
+            .ok_or(ConfigError::VersionNotFound {
+                version: from_version,
+            })?;
+        let to = store
+            .get(to_version)
+            .await?
+            .ok_or(ConfigError::VersionNotFound {
+                version: to_version,
+            })?;
+
+        let redactor = self.redactor();
+        let mut diff = ConfigHistoryDiff::between(&from, &to);
+        for change in diff.changes.iter_mut() {
+            for value in [&mut change.old_value, &mut change.new_value]
+                .into_iter()
+                .flatten()
+            {
+                *value = redactor.redact_value(&change.key, value);
+            }
+        }
+        Ok(diff)
     }
 
     /// 获取配置历史
+    ///
+    /// 返回的快照中敏感配置已脱敏
     pub async fn get_config_history(&self) -> Vec<ConfigSnapshot> {
-        let history = self.config_history.read().await;
-        history.clone()
+        let history = match self.history_store().list().await {
+            Ok(history) => history,
+            Err(e) => {
+                error!("读取配置历史失败: {}", e);
+                return Vec::new();
+            }
+        };
+
+        let redactor = self.redactor();
+        history
+            .into_iter()
+            .map(|mut snapshot| {
+                for (key, value) in snapshot
+                    .config_data
+                    .iter_mut()
+                    .chain(snapshot.raw_config_data.iter_mut())
+                {
+                    *value = redactor.redact_value(key, value);
+                }
+                snapshot
+            })
+            .collect()
     }
 
     /// 清除配置历史
+    ///
+    /// 版本计数不会重置，之后的快照继续使用递增的版本号
     pub async fn clear_config_history(&self) -> Result<(), ConfigError> {
         info!("清除配置历史");
-        let mut history = self.config_history.write().await;
-        history.clear();
-        Ok(())
+        self.history_store().clear().await
     }
 
     /// 是否启用了热重载
@@ -716,12 +1771,12 @@ impl AdSystemConfigManager {
 
     /// 获取最大历史记录大小
     pub fn get_max_history_size(&self) -> usize {
-        self.max_history_size
+        self.max_history_size.load(Ordering::Relaxed)
     }
 
     /// 设置最大历史记录大小
     pub fn set_max_history_size(&mut self, size: usize) {
-        self.max_history_size = size;
+        self.max_history_size.store(size, Ordering::Relaxed);
     }
 
     /// 设置配置事件处理器
@@ -730,30 +1785,60 @@ impl AdSystemConfigManager {
         handler: Arc<Mutex<ConfigEventHandler>>,
     ) -> Result<(), ConfigError> {
         info!("设置配置事件处理器");
-        self.event_handler = Some(handler);
+        *self.event_handler.write() = Some(handler);
         Ok(())
     }
 
     /// 获取配置事件处理器
     pub fn get_event_handler(&self) -> Option<Arc<Mutex<ConfigEventHandler>>> {
-        self.event_handler.clone()
+        self.event_handler.read().clone()
     }
 
-    /// 向事件处理器发送事件
-    async fn send_event_to_handler(&self, event: ConfigChangeEvent) -> Result<(), ConfigError> {
-        if let Some(ref handler) = self.event_handler {
+    /// 分发配置变更事件到事件处理器和变更订阅者
+    async fn dispatch_event(&self, event: &ConfigChangeEvent) -> Result<(), ConfigError> {
+        let redacted = self.redactor.read().redact_event(event);
+        // 没有订阅者时发送失败，忽略即可
+        let _ = self.change_notifier.send(redacted.clone());
+
+        let handler = self.event_handler.read().clone();
+        if let Some(handler) = handler {
             let handler_guard = handler.lock().await;
-            handler_guard.send_event(event).await?;
+            handler_guard.send_event(redacted).await?;
         }
         Ok(())
     }
 
-    /// 克隆管理器用于事件处理（解决借用检查问题）
-    async fn clone_for_event_handling(&self) -> Arc<AdSystemConfigManager> {
-        // 这里需要实现一个适合事件处理的克隆版本
-        // 在实际实现中，可能需要使用 Arc<RwLock<>> 等共享所有权的方式
-        // 暂时返回一个新的实例，实际使用中需要完善
-        Arc::new(AdSystemConfigManager::new())
+    /// 克隆管理器用于事件处理
+    ///
+    /// 克隆体与当前管理器共享配置提供者、缓存、快照历史等状态，但不持有热重载任务
+    pub(crate) fn clone_for_event_handling(&self) -> Arc<AdSystemConfigManager> {
+        Arc::new(AdSystemConfigManager {
+            providers: self.providers.clone(),
+            provider_count: self.provider_count.clone(),
+            validators: self.validators.clone(),
+            merger: self.merger.clone(),
+            interpolator: self.interpolator.clone(),
+            secret_resolver: self.secret_resolver.clone(),
+            redactor: self.redactor.clone(),
+            registered_options: self.registered_options.clone(),
+            options_targets: self.options_targets.clone(),
+            validation_timeout: self.validation_timeout.clone(),
+            config_cache: self.config_cache.clone(),
+            resolved_cache: self.resolved_cache.clone(),
+            cache_generation: self.cache_generation.clone(),
+            cache_enabled: self.cache_enabled,
+            pinned_config: self.pinned_config.clone(),
+            reload_lock: self.reload_lock.clone(),
+            change_notifier: self.change_notifier.clone(),
+            config_watcher: None,
+            hot_reload_enabled: false,
+            history_store: self.history_store.clone(),
+            max_history_size: self.max_history_size.clone(),
+            change_handler_task: None,
+            event_handler: self.event_handler.clone(),
+            last_reload: self.last_reload.clone(),
+            reload_count: self.reload_count.clone(),
+        })
     }
 }
 
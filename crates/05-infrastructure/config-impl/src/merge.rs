@@ -0,0 +1,310 @@
+//! 分层配置合并实现
+//!
+//! 按优先级将多个配置源的配置树深度合并，并提供来源追踪
+
+use config_abstractions::{
+    get_nested_value, ArrayMergeStrategy, ConfigDump, ConfigDumpEntry, ConfigProvenance,
+    ProvenanceEntry,
+};
+use serde_json::{Map, Value};
+use std::collections::HashMap;
+
+/// 配置层
+///
+/// 单个配置源在某一时刻的完整配置树
+#[derive(Debug, Clone)]
+pub struct ConfigLayer {
+    /// 配置源描述
+    pub source: String,
+    /// 配置源优先级
+    pub priority: i32,
+    /// 配置树
+    pub value: Value,
+    /// 叶子键的具体来源（如环境变量名），缺省时使用 `source`
+    pub key_sources: HashMap<String, String>,
+}
+
+impl ConfigLayer {
+    /// 创建新的配置层
+    pub fn new(source: impl Into<String>, priority: i32, value: Value) -> Self {
+        Self {
+            source: source.into(),
+            priority,
+            value,
+            key_sources: HashMap::new(),
+        }
+    }
+
+    /// 设置叶子键的具体来源
+    pub fn with_key_source(mut self, key: impl Into<String>, source: impl Into<String>) -> Self {
+        self.key_sources.insert(key.into(), source.into());
+        self
+    }
+
+    /// 获取指定键的来源描述
+    pub fn source_of(&self, key: &str) -> String {
+        self.key_sources
+            .get(key)
+            .cloned()
+            .unwrap_or_else(|| self.source.clone())
+    }
+}
+
+/// 分层配置合并器
+///
+/// 对象按键深度合并，标量由高优先级覆盖低优先级，
+/// 数组默认整体替换，可按路径配置为追加
+#[derive(Debug, Clone, Default)]
+pub struct ConfigMerger {
+    /// 默认数组合并策略
+    default_array_strategy: ArrayMergeStrategy,
+    /// 按路径覆盖的数组合并策略
+    array_strategies: HashMap<String, ArrayMergeStrategy>,
+}
+
+impl ConfigMerger {
+    /// 创建新的合并器
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// 设置默认数组合并策略
+    pub fn with_default_array_strategy(mut self, strategy: ArrayMergeStrategy) -> Self {
+        self.default_array_strategy = strategy;
+        self
+    }
+
+    /// 设置默认数组合并策略
+    pub fn set_default_array_strategy(&mut self, strategy: ArrayMergeStrategy) {
+        self.default_array_strategy = strategy;
+    }
+
+    /// 为指定路径设置数组合并策略
+    pub fn set_array_strategy(&mut self, path: impl Into<String>, strategy: ArrayMergeStrategy) {
+        self.array_strategies.insert(path.into(), strategy);
+    }
+
+    /// 获取指定路径的数组合并策略
+    pub fn array_strategy_for(&self, path: &str) -> ArrayMergeStrategy {
+        self.array_strategies
+            .get(path)
+            .copied()
+            .unwrap_or(self.default_array_strategy)
+    }
+
+    /// 合并所有配置层
+    ///
+    /// `layers` 可以是任意顺序，优先级高的层覆盖优先级低的层；
+    /// 优先级相同时，排在前面的层胜出
+    pub fn merge(&self, layers: &[ConfigLayer]) -> Value {
+        let mut merged = Value::Object(Map::new());
+        for layer in Self::lowest_first(layers) {
+            self.merge_into(&mut merged, &layer.value, "");
+        }
+        merged
+    }
+
+    /// 查询指定键的来源信息
+    pub fn provenance(&self, layers: &[ConfigLayer], key: &str) -> ConfigProvenance {
+        let merged = self.merge(layers);
+        let effective_value = get_nested_value(&merged, key).cloned();
+
+        let mut entries: Vec<ProvenanceEntry> = Self::highest_first(layers)
+            .into_iter()
+            .filter_map(|layer| {
+                get_nested_value(&layer.value, key).map(|value| ProvenanceEntry {
+                    source: layer.source_of(key),
+                    priority: layer.priority,
+                    value: value.clone(),
+                })
+            })
+            .collect();
+
+        let winner = if entries.is_empty() {
+            None
+        } else {
+            Some(entries.remove(0))
+        };
+        let array_strategy = effective_value
+            .as_ref()
+            .filter(|value| value.is_array())
+            .map(|_| self.array_strategy_for(key));
+
+        ConfigProvenance {
+            key: key.to_string(),
+            effective_value,
+            winner,
+            shadowed: entries,
+            array_strategy,
+        }
+    }
+
+    /// 导出合并后的生效配置，并为每个叶子标注来源
+    pub fn dump(&self, layers: &[ConfigLayer]) -> ConfigDump {
+        let merged = self.merge(layers);
+        let mut leaves = Vec::new();
+        collect_leaves(&merged, String::new(), &mut leaves);
+
+        let ordered = Self::highest_first(layers);
+        let entries = leaves
+            .into_iter()
+            .map(|(key, value)| {
+                let contributing = ordered
+                    .iter()
+                    .filter(|layer| get_nested_value(&layer.value, &key).is_some())
+                    .map(|layer| layer.source_of(&key));
+                let sources = if value.is_array()
+                    && self.array_strategy_for(&key) == ArrayMergeStrategy::Append
+                {
+                    contributing.collect()
+                } else {
+                    contributing.take(1).collect()
+                };
+                ConfigDumpEntry { key, value, sources }
+            })
+            .collect();
+
+        ConfigDump { entries }
+    }
+
+    /// 将 `overlay` 合并到 `base`
+    fn merge_into(&self, base: &mut Value, overlay: &Value, path: &str) {
+        match (base, overlay) {
+            (Value::Object(base_map), Value::Object(overlay_map)) => {
+                for (key, overlay_value) in overlay_map {
+                    let child_path = join_path(path, key);
+                    match base_map.get_mut(key) {
+                        Some(base_value) => {
+                            self.merge_into(base_value, overlay_value, &child_path)
+                        }
+                        None => {
+                            base_map.insert(key.clone(), overlay_value.clone());
+                        }
+                    }
+                }
+            }
+            (Value::Array(base_items), Value::Array(overlay_items))
+                if self.array_strategy_for(path) == ArrayMergeStrategy::Append =>
+            {
+                base_items.extend(overlay_items.iter().cloned());
+            }
+            (base, overlay) => {
+                *base = overlay.clone();
+            }
+        }
+    }
+
+    /// 按优先级从低到高排序（优先级相同时保持排在前面的层最后应用）
+    fn lowest_first(layers: &[ConfigLayer]) -> Vec<&ConfigLayer> {
+        let mut ordered = Self::highest_first(layers);
+        ordered.reverse();
+        ordered
+    }
+
+    /// 按优先级从高到低排序（稳定排序）
+    fn highest_first(layers: &[ConfigLayer]) -> Vec<&ConfigLayer> {
+        let mut ordered: Vec<&ConfigLayer> = layers.iter().collect();
+        ordered.sort_by_key(|layer| std::cmp::Reverse(layer.priority));
+        ordered
+    }
+}
+
+/// 拼接点分路径
+fn join_path(prefix: &str, key: &str) -> String {
+    if prefix.is_empty() {
+        key.to_string()
+    } else {
+        format!("{}.{}", prefix, key)
+    }
+}
+
+/// 收集所有叶子节点（非对象值以及空对象）
+fn collect_leaves(value: &Value, prefix: String, leaves: &mut Vec<(String, Value)>) {
+    match value {
+        Value::Object(map) if !map.is_empty() => {
+            let mut keys: Vec<&String> = map.keys().collect();
+            keys.sort();
+            for key in keys {
+                collect_leaves(&map[key], join_path(&prefix, key), leaves);
+            }
+        }
+        _ if prefix.is_empty() => {}
+        other => leaves.push((prefix, other.clone())),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    fn layers() -> Vec<ConfigLayer> {
+        vec![
+            ConfigLayer::new("env", 200, json!({"database": {"port": 6432}}))
+                .with_key_source("database.port", "ADSP_DATABASE_PORT"),
+            ConfigLayer::new(
+                "config.toml",
+                100,
+                json!({
+                    "database": {"host": "localhost", "port": 5432},
+                    "dsp": {"endpoints": ["a", "b"]}
+                }),
+            ),
+            ConfigLayer::new(
+                "config.local.toml",
+                150,
+                json!({"dsp": {"endpoints": ["c"]}}),
+            ),
+        ]
+    }
+
+    #[test]
+    fn test_deep_merge_objects() {
+        let merged = ConfigMerger::new().merge(&layers());
+        assert_eq!(merged["database"]["host"], json!("localhost"));
+        assert_eq!(merged["database"]["port"], json!(6432));
+        assert_eq!(merged["dsp"]["endpoints"], json!(["c"]));
+    }
+
+    #[test]
+    fn test_array_append_strategy() {
+        let mut merger = ConfigMerger::new();
+        merger.set_array_strategy("dsp.endpoints", ArrayMergeStrategy::Append);
+        let merged = merger.merge(&layers());
+        assert_eq!(merged["dsp"]["endpoints"], json!(["a", "b", "c"]));
+
+        let dump = merger.dump(&layers());
+        let entry = dump.get("dsp.endpoints").unwrap();
+        assert_eq!(entry.sources, vec!["config.local.toml", "config.toml"]);
+    }
+
+    #[test]
+    fn test_provenance_reports_winner_and_shadowed() {
+        let provenance = ConfigMerger::new().provenance(&layers(), "database.port");
+        assert_eq!(provenance.effective_value, Some(json!(6432)));
+        assert_eq!(provenance.winner.unwrap().source, "ADSP_DATABASE_PORT");
+        assert_eq!(provenance.shadowed.len(), 1);
+        assert_eq!(provenance.shadowed[0].source, "config.toml");
+        assert_eq!(provenance.shadowed[0].value, json!(5432));
+
+        let missing = ConfigMerger::new().provenance(&layers(), "database.user");
+        assert!(!missing.is_defined());
+    }
+
+    #[test]
+    fn test_dump_annotates_leaves() {
+        let dump = ConfigMerger::new().dump(&layers());
+        assert_eq!(dump.get("database.host").unwrap().sources, vec!["config.toml"]);
+        assert_eq!(
+            dump.get("database.port").unwrap().sources,
+            vec!["ADSP_DATABASE_PORT"]
+        );
+
+        let annotated = dump.to_annotated_value();
+        assert_eq!(annotated["database"]["port"]["value"], json!(6432));
+        assert_eq!(
+            annotated["database"]["port"]["source"],
+            json!("ADSP_DATABASE_PORT")
+        );
+    }
+}
@@ -0,0 +1,285 @@
+//! 类型化实时配置
+//!
+//! [`OptionsMonitor`] 持有某个配置路径最近一次成功绑定并通过验证的值，
+//! 配置重载后自动更新；重载结果无法绑定或验证失败时保留原值
+
+use crate::manager::AdSystemConfigManager;
+use arc_swap::ArcSwap;
+use config_abstractions::events::{ConfigChangeEvent, ConfigChangeEventType};
+use config_abstractions::{ConfigManager, ConfigValidator};
+use infrastructure_common::{Component, ConfigError};
+use serde::de::DeserializeOwned;
+use serde_json::Value;
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::Arc;
+use tokio::sync::{broadcast, watch, Mutex};
+use tracing::{debug, warn};
+
+/// 配置变更回调
+type OptionsListener<T> = Arc<dyn Fn(&T) + Send + Sync>;
+
+/// 配置变更回调的注册标识，用于移除回调
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct OptionsListenerId(u64);
+
+/// 类型化实时配置句柄
+///
+/// `current()` 无锁读取最新值；也可以通过 `on_change` 注册回调，
+/// 或通过 `subscribe` 获取 `watch` 通道。克隆的句柄共享同一份状态，
+/// 最后一个句柄释放时停止后台更新任务
+pub struct OptionsMonitor<T> {
+    /// 共享状态
+    state: Arc<OptionsState<T>>,
+    /// 后台更新任务
+    task: Arc<OptionsTask>,
+}
+
+/// 实时配置的共享状态
+struct OptionsState<T> {
+    /// 配置路径
+    path: String,
+    /// 配置管理器
+    manager: Arc<AdSystemConfigManager>,
+    /// 当前配置值
+    current: ArcSwap<T>,
+    /// 当前配置值对应的原始配置
+    current_raw: parking_lot::Mutex<Value>,
+    /// 配置值广播
+    sender: watch::Sender<Arc<T>>,
+    /// 配置变更回调
+    listeners: parking_lot::RwLock<Vec<(OptionsListenerId, OptionsListener<T>)>>,
+    /// 下一个回调标识
+    next_listener_id: AtomicU64,
+    /// 配置验证器
+    validator: Option<Arc<dyn ConfigValidator<T>>>,
+    /// 串行化重新绑定，避免较早读取的配置覆盖较新的配置
+    refresh_lock: Mutex<()>,
+}
+
+/// 后台更新任务句柄，释放时终止任务
+struct OptionsTask(tokio::task::JoinHandle<()>);
+
+impl Drop for OptionsTask {
+    fn drop(&mut self) {
+        self.0.abort();
+    }
+}
+
+impl<T> OptionsMonitor<T>
+where
+    T: DeserializeOwned + Send + Sync + 'static,
+{
+    /// 创建实时配置句柄
+    ///
+    /// 首次绑定或验证失败时返回错误
+    pub(crate) async fn start(
+        manager: Arc<AdSystemConfigManager>,
+        path: impl Into<String>,
+        validator: Option<Arc<dyn ConfigValidator<T>>>,
+    ) -> Result<Self, ConfigError> {
+        let path = path.into();
+        // 先订阅再绑定，避免错过两者之间发生的变更
+        let changes = manager.subscribe_changes();
+
+        let raw = manager.get_configuration(&path).await?;
+        let value = Arc::new(bind_and_validate(&raw, validator.as_deref()).await?);
+        let (sender, _) = watch::channel(value.clone());
+
+        let state = Arc::new(OptionsState {
+            path,
+            manager,
+            current: ArcSwap::new(value),
+            current_raw: parking_lot::Mutex::new(raw),
+            sender,
+            listeners: parking_lot::RwLock::new(Vec::new()),
+            next_listener_id: AtomicU64::new(1),
+            validator,
+            refresh_lock: Mutex::new(()),
+        });
+        let task = tokio::spawn(Self::watch_changes(Arc::downgrade(&state), changes));
+
+        Ok(Self {
+            state,
+            task: Arc::new(OptionsTask(task)),
+        })
+    }
+
+    /// 获取当前配置值
+    pub fn current(&self) -> Arc<T> {
+        self.state.current.load_full()
+    }
+
+    /// 获取配置路径
+    pub fn path(&self) -> &str {
+        &self.state.path
+    }
+
+    /// 订阅配置值变化
+    pub fn subscribe(&self) -> watch::Receiver<Arc<T>> {
+        self.state.sender.subscribe()
+    }
+
+    /// 注册配置变更回调
+    ///
+    /// 回调在新值生效后调用，不会为注册前的值调用
+    pub fn on_change<F>(&self, listener: F) -> OptionsListenerId
+    where
+        F: Fn(&T) + Send + Sync + 'static,
+    {
+        let id = OptionsListenerId(self.state.next_listener_id.fetch_add(1, Ordering::Relaxed));
+        self.state.listeners.write().push((id, Arc::new(listener)));
+        id
+    }
+
+    /// 移除配置变更回调
+    pub fn remove_listener(&self, id: OptionsListenerId) -> bool {
+        let mut listeners = self.state.listeners.write();
+        let initial_count = listeners.len();
+        listeners.retain(|(listener_id, _)| *listener_id != id);
+        listeners.len() < initial_count
+    }
+
+    /// 立即从配置管理器重新绑定
+    ///
+    /// 配置值发生变化并生效时返回 `true`；绑定或验证失败时保留原值并返回错误
+    pub async fn refresh(&self) -> Result<bool, ConfigError> {
+        self.state.refresh().await
+    }
+
+    /// 监听配置变更并更新配置值
+    async fn watch_changes(
+        state: std::sync::Weak<OptionsState<T>>,
+        mut changes: broadcast::Receiver<ConfigChangeEvent>,
+    ) {
+        loop {
+            match changes.recv().await {
+                Ok(event) => {
+                    let Some(state) = state.upgrade() else {
+                        return;
+                    };
+                    if !state.is_affected_by(&event) {
+                        continue;
+                    }
+                }
+                // 错过了部分事件，直接重新绑定
+                Err(broadcast::error::RecvError::Lagged(_)) => {}
+                Err(broadcast::error::RecvError::Closed) => return,
+            }
+
+            let Some(state) = state.upgrade() else {
+                return;
+            };
+            if let Err(e) = state.refresh().await {
+                warn!("配置 {} 重新绑定失败，保留原值: {}", state.path, e);
+            }
+        }
+    }
+}
+
+impl<T> OptionsState<T>
+where
+    T: DeserializeOwned + Send + Sync + 'static,
+{
+    /// 配置变更事件是否影响当前配置路径
+    fn is_affected_by(&self, event: &ConfigChangeEvent) -> bool {
+        if !matches!(
+            event.event_type,
+            ConfigChangeEventType::Created
+                | ConfigChangeEventType::Updated
+                | ConfigChangeEventType::Deleted
+                | ConfigChangeEventType::Reloaded
+        ) {
+            return false;
+        }
+
+        let path = self.path.as_str();
+        let changed = event.path.as_str();
+        changed == path
+            || changed
+                .strip_prefix(path)
+                .is_some_and(|rest| rest.starts_with('.'))
+            || path
+                .strip_prefix(changed)
+                .is_some_and(|rest| rest.starts_with('.'))
+    }
+
+    /// 重新绑定配置值
+    async fn refresh(&self) -> Result<bool, ConfigError> {
+        let _refresh_guard = self.refresh_lock.lock().await;
+        let raw = self.manager.get_configuration(&self.path).await?;
+        if *self.current_raw.lock() == raw {
+            return Ok(false);
+        }
+
+        let value = Arc::new(bind_and_validate(&raw, self.validator.as_deref()).await?);
+        self.current.store(value.clone());
+        *self.current_raw.lock() = raw;
+        self.sender.send_replace(value.clone());
+        debug!("配置 {} 已更新", self.path);
+
+        let listeners: Vec<OptionsListener<T>> = self
+            .listeners
+            .read()
+            .iter()
+            .map(|(_, listener)| listener.clone())
+            .collect();
+        for listener in listeners {
+            listener(&value);
+        }
+
+        Ok(true)
+    }
+}
+
+/// 绑定并验证配置值
+async fn bind_and_validate<T>(
+    raw: &Value,
+    validator: Option<&dyn ConfigValidator<T>>,
+) -> Result<T, ConfigError>
+where
+    T: DeserializeOwned,
+{
+    let value: T =
+        serde_json::from_value(raw.clone()).map_err(|e| ConfigError::ParseError {
+            source: Box::new(e),
+        })?;
+
+    if let Some(validator) = validator {
+        validator
+            .validate(&value)
+            .await
+            .map_err(|e| ConfigError::ValidationFailed {
+                errors: vec![format!("{}: {}", validator.name(), e)],
+            })?;
+    }
+
+    Ok(value)
+}
+
+impl<T> Clone for OptionsMonitor<T> {
+    fn clone(&self) -> Self {
+        Self {
+            state: self.state.clone(),
+            task: self.task.clone(),
+        }
+    }
+}
+
+impl<T> std::fmt::Debug for OptionsMonitor<T> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("OptionsMonitor")
+            .field("type", &std::any::type_name::<T>())
+            .field("path", &self.state.path)
+            .field("listeners", &self.state.listeners.read().len())
+            .finish()
+    }
+}
+
+impl<T> Component for OptionsMonitor<T>
+where
+    T: Send + Sync + 'static,
+{
+    fn name(&self) -> &'static str {
+        "OptionsMonitor"
+    }
+}
@@ -0,0 +1,343 @@
+//! 配置档（Profile）配置文件加载
+//!
+//! 按固定顺序发现基础配置文件和配置档覆盖文件：
+//!
+//! 1. `config.{ext}` - 基础配置
+//! 2. `config.{profile}.{ext}` - 每个激活的配置档（按激活顺序）
+//! 3. `config.{profile}.local.{ext}` - 每个激活配置档的本地覆盖（按激活顺序）
+//!
+//! 同一层级内 TOML、JSON、YAML 文件都会被加载，排在后面的文件优先级更高
+
+use crate::providers::{JsonConfigProvider, TomlConfigProvider, YamlConfigProvider};
+use config_abstractions::ConfigProvider;
+use infrastructure_common::{canonical_profile_name, ActiveProfiles, ConfigError};
+use std::path::{Path, PathBuf};
+use tracing::{debug, info};
+
+/// 配置文件格式
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ConfigFileFormat {
+    /// TOML 文件
+    Toml,
+    /// JSON 文件
+    Json,
+    /// YAML 文件
+    Yaml,
+}
+
+impl ConfigFileFormat {
+    /// 所有支持的格式（按同层级内的加载顺序）
+    pub const ALL: [ConfigFileFormat; 3] = [Self::Toml, Self::Json, Self::Yaml];
+
+    /// 根据文件扩展名识别格式
+    pub fn from_extension(extension: &str) -> Option<Self> {
+        match extension.to_lowercase().as_str() {
+            "toml" => Some(Self::Toml),
+            "json" => Some(Self::Json),
+            "yaml" | "yml" => Some(Self::Yaml),
+            _ => None,
+        }
+    }
+
+    /// 创建对应格式的配置提供者
+    pub fn create_provider(
+        &self,
+        path: &Path,
+        priority: i32,
+    ) -> Result<Box<dyn ConfigProvider>, ConfigError> {
+        Ok(match self {
+            Self::Toml => Box::new(TomlConfigProvider::new(path)?.with_priority(priority)),
+            Self::Json => Box::new(JsonConfigProvider::new(path)?.with_priority(priority)),
+            Self::Yaml => Box::new(YamlConfigProvider::new(path)?.with_priority(priority)),
+        })
+    }
+}
+
+/// 发现的配置档配置文件
+#[derive(Debug, Clone)]
+pub struct ProfileConfigFile {
+    /// 文件路径
+    pub path: PathBuf,
+    /// 文件格式
+    pub format: ConfigFileFormat,
+    /// 所属配置档（基础配置为 `None`）
+    pub profile: Option<String>,
+    /// 是否为本地覆盖文件
+    pub local: bool,
+    /// 分配的优先级
+    pub priority: i32,
+}
+
+/// 配置档配置文件加载器
+#[derive(Debug, Clone)]
+pub struct ProfileConfigLoader {
+    /// 配置文件所在目录
+    directory: PathBuf,
+    /// 配置文件基础名称（如 `config`）
+    base_name: String,
+    /// 激活的配置档
+    profiles: ActiveProfiles,
+    /// 基础配置文件的优先级
+    base_priority: i32,
+    /// 相邻层级之间的优先级间隔
+    priority_step: i32,
+}
+
+impl ProfileConfigLoader {
+    /// 创建新的加载器
+    pub fn new(directory: impl AsRef<Path>, base_name: impl Into<String>) -> Self {
+        Self {
+            directory: directory.as_ref().to_path_buf(),
+            base_name: base_name.into(),
+            profiles: ActiveProfiles::from_env(),
+            base_priority: 100,
+            priority_step: 1,
+        }
+    }
+
+    /// 设置激活的配置档
+    pub fn with_profiles(mut self, profiles: ActiveProfiles) -> Self {
+        self.profiles = profiles;
+        self
+    }
+
+    /// 设置基础配置文件优先级
+    pub fn with_base_priority(mut self, priority: i32) -> Self {
+        self.base_priority = priority;
+        self
+    }
+
+    /// 获取激活的配置档
+    pub fn profiles(&self) -> &ActiveProfiles {
+        &self.profiles
+    }
+
+    /// 按加载顺序发现所有存在的配置文件
+    pub fn discover(&self) -> Result<Vec<ProfileConfigFile>, ConfigError> {
+        let files = self
+            .discover_ranked()?
+            .into_iter()
+            .enumerate()
+            .map(|(index, (rank, format, path))| {
+                let priority = self.base_priority + self.priority_step * index as i32;
+                self.config_file(rank, format, path, priority)
+            })
+            .collect::<Vec<_>>();
+
+        for file in &files {
+            debug!(
+                "发现配置文件: {} (配置档: {:?}, 优先级: {})",
+                file.path.display(),
+                file.profile,
+                file.priority
+            );
+        }
+
+        Ok(files)
+    }
+
+    /// 加载所有发现的配置文件为配置提供者
+    pub fn load_providers(&self) -> Result<Vec<Box<dyn ConfigProvider>>, ConfigError> {
+        info!(
+            "加载配置档配置文件: {}/{}.*, 激活配置档: [{}]",
+            self.directory.display(),
+            self.base_name,
+            self.profiles
+        );
+
+        self.discover()?
+            .into_iter()
+            .map(|file| file.format.create_provider(&file.path, file.priority))
+            .collect()
+    }
+
+    /// 发现所有存在的配置文件并按层级排序
+    fn discover_ranked(&self) -> Result<Vec<(FileRank, ConfigFileFormat, PathBuf)>, ConfigError> {
+        let mut candidates = Vec::new();
+
+        if self.directory.is_dir() {
+            for entry in std::fs::read_dir(&self.directory)? {
+                let path = entry?.path();
+                if !path.is_file() {
+                    continue;
+                }
+                if let Some(candidate) = self.classify(&path) {
+                    candidates.push(candidate);
+                }
+            }
+        }
+
+        // 层级顺序：基础配置 < 各配置档 < 各配置档本地覆盖；同层级内按格式排序
+        candidates.sort_by_key(|(rank, format, path)| {
+            (*rank, format_position(*format), path.clone())
+        });
+        Ok(candidates)
+    }
+
+    /// 创建发现的配置文件描述
+    fn config_file(
+        &self,
+        rank: FileRank,
+        format: ConfigFileFormat,
+        path: PathBuf,
+        priority: i32,
+    ) -> ProfileConfigFile {
+        let profile = rank
+            .profile_index()
+            .map(|index| self.profiles.profiles()[index].clone());
+        ProfileConfigFile {
+            path,
+            format,
+            profile,
+            local: matches!(rank, FileRank::ProfileLocal(_)),
+            priority,
+        }
+    }
+
+    /// 判断文件属于哪个层级
+    fn classify(&self, path: &Path) -> Option<(FileRank, ConfigFileFormat, PathBuf)> {
+        let file_name = path.file_name()?.to_str()?;
+        let (stem, extension) = file_name.rsplit_once('.')?;
+        let format = ConfigFileFormat::from_extension(extension)?;
+
+        let mut parts = stem.split('.');
+        if parts.next()? != self.base_name {
+            return None;
+        }
+
+        let rank = match (parts.next(), parts.next(), parts.next()) {
+            (None, _, _) => FileRank::Base,
+            (Some(profile), None, _) => FileRank::Profile(self.profile_index(profile)?),
+            (Some(profile), Some(local), None) if local.eq_ignore_ascii_case("local") => {
+                FileRank::ProfileLocal(self.profile_index(profile)?)
+            }
+            _ => return None,
+        };
+
+        Some((rank, format, path.to_path_buf()))
+    }
+
+    /// 获取配置档的激活顺序
+    fn profile_index(&self, profile: &str) -> Option<usize> {
+        if canonical_profile_name(profile) == "local" {
+            return None;
+        }
+        self.profiles.position(profile)
+    }
+}
+
+/// 配置文件层级
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+enum FileRank {
+    /// 基础配置
+    Base,
+    /// 配置档配置
+    Profile(usize),
+    /// 配置档本地覆盖
+    ProfileLocal(usize),
+}
+
+impl FileRank {
+    /// 获取对应的配置档序号
+    fn profile_index(self) -> Option<usize> {
+        match self {
+            Self::Base => None,
+            Self::Profile(index) | Self::ProfileLocal(index) => Some(index),
+        }
+    }
+}
+
+/// 获取格式在同层级内的加载顺序
+fn format_position(format: ConfigFileFormat) -> usize {
+    ConfigFileFormat::ALL
+        .iter()
+        .position(|f| *f == format)
+        .unwrap_or(ConfigFileFormat::ALL.len())
+}
+
+/// 多组配置档配置文件加载器
+///
+/// 合并多组配置文件（如 `config.*` 和 `appsettings.*`）时按层级交错排序：
+/// 所有组的基础配置 < 所有组的配置档覆盖 < 所有组的本地覆盖，同一层级内按组的添加顺序排列。
+/// 优先级从基础优先级开始逐个递增，超过优先级上限时返回错误
+#[derive(Debug, Clone)]
+pub struct ProfileConfigGroupLoader {
+    /// 各组加载器（按添加顺序）
+    groups: Vec<ProfileConfigLoader>,
+    /// 第一个配置文件的优先级
+    base_priority: i32,
+    /// 允许分配的最高优先级
+    max_priority: i32,
+}
+
+impl ProfileConfigGroupLoader {
+    /// 创建新的多组加载器
+    pub fn new(base_priority: i32, max_priority: i32) -> Self {
+        Self {
+            groups: Vec::new(),
+            base_priority,
+            max_priority,
+        }
+    }
+
+    /// 添加一组配置文件
+    pub fn add_group(mut self, loader: ProfileConfigLoader) -> Self {
+        self.groups.push(loader);
+        self
+    }
+
+    /// 按加载顺序发现所有组中存在的配置文件
+    pub fn discover(&self) -> Result<Vec<ProfileConfigFile>, ConfigError> {
+        let mut candidates = Vec::new();
+        for (group_index, loader) in self.groups.iter().enumerate() {
+            for (rank, format, path) in loader.discover_ranked()? {
+                candidates.push((rank, group_index, format, path));
+            }
+        }
+        candidates.sort_by_key(|(rank, group_index, format, path)| {
+            (*rank, *group_index, format_position(*format), path.clone())
+        });
+
+        let last_priority = self.base_priority + candidates.len() as i32 - 1;
+        if !candidates.is_empty() && last_priority > self.max_priority {
+            return Err(ConfigError::ValidationError {
+                message: format!(
+                    "配置档配置文件过多: {} 个文件需要优先级 {}..={}，超过上限 {}",
+                    candidates.len(),
+                    self.base_priority,
+                    last_priority,
+                    self.max_priority
+                ),
+            });
+        }
+
+        Ok(candidates
+            .into_iter()
+            .enumerate()
+            .map(|(index, (rank, group_index, format, path))| {
+                self.groups[group_index].config_file(
+                    rank,
+                    format,
+                    path,
+                    self.base_priority + index as i32,
+                )
+            })
+            .collect())
+    }
+
+    /// 加载所有组中发现的配置文件为配置提供者
+    pub fn load_providers(&self) -> Result<Vec<Box<dyn ConfigProvider>>, ConfigError> {
+        self.discover()?
+            .into_iter()
+            .map(|file| {
+                debug!(
+                    "加载配置档配置文件: {} (配置档: {:?}, 优先级: {})",
+                    file.path.display(),
+                    file.profile,
+                    file.priority
+                );
+                file.format.create_provider(&file.path, file.priority)
+            })
+            .collect()
+    }
+}
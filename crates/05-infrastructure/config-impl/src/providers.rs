@@ -1,18 +1,27 @@
 //! 配置提供者实现
 
 use async_trait::async_trait;
-use config_abstractions::{ConfigProvider, FileConfigProvider, EnvironmentConfigProvider as EnvironmentConfigProviderTrait};
-use infrastructure_common::{ConfigError, ConfigSection};
+use crate::encryption::{decrypt_config_tree, decrypt_toml_tree, seal_changes};
+use crate::secrets::SecretEncryptionKey;
+use config_abstractions::{
+    insert_nested_value, remove_nested_value, ConfigKeyChange, ConfigProvider,
+    EnvironmentConfigProvider as EnvironmentConfigProviderTrait, FileConfigProvider,
+};
+use infrastructure_common::{ConfigError, ConfigSchema, ConfigSection};
 use serde_json::Value;
 use std::collections::HashMap;
 use std::path::{Path, PathBuf};
 use std::time::SystemTime;
-use tracing::debug;
+use tracing::{debug, warn};
 
 /// TOML 配置提供者
 #[derive(Debug)]
 pub struct TomlConfigProvider {
     file_path: PathBuf,
+    /// 是否允许写回配置文件
+    writable: bool,
+    /// `ENC[...]` 加密值的解密密钥
+    decryption_key: Option<SecretEncryptionKey>,
     config: Option<toml::Value>,
     last_modified: Option<SystemTime>,
     priority: i32,
@@ -21,9 +30,29 @@ pub struct TomlConfigProvider {
 impl TomlConfigProvider {
     /// 创建新的 TOML 配置提供者
     pub fn new<P: AsRef<Path>>(path: P) -> Result<Self, ConfigError> {
+        Self::open(path, None)
+    }
+    
+    /// 创建解密 `ENC[...]` 加密值的配置提供者
+    ///
+    /// 写回配置文件时，文件中已加密的配置项仍以密文保存
+    pub fn open_encrypted<P: AsRef<Path>>(
+        path: P,
+        key: SecretEncryptionKey,
+    ) -> Result<Self, ConfigError> {
+        Self::open(path, Some(key))
+    }
+    
+    /// 打开配置文件并完成初始加载
+    fn open<P: AsRef<Path>>(
+        path: P,
+        decryption_key: Option<SecretEncryptionKey>,
+    ) -> Result<Self, ConfigError> {
         let file_path = path.as_ref().to_path_buf();
         let mut provider = Self {
             file_path,
+            writable: false,
+            decryption_key,
             config: None,
             last_modified: None,
             priority: 100, // TOML 文件默认高优先级
@@ -40,6 +69,12 @@ impl TomlConfigProvider {
         self
     }
     
+    /// 允许通过配置管理器写回配置文件
+    pub fn with_write_back(mut self, enabled: bool) -> Self {
+        self.writable = enabled;
+        self
+    }
+    
     /// 加载配置文件
     fn load_config(&mut self) -> Result<(), ConfigError> {
         debug!("加载 TOML 配置文件: {}", self.file_path.display());
@@ -47,9 +82,11 @@ impl TomlConfigProvider {
         let content = std::fs::read_to_string(&self.file_path)
             .map_err(|e| ConfigError::FileReadError { source: e })?;
         
-        self.config = Some(toml::from_str(&content).map_err(|e| ConfigError::ParseError {
+        let mut config: toml::Value = toml::from_str(&content).map_err(|e| ConfigError::ParseError {
             source: Box::new(e),
-        })?);
+        })?;
+        decrypt_toml_tree(&mut config, self.decryption_key.as_ref())?;
+        self.config = Some(config);
         
         self.last_modified = Some(
             std::fs::metadata(&self.file_path)
@@ -156,6 +193,51 @@ impl ConfigProvider for TomlConfigProvider {
     fn supports_hot_reload(&self) -> bool {
         true
     }
+    
+    fn watch_paths(&self) -> Vec<PathBuf> {
+        vec![self.file_path.clone()]
+    }
+    
+    async fn get_all_configuration(&self) -> Result<Value, ConfigError> {
+        Ok(self
+            .config
+            .as_ref()
+            .map(|config| self.toml_to_json(config))
+            .unwrap_or_else(|| Value::Object(serde_json::Map::new())))
+    }
+    
+    fn source_description(&self) -> String {
+        self.file_path.display().to_string()
+    }
+
+    fn is_writable(&self) -> bool {
+        self.writable
+    }
+
+    /// 在原文件上修改，保留未变更部分的格式和注释
+    async fn apply_changes(&mut self, changes: &[ConfigKeyChange]) -> Result<(), ConfigError> {
+        if !self.writable {
+            return Err(ConfigError::ReadOnlyProvider {
+                provider: self.source_description(),
+            });
+        }
+
+        let content = std::fs::read_to_string(&self.file_path)?;
+        let mut document: toml_edit::DocumentMut =
+            content.parse().map_err(|e| ConfigError::ParseError {
+                source: Box::new(e),
+            })?;
+        let raw: toml::Value = toml::from_str(&content).map_err(|e| ConfigError::ParseError {
+            source: Box::new(e),
+        })?;
+        let changes = seal_changes(&self.toml_to_json(&raw), changes, self.decryption_key.as_ref())?;
+        for change in &changes {
+            apply_toml_change(document.as_table_mut(), &change.key, change.new_value.as_ref())?;
+        }
+
+        write_file_atomically(&self.file_path, &document.to_string())?;
+        self.load_config()
+    }
 }
 
 impl TomlConfigProvider {
@@ -198,6 +280,10 @@ impl FileConfigProvider for TomlConfigProvider {
 #[derive(Debug)]
 pub struct JsonConfigProvider {
     file_path: PathBuf,
+    /// 是否允许写回配置文件
+    writable: bool,
+    /// `ENC[...]` 加密值的解密密钥
+    decryption_key: Option<SecretEncryptionKey>,
     config: Option<Value>,
     last_modified: Option<SystemTime>,
     priority: i32,
@@ -206,9 +292,29 @@ pub struct JsonConfigProvider {
 impl JsonConfigProvider {
     /// 创建新的 JSON 配置提供者
     pub fn new<P: AsRef<Path>>(path: P) -> Result<Self, ConfigError> {
+        Self::open(path, None)
+    }
+    
+    /// 创建解密 `ENC[...]` 加密值的配置提供者
+    ///
+    /// 写回配置文件时，文件中已加密的配置项仍以密文保存
+    pub fn open_encrypted<P: AsRef<Path>>(
+        path: P,
+        key: SecretEncryptionKey,
+    ) -> Result<Self, ConfigError> {
+        Self::open(path, Some(key))
+    }
+    
+    /// 打开配置文件并完成初始加载
+    fn open<P: AsRef<Path>>(
+        path: P,
+        decryption_key: Option<SecretEncryptionKey>,
+    ) -> Result<Self, ConfigError> {
         let file_path = path.as_ref().to_path_buf();
         let mut provider = Self {
             file_path,
+            writable: false,
+            decryption_key,
             config: None,
             last_modified: None,
             priority: 90, // JSON 文件中等优先级
@@ -224,14 +330,19 @@ impl JsonConfigProvider {
         self
     }
     
+    /// 允许通过配置管理器写回配置文件
+    pub fn with_write_back(mut self, enabled: bool) -> Self {
+        self.writable = enabled;
+        self
+    }
+    
     /// 加载配置文件
     fn load_config(&mut self) -> Result<(), ConfigError> {
         debug!("加载 JSON 配置文件: {}", self.file_path.display());
         
-        let content = std::fs::read_to_string(&self.file_path)
-            .map_err(|e| ConfigError::FileReadError { source: e })?;
-        
-        self.config = Some(serde_json::from_str(&content)?);
+        let mut config = self.read_raw_config()?;
+        decrypt_config_tree(&mut config, self.decryption_key.as_ref())?;
+        self.config = Some(config);
         
         self.last_modified = Some(
             std::fs::metadata(&self.file_path)
@@ -243,6 +354,13 @@ impl JsonConfigProvider {
         Ok(())
     }
     
+    /// 读取未解密的配置文件内容
+    fn read_raw_config(&self) -> Result<Value, ConfigError> {
+        let content = std::fs::read_to_string(&self.file_path)
+            .map_err(|e| ConfigError::FileReadError { source: e })?;
+        Ok(serde_json::from_str(&content)?)
+    }
+    
     /// 从嵌套路径获取值
     fn get_nested_value(&self, path: &str) -> Option<&Value> {
         let config = self.config.as_ref()?;
@@ -311,6 +429,41 @@ impl ConfigProvider for JsonConfigProvider {
     fn supports_hot_reload(&self) -> bool {
         true
     }
+    
+    fn watch_paths(&self) -> Vec<PathBuf> {
+        vec![self.file_path.clone()]
+    }
+    
+    async fn get_all_configuration(&self) -> Result<Value, ConfigError> {
+        Ok(self
+            .config
+            .clone()
+            .unwrap_or_else(|| Value::Object(serde_json::Map::new())))
+    }
+    
+    fn source_description(&self) -> String {
+        self.file_path.display().to_string()
+    }
+
+    fn is_writable(&self) -> bool {
+        self.writable
+    }
+
+    async fn apply_changes(&mut self, changes: &[ConfigKeyChange]) -> Result<(), ConfigError> {
+        if !self.writable {
+            return Err(ConfigError::ReadOnlyProvider {
+                provider: self.source_description(),
+            });
+        }
+
+        let raw = self.read_raw_config()?;
+        let changes = seal_changes(&raw, changes, self.decryption_key.as_ref())?;
+        let config = apply_value_changes(Some(raw), &changes);
+        let mut content = serde_json::to_string_pretty(&config)?;
+        content.push('\n');
+        write_file_atomically(&self.file_path, &content)?;
+        self.load_config()
+    }
 }
 
 impl JsonConfigProvider {
@@ -349,151 +502,152 @@ impl FileConfigProvider for JsonConfigProvider {
     }
 }
 
-/// 环境变量配置提供者
+/// YAML 配置提供者
 #[derive(Debug)]
-pub struct EnvironmentConfigProviderImpl {
-    prefix: String,
-    separator: String,
+pub struct YamlConfigProvider {
+    file_path: PathBuf,
+    /// 是否允许写回配置文件
+    writable: bool,
+    /// `ENC[...]` 加密值的解密密钥
+    decryption_key: Option<SecretEncryptionKey>,
+    config: Option<Value>,
+    last_modified: Option<SystemTime>,
     priority: i32,
-    env_vars: HashMap<String, String>,
 }
 
-impl EnvironmentConfigProviderImpl {
-    /// 创建新的环境变量配置提供者
-    pub fn new(prefix: impl Into<String>) -> Result<Self, ConfigError> {
-        let prefix = prefix.into();
+impl YamlConfigProvider {
+    /// 创建新的 YAML 配置提供者
+    pub fn new<P: AsRef<Path>>(path: P) -> Result<Self, ConfigError> {
+        Self::open(path, None)
+    }
+    
+    /// 创建解密 `ENC[...]` 加密值的配置提供者
+    ///
+    /// 写回配置文件时，文件中已加密的配置项仍以密文保存
+    pub fn open_encrypted<P: AsRef<Path>>(
+        path: P,
+        key: SecretEncryptionKey,
+    ) -> Result<Self, ConfigError> {
+        Self::open(path, Some(key))
+    }
+    
+    /// 打开配置文件并完成初始加载
+    fn open<P: AsRef<Path>>(
+        path: P,
+        decryption_key: Option<SecretEncryptionKey>,
+    ) -> Result<Self, ConfigError> {
+        let file_path = path.as_ref().to_path_buf();
         let mut provider = Self {
-            prefix,
-            separator: "_".to_string(),
-            priority: 200, // 环境变量最高优先级
-            env_vars: HashMap::new(),
+            file_path,
+            writable: false,
+            decryption_key,
+            config: None,
+            last_modified: None,
+            priority: 90, // YAML 文件与 JSON 文件同为中等优先级
         };
         
-        provider.load_env_vars()?;
+        provider.load_config()?;
         Ok(provider)
     }
     
-    /// 设置分隔符
-    pub fn with_separator(mut self, separator: impl Into<String>) -> Self {
-        self.separator = separator.into();
-        self
-    }
-    
     /// 设置优先级
     pub fn with_priority(mut self, priority: i32) -> Self {
         self.priority = priority;
         self
     }
     
-    /// 加载环境变量
-    fn load_env_vars(&mut self) -> Result<(), ConfigError> {
-        debug!("加载环境变量，前缀: {}", self.prefix);
+    /// 允许通过配置管理器写回配置文件
+    pub fn with_write_back(mut self, enabled: bool) -> Self {
+        self.writable = enabled;
+        self
+    }
+    
+    /// 加载配置文件
+    fn load_config(&mut self) -> Result<(), ConfigError> {
+        debug!("加载 YAML 配置文件: {}", self.file_path.display());
         
-        self.env_vars.clear();
+        let mut config = self.read_raw_config()?;
+        decrypt_config_tree(&mut config, self.decryption_key.as_ref())?;
+        self.config = Some(config);
         
-        for (key, value) in std::env::vars() {
-            if key.starts_with(&self.prefix) {
-                let config_key = self.env_key_to_config_key(&key);
-                self.env_vars.insert(config_key, value);
-            }
-        }
+        self.last_modified = Some(
+            std::fs::metadata(&self.file_path)
+                .and_then(|m| m.modified())
+                .map_err(|e| ConfigError::FileReadError { source: e })?,
+        );
         
-        debug!("加载了 {} 个环境变量", self.env_vars.len());
+        debug!("YAML 配置文件加载完成");
         Ok(())
     }
     
-    /// 将环境变量键转换为配置键
-    fn env_key_to_config_key(&self, env_key: &str) -> String {
-        let key = env_key
-            .strip_prefix(&self.prefix)
-            .unwrap_or(env_key)
-            .trim_start_matches(&self.separator);
-        
-        // 将下划线转换为点分隔符，并转换为小写
-        key.replace(&self.separator, ".").to_lowercase()
+    /// 读取未解密的配置文件内容
+    fn read_raw_config(&self) -> Result<Value, ConfigError> {
+        let content = std::fs::read_to_string(&self.file_path)
+            .map_err(|e| ConfigError::FileReadError { source: e })?;
+        serde_yaml::from_str(&content).map_err(|e| ConfigError::ParseError {
+            source: Box::new(e),
+        })
     }
     
-    /// 将配置键转换为环境变量键
-    fn config_key_to_env_key(&self, config_key: &str) -> String {
-        format!(
-            "{}{}{}",
-            self.prefix,
-            self.separator,
-            config_key.replace('.', &self.separator).to_uppercase()
-        )
+    /// 从嵌套路径获取值
+    fn get_nested_value(&self, path: &str) -> Option<&Value> {
+        let config = self.config.as_ref()?;
+        let parts: Vec<&str> = path.split('.').collect();
+        
+        let mut current = config;
+        for part in parts {
+            current = current.get(part)?;
+        }
+        
+        Some(current)
     }
 }
 
 #[async_trait]
-impl ConfigProvider for EnvironmentConfigProviderImpl {
+impl ConfigProvider for YamlConfigProvider {
     async fn get_configuration(&self, key: &str) -> Result<Value, ConfigError> {
-        match self.env_vars.get(key) {
-            Some(value) => {
-                // 尝试解析为不同类型
-                if let Ok(bool_val) = value.parse::<bool>() {
-                    Ok(Value::Bool(bool_val))
-                } else if let Ok(int_val) = value.parse::<i64>() {
-                    Ok(Value::Number(serde_json::Number::from(int_val)))
-                } else if let Ok(float_val) = value.parse::<f64>() {
-                    Ok(Value::Number(
-                        serde_json::Number::from_f64(float_val)
-                            .unwrap_or_else(|| serde_json::Number::from(0)),
-                    ))
-                } else {
-                    Ok(Value::String(value.clone()))
-                }
-            }
+        match self.get_nested_value(key) {
+            Some(value) => Ok(value.clone()),
             None => Err(ConfigError::KeyNotFound { key: key.to_string() }),
         }
     }
     
     async fn get_section(&self, section_name: &str) -> Result<ConfigSection, ConfigError> {
-        let mut section = ConfigSection::new();
-        let section_prefix = format!("{}.", section_name);
-        
-        for (key, value) in &self.env_vars {
-            if key.starts_with(&section_prefix) {
-                let sub_key = key.strip_prefix(&section_prefix).unwrap();
-                let json_value = if let Ok(bool_val) = value.parse::<bool>() {
-                    Value::Bool(bool_val)
-                } else if let Ok(int_val) = value.parse::<i64>() {
-                    Value::Number(serde_json::Number::from(int_val))
-                } else if let Ok(float_val) = value.parse::<f64>() {
-                    Value::Number(
-                        serde_json::Number::from_f64(float_val)
-                            .unwrap_or_else(|| serde_json::Number::from(0)),
-                    )
-                } else {
-                    Value::String(value.clone())
-                };
-                
-                section.insert(sub_key.to_string(), json_value);
+        match self.get_nested_value(section_name) {
+            Some(Value::Object(obj)) => {
+                let mut section = ConfigSection::new();
+                for (key, value) in obj {
+                    section.insert(key.clone(), value.clone());
+                }
+                Ok(section)
             }
-        }
-        
-        if section.data.is_empty() {
-            Err(ConfigError::KeyNotFound {
+            Some(_) => Err(ConfigError::TypeConversionError {
+                message: format!("配置节 {} 不是对象类型", section_name),
+            }),
+            None => Err(ConfigError::KeyNotFound {
                 key: section_name.to_string(),
-            })
-        } else {
-            Ok(section)
+            }),
         }
     }
     
     async fn reload(&mut self) -> Result<(), ConfigError> {
-        self.load_env_vars()
+        self.load_config()
     }
     
     async fn contains_key(&self, key: &str) -> Result<bool, ConfigError> {
-        Ok(self.env_vars.contains_key(key))
+        Ok(self.get_nested_value(key).is_some())
     }
     
     async fn get_all_keys(&self) -> Result<Vec<String>, ConfigError> {
-        Ok(self.env_vars.keys().cloned().collect())
+        let mut keys = Vec::new();
+        if let Some(Value::Object(obj)) = &self.config {
+            self.collect_keys(obj, String::new(), &mut keys);
+        }
+        Ok(keys)
     }
     
     fn name(&self) -> &str {
-        "EnvironmentConfigProvider"
+        "YamlConfigProvider"
     }
     
     fn priority(&self) -> i32 {
@@ -503,19 +657,1002 @@ impl ConfigProvider for EnvironmentConfigProviderImpl {
     fn supports_hot_reload(&self) -> bool {
         true
     }
+    
+    fn watch_paths(&self) -> Vec<PathBuf> {
+        vec![self.file_path.clone()]
+    }
+    
+    async fn get_all_configuration(&self) -> Result<Value, ConfigError> {
+        Ok(self
+            .config
+            .clone()
+            .unwrap_or_else(|| Value::Object(serde_json::Map::new())))
+    }
+    
+    fn source_description(&self) -> String {
+        self.file_path.display().to_string()
+    }
+
+    fn is_writable(&self) -> bool {
+        self.writable
+    }
+
+    /// 重新序列化整个文件，注释不会保留
+    async fn apply_changes(&mut self, changes: &[ConfigKeyChange]) -> Result<(), ConfigError> {
+        if !self.writable {
+            return Err(ConfigError::ReadOnlyProvider {
+                provider: self.source_description(),
+            });
+        }
+
+        let raw = self.read_raw_config()?;
+        let changes = seal_changes(&raw, changes, self.decryption_key.as_ref())?;
+        let config = apply_value_changes(Some(raw), &changes);
+        let content = serde_yaml::to_string(&config).map_err(|e| ConfigError::ParseError {
+            source: Box::new(e),
+        })?;
+        write_file_atomically(&self.file_path, &content)?;
+        self.load_config()
+    }
+}
+
+impl YamlConfigProvider {
+    /// 递归收集所有键
+    fn collect_keys(&self, obj: &serde_json::Map<String, Value>, prefix: String, keys: &mut Vec<String>) {
+        for (key, value) in obj {
+            let full_key = if prefix.is_empty() {
+                key.clone()
+            } else {
+                format!("{}.{}", prefix, key)
+            };
+            
+            keys.push(full_key.clone());
+            
+            if let Value::Object(nested_obj) = value {
+                self.collect_keys(nested_obj, full_key, keys);
+            }
+        }
+    }
 }
 
 #[async_trait]
-impl EnvironmentConfigProviderTrait for EnvironmentConfigProviderImpl {
-    fn prefix(&self) -> &str {
-        &self.prefix
+impl FileConfigProvider for YamlConfigProvider {
+    fn file_path(&self) -> &str {
+        self.file_path.to_str().unwrap_or("unknown")
     }
     
-    fn separator(&self) -> &str {
-        &self.separator
+    async fn file_exists(&self) -> bool {
+        self.file_path.exists()
     }
     
-    async fn get_matching_env_vars(&self) -> Result<HashMap<String, String>, ConfigError> {
-        Ok(self.env_vars.clone())
+    async fn last_modified(&self) -> Result<SystemTime, ConfigError> {
+        self.last_modified.ok_or_else(|| ConfigError::ValidationError {
+            message: "文件尚未加载".to_string(),
+        })
+    }
+}
+
+/// 内存配置提供者
+///
+/// 配置保存在内存中并且总是可写，适合作为运行时覆盖层（如管理后台修改的参数）
+#[derive(Debug)]
+pub struct MemoryConfigProvider {
+    name: String,
+    config: Value,
+    priority: i32,
+}
+
+impl MemoryConfigProvider {
+    /// 创建空的内存配置提供者
+    pub fn new(name: impl Into<String>) -> Self {
+        Self {
+            name: name.into(),
+            config: Value::Object(serde_json::Map::new()),
+            priority: 300, // 运行时覆盖高于环境变量
+        }
+    }
+
+    /// 设置初始配置
+    pub fn with_values(mut self, config: Value) -> Self {
+        self.config = config;
+        self
+    }
+
+    /// 设置优先级
+    pub fn with_priority(mut self, priority: i32) -> Self {
+        self.priority = priority;
+        self
+    }
+}
+
+#[async_trait]
+impl ConfigProvider for MemoryConfigProvider {
+    async fn get_configuration(&self, key: &str) -> Result<Value, ConfigError> {
+        config_abstractions::get_nested_value(&self.config, key)
+            .cloned()
+            .ok_or_else(|| ConfigError::KeyNotFound { key: key.to_string() })
+    }
+
+    async fn get_section(&self, section_name: &str) -> Result<ConfigSection, ConfigError> {
+        match config_abstractions::get_nested_value(&self.config, section_name) {
+            Some(Value::Object(obj)) => {
+                let mut section = ConfigSection::new();
+                for (key, value) in obj {
+                    section.insert(key.clone(), value.clone());
+                }
+                Ok(section)
+            }
+            Some(_) => Err(ConfigError::TypeConversionError {
+                message: format!("配置节 {} 不是对象类型", section_name),
+            }),
+            None => Err(ConfigError::KeyNotFound {
+                key: section_name.to_string(),
+            }),
+        }
+    }
+
+    async fn reload(&mut self) -> Result<(), ConfigError> {
+        Ok(())
+    }
+
+    async fn contains_key(&self, key: &str) -> Result<bool, ConfigError> {
+        Ok(config_abstractions::get_nested_value(&self.config, key).is_some())
+    }
+
+    async fn get_all_keys(&self) -> Result<Vec<String>, ConfigError> {
+        Ok(config_abstractions::flatten_leaves(&self.config)
+            .into_keys()
+            .collect())
+    }
+
+    fn name(&self) -> &str {
+        &self.name
+    }
+
+    fn priority(&self) -> i32 {
+        self.priority
+    }
+
+    async fn get_all_configuration(&self) -> Result<Value, ConfigError> {
+        Ok(self.config.clone())
+    }
+
+    fn source_description(&self) -> String {
+        format!("memory:{}", self.name)
+    }
+
+    fn is_writable(&self) -> bool {
+        true
+    }
+
+    async fn apply_changes(&mut self, changes: &[ConfigKeyChange]) -> Result<(), ConfigError> {
+        self.config = apply_value_changes(Some(self.config.clone()), changes);
+        Ok(())
+    }
+}
+
+/// 将配置变更应用到 JSON 配置树
+fn apply_value_changes(config: Option<Value>, changes: &[ConfigKeyChange]) -> Value {
+    let mut config = config.unwrap_or_else(|| Value::Object(serde_json::Map::new()));
+    for change in changes {
+        match &change.new_value {
+            Some(value) => insert_nested_value(&mut config, &change.key, value.clone()),
+            None => {
+                remove_nested_value(&mut config, &change.key);
+            }
+        }
+    }
+    config
+}
+
+/// 在 TOML 文档中设置或删除点分路径上的值
+///
+/// 只修改涉及的键，替换已有值时保留其行尾注释
+pub(crate) fn apply_toml_change(
+    root: &mut toml_edit::Table,
+    key: &str,
+    value: Option<&Value>,
+) -> Result<(), ConfigError> {
+    let parts: Vec<&str> = key.split('.').collect();
+    let (last, parents) = parts.split_last().expect("split 至少返回一个元素");
+
+    let mut inline = false;
+    let mut table: &mut dyn toml_edit::TableLike = root;
+    for part in parents {
+        if value.is_none() && !table.contains_key(part) {
+            return Ok(());
+        }
+        let default = if inline {
+            toml_edit::Item::Value(toml_edit::InlineTable::new().into())
+        } else {
+            let mut table = toml_edit::Table::new();
+            table.set_implicit(true);
+            toml_edit::Item::Table(table)
+        };
+        let item = table.entry(part).or_insert(default);
+        inline = inline || item.is_inline_table();
+        table = item
+            .as_table_like_mut()
+            .ok_or_else(|| ConfigError::TypeConversionError {
+                message: format!("配置键 {} 的上级 {} 不是表", key, part),
+            })?;
+    }
+
+    let Some(value) = value else {
+        table.remove(last);
+        return Ok(());
+    };
+    let item = match value {
+        Value::Object(map) if !inline => toml_edit::Item::Table(json_to_toml_table(key, map)?),
+        _ => toml_edit::Item::Value(json_to_toml_value(key, value)?),
+    };
+    match table.get_mut(last) {
+        Some(toml_edit::Item::Value(existing)) if item.is_value() => {
+            let decor = existing.decor().clone();
+            *existing = item.into_value().expect("已确认为值");
+            *existing.decor_mut() = decor;
+        }
+        _ => {
+            table.insert(last, item);
+        }
+    }
+    Ok(())
+}
+
+/// 将 JSON 对象转换为 TOML 表
+fn json_to_toml_table(
+    key: &str,
+    map: &serde_json::Map<String, Value>,
+) -> Result<toml_edit::Table, ConfigError> {
+    let mut table = toml_edit::Table::new();
+    for (name, value) in map {
+        let item = match value {
+            Value::Object(nested) => toml_edit::Item::Table(json_to_toml_table(key, nested)?),
+            _ => toml_edit::Item::Value(json_to_toml_value(key, value)?),
+        };
+        table.insert(name, item);
+    }
+    Ok(table)
+}
+
+/// 将 JSON 值转换为 TOML 值，对象转换为内联表
+fn json_to_toml_value(key: &str, value: &Value) -> Result<toml_edit::Value, ConfigError> {
+    Ok(match value {
+        Value::Null => {
+            return Err(ConfigError::TypeConversionError {
+                message: format!("配置键 {} 的值为 null，TOML 不支持 null", key),
+            })
+        }
+        Value::Bool(b) => (*b).into(),
+        Value::Number(n) => match n.as_i64() {
+            Some(i) => i.into(),
+            None => n
+                .as_f64()
+                .ok_or_else(|| ConfigError::TypeConversionError {
+                    message: format!("配置键 {} 的数值 {} 超出 TOML 范围", key, n),
+                })?
+                .into(),
+        },
+        Value::String(s) => s.as_str().into(),
+        Value::Array(items) => items
+            .iter()
+            .map(|item| json_to_toml_value(key, item))
+            .collect::<Result<toml_edit::Array, _>>()?
+            .into(),
+        Value::Object(map) => map
+            .iter()
+            .map(|(name, item)| Ok((name.clone(), json_to_toml_value(key, item)?)))
+            .collect::<Result<toml_edit::InlineTable, ConfigError>>()?
+            .into(),
+    })
+}
+
+/// 先写入同目录下的临时文件再重命名，避免写入中途被读取到不完整的内容
+pub(crate) fn write_file_atomically(path: &Path, content: &str) -> Result<(), ConfigError> {
+    let mut temp_name = path.as_os_str().to_owned();
+    temp_name.push(".tmp");
+    let temp_path = PathBuf::from(temp_name);
+
+    std::fs::write(&temp_path, content)?;
+    if let Ok(metadata) = std::fs::metadata(path) {
+        std::fs::set_permissions(&temp_path, metadata.permissions())?;
+    }
+    std::fs::rename(&temp_path, path)?;
+    debug!("配置文件已写回: {}", path.display());
+    Ok(())
+}
+
+/// 默认的环境变量嵌套分隔符
+pub const DEFAULT_ENV_SEPARATOR: &str = "__";
+
+/// 环境变量值的类型提示
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum EnvValueType {
+    /// 字符串，原样保留
+    String,
+    /// 整数
+    Integer,
+    /// 浮点数
+    Number,
+    /// 布尔值
+    Boolean,
+    /// JSON 值（数组或对象）
+    Json,
+}
+
+impl EnvValueType {
+    /// 从 JSON Schema 的 `type` 关键字推断类型提示
+    fn from_schema(schema: &Value) -> Option<Self> {
+        let type_name = match schema.get("type")? {
+            Value::String(name) => name.as_str(),
+            Value::Array(names) => names
+                .iter()
+                .filter_map(Value::as_str)
+                .find(|name| *name != "null")?,
+            _ => return None,
+        };
+        match type_name {
+            "string" => Some(Self::String),
+            "integer" => Some(Self::Integer),
+            "number" => Some(Self::Number),
+            "boolean" => Some(Self::Boolean),
+            "array" | "object" => Some(Self::Json),
+            _ => None,
+        }
+    }
+}
+
+/// 环境变量配置提供者
+///
+/// 变量名去掉前缀后按分隔符（默认 `__`）拆分为小写的配置路径，单个下划线保留在键名中，
+/// 纯数字的路径段表示数组下标，如 `ADSP__DSP__ENDPOINTS__0__URL` 对应 `dsp.endpoints[0].url`。
+/// 值的类型优先按类型提示（可从 JSON Schema 推断）转换，没有提示时只识别
+/// `true`/`false`、规范形式的数字和 JSON 数组/对象，像 `01234` 这样的值保持字符串。
+/// 可以加载 `.env` 文件用于本地开发，进程环境变量优先于文件中的同名变量
+#[derive(Debug)]
+pub struct EnvironmentConfigProviderImpl {
+    prefix: String,
+    separator: String,
+    priority: i32,
+    /// 匹配前缀的原始变量（变量名到值）
+    raw_vars: HashMap<String, String>,
+    /// 配置键到原始值的映射
+    env_vars: HashMap<String, String>,
+    /// 配置键到原始环境变量名的映射
+    env_var_names: HashMap<String, String>,
+    /// 按配置路径的类型提示，数组下标记为 `*`
+    type_hints: HashMap<String, EnvValueType>,
+    /// 本地开发使用的 `.env` 文件
+    dotenv_files: Vec<PathBuf>,
+    /// 组装后的配置树
+    config: Value,
+}
+
+impl EnvironmentConfigProviderImpl {
+    /// 创建新的环境变量配置提供者
+    pub fn new(prefix: impl Into<String>) -> Result<Self, ConfigError> {
+        let prefix = prefix.into();
+        let mut provider = Self {
+            prefix,
+            separator: DEFAULT_ENV_SEPARATOR.to_string(),
+            priority: 200, // 环境变量高于配置文件
+            raw_vars: HashMap::new(),
+            env_vars: HashMap::new(),
+            env_var_names: HashMap::new(),
+            type_hints: HashMap::new(),
+            dotenv_files: Vec::new(),
+            config: Value::Object(serde_json::Map::new()),
+        };
+        
+        provider.load_env_vars()?;
+        Ok(provider)
+    }
+    
+    /// 设置分隔符
+    pub fn with_separator(mut self, separator: impl Into<String>) -> Self {
+        self.separator = separator.into();
+        self.rebuild_config();
+        self
+    }
+    
+    /// 设置优先级
+    pub fn with_priority(mut self, priority: i32) -> Self {
+        self.priority = priority;
+        self
+    }
+
+    /// 加载 `.env` 文件，文件不存在时忽略
+    ///
+    /// 文件中的变量只在当前进程没有同名环境变量时生效，且不会写入进程环境
+    pub fn with_dotenv_file(mut self, path: impl AsRef<Path>) -> Result<Self, ConfigError> {
+        self.dotenv_files.push(path.as_ref().to_path_buf());
+        self.load_env_vars()?;
+        Ok(self)
+    }
+
+    /// 为配置路径设置类型提示，路径中的数组下标可以写作数字或 `*`
+    pub fn with_type_hint(mut self, path: &str, value_type: EnvValueType) -> Self {
+        self.type_hints.insert(normalize_hint_path(path), value_type);
+        self.rebuild_config();
+        self
+    }
+
+    /// 根据配置路径上的 JSON Schema 推断类型提示
+    pub fn with_schema(mut self, path: &str, schema: &Value) -> Self {
+        collect_schema_hints(schema, &normalize_hint_path(path), &mut self.type_hints);
+        self.rebuild_config();
+        self
+    }
+
+    /// 根据绑定类型的 JSON Schema 推断类型提示
+    pub fn with_schema_of<T: ConfigSchema>(self, path: &str) -> Self {
+        self.with_schema(path, &T::config_schema())
+    }
+    
+    /// 加载环境变量
+    fn load_env_vars(&mut self) -> Result<(), ConfigError> {
+        debug!("加载环境变量，前缀: {}", self.prefix);
+        
+        let mut variables: HashMap<String, String> = HashMap::new();
+        for path in &self.dotenv_files {
+            let content = match std::fs::read_to_string(path) {
+                Ok(content) => content,
+                Err(e) if e.kind() == std::io::ErrorKind::NotFound => {
+                    debug!(".env 文件不存在，跳过: {}", path.display());
+                    continue;
+                }
+                Err(e) => return Err(e.into()),
+            };
+            variables.extend(parse_dotenv(&content).map_err(|message| {
+                ConfigError::ParseError {
+                    source: format!("{}: {}", path.display(), message).into(),
+                }
+            })?);
+        }
+        variables.extend(std::env::vars());
+        variables.retain(|key, _| key.starts_with(&self.prefix));
+        self.raw_vars = variables;
+
+        self.rebuild_config();
+        debug!("加载了 {} 个环境变量", self.env_vars.len());
+        Ok(())
+    }
+
+    /// 将变量名转换为配置键，按类型提示转换变量值并组装配置树
+    fn rebuild_config(&mut self) {
+        self.env_vars.clear();
+        self.env_var_names.clear();
+        for (name, value) in &self.raw_vars {
+            if let Some(config_key) = self.env_key_to_config_key(name) {
+                self.env_var_names.insert(config_key.clone(), name.clone());
+                self.env_vars.insert(config_key, value.clone());
+            }
+        }
+
+        let mut keys: Vec<&String> = self.env_vars.keys().collect();
+        // 先插入较短的路径，使更具体的变量覆盖整体赋值中的同名字段
+        keys.sort_by(|a, b| a.split('.').count().cmp(&b.split('.').count()).then(a.cmp(b)));
+
+        let mut config = Value::Object(serde_json::Map::new());
+        for key in keys {
+            let value = self.convert_value(key, &self.env_vars[key]);
+            insert_indexed_value(&mut config, key, value);
+        }
+        self.config = config;
+    }
+
+    /// 转换单个变量值
+    fn convert_value(&self, key: &str, raw: &str) -> Value {
+        match self.type_hints.get(&normalize_hint_path(key)) {
+            Some(value_type) => convert_with_hint(raw, *value_type).unwrap_or_else(|| {
+                warn!("环境变量 {} 的值无法转换为 {:?}，保持字符串", key, value_type);
+                Value::String(raw.to_string())
+            }),
+            None => infer_text_value(raw),
+        }
+    }
+    
+    /// 将环境变量键转换为配置键，不匹配前缀时返回 `None`
+    fn env_key_to_config_key(&self, env_key: &str) -> Option<String> {
+        let rest = env_key.strip_prefix(&self.prefix)?;
+        // 前缀后必须紧跟分隔符，避免 `ADSP` 匹配 `ADSPX_...`
+        if !self.prefix.is_empty() && !self.prefix.ends_with('_') && !rest.starts_with('_') {
+            return None;
+        }
+        let key = rest
+            .trim_start_matches(self.separator.as_str())
+            .trim_start_matches('_');
+        if key.is_empty() {
+            return None;
+        }
+
+        let segments: Vec<String> = key
+            .split(self.separator.as_str())
+            .filter(|segment| !segment.is_empty())
+            .map(str::to_lowercase)
+            .collect();
+        Some(segments.join("."))
+    }
+    
+    /// 将配置键转换为环境变量键
+    fn config_key_to_env_key(&self, config_key: &str) -> String {
+        let prefix = self.prefix.trim_end_matches('_');
+        format!(
+            "{}{}{}",
+            prefix,
+            self.separator,
+            config_key.replace('.', &self.separator).to_uppercase()
+        )
+    }
+}
+
+#[async_trait]
+impl ConfigProvider for EnvironmentConfigProviderImpl {
+    async fn get_configuration(&self, key: &str) -> Result<Value, ConfigError> {
+        config_abstractions::get_nested_value(&self.config, key)
+            .cloned()
+            .ok_or_else(|| ConfigError::KeyNotFound { key: key.to_string() })
+    }
+    
+    async fn get_section(&self, section_name: &str) -> Result<ConfigSection, ConfigError> {
+        match config_abstractions::get_nested_value(&self.config, section_name) {
+            Some(Value::Object(obj)) => {
+                let mut section = ConfigSection::new();
+                for (key, value) in obj {
+                    section.insert(key.clone(), value.clone());
+                }
+                Ok(section)
+            }
+            Some(_) => Err(ConfigError::TypeConversionError {
+                message: format!("配置节 {} 不是对象类型", section_name),
+            }),
+            None => Err(ConfigError::KeyNotFound {
+                key: section_name.to_string(),
+            }),
+        }
+    }
+    
+    async fn reload(&mut self) -> Result<(), ConfigError> {
+        self.load_env_vars()
+    }
+    
+    async fn contains_key(&self, key: &str) -> Result<bool, ConfigError> {
+        Ok(config_abstractions::get_nested_value(&self.config, key).is_some())
+    }
+    
+    async fn get_all_keys(&self) -> Result<Vec<String>, ConfigError> {
+        Ok(config_abstractions::flatten_leaves(&self.config)
+            .into_keys()
+            .collect())
+    }
+
+    async fn get_all_configuration(&self) -> Result<Value, ConfigError> {
+        Ok(self.config.clone())
+    }
+    
+    fn name(&self) -> &str {
+        "EnvironmentConfigProvider"
+    }
+    
+    fn priority(&self) -> i32 {
+        self.priority
+    }
+    
+    fn supports_hot_reload(&self) -> bool {
+        true
+    }
+    
+    fn source_description(&self) -> String {
+        format!("env:{}*", self.prefix)
+    }
+    
+    fn key_source(&self, key: &str) -> String {
+        match self.env_var_names.get(key) {
+            Some(name) => format!("env:{}", name),
+            None => self.source_description(),
+        }
+    }
+
+    fn watch_paths(&self) -> Vec<PathBuf> {
+        self.dotenv_files.clone()
+    }
+}
+
+#[async_trait]
+impl EnvironmentConfigProviderTrait for EnvironmentConfigProviderImpl {
+    fn prefix(&self) -> &str {
+        &self.prefix
+    }
+    
+    fn separator(&self) -> &str {
+        &self.separator
+    }
+    
+    async fn get_matching_env_vars(&self) -> Result<HashMap<String, String>, ConfigError> {
+        Ok(self.env_vars.clone())
+    }
+}
+
+/// 将配置路径中的数组下标替换为 `*`
+fn normalize_hint_path(path: &str) -> String {
+    path.split('.')
+        .map(|segment| {
+            if segment.chars().all(|c| c.is_ascii_digit()) {
+                "*"
+            } else {
+                segment
+            }
+        })
+        .collect::<Vec<_>>()
+        .join(".")
+}
+
+/// 从 JSON Schema 收集类型提示
+fn collect_schema_hints(schema: &Value, path: &str, hints: &mut HashMap<String, EnvValueType>) {
+    if let Some(value_type) = EnvValueType::from_schema(schema) {
+        if !path.is_empty() {
+            hints.insert(path.to_string(), value_type);
+        }
+    }
+
+    let child_path = |name: &str| {
+        if path.is_empty() {
+            name.to_string()
+        } else {
+            format!("{}.{}", path, name)
+        }
+    };
+    if let Some(Value::Object(properties)) = schema.get("properties") {
+        for (name, property) in properties {
+            collect_schema_hints(property, &child_path(name), hints);
+        }
+    }
+    if let Some(items) = schema.get("items") {
+        collect_schema_hints(items, &child_path("*"), hints);
+    }
+}
+
+/// 按类型提示转换变量值
+fn convert_with_hint(raw: &str, value_type: EnvValueType) -> Option<Value> {
+    let trimmed = raw.trim();
+    match value_type {
+        EnvValueType::String => Some(Value::String(raw.to_string())),
+        EnvValueType::Integer => trimmed.parse::<i64>().ok().map(Value::from),
+        EnvValueType::Number => trimmed
+            .parse::<f64>()
+            .ok()
+            .and_then(serde_json::Number::from_f64)
+            .map(Value::Number),
+        EnvValueType::Boolean => match trimmed.to_ascii_lowercase().as_str() {
+            "true" | "1" | "yes" | "on" => Some(Value::Bool(true)),
+            "false" | "0" | "no" | "off" => Some(Value::Bool(false)),
+            _ => None,
+        },
+        EnvValueType::Json => serde_json::from_str(trimmed).ok(),
+    }
+}
+
+/// 没有类型提示时推断环境变量或命令行参数值的类型
+///
+/// 只把规范形式的数字转换为数值，保留前导零、正号等可能有意义的字符串形式
+fn infer_text_value(raw: &str) -> Value {
+    match raw {
+        "true" => return Value::Bool(true),
+        "false" => return Value::Bool(false),
+        _ => {}
+    }
+    if raw.starts_with('[') || raw.starts_with('{') {
+        if let Ok(value) = serde_json::from_str::<Value>(raw) {
+            return value;
+        }
+    }
+    if let Ok(int_val) = raw.parse::<i64>() {
+        if int_val.to_string() == raw {
+            return Value::from(int_val);
+        }
+    }
+    let digits = raw.strip_prefix('-').unwrap_or(raw);
+    let canonical_float = digits.contains('.')
+        && digits.starts_with(|c: char| c.is_ascii_digit())
+        && !digits.ends_with('.')
+        && (digits.starts_with("0.") || !digits.starts_with('0'));
+    if canonical_float {
+        if let Some(number) = raw.parse::<f64>().ok().and_then(serde_json::Number::from_f64) {
+            return Value::Number(number);
+        }
+    }
+    Value::String(raw.to_string())
+}
+
+/// 按点分路径插入值，纯数字的路径段创建数组元素
+fn insert_indexed_value(root: &mut Value, path: &str, value: Value) {
+    let segments: Vec<&str> = path.split('.').collect();
+    let mut current = root;
+    for (i, segment) in segments.iter().enumerate() {
+        let is_last = i == segments.len() - 1;
+        let next_is_index = segments
+            .get(i + 1)
+            .is_some_and(|next| next.parse::<usize>().is_ok());
+
+        let slot = match segment.parse::<usize>() {
+            Ok(index)
+                if current.is_array() || current.as_object().is_some_and(|map| map.is_empty()) =>
+            {
+                if !current.is_array() {
+                    *current = Value::Array(Vec::new());
+                }
+                let items = current.as_array_mut().expect("已确保为数组");
+                if items.len() <= index {
+                    items.resize(index + 1, Value::Null);
+                }
+                &mut items[index]
+            }
+            _ => {
+                if !current.is_object() {
+                    *current = Value::Object(serde_json::Map::new());
+                }
+                current
+                    .as_object_mut()
+                    .expect("已确保为对象")
+                    .entry(segment.to_string())
+                    .or_insert(Value::Null)
+            }
+        };
+
+        if is_last {
+            *slot = value;
+            return;
+        }
+        if slot.is_null() {
+            *slot = if next_is_index {
+                Value::Array(Vec::new())
+            } else {
+                Value::Object(serde_json::Map::new())
+            };
+        }
+        current = slot;
+    }
+}
+
+/// 解析 `.env` 文件内容
+///
+/// 支持 `#` 注释、`export` 前缀、单引号（原样）和双引号（支持 `\n`、`\"` 等转义）的值，
+/// 未加引号的值在 ` #` 之后的部分视为注释
+fn parse_dotenv(content: &str) -> Result<Vec<(String, String)>, String> {
+    let mut variables = Vec::new();
+    for (number, line) in content.lines().enumerate() {
+        let line = line.trim();
+        if line.is_empty() || line.starts_with('#') {
+            continue;
+        }
+        let line = line.strip_prefix("export ").unwrap_or(line).trim_start();
+        let (key, value) = line
+            .split_once('=')
+            .ok_or_else(|| format!("第 {} 行缺少 '='", number + 1))?;
+        let key = key.trim();
+        if key.is_empty() || !key.chars().all(|c| c.is_ascii_alphanumeric() || c == '_') {
+            return Err(format!("第 {} 行的变量名无效: {}", number + 1, key));
+        }
+
+        let value = value.trim();
+        let value = if let Some(quoted) = value.strip_prefix('\'') {
+            quoted
+                .strip_suffix('\'')
+                .ok_or_else(|| format!("第 {} 行的单引号未闭合", number + 1))?
+                .to_string()
+        } else if let Some(quoted) = value.strip_prefix('"') {
+            let quoted = quoted
+                .strip_suffix('"')
+                .ok_or_else(|| format!("第 {} 行的双引号未闭合", number + 1))?;
+            unescape_double_quoted(quoted)
+        } else {
+            match value.find(" #") {
+                Some(comment) => value[..comment].trim_end().to_string(),
+                None => value.to_string(),
+            }
+        };
+        variables.push((key.to_string(), value));
+    }
+    Ok(variables)
+}
+
+/// 处理双引号值中的转义字符
+fn unescape_double_quoted(value: &str) -> String {
+    let mut result = String::with_capacity(value.len());
+    let mut chars = value.chars();
+    while let Some(c) = chars.next() {
+        if c != '\\' {
+            result.push(c);
+            continue;
+        }
+        match chars.next() {
+            Some('n') => result.push('\n'),
+            Some('r') => result.push('\r'),
+            Some('t') => result.push('\t'),
+            Some(other) => result.push(other),
+            None => result.push('\\'),
+        }
+    }
+    result
+}
+
+/// 命令行配置提供者的默认优先级，高于环境变量和内存覆盖层
+pub const DEFAULT_COMMAND_LINE_PRIORITY: i32 = 400;
+
+/// 命令行参数配置提供者
+///
+/// 将 `--section.key=value`、`--section.key value`、`--set key=value` 和 `--set=key=value`
+/// 形式的参数映射为配置键，值的类型推断与环境变量一致，纯数字的路径段表示数组下标。
+/// 其他参数（如应用自身的开关）被忽略，`--` 之后的参数不再解析。
+///
+/// 使用 `clap` 的应用可以声明可重复的 `--set` 参数，再通过
+/// [`CommandLineConfigProvider::from_overrides`] 传入解析结果
+#[derive(Debug)]
+pub struct CommandLineConfigProvider {
+    priority: i32,
+    /// 配置键到参数名（不含参数值）的映射，用于来源追踪
+    arguments: HashMap<String, String>,
+    config: Value,
+}
+
+impl CommandLineConfigProvider {
+    /// 从参数列表创建命令行配置提供者（不包含程序名）
+    pub fn new<I, S>(args: I) -> Result<Self, ConfigError>
+    where
+        I: IntoIterator<Item = S>,
+        S: Into<String>,
+    {
+        let mut provider = Self {
+            priority: DEFAULT_COMMAND_LINE_PRIORITY,
+            arguments: HashMap::new(),
+            config: Value::Object(serde_json::Map::new()),
+        };
+
+        let mut args = args.into_iter().map(Into::into).peekable();
+        while let Some(arg) = args.next() {
+            if arg == "--" {
+                break;
+            }
+            let Some(option) = arg.strip_prefix("--") else {
+                continue;
+            };
+
+            if option == "set" {
+                let assignment = args.next().ok_or_else(|| ConfigError::ParseError {
+                    source: "--set 缺少 key=value 参数".into(),
+                })?;
+                provider.insert_assignment(&assignment)?;
+            } else if let Some(assignment) = option.strip_prefix("set=") {
+                provider.insert_assignment(assignment)?;
+            } else if let Some((key, raw)) = option.split_once('=') {
+                if key.contains('.') {
+                    provider.insert(key, raw, format!("--{}", key.trim()))?;
+                }
+            } else if option.contains('.') {
+                match args.next_if(|next| !next.starts_with("--")) {
+                    Some(raw) => {
+                        provider.insert(option, &raw, format!("--{}", option.trim()))?;
+                    }
+                    None => {
+                        return Err(ConfigError::ParseError {
+                            source: format!("{} 缺少参数值", arg).into(),
+                        })
+                    }
+                }
+            }
+        }
+
+        Ok(provider)
+    }
+
+    /// 从当前进程的命令行参数创建命令行配置提供者
+    pub fn from_env_args() -> Result<Self, ConfigError> {
+        Self::new(std::env::args().skip(1))
+    }
+
+    /// 从 `key=value` 形式的覆盖项创建命令行配置提供者
+    pub fn from_overrides<I, S>(overrides: I) -> Result<Self, ConfigError>
+    where
+        I: IntoIterator<Item = S>,
+        S: Into<String>,
+    {
+        Self::new(overrides.into_iter().flat_map(|assignment| {
+            [String::from("--set"), assignment.into()]
+        }))
+    }
+
+    /// 设置优先级
+    pub fn with_priority(mut self, priority: i32) -> Self {
+        self.priority = priority;
+        self
+    }
+
+    /// 解析 `--set` 的 `key=value` 形式的覆盖项
+    fn insert_assignment(&mut self, assignment: &str) -> Result<(), ConfigError> {
+        let (key, raw) = assignment.split_once('=').ok_or_else(|| ConfigError::ParseError {
+            source: format!("命令行配置覆盖项格式应为 key=value: {}", assignment).into(),
+        })?;
+        self.insert(key, raw, format!("--set {}", key.trim()))
+    }
+
+    /// 写入单个配置键，后出现的参数覆盖先出现的参数
+    ///
+    /// `source` 只包含参数名和配置键，参数值可能是密钥，不记录到来源和日志中
+    fn insert(&mut self, key: &str, raw: &str, source: String) -> Result<(), ConfigError> {
+        let key = key.trim();
+        if key.is_empty() || key.split('.').any(str::is_empty) {
+            return Err(ConfigError::ParseError {
+                source: format!("无效的命令行配置键: {}", source).into(),
+            });
+        }
+        debug!("命令行参数覆盖配置: {}", key);
+        insert_indexed_value(&mut self.config, key, infer_text_value(raw));
+        self.arguments.insert(key.to_string(), source);
+        Ok(())
+    }
+}
+
+#[async_trait]
+impl ConfigProvider for CommandLineConfigProvider {
+    async fn get_configuration(&self, key: &str) -> Result<Value, ConfigError> {
+        config_abstractions::get_nested_value(&self.config, key)
+            .cloned()
+            .ok_or_else(|| ConfigError::KeyNotFound { key: key.to_string() })
+    }
+
+    async fn get_section(&self, section_name: &str) -> Result<ConfigSection, ConfigError> {
+        match config_abstractions::get_nested_value(&self.config, section_name) {
+            Some(Value::Object(obj)) => {
+                let mut section = ConfigSection::new();
+                for (key, value) in obj {
+                    section.insert(key.clone(), value.clone());
+                }
+                Ok(section)
+            }
+            Some(_) => Err(ConfigError::TypeConversionError {
+                message: format!("配置节 {} 不是对象类型", section_name),
+            }),
+            None => Err(ConfigError::KeyNotFound {
+                key: section_name.to_string(),
+            }),
+        }
+    }
+
+    async fn reload(&mut self) -> Result<(), ConfigError> {
+        // 命令行参数在进程生命周期内不变
+        Ok(())
+    }
+
+    async fn contains_key(&self, key: &str) -> Result<bool, ConfigError> {
+        Ok(config_abstractions::get_nested_value(&self.config, key).is_some())
+    }
+
+    async fn get_all_keys(&self) -> Result<Vec<String>, ConfigError> {
+        Ok(config_abstractions::flatten_leaves(&self.config)
+            .into_keys()
+            .collect())
+    }
+
+    fn name(&self) -> &str {
+        "CommandLineConfigProvider"
+    }
+
+    fn priority(&self) -> i32 {
+        self.priority
+    }
+
+    async fn get_all_configuration(&self) -> Result<Value, ConfigError> {
+        Ok(self.config.clone())
+    }
+
+    fn source_description(&self) -> String {
+        "cli".to_string()
+    }
+
+    fn key_source(&self, key: &str) -> String {
+        match self.arguments.get(key) {
+            Some(argument) => format!("cli:{}", argument),
+            None => self.source_description(),
+        }
     }
 }
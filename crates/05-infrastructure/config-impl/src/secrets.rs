@@ -0,0 +1,421 @@
+//! 密钥提供者实现
+//!
+//! 提供 Docker/K8s 密钥文件目录、AES-256-GCM 加密的本地密钥文件和内存密钥提供者，
+//! 以及解析配置值中 `${secret:name}` 引用的 [`SecretResolver`]
+
+use aes_gcm::aead::{Aead, AeadCore, KeyInit, OsRng};
+use aes_gcm::{Aes256Gcm, Nonce};
+use async_trait::async_trait;
+use base64::engine::general_purpose::STANDARD as BASE64;
+use base64::Engine;
+use config_abstractions::{
+    parse_secret_references, ExposeSecret, Secret, SecretProvider, SecretString,
+};
+use infrastructure_common::ConfigError;
+use parking_lot::RwLock;
+use serde_json::Value;
+use std::collections::HashMap;
+use std::path::{Path, PathBuf};
+use std::sync::Arc;
+use tracing::{debug, info};
+
+/// Docker/K8s 默认的密钥挂载目录
+pub const DEFAULT_SECRETS_DIRECTORY: &str = "/run/secrets";
+
+/// AES-GCM 随机数长度（字节）
+const NONCE_LENGTH: usize = 12;
+
+/// 密钥文件加密密钥（AES-256）
+#[derive(Clone)]
+pub struct SecretEncryptionKey {
+    bytes: Secret<[u8; 32]>,
+}
+
+impl SecretEncryptionKey {
+    /// 从 32 字节原始密钥创建
+    pub fn from_bytes(bytes: &[u8]) -> Result<Self, ConfigError> {
+        let bytes: [u8; 32] = bytes.try_into().map_err(|_| ConfigError::SecretError {
+            message: format!("加密密钥长度必须为 32 字节，实际为 {} 字节", bytes.len()),
+        })?;
+        Ok(Self {
+            bytes: Secret::new(bytes),
+        })
+    }
+
+    /// 从 Base64 编码的密钥创建
+    pub fn from_base64(encoded: &str) -> Result<Self, ConfigError> {
+        let bytes = BASE64
+            .decode(encoded.trim())
+            .map_err(|_| ConfigError::SecretError {
+                message: "加密密钥不是有效的 Base64 编码".to_string(),
+            })?;
+        Self::from_bytes(&bytes)
+    }
+
+    /// 从环境变量读取 Base64 编码的密钥
+    pub fn from_env(var_name: &str) -> Result<Self, ConfigError> {
+        let encoded = std::env::var(var_name).map_err(|_| ConfigError::SecretError {
+            message: format!("加密密钥环境变量未设置: {}", var_name),
+        })?;
+        Self::from_base64(&encoded)
+    }
+
+    /// 从密钥文件读取 Base64 编码的密钥
+    pub fn from_file(path: impl AsRef<Path>) -> Result<Self, ConfigError> {
+        let path = path.as_ref();
+        let encoded = std::fs::read_to_string(path).map_err(|e| ConfigError::SecretError {
+            message: format!("读取加密密钥文件失败: {}: {}", path.display(), e),
+        })?;
+        Self::from_base64(&encoded)
+    }
+
+    /// 随机生成新密钥
+    pub fn generate() -> Self {
+        let key = Aes256Gcm::generate_key(OsRng);
+        let mut bytes = [0u8; 32];
+        bytes.copy_from_slice(&key);
+        Self {
+            bytes: Secret::new(bytes),
+        }
+    }
+
+    /// 导出 Base64 编码的密钥
+    pub fn to_base64(&self) -> SecretString {
+        SecretString::new(BASE64.encode(self.bytes.expose_secret()))
+    }
+
+    /// 加密数据，返回 Base64 编码的 `随机数 || 密文`
+    pub fn encrypt(&self, plaintext: &[u8]) -> Result<String, ConfigError> {
+        let nonce = Aes256Gcm::generate_nonce(OsRng);
+        let ciphertext =
+            self.cipher()
+                .encrypt(&nonce, plaintext)
+                .map_err(|_| ConfigError::SecretError {
+                    message: "数据加密失败".to_string(),
+                })?;
+
+        let mut payload = nonce.to_vec();
+        payload.extend_from_slice(&ciphertext);
+        Ok(BASE64.encode(payload))
+    }
+
+    /// 解密 [`encrypt`](Self::encrypt) 生成的数据
+    pub fn decrypt(&self, encoded: &str) -> Result<Vec<u8>, ConfigError> {
+        let payload = BASE64
+            .decode(encoded.trim())
+            .map_err(|_| ConfigError::SecretError {
+                message: "密文不是有效的 Base64 编码".to_string(),
+            })?;
+        if payload.len() <= NONCE_LENGTH {
+            return Err(ConfigError::SecretError {
+                message: "密文长度无效".to_string(),
+            });
+        }
+
+        let (nonce, ciphertext) = payload.split_at(NONCE_LENGTH);
+        self.cipher()
+            .decrypt(Nonce::from_slice(nonce), ciphertext)
+            .map_err(|_| ConfigError::SecretError {
+                message: "解密失败：密钥错误或数据已被篡改".to_string(),
+            })
+    }
+
+    /// 创建 AES-256-GCM 加密器
+    fn cipher(&self) -> Aes256Gcm {
+        Aes256Gcm::new(self.bytes.expose_secret().into())
+    }
+}
+
+impl std::fmt::Debug for SecretEncryptionKey {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("SecretEncryptionKey")
+            .finish_non_exhaustive()
+    }
+}
+
+/// 密钥文件目录提供者
+///
+/// 目录中每个文件是一个密钥，文件名即密钥名，适用于 Docker secrets
+/// （`/run/secrets`）和 K8s 挂载的 Secret 卷
+#[derive(Debug, Clone)]
+pub struct SecretFileProvider {
+    /// 密钥目录
+    directory: PathBuf,
+}
+
+impl SecretFileProvider {
+    /// 创建指定目录的密钥文件提供者
+    pub fn new(directory: impl AsRef<Path>) -> Self {
+        Self {
+            directory: directory.as_ref().to_path_buf(),
+        }
+    }
+
+    /// 使用 Docker/K8s 默认的 `/run/secrets` 目录
+    pub fn docker_default() -> Self {
+        Self::new(DEFAULT_SECRETS_DIRECTORY)
+    }
+
+    /// 获取密钥目录
+    pub fn directory(&self) -> &Path {
+        &self.directory
+    }
+}
+
+#[async_trait]
+impl SecretProvider for SecretFileProvider {
+    fn name(&self) -> &str {
+        "SecretFileProvider"
+    }
+
+    async fn get_secret(&self, name: &str) -> Result<Option<SecretString>, ConfigError> {
+        // 禁止通过密钥名访问目录之外的文件
+        if name.is_empty() || name.starts_with('.') || name.contains(['/', '\\']) {
+            return Err(ConfigError::SecretError {
+                message: format!("无效的密钥名称: {}", name),
+            });
+        }
+
+        let path = self.directory.join(name);
+        if !path.is_file() {
+            return Ok(None);
+        }
+
+        let content =
+            tokio::fs::read_to_string(&path)
+                .await
+                .map_err(|e| ConfigError::SecretError {
+                    message: format!("读取密钥文件失败: {}: {}", path.display(), e),
+                })?;
+        debug!("从密钥文件读取密钥: {}", name);
+
+        Ok(Some(SecretString::new(
+            content.trim_end_matches(['\r', '\n']).to_string(),
+        )))
+    }
+}
+
+/// 加密的本地密钥文件提供者
+///
+/// 文件内容为 [`SecretEncryptionKey::encrypt`] 加密后的 JSON 对象（密钥名 → 密钥值）
+#[derive(Debug)]
+pub struct EncryptedFileSecretProvider {
+    /// 密钥文件路径
+    path: PathBuf,
+    /// 解密密钥
+    key: SecretEncryptionKey,
+    /// 解密后的密钥
+    secrets: RwLock<HashMap<String, SecretString>>,
+}
+
+impl EncryptedFileSecretProvider {
+    /// 加载加密的密钥文件
+    pub fn new(path: impl AsRef<Path>, key: SecretEncryptionKey) -> Result<Self, ConfigError> {
+        let path = path.as_ref().to_path_buf();
+        let secrets = Self::load(&path, &key)?;
+        info!(
+            "加载加密密钥文件: {} ({} 个密钥)",
+            path.display(),
+            secrets.len()
+        );
+
+        Ok(Self {
+            path,
+            key,
+            secrets: RwLock::new(secrets),
+        })
+    }
+
+    /// 加密并写入密钥文件
+    pub fn write(
+        path: impl AsRef<Path>,
+        key: &SecretEncryptionKey,
+        secrets: &HashMap<String, String>,
+    ) -> Result<(), ConfigError> {
+        let plaintext = serde_json::to_vec(secrets)?;
+        std::fs::write(path, key.encrypt(&plaintext)?)?;
+        Ok(())
+    }
+
+    /// 读取并解密密钥文件
+    fn load(
+        path: &Path,
+        key: &SecretEncryptionKey,
+    ) -> Result<HashMap<String, SecretString>, ConfigError> {
+        if !path.exists() {
+            return Err(ConfigError::FileNotFound {
+                path: path.display().to_string(),
+            });
+        }
+
+        let encoded = std::fs::read_to_string(path)?;
+        let plaintext = key
+            .decrypt(&encoded)
+            .map_err(|e| ConfigError::SecretError {
+                message: format!("{}: {}", path.display(), e),
+            })?;
+        let secrets: HashMap<String, String> =
+            serde_json::from_slice(&plaintext).map_err(|_| ConfigError::SecretError {
+                message: format!("密钥文件内容不是有效的 JSON 对象: {}", path.display()),
+            })?;
+
+        Ok(secrets
+            .into_iter()
+            .map(|(name, value)| (name, SecretString::new(value)))
+            .collect())
+    }
+}
+
+#[async_trait]
+impl SecretProvider for EncryptedFileSecretProvider {
+    fn name(&self) -> &str {
+        "EncryptedFileSecretProvider"
+    }
+
+    async fn get_secret(&self, name: &str) -> Result<Option<SecretString>, ConfigError> {
+        Ok(self.secrets.read().get(name).cloned())
+    }
+
+    async fn reload(&self) -> Result<(), ConfigError> {
+        let secrets = Self::load(&self.path, &self.key)?;
+        *self.secrets.write() = secrets;
+        info!("重新加载加密密钥文件: {}", self.path.display());
+        Ok(())
+    }
+}
+
+/// 内存密钥提供者
+///
+/// 主要用于测试和本地开发
+#[derive(Debug, Default)]
+pub struct MemorySecretProvider {
+    /// 密钥
+    secrets: RwLock<HashMap<String, SecretString>>,
+}
+
+impl MemorySecretProvider {
+    /// 创建空的内存密钥提供者
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// 添加密钥
+    pub fn with_secret(self, name: impl Into<String>, value: impl Into<String>) -> Self {
+        self.insert(name, value);
+        self
+    }
+
+    /// 设置密钥
+    pub fn insert(&self, name: impl Into<String>, value: impl Into<String>) {
+        self.secrets
+            .write()
+            .insert(name.into(), SecretString::new(value.into()));
+    }
+
+    /// 移除密钥
+    pub fn remove(&self, name: &str) {
+        self.secrets.write().remove(name);
+    }
+}
+
+#[async_trait]
+impl SecretProvider for MemorySecretProvider {
+    fn name(&self) -> &str {
+        "MemorySecretProvider"
+    }
+
+    async fn get_secret(&self, name: &str) -> Result<Option<SecretString>, ConfigError> {
+        Ok(self.secrets.read().get(name).cloned())
+    }
+}
+
+/// 密钥引用解析器
+///
+/// 按注册顺序依次查询密钥提供者，使用第一个找到的密钥
+#[derive(Clone, Default)]
+pub struct SecretResolver {
+    /// 密钥提供者列表
+    providers: Vec<Arc<dyn SecretProvider>>,
+}
+
+impl SecretResolver {
+    /// 创建新的解析器
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// 添加密钥提供者
+    pub fn add_provider(&mut self, provider: Arc<dyn SecretProvider>) {
+        info!("注册密钥提供者: {}", provider.name());
+        self.providers.push(provider);
+    }
+
+    /// 获取密钥提供者数量
+    pub fn provider_count(&self) -> usize {
+        self.providers.len()
+    }
+
+    /// 按名称解析密钥
+    pub async fn resolve(&self, name: &str) -> Result<SecretString, ConfigError> {
+        for provider in &self.providers {
+            if let Some(secret) = provider.get_secret(name).await? {
+                debug!("密钥 {} 由 {} 提供", name, provider.name());
+                return Ok(secret);
+            }
+        }
+        Err(ConfigError::SecretNotFound {
+            name: name.to_string(),
+        })
+    }
+
+    /// 替换配置值中所有的 `${secret:name}` 引用
+    pub async fn resolve_value(&self, value: &Value) -> Result<Value, ConfigError> {
+        match value {
+            Value::String(s) => Ok(Value::String(self.resolve_string(s).await?)),
+            Value::Array(items) => {
+                let mut resolved = Vec::with_capacity(items.len());
+                for item in items {
+                    resolved.push(Box::pin(self.resolve_value(item)).await?);
+                }
+                Ok(Value::Array(resolved))
+            }
+            Value::Object(map) => {
+                let mut resolved = serde_json::Map::with_capacity(map.len());
+                for (key, child) in map {
+                    resolved.insert(key.clone(), Box::pin(self.resolve_value(child)).await?);
+                }
+                Ok(Value::Object(resolved))
+            }
+            other => Ok(other.clone()),
+        }
+    }
+
+    /// 替换字符串中的密钥引用
+    async fn resolve_string(&self, value: &str) -> Result<String, ConfigError> {
+        let references = parse_secret_references(value);
+        if references.is_empty() {
+            return Ok(value.to_string());
+        }
+
+        let mut resolved = String::with_capacity(value.len());
+        let mut last = 0;
+        for reference in references {
+            resolved.push_str(&value[last..reference.start]);
+            resolved.push_str(self.resolve(&reference.name).await?.expose_secret());
+            last = reference.end;
+        }
+        resolved.push_str(&value[last..]);
+        Ok(resolved)
+    }
+}
+
+impl std::fmt::Debug for SecretResolver {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("SecretResolver")
+            .field(
+                "providers",
+                &self.providers.iter().map(|p| p.name()).collect::<Vec<_>>(),
+            )
+            .finish()
+    }
+}
@@ -0,0 +1,125 @@
+//! 命令行参数配置提供者测试
+
+use super::super::*;
+use config_abstractions::{ConfigManager, ConfigProvider};
+use infrastructure_common::ConfigError;
+use serde_json::json;
+
+/// 测试各种参数形式映射为配置键，非配置参数被忽略
+#[tokio::test]
+async fn test_argument_forms() {
+    let provider = CommandLineConfigProvider::new([
+        "--verbose",
+        "--config",
+        "/etc/app.toml",
+        "--server.port=9090",
+        "--server.host",
+        "127.0.0.1",
+        "--set",
+        "dsp.endpoints.0.url=http://a",
+        "--set=geo.zip=01234",
+        "--pacing.ratio",
+        "-0.5",
+        "--server.port=9191",
+        "--",
+        "--ignored.key=1",
+    ])
+    .unwrap();
+
+    assert_eq!(
+        provider.get_all_configuration().await.unwrap(),
+        json!({
+            "server": {"port": 9191, "host": "127.0.0.1"},
+            "dsp": {"endpoints": [{"url": "http://a"}]},
+            "geo": {"zip": "01234"},
+            "pacing": {"ratio": -0.5}
+        })
+    );
+    assert_eq!(provider.key_source("server.port"), "cli:--server.port");
+    assert_eq!(provider.key_source("geo.zip"), "cli:--set geo.zip");
+    assert_eq!(
+        provider.key_source("dsp.endpoints.0.url"),
+        "cli:--set dsp.endpoints.0.url"
+    );
+}
+
+/// 测试格式错误的参数
+#[tokio::test]
+async fn test_malformed_arguments_rejected() {
+    for args in [
+        vec!["--set"],
+        vec!["--set", "server.port"],
+        vec!["--set=.port=1"],
+        vec!["--server.port"],
+        vec!["--server.port", "--verbose"],
+    ] {
+        assert!(
+            matches!(
+                CommandLineConfigProvider::new(args.clone()),
+                Err(ConfigError::ParseError { .. })
+            ),
+            "{:?} 应该解析失败",
+            args
+        );
+    }
+}
+
+/// 测试命令行参数默认优先级最高，覆盖环境变量和内存配置
+#[tokio::test]
+async fn test_command_line_has_highest_priority() {
+    std::env::set_var("CLIT1__SERVER__PORT", "7000");
+
+    let mut manager = manager::AdSystemConfigManager::new();
+    manager
+        .register_provider(Box::new(
+            CommandLineConfigProvider::from_overrides(["server.port=9000"]).unwrap(),
+        ))
+        .await
+        .unwrap();
+    manager
+        .register_provider(Box::new(EnvironmentConfigProviderImpl::new("CLIT1").unwrap()))
+        .await
+        .unwrap();
+    manager
+        .register_provider(Box::new(
+            MemoryConfigProvider::new("runtime").with_values(json!({"server": {"port": 8000}})),
+        ))
+        .await
+        .unwrap();
+
+    assert_eq!(
+        manager.get_configuration("server.port").await.unwrap(),
+        json!(9000)
+    );
+    let explanation = manager.explain_configuration("server.port").await.unwrap();
+    assert_eq!(
+        explanation.winner.unwrap().source,
+        "cli:--set server.port"
+    );
+    assert_eq!(explanation.shadowed.len(), 2);
+}
+
+/// 测试命令行传入的密钥不会出现在配置导出和来源查询中
+#[tokio::test]
+async fn test_command_line_secrets_not_exposed_in_sources() {
+    let mut manager = manager::AdSystemConfigManager::new();
+    manager
+        .register_provider(Box::new(
+            CommandLineConfigProvider::new([
+                "--set",
+                "db.password=hunter2",
+                "--dsp.api_key=abc123",
+            ])
+            .unwrap(),
+        ))
+        .await
+        .unwrap();
+
+    let dump = manager.dump_effective_configuration().await.unwrap();
+    let dumped = serde_json::to_string(&dump).unwrap();
+    assert!(!dumped.contains("hunter2"));
+    assert!(!dumped.contains("abc123"));
+
+    let explanation = manager.explain_configuration("db.password").await.unwrap();
+    assert_eq!(explanation.winner.unwrap().source, "cli:--set db.password");
+}
@@ -0,0 +1,387 @@
+//! 配置历史、审计与回滚测试
+
+use super::super::*;
+use async_trait::async_trait;
+use config_abstractions::events::ConfigChangeEventType;
+use config_abstractions::{
+    insert_nested_value, ConfigAuditRecord, ConfigKeyChange, ConfigManager,
+    ConfigProvider, REDACTED_VALUE,
+};
+use infrastructure_common::{ConfigError, ConfigSection};
+use serde_json::{json, Value};
+use std::sync::Arc;
+
+/// 辅助函数：创建包含 TOML 配置文件的管理器
+async fn create_manager(
+    dir: &tempfile::TempDir,
+    content: &str,
+) -> (manager::AdSystemConfigManager, std::path::PathBuf) {
+    let path = dir.path().join("app.toml");
+    std::fs::write(&path, content).unwrap();
+
+    let mut manager = manager::AdSystemConfigManager::new();
+    manager
+        .register_provider(Box::new(TomlConfigProvider::new(&path).unwrap()))
+        .await
+        .unwrap();
+    (manager, path)
+}
+
+/// 测试用可写内存配置提供者
+struct WritableProvider {
+    data: Arc<parking_lot::Mutex<Value>>,
+}
+
+#[async_trait]
+impl ConfigProvider for WritableProvider {
+    async fn get_configuration(&self, key: &str) -> Result<Value, ConfigError> {
+        config_abstractions::get_nested_value(&self.data.lock(), key)
+            .cloned()
+            .ok_or_else(|| ConfigError::KeyNotFound {
+                key: key.to_string(),
+            })
+    }
+
+    async fn get_section(&self, section_name: &str) -> Result<ConfigSection, ConfigError> {
+        let value = self.get_configuration(section_name).await?;
+        Ok(ConfigSection {
+            data: serde_json::from_value(value)?,
+        })
+    }
+
+    async fn reload(&mut self) -> Result<(), ConfigError> {
+        Ok(())
+    }
+
+    async fn contains_key(&self, key: &str) -> Result<bool, ConfigError> {
+        Ok(config_abstractions::get_nested_value(&self.data.lock(), key).is_some())
+    }
+
+    async fn get_all_keys(&self) -> Result<Vec<String>, ConfigError> {
+        Ok(config_abstractions::flatten_leaves(&self.data.lock())
+            .into_keys()
+            .collect())
+    }
+
+    fn name(&self) -> &str {
+        "writable"
+    }
+
+    async fn get_all_configuration(&self) -> Result<Value, ConfigError> {
+        Ok(self.data.lock().clone())
+    }
+
+    fn is_writable(&self) -> bool {
+        true
+    }
+
+    async fn apply_changes(&mut self, changes: &[ConfigKeyChange]) -> Result<(), ConfigError> {
+        let mut leaves = config_abstractions::flatten_leaves(&self.data.lock());
+        for change in changes {
+            match &change.new_value {
+                Some(value) => {
+                    leaves.insert(change.key.clone(), value.clone());
+                }
+                None => {
+                    leaves.remove(&change.key);
+                }
+            }
+        }
+        let mut data = json!({});
+        for (key, value) in leaves {
+            insert_nested_value(&mut data, &key, value);
+        }
+        *self.data.lock() = data;
+        Ok(())
+    }
+}
+
+/// 测试历史保存到本地目录，重启后保留且版本号在裁剪后仍单调递增
+#[tokio::test]
+async fn test_file_history_survives_restart_with_monotonic_versions() {
+    let dir = tempfile::tempdir().unwrap();
+    let history_dir = dir.path().join("history");
+    let (mut manager, path) = create_manager(&dir, "[server]\nport = 8000\n").await;
+    manager.set_history_store(Arc::new(
+        FileConfigHistoryStore::open(&history_dir).await.unwrap(),
+    ));
+    manager.set_max_history_size(2);
+
+    for port in 8001..=8003 {
+        std::fs::write(&path, format!("[server]\nport = {}\n", port)).unwrap();
+        manager.reload_and_notify("test").await.unwrap();
+    }
+    let versions: Vec<u64> = manager
+        .get_config_history()
+        .await
+        .iter()
+        .map(|snapshot| snapshot.version)
+        .collect();
+    assert_eq!(versions, vec![3, 4]);
+    drop(manager);
+
+    // 重新打开历史目录，版本号继续递增
+    let (mut restarted, path) = create_manager(&dir, "[server]\nport = 8003\n").await;
+    restarted.set_history_store(Arc::new(
+        FileConfigHistoryStore::open(&history_dir).await.unwrap(),
+    ));
+    restarted.set_max_history_size(2);
+    let history = restarted.get_config_history().await;
+    assert_eq!(history.last().unwrap().config_data["server"], json!({"port": 8003}));
+
+    restarted.clear_config_history().await.unwrap();
+    std::fs::write(&path, "[server]\nport = 9000\n").unwrap();
+    restarted.reload_and_notify("test").await.unwrap();
+    let versions: Vec<u64> = restarted
+        .get_config_history()
+        .await
+        .iter()
+        .map(|snapshot| snapshot.version)
+        .collect();
+    assert_eq!(versions, vec![5, 6]);
+}
+
+/// 测试每次变更都记录操作者、原因和来源
+#[tokio::test]
+async fn test_reload_records_audit() {
+    let dir = tempfile::tempdir().unwrap();
+    let (manager, path) = create_manager(&dir, "[server]\nport = 8000\n").await;
+
+    std::fs::write(&path, "[server]\nport = 8001\n").unwrap();
+    manager
+        .reload_with_audit(
+            ConfigAuditRecord::new("admin-api")
+                .with_actor("alice")
+                .with_reason("扩容"),
+        )
+        .await
+        .unwrap();
+
+    let history = manager.get_config_history().await;
+    assert_eq!(history.len(), 2);
+    assert_eq!(history[0].audit.actor, "system");
+    let audit = &history[1].audit;
+    assert_eq!(audit.actor, "alice");
+    assert_eq!(audit.reason.as_deref(), Some("扩容"));
+    assert_eq!(audit.source, "admin-api");
+}
+
+/// 测试任意两个版本之间的结构化差异，敏感配置已脱敏
+#[tokio::test]
+async fn test_diff_between_versions() {
+    let dir = tempfile::tempdir().unwrap();
+    let (manager, path) = create_manager(
+        &dir,
+        "[db]\nhost = \"a\"\npassword = \"old\"\nlegacy = true\n",
+    )
+    .await;
+
+    std::fs::write(&path, "[db]\nhost = \"b\"\npassword = \"old\"\n").unwrap();
+    manager.reload_and_notify("test").await.unwrap();
+    std::fs::write(&path, "[db]\nhost = \"b\"\npassword = \"new\"\npool = 4\n").unwrap();
+    manager.reload_and_notify("test").await.unwrap();
+
+    let diff = manager.diff_versions(1, 3).await.unwrap();
+    assert_eq!((diff.from_version, diff.to_version), (1, 3));
+    let keys: Vec<&str> = diff.changes.iter().map(|change| change.key.as_str()).collect();
+    assert_eq!(keys, vec!["db.host", "db.legacy", "db.password", "db.pool"]);
+    assert_eq!(diff.changes[0].old_value, Some(json!("a")));
+    assert_eq!(diff.changes[0].new_value, Some(json!("b")));
+    assert_eq!(diff.changes[1].new_value, None);
+    assert_eq!(diff.changes[2].new_value, Some(json!(REDACTED_VALUE)));
+
+    assert!(manager.diff_versions(2, 2).await.unwrap().is_empty());
+    assert!(matches!(
+        manager.diff_versions(1, 42).await,
+        Err(ConfigError::VersionNotFound { version: 42 })
+    ));
+}
+
+/// 测试回滚将配置写回可写提供者、通知监听器并记录为新版本
+#[tokio::test]
+async fn test_rollback_reapplies_to_writable_provider() {
+    let data = Arc::new(parking_lot::Mutex::new(json!({"feature": {"enabled": false}})));
+    let mut manager = manager::AdSystemConfigManager::new();
+    manager
+        .register_provider(Box::new(WritableProvider { data: data.clone() }))
+        .await
+        .unwrap();
+    manager.reload_and_notify("test").await.unwrap();
+
+    *data.lock() = json!({"feature": {"enabled": true, "rollout": 50}});
+    manager.reload_and_notify("test").await.unwrap();
+    let mut changes = manager.subscribe_changes();
+
+    let events = manager
+        .rollback_with_audit(1, ConfigAuditRecord::new("admin-api").with_actor("bob"))
+        .await
+        .unwrap();
+    let summary: Vec<_> = events
+        .iter()
+        .map(|event| (event.path.as_str(), event.event_type.clone()))
+        .collect();
+    assert_eq!(
+        summary,
+        vec![
+            ("feature.enabled", ConfigChangeEventType::Updated),
+            ("feature.rollout", ConfigChangeEventType::Deleted),
+        ]
+    );
+    assert_eq!(changes.recv().await.unwrap().path, "feature.enabled");
+    assert_eq!(*data.lock(), json!({"feature": {"enabled": false}}));
+
+    let history = manager.get_config_history().await;
+    let versions: Vec<u64> = history.iter().map(|snapshot| snapshot.version).collect();
+    assert_eq!(versions, vec![1, 2, 3]);
+    assert_eq!(history[2].audit.actor, "bob");
+    assert_eq!(history[2].description, "Rolled back to version 1");
+
+    // 提供者中已是回滚后的值，重载不会撤销回滚
+    assert!(manager.reload_and_notify("test").await.unwrap().is_empty());
+    assert_eq!(
+        manager.get_configuration("feature.enabled").await.unwrap(),
+        json!(false)
+    );
+}
+
+/// 测试只读提供者的回滚固定使用快照配置并通知监听器
+#[tokio::test]
+async fn test_rollback_pins_snapshot_for_read_only_provider() {
+    let dir = tempfile::tempdir().unwrap();
+    let (manager, path) = create_manager(&dir, "[server]
+port = 8000
+").await;
+    assert!(matches!(
+        manager.rollback_last_change().await,
+        Err(ConfigError::NoRollbackAvailable)
+    ));
+
+    std::fs::write(&path, "[server]
+port = 8001
+").unwrap();
+    manager.reload_and_notify("test").await.unwrap();
+
+    let events = manager.rollback_last_change().await.unwrap();
+    assert_eq!(events.len(), 1);
+    assert_eq!(events[0].old_value, Some(json!(8001)));
+    assert_eq!(events[0].new_value, Some(json!(8000)));
+    assert_eq!(
+        manager.get_configuration("server.port").await.unwrap(),
+        json!(8000)
+    );
+    assert_eq!(manager.get_config_history().await.last().unwrap().version, 3);
+}
+
+/// 测试回滚写回快照中未插值的原始值，占位符不会被替换为解析后的字面量
+#[tokio::test]
+async fn test_rollback_writes_back_raw_placeholders() {
+    let original = json!({"server": {"host": "a.example", "url": "https://${server.host}/bid"}});
+    let data = Arc::new(parking_lot::Mutex::new(original.clone()));
+    let mut manager = manager::AdSystemConfigManager::new();
+    manager
+        .register_provider(Box::new(WritableProvider { data: data.clone() }))
+        .await
+        .unwrap();
+    manager.reload_and_notify("test").await.unwrap();
+
+    *data.lock() = json!({"server": {"host": "a.example", "url": "https://b.example/bid"}});
+    manager.reload_and_notify("test").await.unwrap();
+
+    let events = manager.rollback_to_version(1).await.unwrap();
+    assert_eq!(events.len(), 1);
+    assert_eq!(*data.lock(), original);
+    assert_eq!(
+        manager.get_configuration("server.url").await.unwrap(),
+        json!("https://a.example/bid")
+    );
+}
+
+/// 测试差异键来自其他配置源时回滚只在内存中生效，不写入可写提供者
+#[tokio::test]
+async fn test_rollback_does_not_copy_other_sources_into_writable_provider() {
+    let dir = tempfile::tempdir().unwrap();
+    let path = dir.path().join("override.toml");
+    std::fs::write(&path, "[feature]\nrollout = 10\n").unwrap();
+
+    let data = Arc::new(parking_lot::Mutex::new(json!({"feature": {"enabled": true}})));
+    let mut manager = manager::AdSystemConfigManager::new();
+    manager
+        .register_provider(Box::new(WritableProvider { data: data.clone() }))
+        .await
+        .unwrap();
+    manager
+        .register_provider(Box::new(
+            TomlConfigProvider::new(&path).unwrap().with_priority(200),
+        ))
+        .await
+        .unwrap();
+    manager.reload_and_notify("test").await.unwrap();
+
+    std::fs::write(&path, "[feature]\nrollout = 20\n").unwrap();
+    manager.reload_and_notify("test").await.unwrap();
+
+    manager.rollback_to_version(1).await.unwrap();
+    assert_eq!(*data.lock(), json!({"feature": {"enabled": true}}));
+    assert_eq!(
+        manager.get_configuration("feature.rollout").await.unwrap(),
+        json!(10)
+    );
+}
+
+/// 要求功能开关启用的测试验证器
+struct RequireEnabledValidator;
+
+/// 测试用功能配置
+#[derive(Debug, serde::Deserialize)]
+struct FeatureOptions {
+    enabled: bool,
+}
+
+#[async_trait]
+impl config_abstractions::ConfigValidator<FeatureOptions> for RequireEnabledValidator {
+    async fn validate(
+        &self,
+        config: &FeatureOptions,
+    ) -> Result<(), infrastructure_common::ValidationError> {
+        if config.enabled {
+            Ok(())
+        } else {
+            Err(infrastructure_common::ValidationError::new("功能必须启用"))
+        }
+    }
+
+    fn name(&self) -> &str {
+        "RequireEnabledValidator"
+    }
+}
+
+/// 测试回滚目标未通过验证时不写入提供者、不固定配置也不记录历史
+#[tokio::test]
+async fn test_invalid_rollback_target_rejected_without_side_effects() {
+    let data = Arc::new(parking_lot::Mutex::new(json!({"feature": {"enabled": false}})));
+    let mut manager = manager::AdSystemConfigManager::new();
+    manager
+        .register_provider(Box::new(WritableProvider { data: data.clone() }))
+        .await
+        .unwrap();
+    manager.reload_and_notify("test").await.unwrap();
+
+    *data.lock() = json!({"feature": {"enabled": true}});
+    manager.reload_and_notify("test").await.unwrap();
+    manager.register_options::<FeatureOptions>("feature");
+    manager
+        .register_validator::<FeatureOptions>(Box::new(RequireEnabledValidator))
+        .await
+        .unwrap();
+
+    assert!(matches!(
+        manager.rollback_to_version(1).await,
+        Err(ConfigError::ValidationFailed { .. })
+    ));
+    assert_eq!(*data.lock(), json!({"feature": {"enabled": true}}));
+    assert_eq!(manager.get_config_history().await.len(), 2);
+
+    *data.lock() = json!({"feature": {"enabled": true}, "extra": 1});
+    manager.reload_and_notify("test").await.unwrap();
+    assert_eq!(manager.get_configuration("extra").await.unwrap(), json!(1));
+}
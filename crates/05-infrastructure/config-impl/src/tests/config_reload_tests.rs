@@ -0,0 +1,142 @@
+//! 配置重载流水线测试
+
+use super::super::*;
+use config_abstractions::events::ConfigChangeEventType;
+use config_abstractions::{ConfigManager, ConfigWatcher, FileSystemConfigWatcher};
+use infrastructure_common::ConfigError;
+use serde_json::json;
+use std::sync::Arc;
+use std::time::Duration;
+use tokio::sync::Mutex;
+
+/// 辅助函数：在临时目录中创建 TOML 配置文件并注册到管理器
+async fn create_manager(
+    dir: &tempfile::TempDir,
+    content: &str,
+) -> (manager::AdSystemConfigManager, std::path::PathBuf) {
+    let path = dir.path().join("app.toml");
+    std::fs::write(&path, content).unwrap();
+
+    let mut manager = manager::AdSystemConfigManager::new();
+    manager
+        .register_provider(Box::new(TomlConfigProvider::new(&path).unwrap()))
+        .await
+        .unwrap();
+    (manager, path)
+}
+
+/// 测试重载后按配置键发送包含新旧值的变更事件
+#[tokio::test]
+async fn test_reload_emits_per_key_changes() {
+    let dir = tempfile::tempdir().unwrap();
+    let (manager, path) = create_manager(
+        &dir,
+        "[server]\nhost = \"old.internal\"\nport = 8080\nlegacy = true\n",
+    )
+    .await;
+    let mut changes = manager.subscribe_changes();
+    assert_eq!(
+        manager.get_configuration("server.port").await.unwrap(),
+        json!(8080)
+    );
+
+    std::fs::write(
+        &path,
+        "[server]\nhost = \"old.internal\"\nport = 9090\ntimeout_ms = 50\n",
+    )
+    .unwrap();
+    let events = manager.reload_and_notify("test").await.unwrap();
+
+    let summary: Vec<_> = events
+        .iter()
+        .map(|event| (event.path.as_str(), event.event_type.clone()))
+        .collect();
+    assert_eq!(
+        summary,
+        vec![
+            ("server.legacy", ConfigChangeEventType::Deleted),
+            ("server.port", ConfigChangeEventType::Updated),
+            ("server.timeout_ms", ConfigChangeEventType::Created),
+        ]
+    );
+    assert_eq!(events[1].old_value, Some(json!(8080)));
+    assert_eq!(events[1].new_value, Some(json!(9090)));
+
+    let broadcast = changes.recv().await.unwrap();
+    assert_eq!(broadcast.path, "server.legacy");
+    assert_eq!(
+        manager.get_configuration("server.port").await.unwrap(),
+        json!(9090)
+    );
+
+    // 配置未变化时不发送事件
+    assert!(manager.reload_and_notify("test").await.unwrap().is_empty());
+}
+
+/// 测试无法应用的配置变更自动回滚到上一个快照
+#[tokio::test]
+async fn test_invalid_reload_rolls_back() {
+    let dir = tempfile::tempdir().unwrap();
+    let (manager, path) = create_manager(&dir, "[a]\nvalue = \"stable\"\n").await;
+    let mut changes = manager.subscribe_changes();
+
+    std::fs::write(
+        &path,
+        "[a]\nvalue = \"${b.value}\"\n\n[b]\nvalue = \"${a.value}\"\n",
+    )
+    .unwrap();
+    let result = manager.reload_and_notify("test").await;
+    assert!(matches!(result, Err(ConfigError::CircularReference { .. })));
+    let status = manager.last_reload_status().unwrap();
+    assert!(!status.is_success());
+    assert_eq!(status.source, "test");
+
+    assert_eq!(
+        manager.get_configuration("a.value").await.unwrap(),
+        json!("stable")
+    );
+    let event = changes.recv().await.unwrap();
+    assert_eq!(event.event_type, ConfigChangeEventType::ValidationFailed);
+
+    // 修复后的配置可以再次生效
+    std::fs::write(&path, "[a]\nvalue = \"fixed\"\n").unwrap();
+    manager.reload_and_notify("test").await.unwrap();
+    assert_eq!(
+        manager.get_configuration("a.value").await.unwrap(),
+        json!("fixed")
+    );
+    assert!(manager.last_reload_status().unwrap().is_success());
+
+    let history = manager.get_config_history().await;
+    let versions: Vec<u64> = history.iter().map(|snapshot| snapshot.version).collect();
+    assert_eq!(versions, vec![1, 2]);
+}
+
+/// 测试文件修改经防抖后触发热重载
+#[tokio::test]
+async fn test_file_change_triggers_hot_reload() {
+    let dir = tempfile::tempdir().unwrap();
+    let (mut manager, path) = create_manager(&dir, "[server]\nport = 8080\n").await;
+    let mut changes = manager.subscribe_changes();
+
+    let mut watcher = watcher::ConfigFileWatcher::new().unwrap();
+    watcher.set_debounce_delay(Duration::from_millis(100));
+    let watcher: Arc<Mutex<dyn ConfigWatcher>> = Arc::new(Mutex::new(watcher));
+    manager.enable_hot_reload(watcher).await.unwrap();
+
+    std::fs::write(&path, "[server]\nport = 9090\n").unwrap();
+
+    let event = tokio::time::timeout(Duration::from_secs(10), changes.recv())
+        .await
+        .expect("等待配置变更事件超时")
+        .unwrap();
+    assert_eq!(event.event_type, ConfigChangeEventType::Updated);
+    assert_eq!(event.path, "server.port");
+    assert_eq!(event.new_value, Some(json!(9090)));
+    assert_eq!(
+        manager.get_configuration("server.port").await.unwrap(),
+        json!(9090)
+    );
+
+    manager.disable_hot_reload().await.unwrap();
+}
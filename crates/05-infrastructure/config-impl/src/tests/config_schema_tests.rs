@@ -0,0 +1,240 @@
+//! 配置 JSON Schema 测试
+
+use super::super::*;
+use config_abstractions::ConfigManager;
+use infrastructure_common::{ConfigError, ConfigSchema, ObjectSchema};
+use serde_json::{json, Value};
+
+/// 测试用服务器配置
+struct ServerOptions;
+
+impl ConfigSchema for ServerOptions {
+    fn config_schema() -> Value {
+        ObjectSchema::new("ServerOptions")
+            .with_description("HTTP 服务器")
+            .deny_unknown_properties()
+            .property("host", String::config_schema(), true)
+            .property(
+                "port",
+                json!({"type": "integer", "minimum": 1, "maximum": 65535}),
+                true,
+            )
+            .property("workers", Option::<u32>::config_schema(), false)
+            .build()
+    }
+}
+
+/// 辅助函数：创建包含 TOML 配置文件的管理器
+async fn create_manager(
+    dir: &tempfile::TempDir,
+    content: &str,
+) -> (manager::AdSystemConfigManager, std::path::PathBuf) {
+    let path = dir.path().join("app.toml");
+    std::fs::write(&path, content).unwrap();
+
+    let mut manager = manager::AdSystemConfigManager::new();
+    manager
+        .register_provider(Box::new(TomlConfigProvider::new(&path).unwrap()))
+        .await
+        .unwrap();
+    (manager, path)
+}
+
+/// 测试验证错误使用 JSON Pointer 路径
+#[tokio::test]
+async fn test_schema_errors_use_json_pointer_paths() {
+    let dir = tempfile::tempdir().unwrap();
+    let (manager, _) =
+        create_manager(&dir, "[http.server]\nport = 70000\ntimeout = 5\n").await;
+    manager.register_config_schema::<ServerOptions>("http.server");
+
+    let result = manager.validate_configuration().await.unwrap();
+    assert!(!result.is_valid);
+
+    let mut paths: Vec<&str> = result.errors.iter().map(|e| e.path.as_str()).collect();
+    paths.sort();
+    assert_eq!(
+        paths,
+        vec!["/http/server/host", "/http/server/port", "/http/server/timeout"]
+    );
+}
+
+/// 测试应用配置 Schema 按配置路径组装并可以导出
+#[tokio::test]
+async fn test_application_schema_export() {
+    let dir = tempfile::tempdir().unwrap();
+    let (manager, _) = create_manager(&dir, "[http.server]\nhost = \"0.0.0.0\"\nport = 8080\n").await;
+    manager.register_config_schema::<ServerOptions>("http.server");
+    manager.register_config_schema::<Option<Vec<String>>>("http.cors_origins");
+
+    let schema = manager.application_schema();
+    assert_eq!(schema["$schema"], json!(infrastructure_common::JSON_SCHEMA_DIALECT));
+    assert_eq!(schema["required"], json!(["http"]));
+    assert_eq!(schema["properties"]["http"]["required"], json!(["server"]));
+    assert_eq!(
+        schema["properties"]["http"]["properties"]["server"]["description"],
+        json!("HTTP 服务器")
+    );
+    assert_eq!(
+        schema["properties"]["http"]["properties"]["cors_origins"]["type"],
+        json!(["array", "null"])
+    );
+    assert!(manager.validate_configuration().await.unwrap().is_valid);
+
+    let export_path = dir.path().join("config.schema.json");
+    manager.export_application_schema(&export_path).await.unwrap();
+    let exported: Value =
+        serde_json::from_str(&std::fs::read_to_string(&export_path).unwrap()).unwrap();
+    assert_eq!(exported, schema);
+}
+
+/// 测试不满足 Schema 的配置变更被回滚
+#[tokio::test]
+async fn test_reload_rejects_schema_violation() {
+    let dir = tempfile::tempdir().unwrap();
+    let (manager, path) =
+        create_manager(&dir, "[server]\nhost = \"0.0.0.0\"\nport = 8080\n").await;
+    manager.register_config_schema::<ServerOptions>("server");
+
+    std::fs::write(&path, "[server]\nhost = \"0.0.0.0\"\nport = 0\n").unwrap();
+    let result = manager.reload_and_notify("test").await;
+    match result {
+        Err(ConfigError::ValidationFailed { errors }) => {
+            assert!(errors.iter().any(|e| e.contains("/server/port")));
+        }
+        other => panic!("期望验证失败，实际: {:?}", other),
+    }
+    assert_eq!(
+        manager.get_configuration("server.port").await.unwrap(),
+        json!(8080)
+    );
+}
+
+/// 辅助函数：按 Schema 验证配置值，返回错误路径和消息
+fn schema_errors(schema: Value, instance: Value) -> Vec<(String, String)> {
+    config_abstractions::SchemaValidator::new(schema)
+        .validate(&instance)
+        .into_iter()
+        .map(|error| (error.path, error.message))
+        .collect()
+}
+
+/// 测试组合关键字 anyOf、oneOf 和 not
+#[test]
+fn test_schema_combinators() {
+    let any_of = json!({"anyOf": [{"type": "string"}, {"type": "integer", "minimum": 10}]});
+    assert!(schema_errors(any_of.clone(), json!("a")).is_empty());
+    assert!(schema_errors(any_of.clone(), json!(12)).is_empty());
+    assert_eq!(schema_errors(any_of, json!(3)).len(), 1);
+
+    let one_of = json!({"oneOf": [{"type": "integer"}, {"minimum": 5}]});
+    assert!(schema_errors(one_of.clone(), json!(1)).is_empty());
+    assert!(schema_errors(one_of.clone(), json!(7.5)).is_empty());
+    let errors = schema_errors(one_of, json!(6));
+    assert_eq!(errors.len(), 1);
+    assert!(errors[0].1.contains("实际满足 2 个"), "{:?}", errors);
+
+    let not = json!({"not": {"enum": ["root", "admin"]}});
+    assert!(schema_errors(not.clone(), json!("ads")).is_empty());
+    assert_eq!(schema_errors(not, json!("root")).len(), 1);
+}
+
+/// 测试文档内 `$ref` 引用
+#[test]
+fn test_schema_refs() {
+    let schema = json!({
+        "$defs": {"port": {"type": "integer", "minimum": 1, "maximum": 65535}},
+        "type": "object",
+        "properties": {
+            "http": {"$ref": "#/$defs/port"},
+            "grpc": {"$ref": "#/$defs/missing"}
+        }
+    });
+    assert!(schema_errors(schema.clone(), json!({"http": 8080})).is_empty());
+    assert_eq!(
+        schema_errors(schema.clone(), json!({"http": 0})),
+        vec![("/http".to_string(), "值超出范围".to_string())]
+    );
+    let errors = schema_errors(schema, json!({"grpc": 9090}));
+    assert_eq!(errors.len(), 1);
+    assert!(errors[0].1.contains("#/$defs/missing"));
+}
+
+/// 测试数组关键字 uniqueItems，数值按 JSON Schema 语义比较
+#[test]
+fn test_schema_unique_items() {
+    let schema = json!({"type": "array", "uniqueItems": true, "items": {"type": "number"}});
+    assert!(schema_errors(schema.clone(), json!([1, 2, 3])).is_empty());
+    assert_eq!(schema_errors(schema.clone(), json!([1, 2, 1.0])).len(), 1);
+    assert_eq!(
+        schema_errors(schema, json!([1, "a"])),
+        vec![("/1".to_string(), "类型不匹配".to_string())]
+    );
+}
+
+/// 测试数值关键字 multipleOf、exclusiveMinimum 和 exclusiveMaximum
+#[test]
+fn test_schema_numeric_bounds() {
+    let schema = json!({"multipleOf": 0.25, "exclusiveMinimum": 0, "exclusiveMaximum": 1});
+    assert!(schema_errors(schema.clone(), json!(0.75)).is_empty());
+    assert_eq!(schema_errors(schema.clone(), json!(0.3)).len(), 1);
+    assert_eq!(schema_errors(schema.clone(), json!(0)).len(), 1);
+    assert_eq!(schema_errors(schema, json!(1)).len(), 1);
+
+    let errors = config_abstractions::SchemaValidator::new(json!({"exclusiveMinimum": 0}))
+        .validate(&json!(-1));
+    assert_eq!(errors[0].expected.as_deref(), Some("> 0"));
+}
+
+/// 测试 additionalProperties 为 false 和子 Schema 时的行为
+#[test]
+fn test_schema_additional_properties() {
+    let closed = json!({"properties": {"host": {"type": "string"}}, "additionalProperties": false});
+    assert!(schema_errors(closed.clone(), json!({"host": "a"})).is_empty());
+    assert_eq!(
+        schema_errors(closed, json!({"host": "a", "port": 1})),
+        vec![("/port".to_string(), "不允许的配置项: port".to_string())]
+    );
+
+    let typed = json!({"additionalProperties": {"type": "integer"}});
+    assert!(schema_errors(typed.clone(), json!({"a": 1, "b": 2})).is_empty());
+    assert_eq!(
+        schema_errors(typed, json!({"a": 1, "b": "x"})),
+        vec![("/b".to_string(), "类型不匹配".to_string())]
+    );
+}
+
+/// 测试整数值的浮点数满足 integer 类型
+#[test]
+fn test_schema_integer_valued_floats() {
+    let schema = json!({"type": "integer", "maximum": 10});
+    assert!(schema_errors(schema.clone(), json!(3.0)).is_empty());
+    assert_eq!(
+        schema_errors(schema.clone(), json!(3.5)),
+        vec![(String::new(), "类型不匹配".to_string())]
+    );
+    assert_eq!(schema_errors(schema, json!(11.0)).len(), 1);
+}
+
+/// 测试验证错误不记录配置的实际值
+#[test]
+fn test_schema_errors_omit_actual_values() {
+    let schema = json!({
+        "type": "object",
+        "properties": {
+            "token": {"type": "string", "pattern": "^tk_"},
+            "mode": {"enum": ["a", "b"]},
+            "fixed": {"const": "x"},
+            "pin": {"type": "integer", "maximum": 9999}
+        }
+    });
+    let instance =
+        json!({"token": "hunter2", "mode": "hunter3", "fixed": "hunter4", "pin": 123456});
+    let errors = config_abstractions::SchemaValidator::new(schema).validate(&instance);
+    assert_eq!(errors.len(), 4);
+    assert!(errors.iter().all(|error| error.actual.is_none()));
+    let dumped = format!("{:?}", errors);
+    for secret in ["hunter2", "hunter3", "hunter4", "123456"] {
+        assert!(!dumped.contains(secret), "{}", dumped);
+    }
+}
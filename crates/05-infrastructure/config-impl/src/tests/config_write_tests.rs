@@ -0,0 +1,306 @@
+//! 配置写回测试
+
+use super::super::*;
+use config_abstractions::events::ConfigChangeEventType;
+use config_abstractions::{ConfigAuditRecord, ConfigManager, ConfigWriteOptions};
+use infrastructure_common::{ConfigError, ConfigSchema, ObjectSchema};
+use serde_json::{json, Value};
+
+/// 测试用 TOML 配置
+const PACING_TOML: &str = r#"# 投放节奏配置
+[pacing]
+# 预算消耗阈值
+threshold = 0.5 # 超过后降速
+interval_ms = 100
+
+[server]
+port = 8080
+"#;
+
+/// 测试用节奏配置
+struct PacingOptions;
+
+impl ConfigSchema for PacingOptions {
+    fn config_schema() -> Value {
+        ObjectSchema::new("PacingOptions")
+            .property(
+                "threshold",
+                json!({"type": "number", "minimum": 0, "maximum": 1}),
+                true,
+            )
+            .build()
+    }
+}
+
+/// 辅助函数：创建可写回 TOML 文件的管理器
+async fn create_manager(
+    dir: &tempfile::TempDir,
+) -> (manager::AdSystemConfigManager, std::path::PathBuf) {
+    let path = dir.path().join("app.toml");
+    std::fs::write(&path, PACING_TOML).unwrap();
+
+    let mut manager = manager::AdSystemConfigManager::new();
+    manager
+        .register_provider(Box::new(
+            TomlConfigProvider::new(&path).unwrap().with_write_back(true),
+        ))
+        .await
+        .unwrap();
+    (manager, path)
+}
+
+/// 测试写回 TOML 文件时保留注释和格式，并发送变更事件
+#[tokio::test]
+async fn test_set_value_preserves_toml_comments() {
+    let dir = tempfile::tempdir().unwrap();
+    let (manager, path) = create_manager(&dir).await;
+    let mut changes = manager.subscribe_changes();
+
+    let result = manager
+        .set_value(
+            "pacing.threshold",
+            json!(0.8),
+            ConfigWriteOptions::new("admin-ui").with_audit(
+                ConfigAuditRecord::new("admin-ui")
+                    .with_actor("alice")
+                    .with_reason("提高阈值"),
+            ),
+        )
+        .await
+        .unwrap();
+
+    assert_eq!(result.version, 2);
+    assert_eq!(result.events.len(), 1);
+    assert_eq!(result.events[0].event_type, ConfigChangeEventType::Updated);
+    assert_eq!(result.events[0].new_value, Some(json!(0.8)));
+    assert_eq!(changes.recv().await.unwrap().path, "pacing.threshold");
+    assert_eq!(
+        manager.get_configuration("pacing.threshold").await.unwrap(),
+        json!(0.8)
+    );
+
+    let content = std::fs::read_to_string(&path).unwrap();
+    assert_eq!(content, PACING_TOML.replace("threshold = 0.5", "threshold = 0.8"));
+
+    let history = manager.get_config_history().await;
+    assert_eq!(history.last().unwrap().audit.actor, "alice");
+
+    // 写入新的配置节
+    manager
+        .set_value(
+            "pacing.burst",
+            json!({"max": 3}),
+            ConfigWriteOptions::new("admin-ui"),
+        )
+        .await
+        .unwrap();
+    manager
+        .delete_value("pacing.interval_ms", ConfigWriteOptions::new("admin-ui"))
+        .await
+        .unwrap();
+    let reloaded = TomlConfigProvider::new(&path).unwrap();
+    assert_eq!(
+        config_abstractions::ConfigProvider::get_all_configuration(&reloaded)
+            .await
+            .unwrap()["pacing"],
+        json!({"threshold": 0.8, "burst": {"max": 3}})
+    );
+    assert!(std::fs::read_to_string(&path)
+        .unwrap()
+        .contains("# 超过后降速"));
+}
+
+/// 测试期望版本与当前版本不一致时拒绝写入
+#[tokio::test]
+async fn test_write_with_stale_version_rejected() {
+    let dir = tempfile::tempdir().unwrap();
+    let (manager, path) = create_manager(&dir).await;
+
+    let version = manager.current_version().await.unwrap();
+    manager
+        .set_value(
+            "server.port",
+            json!(9090),
+            ConfigWriteOptions::new("admin-ui").with_expected_version(version),
+        )
+        .await
+        .unwrap();
+
+    let result = manager
+        .set_value(
+            "server.port",
+            json!(9191),
+            ConfigWriteOptions::new("admin-ui").with_expected_version(version),
+        )
+        .await;
+    assert!(matches!(
+        result,
+        Err(ConfigError::VersionConflict { expected, actual }) if expected == version && actual == version + 1
+    ));
+    assert!(std::fs::read_to_string(&path).unwrap().contains("port = 9090"));
+}
+
+/// 测试写入前执行验证，未通过验证时不修改配置文件
+#[tokio::test]
+async fn test_invalid_write_rejected_before_writing() {
+    let dir = tempfile::tempdir().unwrap();
+    let (manager, path) = create_manager(&dir).await;
+    manager.register_config_schema::<PacingOptions>("pacing");
+
+    let result = manager
+        .set_value("pacing.threshold", json!(1.5), ConfigWriteOptions::new("admin-ui"))
+        .await;
+    assert!(matches!(result, Err(ConfigError::ValidationFailed { .. })));
+    assert_eq!(std::fs::read_to_string(&path).unwrap(), PACING_TOML);
+    assert_eq!(
+        manager.get_configuration("pacing.threshold").await.unwrap(),
+        json!(0.5)
+    );
+}
+
+/// 测试用令牌配置
+struct TokenOptions;
+
+impl ConfigSchema for TokenOptions {
+    fn config_schema() -> Value {
+        ObjectSchema::new("TokenOptions")
+            .property("api_token", json!({"type": "string", "pattern": "^tk_"}), true)
+            .build()
+    }
+}
+
+/// 测试被拒绝的写入不会在验证错误中回显提交的值
+#[tokio::test]
+async fn test_rejected_write_does_not_echo_submitted_value() {
+    let dir = tempfile::tempdir().unwrap();
+    let (manager, _) = create_manager(&dir).await;
+    manager.register_config_schema::<TokenOptions>("upstream");
+
+    let result = manager
+        .set_value("upstream.api_token", json!("hunter2"), ConfigWriteOptions::new("admin-ui"))
+        .await;
+    match result {
+        Err(ConfigError::ValidationFailed { errors }) => {
+            assert!(errors.iter().any(|error| error.contains("api_token")), "{:?}", errors);
+            assert!(errors.iter().all(|error| !error.contains("hunter2")), "{:?}", errors);
+        }
+        other => panic!("写入应该验证失败: {:?}", other.map(|result| result.version)),
+    }
+}
+
+/// 测试写入路由到内存覆盖层，删除后较低优先级的值重新生效
+#[tokio::test]
+async fn test_memory_overlay_and_routing() {
+    let dir = tempfile::tempdir().unwrap();
+    let path = dir.path().join("app.toml");
+    std::fs::write(&path, PACING_TOML).unwrap();
+
+    let mut manager = manager::AdSystemConfigManager::new();
+    manager
+        .register_provider(Box::new(TomlConfigProvider::new(&path).unwrap()))
+        .await
+        .unwrap();
+    assert!(matches!(
+        manager
+            .set_value("server.port", json!(1), ConfigWriteOptions::new("test"))
+            .await,
+        Err(ConfigError::NoWritableProvider)
+    ));
+    assert!(matches!(
+        manager
+            .set_value(
+                "server.port",
+                json!(1),
+                ConfigWriteOptions::new("test").with_provider("TomlConfigProvider"),
+            )
+            .await,
+        Err(ConfigError::ReadOnlyProvider { .. })
+    ));
+
+    manager
+        .register_provider(Box::new(MemoryConfigProvider::new("runtime")))
+        .await
+        .unwrap();
+    let result = manager
+        .set_value("server.port", json!(9000), ConfigWriteOptions::new("test"))
+        .await
+        .unwrap();
+    assert_eq!(result.events[0].old_value, Some(json!(8080)));
+    assert_eq!(
+        manager.explain_configuration("server.port").await.unwrap().winner.unwrap().source,
+        "memory:runtime"
+    );
+
+    let result = manager
+        .delete_value("server.port", ConfigWriteOptions::new("test"))
+        .await
+        .unwrap();
+    assert_eq!(result.events[0].new_value, Some(json!(8080)));
+    assert!(matches!(
+        manager
+            .delete_value("server.port", ConfigWriteOptions::new("test"))
+            .await,
+        Err(ConfigError::KeyNotFound { .. })
+    ));
+}
+
+/// 测试写入被更高优先级配置源覆盖的键时报错
+#[tokio::test]
+async fn test_write_shadowed_key_rejected() {
+    let dir = tempfile::tempdir().unwrap();
+    let toml_path = dir.path().join("app.toml");
+    let json_path = dir.path().join("app.json");
+    std::fs::write(&toml_path, PACING_TOML).unwrap();
+    std::fs::write(&json_path, "{\"server\": {\"host\": \"a\"}}").unwrap();
+
+    let mut manager = manager::AdSystemConfigManager::new();
+    manager
+        .register_provider(Box::new(TomlConfigProvider::new(&toml_path).unwrap()))
+        .await
+        .unwrap();
+    manager
+        .register_provider(Box::new(
+            JsonConfigProvider::new(&json_path).unwrap().with_write_back(true),
+        ))
+        .await
+        .unwrap();
+
+    let result = manager
+        .set_value("server.port", json!(1), ConfigWriteOptions::new("test"))
+        .await;
+    assert!(matches!(
+        result,
+        Err(ConfigError::KeyShadowed { key, source_name })
+            if key == "server.port" && source_name == toml_path.display().to_string()
+    ));
+
+    manager
+        .set_value("server.host", json!("b"), ConfigWriteOptions::new("test"))
+        .await
+        .unwrap();
+    let written: Value = serde_json::from_str(&std::fs::read_to_string(&json_path).unwrap()).unwrap();
+    assert_eq!(written, json!({"server": {"host": "b"}}));
+}
+
+/// 测试写回 YAML 文件
+#[tokio::test]
+async fn test_yaml_write_back() {
+    let dir = tempfile::tempdir().unwrap();
+    let path = dir.path().join("app.yaml");
+    std::fs::write(&path, "server:\n  port: 8080\n").unwrap();
+
+    let mut manager = manager::AdSystemConfigManager::new();
+    manager
+        .register_provider(Box::new(
+            YamlConfigProvider::new(&path).unwrap().with_write_back(true),
+        ))
+        .await
+        .unwrap();
+    manager
+        .set_value("server.hosts", json!(["a", "b"]), ConfigWriteOptions::new("test"))
+        .await
+        .unwrap();
+
+    let written: Value = serde_yaml::from_str(&std::fs::read_to_string(&path).unwrap()).unwrap();
+    assert_eq!(written, json!({"server": {"port": 8080, "hosts": ["a", "b"]}}));
+}
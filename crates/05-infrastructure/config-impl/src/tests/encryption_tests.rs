@@ -0,0 +1,227 @@
+//! 配置值静态加密测试
+
+use super::super::*;
+use config_abstractions::{ConfigManager, ConfigWriteOptions};
+use infrastructure_common::ConfigError;
+use serde_json::{json, Value};
+
+/// 测试用 TOML 配置
+const DATABASE_TOML: &str = r#"# 数据库配置
+[database]
+host = "db.internal"
+password = "s3cr3t-pa55" # 生产密码
+port = 5432
+
+[database.replica]
+user = "reader"
+"#;
+
+/// 辅助函数：创建使用解密密钥的 TOML 配置管理器
+async fn create_manager(
+    path: &std::path::Path,
+    key: &SecretEncryptionKey,
+) -> Result<manager::AdSystemConfigManager, ConfigError> {
+    let mut manager = manager::AdSystemConfigManager::new();
+    manager
+        .register_provider(Box::new(
+            TomlConfigProvider::open_encrypted(path, key.clone())?,
+        ))
+        .await?;
+    Ok(manager)
+}
+
+/// 测试 TOML 文件原地加密后由提供者透明解密，保留注释和值类型
+#[tokio::test]
+async fn test_encrypt_toml_in_place_and_decrypt() {
+    let dir = tempfile::tempdir().unwrap();
+    let path = dir.path().join("app.toml");
+    std::fs::write(&path, DATABASE_TOML).unwrap();
+    let key = SecretEncryptionKey::generate();
+
+    let encryptor = ConfigFileEncryptor::new(key.clone());
+    let count = encryptor
+        .encrypt_keys(&path, &["database.password", "database.port", "database.replica"])
+        .unwrap();
+    assert_eq!(count, 3);
+    // 已加密的配置项不会重复加密
+    assert_eq!(encryptor.encrypt_keys(&path, &["database.password"]).unwrap(), 0);
+
+    let content = std::fs::read_to_string(&path).unwrap();
+    assert!(!content.contains("s3cr3t-pa55"));
+    assert!(content.contains("# 生产密码"));
+    assert!(content.contains("password = \"ENC["));
+
+    let manager = create_manager(&path, &key).await.unwrap();
+    assert_eq!(
+        manager.get_configuration("database").await.unwrap(),
+        json!({
+            "host": "db.internal",
+            "password": "s3cr3t-pa55",
+            "port": 5432,
+            "replica": {"user": "reader"}
+        })
+    );
+
+    // 配置项不存在时不修改文件
+    assert!(matches!(
+        encryptor.encrypt_keys(&path, &["database.host", "database.missing"]),
+        Err(ConfigError::KeyNotFound { key }) if key == "database.missing"
+    ));
+    assert_eq!(std::fs::read_to_string(&path).unwrap(), content);
+}
+
+/// 测试解密失败时错误包含配置键路径，但不包含明文
+#[tokio::test]
+async fn test_decryption_errors_report_key_path() {
+    let dir = tempfile::tempdir().unwrap();
+    let path = dir.path().join("app.toml");
+    std::fs::write(&path, DATABASE_TOML).unwrap();
+    let key = SecretEncryptionKey::generate();
+    ConfigFileEncryptor::new(key.clone())
+        .encrypt_keys(&path, &["database.password"])
+        .unwrap();
+
+    let missing_key = TomlConfigProvider::new(&path).unwrap_err();
+    assert!(matches!(
+        &missing_key,
+        ConfigError::DecryptionFailed { key, .. } if key == "database.password"
+    ));
+
+    let wrong_key = create_manager(&path, &SecretEncryptionKey::generate())
+        .await
+        .unwrap_err();
+    assert!(matches!(
+        &wrong_key,
+        ConfigError::DecryptionFailed { key, .. } if key == "database.password"
+    ));
+    assert!(!wrong_key.to_string().contains("s3cr3t"));
+
+    // 密文被篡改
+    let content = std::fs::read_to_string(&path).unwrap();
+    std::fs::write(&path, content.replace("ENC[", "ENC[AAAA")).unwrap();
+    assert!(matches!(
+        create_manager(&path, &key).await,
+        Err(ConfigError::DecryptionFailed { .. })
+    ));
+}
+
+/// 测试写回时已加密的配置项仍以密文保存
+#[tokio::test]
+async fn test_write_back_keeps_encrypted_values_sealed() {
+    let dir = tempfile::tempdir().unwrap();
+    let path = dir.path().join("app.json");
+    let key = SecretEncryptionKey::generate();
+    let config = json!({
+        "dsp": {
+            "api_key": encrypt_config_value(&key, &json!("old-key")).unwrap(),
+            "endpoint": "http://a"
+        }
+    });
+    std::fs::write(&path, serde_json::to_string_pretty(&config).unwrap()).unwrap();
+
+    let mut manager = manager::AdSystemConfigManager::new();
+    manager
+        .register_provider(Box::new(
+            JsonConfigProvider::open_encrypted(&path, key.clone())
+                .unwrap()
+                .with_write_back(true),
+        ))
+        .await
+        .unwrap();
+    manager
+        .set_value("dsp.api_key", json!("new-key"), ConfigWriteOptions::new("test"))
+        .await
+        .unwrap();
+    manager
+        .set_value("dsp.endpoint", json!("http://b"), ConfigWriteOptions::new("test"))
+        .await
+        .unwrap();
+
+    let written: Value = serde_json::from_str(&std::fs::read_to_string(&path).unwrap()).unwrap();
+    let sealed = written["dsp"]["api_key"].as_str().unwrap();
+    assert!(is_encrypted_value(sealed));
+    assert_eq!(
+        decrypt_config_value(&key, "dsp.api_key", sealed).unwrap(),
+        json!("new-key")
+    );
+    assert_eq!(written["dsp"]["endpoint"], json!("http://b"));
+    assert_eq!(
+        manager.get_configuration("dsp.api_key").await.unwrap(),
+        json!("new-key")
+    );
+}
+
+/// 测试轮换密钥后旧密钥无法解密
+#[tokio::test]
+async fn test_rotate_key() {
+    let dir = tempfile::tempdir().unwrap();
+    let path = dir.path().join("app.yaml");
+    std::fs::write(&path, "database:\n  password: s3cr3t-pa55\n  pool: 8\n").unwrap();
+    let old_key = SecretEncryptionKey::generate();
+    let new_key = SecretEncryptionKey::generate();
+
+    let encryptor = ConfigFileEncryptor::new(old_key.clone());
+    encryptor
+        .encrypt_keys(&path, &["database.password", "database.pool"])
+        .unwrap();
+    assert!(matches!(
+        ConfigFileEncryptor::new(new_key.clone()).rotate(&path, &old_key),
+        Err(ConfigError::DecryptionFailed { .. })
+    ));
+    assert_eq!(encryptor.rotate(&path, &new_key).unwrap(), 2);
+
+    assert!(matches!(
+        YamlConfigProvider::open_encrypted(&path, old_key),
+        Err(ConfigError::DecryptionFailed { .. })
+    ));
+    let provider = YamlConfigProvider::open_encrypted(&path, new_key).unwrap();
+    assert_eq!(
+        config_abstractions::ConfigProvider::get_all_configuration(&provider)
+            .await
+            .unwrap(),
+        json!({"database": {"password": "s3cr3t-pa55", "pool": 8}})
+    );
+}
+
+/// 测试密钥轮换会重新加密数组元素中的加密值，并保留 TOML 注释
+#[tokio::test]
+async fn test_rotate_key_reencrypts_array_elements() {
+    let dir = tempfile::tempdir().unwrap();
+    let path = dir.path().join("app.toml");
+    let old_key = SecretEncryptionKey::generate();
+    let new_key = SecretEncryptionKey::generate();
+    let seal = |value: &str| encrypt_config_value(&old_key, &json!(value)).unwrap();
+    std::fs::write(
+        &path,
+        format!(
+            "# 上游 DSP\n[dsp]\ntokens = [\"{}\", \"plain\"]\nendpoints = [{{ url = \"https://a\", api_key = \"{}\" }}]\n\n[[bidders]]\nsecret = \"{}\" # 出价方密钥\n",
+            seal("t1"),
+            seal("k1"),
+            seal("b1")
+        ),
+    )
+    .unwrap();
+
+    let encryptor = ConfigFileEncryptor::new(old_key.clone());
+    assert_eq!(encryptor.rotate(&path, &new_key).unwrap(), 3);
+
+    let content = std::fs::read_to_string(&path).unwrap();
+    assert!(content.contains("# 出价方密钥"));
+    assert!(matches!(
+        TomlConfigProvider::open_encrypted(&path, old_key),
+        Err(ConfigError::DecryptionFailed { .. })
+    ));
+    let provider = TomlConfigProvider::open_encrypted(&path, new_key).unwrap();
+    assert_eq!(
+        config_abstractions::ConfigProvider::get_all_configuration(&provider)
+            .await
+            .unwrap(),
+        json!({
+            "dsp": {
+                "tokens": ["t1", "plain"],
+                "endpoints": [{"url": "https://a", "api_key": "k1"}]
+            },
+            "bidders": [{"secret": "b1"}]
+        })
+    );
+}
@@ -0,0 +1,174 @@
+//! 环境变量配置提供者测试
+//!
+//! 每个测试使用独立的变量前缀，避免并行执行时互相影响
+
+use super::super::*;
+use config_abstractions::{ConfigManager, ConfigProvider};
+use infrastructure_common::{ConfigSchema, ObjectSchema};
+use serde::Deserialize;
+use serde_json::{json, Value};
+
+/// 测试用 DSP 端点配置
+#[derive(Debug, Deserialize, PartialEq)]
+struct EndpointOptions {
+    url: String,
+    timeout_ms: u64,
+}
+
+/// 测试用 DSP 配置
+#[derive(Debug, Deserialize, PartialEq)]
+struct DspOptions {
+    endpoints: Vec<EndpointOptions>,
+    zip: String,
+}
+
+impl ConfigSchema for DspOptions {
+    fn config_schema() -> Value {
+        ObjectSchema::new("DspOptions")
+            .property(
+                "endpoints",
+                json!({
+                    "type": "array",
+                    "items": {
+                        "type": "object",
+                        "properties": {
+                            "url": {"type": "string"},
+                            "timeout_ms": {"type": "integer"}
+                        }
+                    }
+                }),
+                true,
+            )
+            .property("zip", String::config_schema(), true)
+            .build()
+    }
+}
+
+/// 测试双下划线嵌套、数组下标、带下划线的键和保守的类型推断
+#[tokio::test]
+async fn test_nested_keys_arrays_and_inference() {
+    std::env::set_var("ENVT1__DSP__ENDPOINTS__0__URL", "http://a");
+    std::env::set_var("ENVT1__DSP__ENDPOINTS__1__URL", "http://b");
+    std::env::set_var("ENVT1__PACING__MAX_QPS", "100");
+    std::env::set_var("ENVT1__PACING__RATIO", "0.25");
+    std::env::set_var("ENVT1__GEO__ZIP", "01234");
+    std::env::set_var("ENVT1__GEO__ENABLED", "true");
+    std::env::set_var("ENVT1__TAGS", r#"["a", "b"]"#);
+    std::env::set_var("ENVT1X__IGNORED", "1");
+
+    let provider = EnvironmentConfigProviderImpl::new("ENVT1").unwrap();
+    assert_eq!(
+        provider.get_all_configuration().await.unwrap(),
+        json!({
+            "dsp": {"endpoints": [{"url": "http://a"}, {"url": "http://b"}]},
+            "pacing": {"max_qps": 100, "ratio": 0.25},
+            "geo": {"zip": "01234", "enabled": true},
+            "tags": ["a", "b"]
+        })
+    );
+    assert_eq!(
+        provider.get_configuration("dsp.endpoints.1.url").await.unwrap(),
+        json!("http://b")
+    );
+    assert_eq!(
+        provider.key_source("pacing.max_qps"),
+        "env:ENVT1__PACING__MAX_QPS"
+    );
+}
+
+/// 测试根据绑定类型的 Schema 和类型提示转换变量值
+#[tokio::test]
+async fn test_schema_aware_typing() {
+    std::env::set_var("ENVT2__DSP__ENDPOINTS__0__URL", "http://a");
+    std::env::set_var("ENVT2__DSP__ENDPOINTS__0__TIMEOUT_MS", "250");
+    std::env::set_var("ENVT2__DSP__ZIP", "12345");
+    std::env::set_var("ENVT2__FEATURE__ENABLED", "on");
+    std::env::set_var("ENVT2__FEATURE__LEVEL", "007");
+
+    let provider = EnvironmentConfigProviderImpl::new("ENVT2")
+        .unwrap()
+        .with_schema_of::<DspOptions>("dsp")
+        .with_type_hint("feature.enabled", EnvValueType::Boolean)
+        .with_type_hint("feature.level", EnvValueType::Integer);
+
+    let mut manager = manager::AdSystemConfigManager::new();
+    manager.register_provider(Box::new(provider)).await.unwrap();
+    let dsp: DspOptions = manager.bind_configuration("dsp").await.unwrap();
+    assert_eq!(
+        dsp,
+        DspOptions {
+            endpoints: vec![EndpointOptions {
+                url: "http://a".to_string(),
+                timeout_ms: 250,
+            }],
+            zip: "12345".to_string(),
+        }
+    );
+    assert_eq!(
+        manager.get_configuration("feature").await.unwrap(),
+        json!({"enabled": true, "level": 7})
+    );
+}
+
+/// 测试从 .env 文件加载变量，进程环境变量优先
+#[tokio::test]
+async fn test_dotenv_file() {
+    let dir = tempfile::tempdir().unwrap();
+    let path = dir.path().join(".env");
+    std::fs::write(
+        &path,
+        r#"# 本地开发配置
+export ENVT3__DB__HOST=localhost
+ENVT3__DB__PASSWORD='p#ss word'
+ENVT3__DB__BANNER="line1\nline2"
+ENVT3__DB__PORT=5432 # 默认端口
+ENVT3__DB__NAME=from_file
+"#,
+    )
+    .unwrap();
+    std::env::set_var("ENVT3__DB__NAME", "from_env");
+
+    let mut provider = EnvironmentConfigProviderImpl::new("ENVT3")
+        .unwrap()
+        .with_dotenv_file(&path)
+        .unwrap()
+        .with_dotenv_file(dir.path().join("missing.env"))
+        .unwrap();
+    assert_eq!(
+        provider.get_configuration("db").await.unwrap(),
+        json!({
+            "host": "localhost",
+            "password": "p#ss word",
+            "banner": "line1\nline2",
+            "port": 5432,
+            "name": "from_env"
+        })
+    );
+    assert!(std::env::var("ENVT3__DB__HOST").is_err(), ".env 不应写入进程环境");
+    assert_eq!(provider.watch_paths().len(), 2);
+
+    std::fs::write(&path, "ENVT3__DB__HOST=db.internal\n").unwrap();
+    provider.reload().await.unwrap();
+    assert_eq!(
+        provider.get_configuration("db.host").await.unwrap(),
+        json!("db.internal")
+    );
+    assert!(!provider.contains_key("db.port").await.unwrap());
+
+    std::fs::write(&path, "NOT A VARIABLE\n").unwrap();
+    assert!(provider.reload().await.is_err());
+}
+
+/// 测试单下划线分隔符兼容旧的变量命名
+#[tokio::test]
+async fn test_custom_separator() {
+    std::env::set_var("ENVT4_SERVER_PORT", "9090");
+
+    let provider = EnvironmentConfigProviderImpl::new("ENVT4")
+        .unwrap()
+        .with_separator("_");
+    assert_eq!(
+        provider.get_configuration("server.port").await.unwrap(),
+        json!(9090)
+    );
+}
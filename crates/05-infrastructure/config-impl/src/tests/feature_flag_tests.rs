@@ -0,0 +1,171 @@
+//! 功能开关测试
+
+use super::super::*;
+use config_abstractions::{ConfigManager, ConfigWriteOptions};
+use infrastructure_common::ConfigError;
+use serde_json::json;
+use std::time::Duration;
+
+/// 辅助函数：创建包含内存配置的管理器
+async fn create_manager(flags: serde_json::Value) -> manager::AdSystemConfigManager {
+    let mut manager = manager::AdSystemConfigManager::new();
+    manager
+        .register_provider(Box::new(
+            MemoryConfigProvider::new("flags").with_values(json!({ "feature_flags": flags })),
+        ))
+        .await
+        .unwrap();
+    manager
+}
+
+/// 测试布尔开关、总开关和定向规则
+#[tokio::test]
+async fn test_toggle_and_targeting() {
+    let manager = create_manager(json!({
+        "simple": true,
+        "off": false,
+        "killed": {"enabled": false, "countries": ["US"]},
+        "us_mobile": {"countries": ["us", "CA"], "devices": ["mobile"]},
+        "publisher_only": {"publishers": ["pub-1"]}
+    }))
+    .await;
+    let flags = manager.feature_flags(DEFAULT_FEATURE_FLAGS_PATH).await.unwrap();
+
+    let us_mobile = FeatureContext::new("user-1")
+        .with_country("US")
+        .with_device("Mobile")
+        .with_publisher("pub-2");
+    assert!(flags.is_enabled("simple", &FeatureContext::default()));
+    assert!(!flags.is_enabled("off", &us_mobile));
+    assert_eq!(
+        flags.evaluate("killed", &us_mobile).reason,
+        FeatureFlagReason::Disabled
+    );
+    assert!(flags.is_enabled("us_mobile", &us_mobile));
+    assert_eq!(
+        flags
+            .evaluate("us_mobile", &us_mobile.clone().with_device("desktop"))
+            .reason,
+        FeatureFlagReason::TargetingMismatch
+    );
+    assert_eq!(
+        flags.evaluate("publisher_only", &us_mobile).reason,
+        FeatureFlagReason::TargetingMismatch
+    );
+    assert!(flags.is_enabled("publisher_only", &us_mobile.with_publisher("pub-1")));
+    assert_eq!(
+        flags.evaluate("missing", &FeatureContext::default()).reason,
+        FeatureFlagReason::NotFound
+    );
+}
+
+/// 测试百分比灰度的分桶稳定且比例接近配置值
+#[tokio::test]
+async fn test_percentage_rollout_is_stable() {
+    let manager = create_manager(json!({
+        "quarter": {"rollout": 25.0},
+        "resalted": {"rollout": 25.0, "salt": "v2"},
+        "none": {"rollout": 0}
+    }))
+    .await;
+    let flags = manager.feature_flags("feature_flags").await.unwrap();
+
+    let contexts: Vec<FeatureContext> = (0..10_000)
+        .map(|i| FeatureContext::new(format!("user-{}", i)))
+        .collect();
+    let enabled: Vec<bool> = contexts
+        .iter()
+        .map(|context| flags.is_enabled("quarter", context))
+        .collect();
+    let count = enabled.iter().filter(|enabled| **enabled).count();
+    assert!((2_300..=2_700).contains(&count), "灰度比例偏差过大: {}", count);
+
+    // 同一个键的结果稳定，与求值器实例无关
+    let restarted = manager.feature_flags("feature_flags").await.unwrap();
+    for (context, expected) in contexts.iter().zip(&enabled).take(500) {
+        assert_eq!(restarted.is_enabled("quarter", context), *expected);
+    }
+
+    // 不同的盐重新分配灰度人群
+    let resalted: Vec<bool> = contexts
+        .iter()
+        .map(|context| flags.is_enabled("resalted", context))
+        .collect();
+    assert_ne!(enabled, resalted);
+
+    assert!(contexts.iter().all(|context| !flags.is_enabled("none", context)));
+    assert_eq!(
+        flags.evaluate("quarter", &FeatureContext::default()).reason,
+        FeatureFlagReason::MissingContextKey
+    );
+}
+
+/// 测试配置变更后开关定义自动更新，无效定义被拒绝并保留原有定义
+#[tokio::test]
+async fn test_flags_hot_reload() {
+    let manager = create_manager(json!({"new_bidder": {"rollout": 0}})).await;
+    let flags = manager.feature_flags("feature_flags").await.unwrap();
+    let context = FeatureContext::new("user-1");
+    assert!(!flags.is_enabled("new_bidder", &context));
+
+    manager
+        .set_value(
+            "feature_flags.new_bidder.rollout",
+            json!(100),
+            ConfigWriteOptions::new("test"),
+        )
+        .await
+        .unwrap();
+    tokio::time::timeout(Duration::from_secs(5), async {
+        while !flags.is_enabled("new_bidder", &context) {
+            tokio::time::sleep(Duration::from_millis(10)).await;
+        }
+    })
+    .await
+    .expect("等待功能开关更新超时");
+
+    // 写入无效的灰度百分比后刷新失败，保留原有定义
+    manager
+        .set_value(
+            "feature_flags.broken",
+            json!({"rollout": 150}),
+            ConfigWriteOptions::new("test"),
+        )
+        .await
+        .unwrap();
+    assert!(matches!(
+        flags.refresh().await,
+        Err(ConfigError::ValidationFailed { .. })
+    ));
+    assert!(flags.is_enabled("new_bidder", &context));
+    assert!(!flags.flags().contains_key("broken"));
+}
+
+/// 测试曝光计数
+#[tokio::test]
+async fn test_exposure_counters() {
+    let manager = create_manager(json!({"simple": true, "off": false})).await;
+    let flags = manager.feature_flags("feature_flags").await.unwrap();
+    let handle = flags.clone();
+
+    for _ in 0..3 {
+        flags.is_enabled("simple", &FeatureContext::default());
+    }
+    handle.is_enabled("off", &FeatureContext::default());
+
+    let exposures = flags.exposures();
+    assert_eq!(exposures["simple"], FeatureExposure { enabled: 3, disabled: 0 });
+    assert_eq!(exposures["off"], FeatureExposure { enabled: 0, disabled: 1 });
+
+    flags.reset_exposures();
+    assert!(handle.exposures().is_empty());
+}
+
+/// 测试未配置开关时所有开关均关闭
+#[tokio::test]
+async fn test_missing_flags_section() {
+    let manager = manager::AdSystemConfigManager::new();
+    let flags = manager.feature_flags("feature_flags").await.unwrap();
+    assert!(flags.flags().is_empty());
+    assert!(!flags.is_enabled("anything", &FeatureContext::new("user-1")));
+}
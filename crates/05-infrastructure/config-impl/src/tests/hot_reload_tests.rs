@@ -113,3 +113,79 @@ async fn test_hot_reload_integration() {
     config_manager.disable_hot_reload().await.unwrap();
     assert!(!config_manager.is_hot_reload_enabled());
 }
+
+/// 测试监控器运行期间添加的路径立即参与事件匹配
+#[tokio::test]
+async fn test_watch_path_added_while_running_emits_events() {
+    use config_abstractions::FileSystemConfigWatcher;
+    use std::time::Duration;
+
+    let dir = tempfile::tempdir().unwrap();
+    let first = dir.path().join("first.toml");
+    let second = dir.path().join("second.toml");
+    std::fs::write(&first, "a = 1\n").unwrap();
+    std::fs::write(&second, "b = 1\n").unwrap();
+
+    let mut watcher = watcher::ConfigFileWatcher::new().unwrap();
+    watcher.set_debounce_delay(Duration::from_millis(50));
+    watcher.add_watch_path(&first).await.unwrap();
+    let mut changes = watcher.take_change_receiver().unwrap();
+    watcher.start_watching().await.unwrap();
+
+    watcher.add_watch_path(&second).await.unwrap();
+    tokio::time::sleep(Duration::from_millis(100)).await;
+    std::fs::write(&second, "b = 2\n").unwrap();
+
+    let event = tokio::time::timeout(Duration::from_secs(5), changes.recv())
+        .await
+        .expect("运行期间添加的路径应该产生变更事件")
+        .unwrap();
+    assert!(event.path.ends_with("second.toml"));
+
+    watcher.stop_watching().await.unwrap();
+}
+
+/// 测试移除同目录下的文件不影响其他文件的监控，尚未创建的文件同样按文件注册
+#[tokio::test]
+async fn test_removing_sibling_keeps_shared_directory_watched() {
+    use config_abstractions::FileSystemConfigWatcher;
+    use std::time::Duration;
+
+    let dir = tempfile::tempdir().unwrap();
+    let base = dir.path().join("config.toml");
+    let overlay = dir.path().join("config.prod.toml");
+    let pending = dir.path().join("config.local.toml");
+    std::fs::write(&base, "a = 1\n").unwrap();
+    std::fs::write(&overlay, "a = 2\n").unwrap();
+
+    let mut watcher = watcher::ConfigFileWatcher::new().unwrap();
+    watcher.set_debounce_delay(Duration::from_millis(50));
+    watcher.add_watch_path(&base).await.unwrap();
+    watcher.add_watch_path(&overlay).await.unwrap();
+    watcher.add_watch_path(&pending).await.unwrap();
+    let mut changes = watcher.take_change_receiver().unwrap();
+    watcher.start_watching().await.unwrap();
+
+    watcher.remove_watch_path(&overlay).await.unwrap();
+    tokio::time::sleep(Duration::from_millis(100)).await;
+    std::fs::write(&base, "a = 3\n").unwrap();
+
+    let event = tokio::time::timeout(Duration::from_secs(5), changes.recv())
+        .await
+        .expect("移除同目录文件后其他文件仍应产生变更事件")
+        .unwrap();
+    assert!(event.path.ends_with("config.toml"));
+
+    // 注册时尚未存在的文件在创建后同样产生事件
+    tokio::time::sleep(Duration::from_millis(100)).await;
+    while changes.try_recv().is_ok() {}
+    std::fs::write(&pending, "a = 4\n").unwrap();
+
+    let event = tokio::time::timeout(Duration::from_secs(5), changes.recv())
+        .await
+        .expect("注册时尚未创建的文件应该产生变更事件")
+        .unwrap();
+    assert!(event.path.ends_with("config.local.toml"));
+
+    watcher.stop_watching().await.unwrap();
+}
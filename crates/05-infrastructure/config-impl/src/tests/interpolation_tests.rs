@@ -0,0 +1,137 @@
+//! 配置值插值测试
+
+use super::super::*;
+use config_abstractions::ConfigManager;
+use infrastructure_common::ConfigError;
+use serde_json::json;
+use std::io::Write;
+use tempfile::NamedTempFile;
+
+/// 辅助函数：创建带指定扩展名的临时配置文件
+fn write_temp_config(suffix: &str, content: &str) -> NamedTempFile {
+    let mut file = tempfile::Builder::new().suffix(suffix).tempfile().unwrap();
+    file.write_all(content.as_bytes()).unwrap();
+    file
+}
+
+/// 辅助函数：创建包含指定配置文件的管理器
+async fn create_manager(files: &[&NamedTempFile]) -> manager::AdSystemConfigManager {
+    let mut manager = manager::AdSystemConfigManager::new();
+    for (index, file) in files.iter().enumerate() {
+        let provider: Box<dyn config_abstractions::ConfigProvider> =
+            if file.path().extension().is_some_and(|ext| ext == "json") {
+                Box::new(
+                    JsonConfigProvider::new(file.path())
+                        .unwrap()
+                        .with_priority(100 + index as i32),
+                )
+            } else {
+                Box::new(
+                    TomlConfigProvider::new(file.path())
+                        .unwrap()
+                        .with_priority(100 + index as i32),
+                )
+            };
+        manager.register_provider(provider).await.unwrap();
+    }
+    manager
+}
+
+/// 测试跨配置源的键引用和文件引用
+#[tokio::test]
+async fn test_references_are_resolved_after_merge() {
+    let host_file = tempfile::NamedTempFile::new().unwrap();
+    std::fs::write(host_file.path(), "ads.internal\n").unwrap();
+
+    let base = write_temp_config(
+        ".toml",
+        &format!(
+            "[server]\nhost = \"${{file:{}}}\"\nport = 8080\n\n[dsp]\nendpoint = \"http://${{server.host}}:${{server.port}}/bid\"\n",
+            host_file.path().display()
+        ),
+    );
+    let overlay = write_temp_config(".json", &json!({"server": {"port": 9090}}).to_string());
+    let manager = create_manager(&[&base, &overlay]).await;
+
+    let endpoint = manager.get_configuration("dsp.endpoint").await.unwrap();
+    assert_eq!(endpoint, json!("http://ads.internal:9090/bid"));
+
+    let dump = manager.dump_effective_configuration().await.unwrap();
+    assert_eq!(
+        dump.get("dsp.endpoint").unwrap().value,
+        json!("http://ads.internal:9090/bid")
+    );
+}
+
+/// 测试循环引用报告为配置错误
+#[tokio::test]
+async fn test_cycles_are_reported_as_config_error() {
+    let base = write_temp_config(
+        ".toml",
+        "[a]\nvalue = \"${b.value}\"\n\n[b]\nvalue = \"${a.value}\"\n",
+    );
+    let manager = create_manager(&[&base]).await;
+
+    let result = manager.get_configuration("a").await;
+    assert!(matches!(result, Err(ConfigError::CircularReference { .. })));
+}
+
+/// 测试被引用的键变化后，依赖值在重载时重新计算
+#[tokio::test]
+async fn test_dependent_values_are_reevaluated_on_reload() {
+    let base = write_temp_config(
+        ".toml",
+        "[server]\nhost = \"old.internal\"\n\n[dsp]\nendpoint = \"http://${server.host}/bid\"\n",
+    );
+    let mut manager = create_manager(&[&base]).await;
+    assert_eq!(
+        manager.get_configuration("dsp.endpoint").await.unwrap(),
+        json!("http://old.internal/bid")
+    );
+
+    std::fs::write(
+        base.path(),
+        "[server]\nhost = \"new.internal\"\n\n[dsp]\nendpoint = \"http://${server.host}/bid\"\n",
+    )
+    .unwrap();
+    manager.reload_all().await.unwrap();
+
+    assert_eq!(
+        manager.get_configuration("dsp.endpoint").await.unwrap(),
+        json!("http://new.internal/bid")
+    );
+}
+
+/// 测试插值失败只影响引用了缺失来源的配置键
+#[tokio::test]
+async fn test_unresolved_placeholders_only_fail_affected_keys() {
+    let host_file = tempfile::NamedTempFile::new().unwrap();
+    std::fs::write(host_file.path(), "ads.internal\n").unwrap();
+
+    let base = write_temp_config(
+        ".toml",
+        &format!(
+            "[server]\nhost = \"${{file:{}}}\"\n\n[db]\npassword = \"${{env:ADSP_INTERPOLATION_TEST_MISSING}}\"\n",
+            host_file.path().display()
+        ),
+    );
+    let manager = create_manager(&[&base]).await;
+
+    assert_eq!(
+        manager.get_configuration("server.host").await.unwrap(),
+        json!("ads.internal")
+    );
+    let result = manager.get_configuration("db.password").await;
+    assert!(
+        matches!(result, Err(ConfigError::InterpolationError { key, .. }) if key == "db.password")
+    );
+    assert!(manager.get_configuration("db").await.is_err());
+    assert!(manager.merged_configuration().await.is_ok());
+
+    // 文件引用的结果在缓存清除前复用，不会每次读取都重新读取文件
+    std::fs::write(host_file.path(), "changed.internal\n").unwrap();
+    assert_eq!(
+        manager.get_configuration("server").await.unwrap(),
+        json!({"host": "ads.internal"})
+    );
+}
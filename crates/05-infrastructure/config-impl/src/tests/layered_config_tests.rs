@@ -0,0 +1,95 @@
+//! 分层配置合并与来源追踪测试
+
+use super::super::*;
+use config_abstractions::{ArrayMergeStrategy, ConfigManager};
+use serde_json::json;
+use std::io::Write;
+use tempfile::NamedTempFile;
+
+/// 辅助函数：创建带指定扩展名的临时配置文件
+fn write_temp_config(suffix: &str, content: &str) -> NamedTempFile {
+    let mut file = tempfile::Builder::new().suffix(suffix).tempfile().unwrap();
+    file.write_all(content.as_bytes()).unwrap();
+    file
+}
+
+/// 辅助函数：创建包含 TOML 基础配置和 JSON 覆盖配置的管理器
+async fn create_layered_manager(
+    base: &NamedTempFile,
+    overlay: &NamedTempFile,
+) -> manager::AdSystemConfigManager {
+    let mut manager = manager::AdSystemConfigManager::new();
+    manager
+        .register_provider(Box::new(
+            TomlConfigProvider::new(base.path()).unwrap().with_priority(100),
+        ))
+        .await
+        .unwrap();
+    manager
+        .register_provider(Box::new(
+            JsonConfigProvider::new(overlay.path()).unwrap().with_priority(150),
+        ))
+        .await
+        .unwrap();
+    manager
+}
+
+/// 测试配置节跨提供者深度合并
+#[tokio::test]
+async fn test_sections_are_deep_merged_across_providers() {
+    let base = write_temp_config(
+        ".toml",
+        "[database]\nhost = \"localhost\"\nport = 5432\n\n[dsp]\nendpoints = [\"a\", \"b\"]\n",
+    );
+    let overlay = write_temp_config(
+        ".json",
+        &json!({"database": {"port": 6432}, "dsp": {"endpoints": ["c"]}}).to_string(),
+    );
+    let manager = create_layered_manager(&base, &overlay).await;
+
+    let database = manager.get_configuration("database").await.unwrap();
+    assert_eq!(database, json!({"host": "localhost", "port": 6432}));
+
+    let section = manager.get_section("database").await.unwrap();
+    assert_eq!(section.get("host"), Some(&json!("localhost")));
+
+    let endpoints: Vec<String> = manager.bind_configuration("dsp.endpoints").await.unwrap();
+    assert_eq!(endpoints, vec!["c"]);
+}
+
+/// 测试数组追加策略
+#[tokio::test]
+async fn test_array_append_strategy() {
+    let base = write_temp_config(".toml", "[dsp]\nendpoints = [\"a\", \"b\"]\n");
+    let overlay = write_temp_config(".json", &json!({"dsp": {"endpoints": ["c"]}}).to_string());
+    let mut manager = create_layered_manager(&base, &overlay).await;
+    manager
+        .set_array_merge_strategy("dsp.endpoints", ArrayMergeStrategy::Append)
+        .await
+        .unwrap();
+
+    let endpoints = manager.get_configuration("dsp.endpoints").await.unwrap();
+    assert_eq!(endpoints, json!(["a", "b", "c"]));
+}
+
+/// 测试来源查询与生效配置导出
+#[tokio::test]
+async fn test_explain_and_dump_configuration() {
+    let base = write_temp_config(".toml", "[database]\nhost = \"localhost\"\nport = 5432\n");
+    let overlay = write_temp_config(".json", &json!({"database": {"port": 6432}}).to_string());
+    let manager = create_layered_manager(&base, &overlay).await;
+
+    let provenance = manager.explain_configuration("database.port").await.unwrap();
+    let winner = provenance.winner.as_ref().unwrap();
+    assert_eq!(winner.source, overlay.path().display().to_string());
+    assert_eq!(provenance.shadowed.len(), 1);
+    assert_eq!(provenance.shadowed[0].value, json!(5432));
+    assert!(provenance.to_string().contains("被覆盖"));
+
+    let dump = manager.dump_effective_configuration().await.unwrap();
+    assert_eq!(
+        dump.get("database.host").unwrap().sources,
+        vec![base.path().display().to_string()]
+    );
+    assert_eq!(dump.get("database.port").unwrap().value, json!(6432));
+}
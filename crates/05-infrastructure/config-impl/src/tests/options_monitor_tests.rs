@@ -0,0 +1,124 @@
+//! 类型化实时配置测试
+
+use super::super::*;
+use async_trait::async_trait;
+use config_abstractions::{ConfigManager, ConfigValidator};
+use infrastructure_common::ValidationError;
+use serde::Deserialize;
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::Arc;
+use std::time::Duration;
+
+/// 测试用服务器配置
+#[derive(Debug, Clone, PartialEq, Deserialize)]
+struct ServerOptions {
+    host: String,
+    port: u16,
+}
+
+/// 端口不能为 0 的验证器
+struct NonZeroPortValidator;
+
+#[async_trait]
+impl ConfigValidator<ServerOptions> for NonZeroPortValidator {
+    async fn validate(&self, config: &ServerOptions) -> Result<(), ValidationError> {
+        if config.port == 0 {
+            return Err(ValidationError::invalid_field_value(
+                "server.port",
+                config.port.to_string(),
+                "端口不能为 0",
+            ));
+        }
+        Ok(())
+    }
+
+    fn name(&self) -> &str {
+        "NonZeroPortValidator"
+    }
+}
+
+/// 辅助函数：创建包含 TOML 配置文件的管理器
+async fn create_manager(
+    dir: &tempfile::TempDir,
+    content: &str,
+) -> (manager::AdSystemConfigManager, std::path::PathBuf) {
+    let path = dir.path().join("app.toml");
+    std::fs::write(&path, content).unwrap();
+
+    let mut manager = manager::AdSystemConfigManager::new();
+    manager
+        .register_provider(Box::new(TomlConfigProvider::new(&path).unwrap()))
+        .await
+        .unwrap();
+    (manager, path)
+}
+
+/// 测试重载后实时配置自动更新，并通知回调和 watch 通道
+#[tokio::test]
+async fn test_monitor_follows_reloads() {
+    let dir = tempfile::tempdir().unwrap();
+    let (manager, path) =
+        create_manager(&dir, "[server]\nhost = \"a.internal\"\nport = 8080\n").await;
+
+    let monitor = manager
+        .options_monitor::<ServerOptions>("server")
+        .await
+        .unwrap();
+    assert_eq!(monitor.current().port, 8080);
+
+    let notified = Arc::new(AtomicUsize::new(0));
+    let counter = notified.clone();
+    monitor.on_change(move |options: &ServerOptions| {
+        assert_eq!(options.port, 9090);
+        counter.fetch_add(1, Ordering::SeqCst);
+    });
+    let mut receiver = monitor.subscribe();
+
+    std::fs::write(&path, "[server]\nhost = \"a.internal\"\nport = 9090\n").unwrap();
+    manager.reload_and_notify("test").await.unwrap();
+
+    tokio::time::timeout(Duration::from_secs(5), receiver.changed())
+        .await
+        .expect("等待配置更新超时")
+        .unwrap();
+    assert_eq!(receiver.borrow().port, 9090);
+    assert_eq!(monitor.current().port, 9090);
+    assert_eq!(notified.load(Ordering::SeqCst), 1);
+}
+
+/// 测试无法绑定或未通过验证的新值不会替换当前值
+#[tokio::test]
+async fn test_monitor_keeps_previous_value_when_invalid() {
+    let dir = tempfile::tempdir().unwrap();
+    let (manager, path) =
+        create_manager(&dir, "[server]\nhost = \"a.internal\"\nport = 8080\n").await;
+
+    let monitor = manager
+        .options_monitor_with_validator::<ServerOptions>(
+            "server",
+            Arc::new(NonZeroPortValidator),
+        )
+        .await
+        .unwrap();
+
+    std::fs::write(&path, "[server]\nhost = \"a.internal\"\nport = 0\n").unwrap();
+    manager.reload_and_notify("test").await.unwrap();
+    assert!(monitor.refresh().await.is_err());
+    assert_eq!(monitor.current().port, 8080);
+
+    std::fs::write(&path, "[server]\nhost = \"a.internal\"\nport = \"http\"\n").unwrap();
+    manager.reload_and_notify("test").await.unwrap();
+    assert!(monitor.refresh().await.is_err());
+    assert_eq!(monitor.current().port, 8080);
+
+    std::fs::write(&path, "[server]\nhost = \"b.internal\"\nport = 8081\n").unwrap();
+    manager.reload_and_notify("test").await.unwrap();
+    monitor.refresh().await.unwrap();
+    assert_eq!(
+        *monitor.current(),
+        ServerOptions {
+            host: "b.internal".to_string(),
+            port: 8081,
+        }
+    );
+}
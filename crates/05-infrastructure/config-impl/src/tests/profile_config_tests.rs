@@ -0,0 +1,131 @@
+//! 配置档配置文件加载测试
+
+use super::super::*;
+use config_abstractions::ConfigManager;
+use infrastructure_common::{ActiveProfiles, ConfigError};
+use serde_json::json;
+use std::path::Path;
+use tempfile::TempDir;
+
+/// 辅助函数：在目录中写入配置文件
+fn write_file(dir: &Path, name: &str, content: &str) {
+    std::fs::write(dir.join(name), content).unwrap();
+}
+
+/// 辅助函数：创建包含基础配置和配置档覆盖文件的目录
+fn create_config_dir() -> TempDir {
+    let dir = TempDir::new().unwrap();
+    write_file(
+        dir.path(),
+        "config.toml",
+        "[app]\nname = \"base\"\nregion = \"cn\"\nlevel = \"info\"\n",
+    );
+    write_file(dir.path(), "config.prod.json", r#"{"app": {"name": "prod"}}"#);
+    write_file(dir.path(), "config.eu.yaml", "app:\n  region: eu\n");
+    write_file(dir.path(), "config.prod.local.toml", "[app]\nlevel = \"debug\"\n");
+    write_file(dir.path(), "config.dev.toml", "[app]\nname = \"dev\"\n");
+    write_file(dir.path(), "other.prod.toml", "[app]\nname = \"other\"\n");
+    dir
+}
+
+/// 测试配置文件的发现顺序
+#[test]
+fn test_discover_order() {
+    let dir = create_config_dir();
+    let loader = ProfileConfigLoader::new(dir.path(), "config")
+        .with_profiles(ActiveProfiles::new(["production", "eu"]));
+
+    let files = loader.discover().unwrap();
+    let names: Vec<String> = files
+        .iter()
+        .map(|file| file.path.file_name().unwrap().to_string_lossy().to_string())
+        .collect();
+    assert_eq!(
+        names,
+        vec![
+            "config.toml",
+            "config.prod.json",
+            "config.eu.yaml",
+            "config.prod.local.toml"
+        ]
+    );
+    assert!(files.windows(2).all(|pair| pair[0].priority < pair[1].priority));
+    assert!(files[3].local);
+    assert_eq!(files[2].profile.as_deref(), Some("eu"));
+}
+
+/// 测试多个配置档叠加后的生效配置
+#[tokio::test]
+async fn test_profile_overlays_are_merged() {
+    let dir = create_config_dir();
+    let loader = ProfileConfigLoader::new(dir.path(), "config")
+        .with_profiles(ActiveProfiles::parse("prod,eu"));
+
+    let mut manager = manager::AdSystemConfigManager::new();
+    for provider in loader.load_providers().unwrap() {
+        manager.register_provider(provider).await.unwrap();
+    }
+
+    let app = manager.get_configuration("app").await.unwrap();
+    assert_eq!(
+        app,
+        json!({"name": "prod", "region": "eu", "level": "debug"})
+    );
+}
+
+/// 测试未激活配置档时只加载基础配置
+#[test]
+fn test_no_profiles_loads_base_only() {
+    let dir = create_config_dir();
+    let loader =
+        ProfileConfigLoader::new(dir.path(), "config").with_profiles(ActiveProfiles::default());
+
+    let files = loader.discover().unwrap();
+    assert_eq!(files.len(), 1);
+    assert!(files[0].profile.is_none());
+}
+
+/// 测试多组配置文件中所有基础配置都排在配置档覆盖之前
+#[test]
+fn test_group_loader_orders_base_files_below_overlays() {
+    let dir = create_config_dir();
+    write_file(dir.path(), "other.toml", "[app]\nname = \"other-base\"\n");
+    let profiles = ActiveProfiles::new(["prod"]);
+    let loader = ProfileConfigGroupLoader::new(100, 199)
+        .add_group(ProfileConfigLoader::new(dir.path(), "config").with_profiles(profiles.clone()))
+        .add_group(ProfileConfigLoader::new(dir.path(), "other").with_profiles(profiles));
+
+    let files = loader.discover().unwrap();
+    let names: Vec<(String, i32)> = files
+        .iter()
+        .map(|file| {
+            let name = file.path.file_name().unwrap().to_string_lossy().to_string();
+            (name, file.priority)
+        })
+        .collect();
+    assert_eq!(
+        names,
+        vec![
+            ("config.toml".to_string(), 100),
+            ("other.toml".to_string(), 101),
+            ("config.prod.json".to_string(), 102),
+            ("other.prod.toml".to_string(), 103),
+            ("config.prod.local.toml".to_string(), 104),
+        ]
+    );
+}
+
+/// 测试配置文件超过优先级上限时返回错误
+#[test]
+fn test_group_loader_rejects_priority_overflow() {
+    let dir = create_config_dir();
+    let loader = ProfileConfigGroupLoader::new(100, 102).add_group(
+        ProfileConfigLoader::new(dir.path(), "config")
+            .with_profiles(ActiveProfiles::new(["prod", "eu"])),
+    );
+
+    assert!(matches!(
+        loader.discover(),
+        Err(ConfigError::ValidationError { .. })
+    ));
+}
@@ -1,18 +1,338 @@
 //! 配置验证管理器实现
+//!
+//! 验证器以类型擦除的形式按配置类型注册，配置选项注册时同时记录一个
+//! [`OptionsValidationTarget`]，负责把原始配置绑定为具体类型并调用对应的验证器。
+//! 同步验证器依次执行，异步验证器（`is_async`）并发执行并受超时限制
 
+use crate::manager::AdSystemConfigManager;
 use async_trait::async_trait;
-use config_abstractions::{ConfigValidationManager, ConfigValidator, ValidatorInfo};
+use config_abstractions::manager::{ValidationError as ConfigValidationError, ValidationErrorType};
 use config_abstractions::validator::ValidationResult;
-use infrastructure_common::ConfigError;
-use std::any::TypeId;
+use config_abstractions::{
+    ChainedConfigValidator, ConfigManager, ConfigValidationManager, ConfigValidator, ValidatorInfo,
+    REDACTED_VALUE,
+};
+use infrastructure_common::{ConfigError, ValidationError};
+use serde::de::DeserializeOwned;
+use serde_json::Value;
+use std::any::{Any, TypeId};
 use std::collections::HashMap;
+use std::marker::PhantomData;
+use std::sync::Arc;
+use std::time::Duration;
 use tracing::{debug, info, warn};
 
+/// 异步验证器的默认超时时间
+pub const DEFAULT_ASYNC_VALIDATION_TIMEOUT: Duration = Duration::from_secs(5);
+
+/// 类型擦除的验证器
+type ErasedValidator = Box<dyn Any + Send + Sync>;
+
+/// 类型擦除的配置选项验证目标
+///
+/// 在配置类型已知的位置创建（如注册组件配置选项时），
+/// 验证时把原始配置绑定为该类型并调用已注册的验证器
+#[async_trait]
+pub trait OptionsValidationTarget: Send + Sync {
+    /// 配置类型标识
+    fn options_type_id(&self) -> TypeId;
+
+    /// 配置类型名称
+    fn options_type_name(&self) -> &'static str;
+
+    /// 获取验证器的名称和是否为异步验证器；验证器类型不匹配时返回 `None`
+    fn validator_info(&self, validator: &(dyn Any + Send + Sync)) -> Option<(String, bool)>;
+
+    /// 绑定原始配置并执行验证器
+    async fn validate(
+        &self,
+        validator: &(dyn Any + Send + Sync),
+        raw: &Value,
+    ) -> Result<(), ValidationError>;
+}
+
+/// 具体配置类型的验证目标
+pub struct TypedOptionsTarget<T> {
+    _marker: PhantomData<fn() -> T>,
+}
+
+impl<T> TypedOptionsTarget<T>
+where
+    T: DeserializeOwned + Send + Sync + 'static,
+{
+    /// 创建类型擦除的验证目标
+    pub fn shared() -> Arc<dyn OptionsValidationTarget> {
+        Arc::new(Self {
+            _marker: PhantomData,
+        })
+    }
+}
+
+#[async_trait]
+impl<T> OptionsValidationTarget for TypedOptionsTarget<T>
+where
+    T: DeserializeOwned + Send + Sync + 'static,
+{
+    fn options_type_id(&self) -> TypeId {
+        TypeId::of::<T>()
+    }
+
+    fn options_type_name(&self) -> &'static str {
+        std::any::type_name::<T>()
+    }
+
+    fn validator_info(&self, validator: &(dyn Any + Send + Sync)) -> Option<(String, bool)> {
+        validator
+            .downcast_ref::<Box<dyn ConfigValidator<T>>>()
+            .map(|validator| (validator.name().to_string(), validator.is_async()))
+    }
+
+    async fn validate(
+        &self,
+        validator: &(dyn Any + Send + Sync),
+        raw: &Value,
+    ) -> Result<(), ValidationError> {
+        let Some(validator) = validator.downcast_ref::<Box<dyn ConfigValidator<T>>>() else {
+            return Ok(());
+        };
+        // 原始配置已解析密钥引用，错误中只报告绑定失败原因，不包含配置值
+        let options: T = serde_json::from_value(raw.clone()).map_err(|e| {
+            ValidationError::invalid_field_value(
+                "",
+                REDACTED_VALUE,
+                format!("无法绑定为 {}: {}", std::any::type_name::<T>(), e),
+            )
+        })?;
+        validator.validate(&options).await
+    }
+}
+
+/// 待验证的配置选项
+pub struct OptionsValidationEntry {
+    /// 配置路径
+    pub path: String,
+    /// 是否必需
+    pub required: bool,
+    /// 验证目标
+    pub target: Arc<dyn OptionsValidationTarget>,
+    /// 原始配置（获取失败时为错误）
+    pub raw: Result<Value, ConfigError>,
+}
+
+/// 单个配置选项的验证失败
+#[derive(Debug, Clone)]
+pub struct OptionsValidationFailure {
+    /// 完整配置路径（配置路径加字段名）
+    pub path: String,
+    /// 验证器名称
+    pub validator: Option<String>,
+    /// 验证错误
+    pub error: ValidationError,
+}
+
+impl OptionsValidationFailure {
+    /// 创建验证失败，字段名不是完整路径时以配置路径作为前缀
+    fn new(path: &str, validator: Option<String>, error: ValidationError) -> Self {
+        let path = match field_name(&error) {
+            Some(field) if !field.is_empty() => qualify_path(path, field),
+            _ => path.to_string(),
+        };
+        Self {
+            path,
+            validator,
+            error,
+        }
+    }
+
+    /// 转换为字段名为完整路径的验证错误
+    pub fn to_qualified_error(&self) -> ValidationError {
+        let mut error = self.error.clone();
+        match &mut error {
+            ValidationError::RequiredFieldMissing { field_name }
+            | ValidationError::InvalidFieldValue { field_name, .. }
+            | ValidationError::ValueOutOfRange { field_name, .. }
+            | ValidationError::FormatError { field_name, .. } => {
+                *field_name = self.path.clone();
+            }
+            ValidationError::ValidationFailed { message } => {
+                *message = format!("{}: {}", self.path, message);
+            }
+        }
+        error
+    }
+
+    /// 转换为配置管理器的验证错误
+    pub fn to_config_validation_error(&self) -> ConfigValidationError {
+        let path = self.path.clone();
+        let mut error = match &self.error {
+            ValidationError::RequiredFieldMissing { .. } => {
+                ConfigValidationError::required_field_missing(path)
+            }
+            // 验证器作用于解析密钥后的配置，不保留字段的实际值
+            ValidationError::InvalidFieldValue { reason, .. } => {
+                ConfigValidationError::new(path, reason.clone(), ValidationErrorType::Custom)
+            }
+            ValidationError::ValueOutOfRange { range, .. } => {
+                let mut error = ConfigValidationError::new(
+                    path,
+                    "值超出范围",
+                    ValidationErrorType::ValueOutOfRange,
+                );
+                error.expected = Some(range.clone());
+                error
+            }
+            ValidationError::FormatError {
+                expected_format, ..
+            } => ConfigValidationError::format_error(path, expected_format.clone()),
+            ValidationError::ValidationFailed { message } => {
+                ConfigValidationError::new(path, message.clone(), ValidationErrorType::Custom)
+            }
+        };
+        if let Some(validator) = &self.validator {
+            error.message = format!("[{}] {}", validator, error.message);
+        }
+        error
+    }
+}
+
+/// 验证错误中的字段名
+fn field_name(error: &ValidationError) -> Option<&str> {
+    match error {
+        ValidationError::RequiredFieldMissing { field_name }
+        | ValidationError::InvalidFieldValue { field_name, .. }
+        | ValidationError::ValueOutOfRange { field_name, .. }
+        | ValidationError::FormatError { field_name, .. } => Some(field_name),
+        ValidationError::ValidationFailed { .. } => None,
+    }
+}
+
+/// 以配置路径限定字段名，字段名已是完整路径时保持不变
+fn qualify_path(path: &str, field: &str) -> String {
+    let already_qualified = field == path
+        || field
+            .strip_prefix(path)
+            .is_some_and(|rest| rest.starts_with('.'));
+    if already_qualified || path.is_empty() {
+        field.to_string()
+    } else {
+        format!("{}.{}", path, field)
+    }
+}
+
+/// 注册类型擦除的验证器，同一类型已有验证器时与其串联
+pub(crate) fn insert_chained_validator<T>(
+    validators: &mut HashMap<TypeId, ErasedValidator>,
+    validator: Box<dyn ConfigValidator<T>>,
+) where
+    T: Send + Sync + 'static,
+{
+    let type_id = TypeId::of::<T>();
+    let validator = match validators.remove(&type_id).map(|existing| existing.downcast::<Box<dyn ConfigValidator<T>>>()) {
+        Some(Ok(existing)) => {
+            let existing: Box<dyn ConfigValidator<T>> = *existing;
+            debug!(
+                "串联配置验证器: {} + {} -> {}",
+                existing.name(),
+                validator.name(),
+                std::any::type_name::<T>()
+            );
+            Box::new(ChainedConfigValidator::new(existing, validator))
+        }
+        _ => validator,
+    };
+    validators.insert(type_id, Box::new(validator));
+}
+
+/// 对所有配置选项执行已注册的验证器
+///
+/// 返回验证失败列表和实际执行的验证器数量
+pub async fn run_options_validators(
+    entries: Vec<OptionsValidationEntry>,
+    validators: &HashMap<TypeId, ErasedValidator>,
+    async_timeout: Duration,
+) -> (Vec<OptionsValidationFailure>, usize) {
+    let mut failures = Vec::new();
+    let mut validated_count = 0;
+    let mut async_runs = Vec::new();
+
+    for entry in &entries {
+        let Some(validator) = validators.get(&entry.target.options_type_id()) else {
+            continue;
+        };
+        let Some((name, is_async)) = entry.target.validator_info(validator.as_ref()) else {
+            continue;
+        };
+
+        let raw = match &entry.raw {
+            Ok(raw) => raw,
+            Err(ConfigError::KeyNotFound { .. }) => {
+                if entry.required {
+                    failures.push(OptionsValidationFailure::new(
+                        &entry.path,
+                        Some(name),
+                        ValidationError::required_field_missing(""),
+                    ));
+                }
+                continue;
+            }
+            Err(e) => {
+                failures.push(OptionsValidationFailure::new(
+                    &entry.path,
+                    Some(name),
+                    ValidationError::new(format!("获取配置失败: {}", e)),
+                ));
+                continue;
+            }
+        };
+
+        validated_count += 1;
+        if is_async {
+            async_runs.push(async move {
+                let result = tokio::time::timeout(
+                    async_timeout,
+                    entry.target.validate(validator.as_ref(), raw),
+                )
+                .await;
+                (entry.path.as_str(), name, result)
+            });
+        } else {
+            debug!("执行配置验证器: {} -> {}", name, entry.path);
+            if let Err(error) = entry.target.validate(validator.as_ref(), raw).await {
+                failures.push(OptionsValidationFailure::new(&entry.path, Some(name), error));
+            }
+        }
+    }
+
+    for (path, name, result) in futures::future::join_all(async_runs).await {
+        match result {
+            Ok(Ok(())) => {}
+            Ok(Err(error)) => {
+                failures.push(OptionsValidationFailure::new(path, Some(name), error));
+            }
+            Err(_) => {
+                warn!("异步配置验证器超时: {} -> {}", name, path);
+                failures.push(OptionsValidationFailure::new(
+                    path,
+                    Some(name),
+                    ValidationError::new(format!("验证超时（{:?}）", async_timeout)),
+                ));
+            }
+        }
+    }
+
+    (failures, validated_count)
+}
+
 /// 配置验证管理器实现
-#[derive(Debug)]
 pub struct ConfigValidationManagerImpl {
     /// 验证器映射（TypeId -> 验证器）
-    validators: HashMap<TypeId, Box<dyn std::any::Any + Send + Sync>>,
+    validators: HashMap<TypeId, ErasedValidator>,
+    /// 已注册的配置选项（路径 -> 是否必需与验证目标）
+    options: HashMap<String, (bool, Arc<dyn OptionsValidationTarget>)>,
+    /// 配置管理器
+    config_manager: Option<Arc<AdSystemConfigManager>>,
+    /// 异步验证器超时时间
+    async_timeout: Duration,
 }
 
 impl ConfigValidationManagerImpl {
@@ -20,13 +340,35 @@ impl ConfigValidationManagerImpl {
     pub fn new() -> Self {
         Self {
             validators: HashMap::new(),
+            options: HashMap::new(),
+            config_manager: None,
+            async_timeout: DEFAULT_ASYNC_VALIDATION_TIMEOUT,
         }
     }
-    
+
     /// 获取已注册的验证器数量
     pub fn validator_count(&self) -> usize {
         self.validators.len()
     }
+
+    /// 设置配置管理器，`validate_all` 从中绑定配置选项
+    pub fn set_config_manager(&mut self, manager: Arc<AdSystemConfigManager>) {
+        self.config_manager = Some(manager);
+    }
+
+    /// 设置异步验证器超时时间
+    pub fn set_async_timeout(&mut self, timeout: Duration) {
+        self.async_timeout = timeout;
+    }
+
+    /// 注册需要验证的配置选项
+    pub fn register_options<T>(&mut self, path: impl Into<String>, required: bool)
+    where
+        T: DeserializeOwned + Send + Sync + 'static,
+    {
+        self.options
+            .insert(path.into(), (required, TypedOptionsTarget::<T>::shared()));
+    }
 }
 
 impl Default for ConfigValidationManagerImpl {
@@ -35,26 +377,37 @@ impl Default for ConfigValidationManagerImpl {
     }
 }
 
+impl std::fmt::Debug for ConfigValidationManagerImpl {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let mut options: Vec<&String> = self.options.keys().collect();
+        options.sort();
+        f.debug_struct("ConfigValidationManagerImpl")
+            .field("validators_count", &self.validators.len())
+            .field("options", &options)
+            .field("has_config_manager", &self.config_manager.is_some())
+            .field("async_timeout", &self.async_timeout)
+            .finish()
+    }
+}
+
 #[async_trait]
 impl ConfigValidationManager for ConfigValidationManagerImpl {
     async fn register_validator<T>(&mut self, validator: Box<dyn ConfigValidator<T>>) -> Result<(), ConfigError>
     where
-        T: 'static,
+        T: Send + Sync + 'static,
     {
-        let type_id = TypeId::of::<T>();
         info!("注册配置验证器: {} -> {}", validator.name(), std::any::type_name::<T>());
-        
-        let boxed_validator: Box<dyn std::any::Any + Send + Sync> = Box::new(validator);
-        self.validators.insert(type_id, boxed_validator);
+
+        insert_chained_validator(&mut self.validators, validator);
         Ok(())
     }
-    
+
     async fn unregister_validator<T>(&mut self) -> Result<(), ConfigError>
     where
         T: 'static,
     {
         let type_id = TypeId::of::<T>();
-        
+
         if self.validators.remove(&type_id).is_some() {
             info!("移除配置验证器: {}", std::any::type_name::<T>());
             Ok(())
@@ -65,74 +418,124 @@ impl ConfigValidationManager for ConfigValidationManagerImpl {
             })
         }
     }
-    
+
     async fn validate_options_type<T>(&self, config: &T) -> Result<ValidationResult, ConfigError>
     where
-        T: 'static,
+        T: Send + Sync + 'static,
     {
         let type_id = TypeId::of::<T>();
-        
-        if let Some(validator) = self.validators.get(&type_id) {
-            // 由于类型擦除，这里需要进行类型转换
-            // 实际实现中可能需要更复杂的处理
-            debug!("验证配置类型: {}", std::any::type_name::<T>());
-            
-            let start_time = std::time::Instant::now();
-            
-            // 这里应该调用实际的验证逻辑
-            // 由于类型系统的限制，暂时返回成功结果
-            let mut result = ValidationResult::success();
-            result.duration = start_time.elapsed();
-            result.validated_count = 1;
-            
-            Ok(result)
-        } else {
+
+        let Some(validator) = self
+            .validators
+            .get(&type_id)
+            .and_then(|validator| validator.downcast_ref::<Box<dyn ConfigValidator<T>>>())
+        else {
             warn!("配置验证器未注册: {}", std::any::type_name::<T>());
-            Err(ConfigError::KeyNotFound {
+            return Err(ConfigError::KeyNotFound {
                 key: std::any::type_name::<T>().to_string(),
-            })
-        }
+            });
+        };
+
+        debug!("验证配置类型: {}", std::any::type_name::<T>());
+        let start_time = std::time::Instant::now();
+
+        let outcome = if validator.is_async() {
+            tokio::time::timeout(self.async_timeout, validator.validate(config))
+                .await
+                .unwrap_or_else(|_| {
+                    Err(ValidationError::new(format!("验证超时（{:?}）", self.async_timeout)))
+                })
+        } else {
+            validator.validate(config).await
+        };
+
+        let mut result = match outcome {
+            Ok(()) => ValidationResult::success(),
+            Err(error) => ValidationResult::failure(vec![error]),
+        };
+        result.duration = start_time.elapsed();
+        result.validated_count = 1;
+        Ok(result)
     }
-    
+
     async fn validate_all(&self) -> Result<ValidationResult, ConfigError> {
         info!("验证所有已注册的配置类型");
-        
+
         let start_time = std::time::Instant::now();
+        let Some(manager) = &self.config_manager else {
+            warn!("未设置配置管理器，跳过配置选项验证");
+            return Ok(ValidationResult::success());
+        };
+
+        let mut entries = Vec::with_capacity(self.options.len());
+        for (path, (required, target)) in &self.options {
+            entries.push(OptionsValidationEntry {
+                path: path.clone(),
+                required: *required,
+                target: target.clone(),
+                raw: manager.get_configuration(path).await,
+            });
+        }
+
+        let (failures, validated_count) =
+            run_options_validators(entries, &self.validators, self.async_timeout).await;
+
         let mut overall_result = ValidationResult::success();
-        
-        // 遍历所有验证器并执行验证
-        for (_type_id, _validator) in &self.validators {
-            // 这里需要实际的验证逻辑
-            // 由于类型擦除的问题，实际实现会更复杂
+        for failure in &failures {
+            overall_result.add_error(failure.to_qualified_error());
         }
-        
         overall_result.duration = start_time.elapsed();
-        overall_result.validated_count = self.validators.len();
-        
-        info!("配置验证完成，验证了 {} 个类型", overall_result.validated_count);
+        overall_result.validated_count = validated_count;
+
+        info!(
+            "配置验证完成，验证了 {} 个配置选项，错误数: {}",
+            overall_result.validated_count,
+            overall_result.errors.len()
+        );
         Ok(overall_result)
     }
-    
+
     async fn register_all_validators(&mut self) -> Result<(), ConfigError> {
         info!("注册所有配置验证器");
-        
+
         // 这里应该扫描所有实现了 ConfigValidator<T> 的类型
         // 并自动注册它们
         // 实际实现需要结合组件发现机制
-        
+
         info!("完成注册所有配置验证器");
         Ok(())
     }
-    
+
     fn get_registered_validators(&self) -> Vec<ValidatorInfo> {
         self.validators
-            .keys()
-            .map(|type_id| ValidatorInfo {
-                name: "UnknownValidator".to_string(),
-                target_type: format!("{:?}", type_id),
-                version: "1.0.0".to_string(),
-                description: None,
-                is_async: false,
+            .iter()
+            .map(|(type_id, validator)| {
+                let described = self
+                    .options
+                    .values()
+                    .map(|(_, target)| target)
+                    .filter(|target| target.options_type_id() == *type_id)
+                    .find_map(|target| {
+                        target
+                            .validator_info(validator.as_ref())
+                            .map(|info| (target.options_type_name(), info))
+                    });
+                match described {
+                    Some((target_type, (name, is_async))) => ValidatorInfo {
+                        name,
+                        target_type: target_type.to_string(),
+                        version: "1.0.0".to_string(),
+                        description: None,
+                        is_async,
+                    },
+                    None => ValidatorInfo {
+                        name: "UnknownValidator".to_string(),
+                        target_type: format!("{:?}", type_id),
+                        version: "1.0.0".to_string(),
+                        description: None,
+                        is_async: false,
+                    },
+                }
             })
             .collect()
     }
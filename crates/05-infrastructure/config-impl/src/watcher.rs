@@ -3,14 +3,23 @@
 use async_trait::async_trait;
 use config_abstractions::{ConfigWatcher, FileSystemConfigWatcher, FileFilter};
 use config_abstractions::events::ConfigChangeEvent;
-use infrastructure_common::ConfigError;
+use infrastructure_common::{ConfigError, CONFIG_WATCH_EVENTS_TOTAL};
 use notify::{Watcher, RecursiveMode, recommended_watcher, Event, EventKind};
 use std::path::{Path, PathBuf};
+use std::sync::Arc;
 use std::time::Duration;
 use tokio::sync::mpsc;
 use tracing::{debug, error, info, warn};
 
+/// 原始文件系统事件通道容量
+///
+/// 通道已满时丢弃新事件：积压的事件已足以触发一次防抖后的重载
+const RAW_EVENT_CHANNEL_CAPACITY: usize = 1024;
+
 /// 配置文件监控器实现
+///
+/// notify 回调中的原始文件系统事件经通道转发到后台任务，
+/// 在防抖延迟内没有新事件后，按文件合并为配置变更事件发送
 pub struct ConfigFileWatcher {
     /// 文件系统监控器
     watcher: Option<notify::RecommendedWatcher>,
@@ -18,8 +27,10 @@ pub struct ConfigFileWatcher {
     change_sender: mpsc::Sender<ConfigChangeEvent>,
     /// 配置变更事件接收器
     change_receiver: Option<mpsc::Receiver<ConfigChangeEvent>>,
-    /// 监控路径列表
-    watched_paths: Vec<PathBuf>,
+    /// 已注册的监控路径
+    registrations: Vec<WatchRegistration>,
+    /// 防抖任务用于匹配事件的监控范围，运行期间增删监控路径时同步更新
+    scopes: Arc<parking_lot::RwLock<Vec<WatchScope>>>,
     /// 是否正在监控
     is_watching: bool,
     /// 监控间隔
@@ -27,13 +38,15 @@ pub struct ConfigFileWatcher {
     /// 防抖延迟
     debounce_delay: Duration,
     /// 文件过滤器
-    file_filter: Option<Box<dyn FileFilter>>,
+    file_filter: Option<Arc<dyn FileFilter>>,
+    /// 防抖处理任务句柄
+    debounce_task: Option<tokio::task::JoinHandle<()>>,
 }
 
 impl std::fmt::Debug for ConfigFileWatcher {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         f.debug_struct("ConfigFileWatcher")
-            .field("watched_paths", &self.watched_paths)
+            .field("watched_paths", &self.get_watched_paths())
             .field("is_watching", &self.is_watching)
             .field("watch_interval", &self.watch_interval)
             .field("debounce_delay", &self.debounce_delay)
@@ -51,56 +64,229 @@ impl ConfigFileWatcher {
             watcher: None,
             change_sender,
             change_receiver: Some(change_receiver),
-            watched_paths: Vec::new(),
+            registrations: Vec::new(),
+            scopes: Arc::new(parking_lot::RwLock::new(Vec::new())),
             is_watching: false,
             watch_interval: Duration::from_secs(1),
             debounce_delay: Duration::from_millis(500),
-            file_filter: Some(Box::new(config_abstractions::watcher::ExtensionFileFilter::config_files())),
+            file_filter: Some(Arc::new(config_abstractions::watcher::ExtensionFileFilter::config_files())),
+            debounce_task: None,
         })
     }
     
-    /// 处理文件系统事件
-    async fn handle_file_event(&self, event: Event) -> Result<(), ConfigError> {
-        debug!("处理文件系统事件: {:?}", event);
-        
-        for path in event.paths {
-            // 检查文件是否应该被监控
-            if let Some(ref filter) = self.file_filter {
-                if !filter.should_watch(&path) {
-                    continue;
+    /// 目标当前需要的监控模式，没有注册引用该目标时返回 None
+    ///
+    /// 多个注册共享同一个目标（如同一目录下的多个文件）时，
+    /// 只有最后一个引用移除后才释放目标，任一注册需要递归时按递归监控
+    fn target_mode(&self, target: &Path) -> Option<RecursiveMode> {
+        self.registrations
+            .iter()
+            .filter(|registration| registration.target == target)
+            .map(|registration| registration.mode)
+            .reduce(|a, b| {
+                if a == RecursiveMode::Recursive || b == RecursiveMode::Recursive {
+                    RecursiveMode::Recursive
+                } else {
+                    RecursiveMode::NonRecursive
                 }
+            })
+    }
+    
+    /// 注册变化后让正在运行的监控器与目标需要的监控模式保持一致
+    fn sync_target(&mut self, target: &Path, before: Option<RecursiveMode>) -> Result<(), notify::Error> {
+        let after = self.target_mode(target);
+        let Some(watcher) = self.watcher.as_mut() else {
+            return Ok(());
+        };
+        match (before, after) {
+            (Some(_), None) => watcher.unwatch(target),
+            (_, Some(mode)) if before != after => watcher.watch(target, mode),
+            _ => Ok(()),
+        }
+    }
+    
+    /// 根据监控路径列表刷新共享的监控范围
+    fn refresh_scopes(&self) {
+        *self.scopes.write() = self
+            .registrations
+            .iter()
+            .map(|registration| registration.scope.clone())
+            .collect();
+    }
+    
+    /// 防抖处理循环
+    ///
+    /// 收到第一个事件后等待防抖延迟，期间的新事件会重置计时，
+    /// 计时结束后每个文件只发送一个配置变更事件
+    async fn run_debounce_loop(
+        mut raw_receiver: mpsc::Receiver<Event>,
+        change_sender: mpsc::Sender<ConfigChangeEvent>,
+        scopes: Arc<parking_lot::RwLock<Vec<WatchScope>>>,
+        file_filter: Option<Arc<dyn FileFilter>>,
+        debounce_delay: Duration,
+    ) {
+        while let Some(first) = raw_receiver.recv().await {
+            let mut pending: Vec<(PathBuf, EventKind)> = Vec::new();
+            Self::collect_event(&mut pending, first);
+
+            while let Ok(Some(event)) =
+                tokio::time::timeout(debounce_delay, raw_receiver.recv()).await
+            {
+                Self::collect_event(&mut pending, event);
             }
-            
-            let config_event = match event.kind {
-                EventKind::Create(_) => {
-                    ConfigChangeEvent::created(
-                        path.to_string_lossy().to_string(),
-                        serde_json::Value::Null,
-                        "FileSystemWatcher",
-                    )
+
+            let scopes = scopes.read().clone();
+            for (path, kind) in pending {
+                if !scopes.iter().any(|scope| scope.contains(&path)) {
+                    continue;
                 }
-                EventKind::Modify(_) => {
-                    ConfigChangeEvent::reloaded(
-                        path.to_string_lossy().to_string(),
-                        "FileSystemWatcher",
-                    )
+                if let Some(ref filter) = file_filter {
+                    if !filter.should_watch(&path) {
+                        continue;
+                    }
                 }
-                EventKind::Remove(_) => {
-                    ConfigChangeEvent::deleted(
-                        path.to_string_lossy().to_string(),
-                        serde_json::Value::Null,
-                        "FileSystemWatcher",
-                    )
+                let Some(config_event) = Self::to_config_event(&path, &kind) else {
+                    continue;
+                };
+
+                debug!("配置文件变更: {} ({:?})", path.display(), config_event.event_type);
+                metrics::counter!(CONFIG_WATCH_EVENTS_TOTAL, "kind" => Self::kind_label(&kind))
+                    .increment(1);
+                if let Err(e) = change_sender.send(config_event).await {
+                    error!("发送配置变更事件失败: {}", e);
+                    return;
                 }
-                _ => continue,
-            };
-            
-            if let Err(e) = self.change_sender.send(config_event).await {
-                error!("发送配置变更事件失败: {}", e);
             }
         }
-        
-        Ok(())
+    }
+    
+    /// 按文件合并事件
+    fn collect_event(pending: &mut Vec<(PathBuf, EventKind)>, event: Event) {
+        if matches!(event.kind, EventKind::Access(_) | EventKind::Any | EventKind::Other) {
+            return;
+        }
+
+        for path in event.paths {
+            match pending.iter_mut().find(|(existing, _)| *existing == path) {
+                Some((_, kind)) => {
+                    *kind = match (&*kind, &event.kind) {
+                        // 先创建后修改仍视为创建
+                        (EventKind::Create(_), EventKind::Modify(_)) => *kind,
+                        // 删除后重新创建（如编辑器原子替换）视为修改
+                        (EventKind::Remove(_), EventKind::Create(_)) => {
+                            EventKind::Modify(notify::event::ModifyKind::Any)
+                        }
+                        (_, new_kind) => *new_kind,
+                    };
+                }
+                None => pending.push((path, event.kind)),
+            }
+        }
+    }
+    
+    /// 文件系统事件的指标标签值
+    fn kind_label(kind: &EventKind) -> &'static str {
+        match kind {
+            EventKind::Create(_) => "created",
+            EventKind::Remove(_) => "removed",
+            _ => "modified",
+        }
+    }
+
+    /// 将文件系统事件转换为配置变更事件
+    fn to_config_event(path: &Path, kind: &EventKind) -> Option<ConfigChangeEvent> {
+        let path = path.to_string_lossy().to_string();
+        match kind {
+            EventKind::Create(_) => Some(ConfigChangeEvent::created(
+                path,
+                serde_json::Value::Null,
+                "FileSystemWatcher",
+            )),
+            EventKind::Modify(_) => Some(ConfigChangeEvent::reloaded(path, "FileSystemWatcher")),
+            EventKind::Remove(_) => Some(ConfigChangeEvent::deleted(
+                path,
+                serde_json::Value::Null,
+                "FileSystemWatcher",
+            )),
+            _ => None,
+        }
+    }
+}
+
+/// 已注册的监控路径
+///
+/// 监控目标和匹配范围在注册时确定，不随文件之后是否存在而改变
+#[derive(Debug, Clone)]
+struct WatchRegistration {
+    /// 注册的路径
+    path: PathBuf,
+    /// 实际监控的目标
+    target: PathBuf,
+    /// 监控模式
+    mode: RecursiveMode,
+    /// 事件匹配范围
+    scope: WatchScope,
+}
+
+impl WatchRegistration {
+    /// 按注册时的路径类型确定监控目标
+    ///
+    /// 目录递归监控自身；文件（包括尚未创建的文件）通过监控其所在目录实现，
+    /// 以便在编辑器整体替换文件或文件被重新创建时仍能收到事件
+    fn new(path: &Path) -> Self {
+        let (target, mode) = if path.is_dir() {
+            (path.to_path_buf(), RecursiveMode::Recursive)
+        } else {
+            let parent = path
+                .parent()
+                .filter(|parent| !parent.as_os_str().is_empty())
+                .unwrap_or_else(|| Path::new("."));
+            (parent.to_path_buf(), RecursiveMode::NonRecursive)
+        };
+        Self {
+            path: path.to_path_buf(),
+            target,
+            mode,
+            scope: WatchScope::new(path),
+        }
+    }
+}
+
+/// 监控范围
+#[derive(Debug, Clone)]
+enum WatchScope {
+    /// 单个文件
+    File(PathBuf),
+    /// 目录及其子目录
+    Directory(PathBuf),
+}
+
+impl WatchScope {
+    /// 监控路径对应的监控范围（使用规范化路径匹配文件系统事件）
+    fn new(path: &Path) -> Self {
+        let canonical = path.canonicalize().unwrap_or_else(|_| path.to_path_buf());
+        if path.is_dir() {
+            Self::Directory(canonical)
+        } else {
+            Self::File(canonical)
+        }
+    }
+
+    /// 事件路径是否属于此监控范围
+    fn contains(&self, path: &Path) -> bool {
+        let path = path
+            .canonicalize()
+            .unwrap_or_else(|_| path.to_path_buf());
+        match self {
+            Self::File(file) => {
+                path == *file
+                    // 已删除的文件无法规范化，比较所在目录和文件名
+                    || (path.file_name() == file.file_name()
+                        && path.parent().and_then(|p| p.canonicalize().ok()).as_deref()
+                            == file.parent())
+            }
+            Self::Directory(directory) => path.starts_with(directory),
+        }
     }
 }
 
@@ -120,32 +306,49 @@ impl ConfigWatcher for ConfigFileWatcher {
         
         info!("启动配置文件监控");
         
-        let change_sender = self.change_sender.clone();
+        let (raw_sender, raw_receiver) = mpsc::channel(RAW_EVENT_CHANNEL_CAPACITY);
         
         let mut watcher = recommended_watcher(move |res: Result<Event, notify::Error>| {
             match res {
                 Ok(event) => {
-                    debug!("文件系统事件: {:?}", event);
-                    // 在实际实现中，这里需要异步处理事件
-                    // 由于 notify 的回调是同步的，可能需要使用 channel 来传递事件
+                    // notify 的回调是同步的，通过通道转发给异步防抖任务
+                    if let Err(mpsc::error::TrySendError::Full(_)) = raw_sender.try_send(event) {
+                        debug!("文件系统事件积压，丢弃事件");
+                    }
                 }
                 Err(e) => {
                     error!("文件监控错误: {:?}", e);
                 }
             }
-        }).map_err(|e| ConfigError::ReloadError {
+        }).map_err(|e| ConfigError::WatchError {
             message: format!("创建文件监控器失败: {}", e),
         })?;
         
-        // 添加所有监控路径
-        for path in &self.watched_paths {
-            if let Err(e) = watcher.watch(path, RecursiveMode::Recursive) {
-                error!("添加监控路径失败: {} - {}", path.display(), e);
+        // 每个目标只监控一次
+        let mut targets: Vec<&Path> = self
+            .registrations
+            .iter()
+            .map(|registration| registration.target.as_path())
+            .collect();
+        targets.sort();
+        targets.dedup();
+        for target in targets {
+            let mode = self.target_mode(target).unwrap_or(RecursiveMode::NonRecursive);
+            if let Err(e) = watcher.watch(target, mode) {
+                error!("添加监控目标失败: {} - {}", target.display(), e);
             } else {
-                info!("添加监控路径: {}", path.display());
+                info!("添加监控目标: {}", target.display());
             }
         }
         
+        self.refresh_scopes();
+        self.debounce_task = Some(tokio::spawn(Self::run_debounce_loop(
+            raw_receiver,
+            self.change_sender.clone(),
+            self.scopes.clone(),
+            self.file_filter.clone(),
+            self.debounce_delay,
+        )));
         self.watcher = Some(watcher);
         self.is_watching = true;
         
@@ -162,6 +365,9 @@ impl ConfigWatcher for ConfigFileWatcher {
         info!("停止配置文件监控");
         
         self.watcher = None;
+        if let Some(task) = self.debounce_task.take() {
+            task.abort();
+        }
         self.is_watching = false;
         
         info!("配置文件监控停止完成");
@@ -169,9 +375,7 @@ impl ConfigWatcher for ConfigFileWatcher {
     }
     
     async fn add_watch_path(&mut self, path: &Path) -> Result<(), ConfigError> {
-        let path_buf = path.to_path_buf();
-        
-        if self.watched_paths.contains(&path_buf) {
+        if self.registrations.iter().any(|registration| registration.path == path) {
             warn!("路径已在监控列表中: {}", path.display());
             return Ok(());
         }
@@ -179,28 +383,31 @@ impl ConfigWatcher for ConfigFileWatcher {
         info!("添加监控路径: {}", path.display());
         
         // 如果监控器正在运行，立即添加监控
-        if let Some(ref mut watcher) = self.watcher {
-            watcher.watch(path, RecursiveMode::Recursive)
-                .map_err(|e| ConfigError::ReloadError {
-                    message: format!("添加监控路径失败: {}", e),
-                })?;
+        let registration = WatchRegistration::new(path);
+        let target = registration.target.clone();
+        let before = self.target_mode(&target);
+        self.registrations.push(registration);
+        if let Err(e) = self.sync_target(&target, before) {
+            self.registrations.pop();
+            return Err(ConfigError::WatchError {
+                message: format!("添加监控路径失败: {}", e),
+            });
         }
         
-        self.watched_paths.push(path_buf);
+        self.refresh_scopes();
         Ok(())
     }
     
     async fn remove_watch_path(&mut self, path: &Path) -> Result<(), ConfigError> {
-        let path_buf = path.to_path_buf();
-        
-        if let Some(pos) = self.watched_paths.iter().position(|p| p == &path_buf) {
-            self.watched_paths.remove(pos);
+        if let Some(pos) = self.registrations.iter().position(|registration| registration.path == path) {
+            let target = self.registrations[pos].target.clone();
+            let before = self.target_mode(&target);
+            self.registrations.remove(pos);
+            self.refresh_scopes();
             
-            // 如果监控器正在运行，移除监控
-            if let Some(ref mut watcher) = self.watcher {
-                if let Err(e) = watcher.unwatch(path) {
-                    warn!("移除监控路径失败: {} - {}", path.display(), e);
-                }
+            // 如果监控器正在运行，只在没有其他路径引用该目标时移除监控
+            if let Err(e) = self.sync_target(&target, before) {
+                warn!("移除监控路径失败: {} - {}", path.display(), e);
             }
             
             info!("移除监控路径: {}", path.display());
@@ -213,17 +420,8 @@ impl ConfigWatcher for ConfigFileWatcher {
         }
     }
     
-    fn get_change_receiver(&self) -> mpsc::Receiver<ConfigChangeEvent> {
-        // 这里有一个设计问题：receiver 的所有权只能转移一次
-        // 实际实现中可能需要使用广播 channel 或其他方式
-        if let Some(receiver) = self.change_receiver.as_ref() {
-            // 创建一个新的 receiver
-            let (sender, receiver) = mpsc::channel(1000);
-            receiver
-        } else {
-            let (_, receiver) = mpsc::channel(1000);
-            receiver
-        }
+    fn take_change_receiver(&mut self) -> Option<mpsc::Receiver<ConfigChangeEvent>> {
+        self.change_receiver.take()
     }
     
     fn is_watching(&self) -> bool {
@@ -231,7 +429,10 @@ impl ConfigWatcher for ConfigFileWatcher {
     }
     
     fn get_watched_paths(&self) -> Vec<PathBuf> {
-        self.watched_paths.clone()
+        self.registrations
+            .iter()
+            .map(|registration| registration.path.clone())
+            .collect()
     }
 }
 
@@ -257,6 +458,6 @@ impl FileSystemConfigWatcher for ConfigFileWatcher {
     
     fn set_file_filter(&mut self, filter: Box<dyn FileFilter>) {
         info!("设置文件过滤器: {}", filter.name());
-        self.file_filter = Some(filter);
+        self.file_filter = Some(Arc::from(filter));
     }
 }
@@ -8,12 +8,15 @@ use di_abstractions::{
     DiContainer,
 };
 use infrastructure_common::{
-    Component, ComponentDescriptor, ComponentError, ComponentMetadata, DependencyError, Lifetime,
-    Scope, TypeInfo,
+    result_label, Component, ComponentDescriptor, ComponentError, ComponentMetadata,
+    DependencyError, Lifetime, Scope, TypeInfo, DI_FACTORY_DURATION_SECONDS,
+    DI_FACTORY_FAILURES_TOTAL, DI_RESOLUTIONS_TOTAL, DI_RESOLVE_DURATION_SECONDS,
 };
 use std::any::{Any, TypeId};
 use std::collections::HashMap;
+use std::sync::atomic::{AtomicU64, Ordering};
 use std::sync::Arc;
+use std::time::Instant;
 use tokio::sync::RwLock;
 use tracing::{debug, error, info, warn};
 
@@ -23,16 +26,38 @@ pub struct DiContainerImpl {
     registrations: Arc<RwLock<HashMap<TypeId, ComponentRegistration>>>,
     /// 单例实例缓存
     singletons: Arc<RwLock<HashMap<TypeId, Arc<dyn Any + Send + Sync>>>>,
+    /// 工厂最近一次创建失败的原因（组件名称 -> 错误信息）
+    factory_failures: Arc<RwLock<HashMap<String, String>>>,
+    /// 组件解析次数
+    resolution_count: Arc<AtomicU64>,
 }
 
+/// 容器健康状况
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct ContainerHealthReport {
+    /// 已注册的组件数量
+    pub registered_count: usize,
+    /// 已创建的单例数量
+    pub created_singletons: usize,
+    /// 已注册但尚未创建的单例
+    pub pending_singletons: Vec<String>,
+    /// 既没有工厂也没有实例、无法解析的组件
+    pub unresolvable: Vec<String>,
+    /// 最近一次创建失败且尚未恢复的工厂（组件名称 -> 错误信息）
+    pub factory_failures: HashMap<String, String>,
+}
+
+/// 组件工厂函数类型
+type ComponentFactoryFn =
+    Arc<dyn Fn() -> Result<Arc<dyn Any + Send + Sync>, ComponentError> + Send + Sync>;
+
 /// 简单的组件注册信息
 #[derive(Clone)]
 struct ComponentRegistration {
     /// 组件元数据
     metadata: ComponentMetadata,
     /// 组件工厂函数
-    factory:
-        Option<Arc<dyn Fn() -> Result<Arc<dyn Any + Send + Sync>, ComponentError> + Send + Sync>>,
+    factory: Option<ComponentFactoryFn>,
     /// 生命周期
     lifetime: Lifetime,
     /// 单例实例（如果有）
@@ -56,6 +81,103 @@ impl DiContainerImpl {
         Self {
             registrations: Arc::new(RwLock::new(HashMap::new())),
             singletons: Arc::new(RwLock::new(HashMap::new())),
+            factory_failures: Arc::new(RwLock::new(HashMap::new())),
+            resolution_count: Arc::new(AtomicU64::new(0)),
+        }
+    }
+
+    /// 获取组件解析次数，包括直接在容器上进行的解析
+    pub fn resolution_count(&self) -> u64 {
+        self.resolution_count.load(Ordering::SeqCst)
+    }
+
+    /// 获取容器健康状况
+    pub async fn health_report(&self) -> ContainerHealthReport {
+        let registrations = self.registrations.read().await;
+        let singletons = self.singletons.read().await;
+        let mut report = ContainerHealthReport {
+            registered_count: registrations.len(),
+            factory_failures: self.factory_failures.read().await.clone(),
+            ..ContainerHealthReport::default()
+        };
+
+        for (type_id, registration) in registrations.iter() {
+            let name = &registration.metadata.name;
+            if registration.factory.is_none() && registration.singleton.is_none() {
+                report.unresolvable.push(name.clone());
+            } else if matches!(registration.lifetime, Lifetime::Singleton) {
+                if singletons.contains_key(type_id) {
+                    report.created_singletons += 1;
+                } else {
+                    report.pending_singletons.push(name.clone());
+                }
+            }
+        }
+        report.pending_singletons.sort();
+        report.unresolvable.sort();
+
+        report
+    }
+
+    /// 预先创建所有由工厂创建且尚未创建的单例
+    ///
+    /// 返回每个单例的创建结果（按组件名称排序），创建失败会记录到容器健康状况中
+    pub async fn warm_up_singletons(&self) -> Vec<(String, Result<(), ComponentError>)> {
+        let registrations = self.registrations.read().await;
+        let mut results = Vec::new();
+
+        for (type_id, registration) in registrations.iter() {
+            let Some(factory) = &registration.factory else {
+                continue;
+            };
+            if !matches!(registration.lifetime, Lifetime::Singleton)
+                || self.singletons.read().await.contains_key(type_id)
+            {
+                continue;
+            }
+
+            debug!("预先创建单例: {}", registration.metadata.name);
+            let result = match self.create_instance(registration, factory).await {
+                Ok(instance) => {
+                    self.singletons.write().await.insert(*type_id, instance);
+                    Ok(())
+                }
+                Err(e) => Err(e),
+            };
+            results.push((registration.metadata.name.clone(), result));
+        }
+        results.sort_by(|a, b| a.0.cmp(&b.0));
+
+        results
+    }
+
+    /// 调用工厂创建组件实例，记录创建耗时和失败原因
+    async fn create_instance(
+        &self,
+        registration: &ComponentRegistration,
+        factory: &ComponentFactoryFn,
+    ) -> Result<Arc<dyn Any + Send + Sync>, ComponentError> {
+        let name = &registration.metadata.name;
+        let started = Instant::now();
+        let created = factory();
+        metrics::histogram!(DI_FACTORY_DURATION_SECONDS, "component" => name.clone())
+            .record(started.elapsed().as_secs_f64());
+
+        match created {
+            Ok(instance) => {
+                self.factory_failures.write().await.remove(name);
+                Ok(instance)
+            }
+            Err(e) => {
+                error!("组件 {} 创建失败: {}", name, e);
+                metrics::counter!(DI_FACTORY_FAILURES_TOTAL, "component" => name.clone())
+                    .increment(1);
+                self.factory_failures
+                    .write()
+                    .await
+                    .insert(name.clone(), e.to_string());
+                Err(e)
+            }
         }
     }
 }
@@ -159,6 +281,7 @@ impl ComponentRegistry for DiContainerImpl {
     where
         T: Component + 'static,
     {
+        self.resolution_count.fetch_add(1, Ordering::SeqCst);
         let type_id = TypeId::of::<T>();
 
         // 首先检查单例缓存
@@ -181,10 +304,13 @@ impl ComponentRegistry for DiContainerImpl {
         let registrations = self.registrations.read().await;
         if let Some(registration) = registrations.get(&type_id) {
             if let Some(factory) = &registration.factory {
-                let instance = factory().map_err(|e| DependencyError::ComponentCreationFailed {
-                    type_name: std::any::type_name::<T>().to_string(),
-                    source: Box::new(e),
-                })?;
+                let instance = self
+                    .create_instance(registration, factory)
+                    .await
+                    .map_err(|e| DependencyError::ComponentCreationFailed {
+                        type_name: std::any::type_name::<T>().to_string(),
+                        source: Box::new(e),
+                    })?;
 
                 let typed_instance = instance.downcast::<T>().map_err(|_| {
                     DependencyError::ComponentCreationFailed {
@@ -327,6 +453,8 @@ impl ComponentRegistry for DiContainerImpl {
         let mut singletons = self.singletons.write().await;
         singletons.clear();
 
+        self.factory_failures.write().await.clear();
+
         Ok(())
     }
 }
@@ -412,7 +540,20 @@ impl DiContainer for DiContainerImpl {
     where
         T: Component + 'static,
     {
-        ComponentRegistry::resolve(self).await
+        let started = Instant::now();
+        let result = ComponentRegistry::resolve(self).await;
+
+        let component = std::any::type_name::<T>();
+        metrics::histogram!(DI_RESOLVE_DURATION_SECONDS, "component" => component)
+            .record(started.elapsed().as_secs_f64());
+        metrics::counter!(
+            DI_RESOLUTIONS_TOTAL,
+            "component" => component,
+            "result" => result_label(&result)
+        )
+        .increment(1);
+
+        result
     }
 
     async fn resolve_by_type_id(
@@ -13,12 +13,16 @@ use syn::{
 pub struct ComponentArgs {
     /// 生命周期类型
     pub lifetime: ComponentLifetime,
+    /// 生命周期是否显式指定
+    pub explicit_lifetime: bool,
     /// 组件优先级
     pub priority: i32,
     /// 自定义组件名称
     pub name: Option<String>,
     /// 是否启用
     pub enabled: bool,
+    /// 配置节
+    pub config: Option<String>,
 }
 
 /// 组件生命周期类型
@@ -29,13 +33,26 @@ pub enum ComponentLifetime {
     Transient,
 }
 
+impl ComponentLifetime {
+    /// 生命周期名称，与 `Lifetime` 的序列化形式一致
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Self::Singleton => "singleton",
+            Self::Scoped => "scoped",
+            Self::Transient => "transient",
+        }
+    }
+}
+
 impl Default for ComponentArgs {
     fn default() -> Self {
         Self {
             lifetime: ComponentLifetime::Singleton,
+            explicit_lifetime: false,
             priority: 0,
             name: None,
             enabled: true,
+            config: None,
         }
     }
 }
@@ -51,10 +68,13 @@ impl Parse for ComponentArgs {
                 Meta::Path(path) => {
                     if path.is_ident("singleton") {
                         args.lifetime = ComponentLifetime::Singleton;
+                        args.explicit_lifetime = true;
                     } else if path.is_ident("scoped") {
                         args.lifetime = ComponentLifetime::Scoped;
+                        args.explicit_lifetime = true;
                     } else if path.is_ident("transient") {
                         args.lifetime = ComponentLifetime::Transient;
+                        args.explicit_lifetime = true;
                     } else if path.is_ident("enabled") {
                         args.enabled = true;
                     } else if path.is_ident("disabled") {
@@ -74,6 +94,12 @@ impl Parse for ComponentArgs {
                                 args.name = Some(lit_str.value());
                             }
                         }
+                    } else if nv.path.is_ident("config") {
+                        if let Expr::Lit(expr_lit) = nv.value {
+                            if let Lit::Str(lit_str) = expr_lit.lit {
+                                args.config = Some(lit_str.value());
+                            }
+                        }
                     }
                 }
                 _ => {}
@@ -135,8 +161,7 @@ pub fn component_impl(args: TokenStream, input: TokenStream) -> TokenStream {
         struct_name,
         component_name,
         &lifetime_variant,
-        priority,
-        enabled,
+        &component_args,
     );
 
     let expanded = quote! {
@@ -151,13 +176,36 @@ pub fn component_impl(args: TokenStream, input: TokenStream) -> TokenStream {
 }
 
 /// 生成组件自动注册代码
+///
+/// 描述符元数据记录类型名称和所在模块，以及显式指定的生命周期和配置节，
+/// 供自动发现判断哪些属性需要按约定推断
 fn generate_registration_code(
     struct_name: &Ident,
     component_name: &str,
     lifetime: &proc_macro2::TokenStream,
-    priority: i32,
-    enabled: bool,
+    component_args: &ComponentArgs,
 ) -> proc_macro2::TokenStream {
+    let struct_name_string = struct_name.to_string();
+    let priority = component_args.priority;
+    let enabled = component_args.enabled;
+    let explicit_lifetime = component_args.explicit_lifetime.then(|| {
+        let lifetime_name = component_args.lifetime.as_str();
+        quote! {
+            metadata.insert(
+                infrastructure_common::COMPONENT_LIFETIME_KEY.to_string(),
+                #lifetime_name.to_string(),
+            );
+        }
+    });
+    let explicit_config = component_args.config.as_ref().map(|config| {
+        quote! {
+            metadata.insert(
+                infrastructure_common::COMPONENT_CONFIG_SECTION_KEY.to_string(),
+                #config.to_string(),
+            );
+        }
+    });
+
     let registration_fn_name = Ident::new(
         &format!(
             "__register_component_{}",
@@ -174,13 +222,25 @@ fn generate_registration_code(
             use std::any::TypeId;
             use std::collections::HashMap;
 
+            let mut metadata = HashMap::new();
+            metadata.insert(
+                infrastructure_common::COMPONENT_TYPE_NAME_KEY.to_string(),
+                #struct_name_string.to_string(),
+            );
+            metadata.insert(
+                infrastructure_common::COMPONENT_MODULE_PATH_KEY.to_string(),
+                module_path!().to_string(),
+            );
+            #explicit_lifetime
+            #explicit_config
+
             let descriptor = ComponentDescriptor {
                 name: #component_name.to_string(),
                 type_id: TypeId::of::<#struct_name>(),
                 lifetime: #lifetime,
                 priority: #priority,
                 enabled: #enabled,
-                metadata: HashMap::new(),
+                metadata,
             };
 
             // 注册到全局组件注册表
@@ -259,5 +319,21 @@ mod tests {
         assert_eq!(args.priority, 0);
         assert_eq!(args.name, None);
         assert!(args.enabled);
+        assert!(!args.explicit_lifetime);
+        assert_eq!(args.config, None);
+    }
+
+    #[test]
+    fn test_component_args_explicit_lifetime_and_config() {
+        let args: ComponentArgs =
+            syn::parse_str(r#"transient, priority = 10, config = "bidding.strategy""#).unwrap();
+
+        assert_eq!(args.lifetime, ComponentLifetime::Transient);
+        assert!(args.explicit_lifetime);
+        assert_eq!(args.priority, 10);
+        assert_eq!(args.config.as_deref(), Some("bidding.strategy"));
+
+        let args: ComponentArgs = syn::parse_str("priority = 10").unwrap();
+        assert!(!args.explicit_lifetime);
     }
 }
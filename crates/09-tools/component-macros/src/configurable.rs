@@ -21,6 +21,10 @@ pub struct ConfigurableArgs {
     pub use_default: bool,
     /// 配置字段名称
     pub config_field: Option<String>,
+    /// 是否生成配置 JSON Schema
+    pub schema: bool,
+    /// 是否生成配置验证器
+    pub validate: bool,
 }
 
 impl Default for ConfigurableArgs {
@@ -30,6 +34,8 @@ impl Default for ConfigurableArgs {
             optional: false,
             use_default: false,
             config_field: None,
+            schema: false,
+            validate: false,
         }
     }
 }
@@ -47,6 +53,10 @@ impl Parse for ConfigurableArgs {
                         args.optional = true;
                     } else if path.is_ident("default") {
                         args.use_default = true;
+                    } else if path.is_ident("schema") {
+                        args.schema = true;
+                    } else if path.is_ident("validate") {
+                        args.validate = true;
                     }
                 }
                 Meta::NameValue(nv) => {
@@ -109,6 +119,8 @@ pub fn configurable_impl(args: TokenStream, input: TokenStream) -> TokenStream {
         config_path,
         configurable_args.optional,
         configurable_args.use_default,
+        configurable_args.schema,
+        configurable_args.validate,
     );
 
     let expanded = quote! {
@@ -157,6 +169,8 @@ fn generate_configurable_impl(
     config_path: &str,
     optional: bool,
     use_default: bool,
+    schema: bool,
+    validate: bool,
 ) -> proc_macro2::TokenStream {
     // 从 Option<T> 中提取 T 类型
     let actual_config_type = if let Type::Path(type_path) = config_type {
@@ -212,6 +226,9 @@ fn generate_configurable_impl(
         quote! {}
     };
 
+    let config_schema_impl = generate_config_schema_impl(schema);
+    let config_validator_impl = generate_config_validator_impl(validate);
+
     quote! {
         impl infrastructure_common::Configurable for #struct_name {
             type Config = #actual_config_type;
@@ -223,7 +240,37 @@ fn generate_configurable_impl(
             }
 
             #default_config_impl
+
+            #config_schema_impl
+
+            #config_validator_impl
+        }
+    }
+}
+
+/// 生成配置验证器方法
+fn generate_config_validator_impl(validate: bool) -> proc_macro2::TokenStream {
+    if validate {
+        quote! {
+            fn config_validator() -> Option<Box<dyn ::infrastructure_common::ConfigValidator<Self::Config>>> {
+                Some(Box::new(::infrastructure_common::DeclarativeValidator::<Self::Config>::new()))
+            }
+        }
+    } else {
+        quote! {}
+    }
+}
+
+/// 生成配置 JSON Schema 方法
+fn generate_config_schema_impl(schema: bool) -> proc_macro2::TokenStream {
+    if schema {
+        quote! {
+            fn config_schema() -> Option<::infrastructure_common::__private::serde_json::Value> {
+                Some(<Self::Config as ::infrastructure_common::ConfigSchema>::config_schema())
+            }
         }
+    } else {
+        quote! {}
     }
 }
 
@@ -235,6 +282,8 @@ pub fn derive_configurable_impl(input: DeriveInput) -> TokenStream {
     let mut config_path = None;
     let mut optional = false;
     let mut use_default = false;
+    let mut schema = false;
+    let mut validate = false;
 
     for attr in &input.attrs {
         if attr.path().is_ident("configurable") {
@@ -249,6 +298,10 @@ pub fn derive_configurable_impl(input: DeriveInput) -> TokenStream {
                     optional = true;
                 } else if meta.path.is_ident("default") {
                     use_default = true;
+                } else if meta.path.is_ident("schema") {
+                    schema = true;
+                } else if meta.path.is_ident("validate") {
+                    validate = true;
                 }
                 Ok(())
             });
@@ -294,6 +347,9 @@ pub fn derive_configurable_impl(input: DeriveInput) -> TokenStream {
         quote! {}
     };
 
+    let config_schema_impl = generate_config_schema_impl(schema);
+    let config_validator_impl = generate_config_validator_impl(validate);
+
     let expanded = quote! {
         impl infrastructure_common::Configurable for #struct_name {
             type Config = #config_type;
@@ -305,6 +361,10 @@ pub fn derive_configurable_impl(input: DeriveInput) -> TokenStream {
             }
 
             #default_config_impl
+
+            #config_schema_impl
+
+            #config_validator_impl
         }
     };
 
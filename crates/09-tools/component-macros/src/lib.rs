@@ -6,6 +6,8 @@
 //!
 //! - [`component`] - 自动组件注册宏
 //! - [`configurable`] - 自动配置绑定宏
+//! - [`ConfigSchema`](derive@ConfigSchema) - 配置类型 JSON Schema 派生宏
+//! - [`Validate`](derive@Validate) - 声明式配置验证派生宏
 //!
 //! ## 使用示例
 //!
@@ -34,7 +36,9 @@ use syn::{parse_macro_input, DeriveInput};
 mod component;
 mod configurable;
 mod lifecycle;
+mod schema;
 mod utils;
+mod validate;
 
 // Re-exports are not allowed in proc-macro crates
 
@@ -49,6 +53,9 @@ mod utils;
 /// - `transient` - 瞬态生命周期
 /// - `priority = N` - 组件优先级（默认为 0）
 /// - `name = "custom_name"` - 自定义组件名称
+/// - `config = "section.path"` - 配置节
+///
+/// 未指定生命周期或配置节时，自动发现会按类型名称约定推断（如 `*Strategy` 为瞬态）
 ///
 /// # 示例
 ///
@@ -73,6 +80,8 @@ pub fn component(args: TokenStream, input: TokenStream) -> TokenStream {
 /// - `path = "config.path"` - 配置路径
 /// - `optional` - 配置是否可选（默认为必需）
 /// - `default` - 使用默认配置
+/// - `schema` - 通过 `ConfigSchema` 生成配置 JSON Schema
+/// - `validate` - 通过 `Validate` 生成配置验证器，注册配置选项时自动注册
 ///
 /// # 示例
 ///
@@ -153,3 +162,97 @@ pub fn derive_configurable(input: TokenStream) -> TokenStream {
     let input = parse_macro_input!(input as DeriveInput);
     configurable::derive_configurable_impl(input)
 }
+
+/// 配置类型 JSON Schema 派生宏
+///
+/// 为配置结构体或单元变体枚举实现 `ConfigSchema` trait。
+/// 文档注释作为字段描述，并识别 serde 的 `rename`、`rename_all`、`default`、
+/// `skip`、`flatten` 和 `deny_unknown_fields` 属性。
+///
+/// 字段可以通过 `#[schema(...)]` 补充约束：`description`、`default`、`example`、
+/// `minimum`、`maximum`、`exclusive_minimum`、`exclusive_maximum`、`multiple_of`、
+/// `min_length`、`max_length`、`min_items`、`max_items`、`pattern`、`format`，
+/// 其中 `pattern` 在编译期检查是否为有效的正则表达式
+///
+/// # 示例
+///
+/// ```rust
+/// use component_macros::ConfigSchema;
+/// use serde::Deserialize;
+/// #[derive(Debug, Deserialize, ConfigSchema)]
+/// pub struct ServerConfig {
+///     /// 监听地址
+///     pub host: String,
+///     #[schema(minimum = 1, maximum = 65535, example = 8080)]
+///     pub port: u16,
+///     #[serde(default)]
+///     pub workers: Option<usize>,
+/// }
+/// ```
+#[proc_macro_derive(ConfigSchema, attributes(schema))]
+pub fn derive_config_schema(input: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(input as DeriveInput);
+    schema::derive_config_schema_impl(input)
+}
+
+/// 声明式配置验证派生宏
+///
+/// 为配置结构体实现 `ValidateConfig` trait，配合 `#[configurable(..., validate)]`
+/// 生成的验证器在注册组件配置选项时自动注册。`Option` 字段仅在有值时验证。
+///
+/// 字段规则：
+///
+/// - `range(min = .., max = ..)` - 数值范围（闭区间）
+/// - `length(min = .., max = ..)` - 字符串字符数或集合元素个数
+/// - `regex = "..."` - 正则表达式（编译期检查，无效的模式是编译错误）
+/// - `url` - 带主机名的 URL
+/// - `custom = "path::to::fn"` - 自定义函数 `fn(&FieldType) -> Result<(), ValidationError>`
+///
+/// 结构体上的 `#[validate(custom = "path::to::fn")]` 用于跨字段规则，
+/// 函数签名为 `fn(&Self) -> Result<(), ValidationError>`
+///
+/// # 示例
+///
+/// ```rust
+/// use component_macros::Validate;
+/// use infrastructure_common::ValidationError;
+/// use serde::Deserialize;
+///
+/// fn check_pool(config: &PoolConfig) -> Result<(), ValidationError> {
+///     if config.min_size > config.max_size {
+///         return Err(ValidationError::new("min_size 不能大于 max_size"));
+///     }
+///     Ok(())
+/// }
+///
+/// #[derive(Debug, Deserialize, Validate)]
+/// #[validate(custom = "check_pool")]
+/// pub struct PoolConfig {
+///     #[validate(url)]
+///     pub endpoint: String,
+///     #[validate(range(min = 1, max = 100))]
+///     pub min_size: u32,
+///     #[validate(range(min = 1, max = 100))]
+///     pub max_size: u32,
+///     #[validate(length(max = 64), regex = "^[a-z_]+$")]
+///     pub name: Option<String>,
+/// }
+/// ```
+///
+/// 无效的正则表达式在编译期报错：
+///
+/// ```compile_fail
+/// use component_macros::Validate;
+/// use serde::Deserialize;
+///
+/// #[derive(Debug, Deserialize, Validate)]
+/// pub struct NameConfig {
+///     #[validate(regex = "^[a-z+$")]
+///     pub name: String,
+/// }
+/// ```
+#[proc_macro_derive(Validate, attributes(validate))]
+pub fn derive_validate(input: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(input as DeriveInput);
+    validate::derive_validate_impl(input)
+}
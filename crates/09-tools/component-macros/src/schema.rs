@@ -0,0 +1,366 @@
+//! 配置 JSON Schema 派生宏实现
+
+use proc_macro::TokenStream;
+use proc_macro2::TokenStream as TokenStream2;
+use quote::quote;
+use syn::{
+    parse::ParseStream, Attribute, Data, DeriveInput, Expr, ExprLit, Fields, Lit, LitStr, Result,
+    Token,
+};
+
+use crate::utils::{to_camel_case, to_pascal_case, to_snake_case};
+use crate::validate::check_regex_literal;
+
+/// `#[schema(...)]` 支持的关键字及对应的 JSON Schema 关键字
+const SCHEMA_KEYWORDS: &[(&str, &str)] = &[
+    ("description", "description"),
+    ("default", "default"),
+    ("example", "examples"),
+    ("minimum", "minimum"),
+    ("maximum", "maximum"),
+    ("exclusive_minimum", "exclusiveMinimum"),
+    ("exclusive_maximum", "exclusiveMaximum"),
+    ("multiple_of", "multipleOf"),
+    ("min_length", "minLength"),
+    ("max_length", "maxLength"),
+    ("min_items", "minItems"),
+    ("max_items", "maxItems"),
+    ("pattern", "pattern"),
+    ("format", "format"),
+];
+
+/// serde 容器属性
+#[derive(Debug, Default)]
+pub(crate) struct SerdeContainerAttrs {
+    /// 字段/变体重命名规则
+    pub(crate) rename_all: Option<String>,
+    /// 结构体级别的 `default`
+    default: bool,
+    /// 拒绝未知字段
+    deny_unknown_fields: bool,
+}
+
+/// serde 字段属性
+#[derive(Debug, Default)]
+pub(crate) struct SerdeFieldAttrs {
+    /// 重命名
+    pub(crate) rename: Option<String>,
+    /// 字段级别的 `default`
+    default: bool,
+    /// 反序列化时跳过
+    pub(crate) skip: bool,
+    /// 展开到父对象
+    pub(crate) flatten: bool,
+}
+
+/// 实现 #[derive(ConfigSchema)] 宏
+pub fn derive_config_schema_impl(input: DeriveInput) -> TokenStream {
+    match expand(&input) {
+        Ok(tokens) => tokens.into(),
+        Err(e) => e.to_compile_error().into(),
+    }
+}
+
+/// 生成 ConfigSchema 实现
+fn expand(input: &DeriveInput) -> Result<TokenStream2> {
+    let name = &input.ident;
+    let (impl_generics, ty_generics, where_clause) = input.generics.split_for_impl();
+    let container = parse_serde_container_attrs(&input.attrs)?;
+    let description = doc_comment(&input.attrs);
+
+    let body = match &input.data {
+        Data::Struct(data) => match &data.fields {
+            Fields::Named(fields) => {
+                let title = name.to_string();
+                let mut builder = quote! {
+                    ::infrastructure_common::ObjectSchema::new(#title)
+                };
+                if let Some(description) = &description {
+                    builder.extend(quote! { .with_description(#description) });
+                }
+                if container.deny_unknown_fields {
+                    builder.extend(quote! { .deny_unknown_properties() });
+                }
+
+                for field in &fields.named {
+                    let serde_attrs = parse_serde_field_attrs(&field.attrs)?;
+                    if serde_attrs.skip {
+                        continue;
+                    }
+
+                    let ty = &field.ty;
+                    let field_schema = annotated_schema(
+                        quote! { <#ty as ::infrastructure_common::ConfigSchema>::config_schema() },
+                        &field.attrs,
+                    )?;
+                    if serde_attrs.flatten {
+                        builder.extend(quote! { .flatten(#field_schema) });
+                        continue;
+                    }
+
+                    let ident = field.ident.as_ref().expect("命名字段必须有名称");
+                    let property_name = serde_attrs.rename.unwrap_or_else(|| {
+                        rename_field(&ident.to_string(), container.rename_all.as_deref())
+                    });
+                    let required = if serde_attrs.default || container.default {
+                        quote! { false }
+                    } else {
+                        quote! { !<#ty as ::infrastructure_common::ConfigSchema>::is_optional() }
+                    };
+                    builder.extend(quote! { .property(#property_name, #field_schema, #required) });
+                }
+
+                quote! { #builder.build() }
+            }
+            Fields::Unnamed(fields) if fields.unnamed.len() == 1 => {
+                let ty = &fields.unnamed[0].ty;
+                let schema = quote! { <#ty as ::infrastructure_common::ConfigSchema>::config_schema() };
+                with_description(schema, description.as_deref())
+            }
+            _ => {
+                return Err(syn::Error::new_spanned(
+                    name,
+                    "ConfigSchema 仅支持命名字段结构体、新类型结构体和单元变体枚举",
+                ))
+            }
+        },
+        Data::Enum(data) => {
+            let mut variants = Vec::with_capacity(data.variants.len());
+            for variant in &data.variants {
+                if !matches!(variant.fields, Fields::Unit) {
+                    return Err(syn::Error::new_spanned(
+                        variant,
+                        "ConfigSchema 仅支持单元变体枚举",
+                    ));
+                }
+                let serde_attrs = parse_serde_field_attrs(&variant.attrs)?;
+                if serde_attrs.skip {
+                    continue;
+                }
+                variants.push(serde_attrs.rename.unwrap_or_else(|| {
+                    rename_variant(&variant.ident.to_string(), container.rename_all.as_deref())
+                }));
+            }
+
+            let title = name.to_string();
+            let schema = quote! {
+                ::infrastructure_common::__private::serde_json::json!({
+                    "type": "string",
+                    "title": #title,
+                    "enum": [#(#variants),*]
+                })
+            };
+            with_description(schema, description.as_deref())
+        }
+        Data::Union(_) => {
+            return Err(syn::Error::new_spanned(name, "ConfigSchema 不支持联合体"));
+        }
+    };
+
+    Ok(quote! {
+        impl #impl_generics ::infrastructure_common::ConfigSchema for #name #ty_generics #where_clause {
+            fn config_schema() -> ::infrastructure_common::__private::serde_json::Value {
+                #body
+            }
+        }
+    })
+}
+
+/// 为 Schema 表达式添加描述
+fn with_description(schema: TokenStream2, description: Option<&str>) -> TokenStream2 {
+    match description {
+        Some(description) => quote! {
+            ::infrastructure_common::annotate_schema(
+                #schema,
+                "description",
+                ::infrastructure_common::__private::serde_json::Value::from(#description),
+            )
+        },
+        None => schema,
+    }
+}
+
+/// 根据文档注释和 `#[schema(...)]` 属性为字段 Schema 添加关键字
+fn annotated_schema(schema: TokenStream2, attrs: &[Attribute]) -> Result<TokenStream2> {
+    let mut annotations = Vec::new();
+    let mut has_description = false;
+
+    for attr in attrs {
+        if !attr.path().is_ident("schema") {
+            continue;
+        }
+        attr.parse_nested_meta(|meta| {
+            let key = meta
+                .path
+                .get_ident()
+                .map(|ident| ident.to_string())
+                .unwrap_or_default();
+            let Some((_, keyword)) = SCHEMA_KEYWORDS.iter().find(|(name, _)| *name == key) else {
+                return Err(meta.error(format!("未知的 schema 属性: {}", key)));
+            };
+            let value: Expr = meta.value()?.parse()?;
+            if key == "pattern" {
+                if let Expr::Lit(ExprLit {
+                    lit: Lit::Str(pattern),
+                    ..
+                }) = &value
+                {
+                    check_regex_literal(pattern)?;
+                }
+            }
+            if key == "description" {
+                has_description = true;
+            }
+            annotations.push((*keyword, value));
+            Ok(())
+        })?;
+    }
+
+    let mut tokens = schema;
+    if !has_description {
+        if let Some(description) = doc_comment(attrs) {
+            tokens = with_description(tokens, Some(&description));
+        }
+    }
+    for (keyword, value) in annotations {
+        tokens = quote! {
+            ::infrastructure_common::annotate_schema(
+                #tokens,
+                #keyword,
+                ::infrastructure_common::__private::serde_json::Value::from(#value),
+            )
+        };
+    }
+    Ok(tokens)
+}
+
+/// 提取文档注释
+fn doc_comment(attrs: &[Attribute]) -> Option<String> {
+    let lines: Vec<String> = attrs
+        .iter()
+        .filter(|attr| attr.path().is_ident("doc"))
+        .filter_map(|attr| match &attr.meta {
+            syn::Meta::NameValue(nv) => match &nv.value {
+                Expr::Lit(ExprLit {
+                    lit: Lit::Str(s), ..
+                }) => Some(s.value().trim().to_string()),
+                _ => None,
+            },
+            _ => None,
+        })
+        .collect();
+
+    let description = lines.join("\n").trim().to_string();
+    (!description.is_empty()).then_some(description)
+}
+
+/// 解析 serde 容器属性
+pub(crate) fn parse_serde_container_attrs(attrs: &[Attribute]) -> Result<SerdeContainerAttrs> {
+    let mut parsed = SerdeContainerAttrs::default();
+    for attr in attrs.iter().filter(|attr| attr.path().is_ident("serde")) {
+        attr.parse_nested_meta(|meta| {
+            if meta.path.is_ident("rename_all") {
+                let value: LitStr = meta.value()?.parse()?;
+                parsed.rename_all = Some(value.value());
+            } else if meta.path.is_ident("default") {
+                parsed.default = true;
+                skip_meta_value(meta.input)?;
+            } else if meta.path.is_ident("deny_unknown_fields") {
+                parsed.deny_unknown_fields = true;
+            } else {
+                skip_meta_value(meta.input)?;
+            }
+            Ok(())
+        })?;
+    }
+    Ok(parsed)
+}
+
+/// 解析 serde 字段或变体属性
+pub(crate) fn parse_serde_field_attrs(attrs: &[Attribute]) -> Result<SerdeFieldAttrs> {
+    let mut parsed = SerdeFieldAttrs::default();
+    for attr in attrs.iter().filter(|attr| attr.path().is_ident("serde")) {
+        attr.parse_nested_meta(|meta| {
+            if meta.path.is_ident("rename") {
+                if meta.input.peek(Token![=]) {
+                    let value: LitStr = meta.value()?.parse()?;
+                    parsed.rename = Some(value.value());
+                } else {
+                    // rename(deserialize = "...") 形式
+                    meta.parse_nested_meta(|nested| {
+                        let value: LitStr = nested.value()?.parse()?;
+                        if nested.path.is_ident("deserialize") {
+                            parsed.rename = Some(value.value());
+                        }
+                        Ok(())
+                    })?;
+                }
+            } else if meta.path.is_ident("default") {
+                parsed.default = true;
+                skip_meta_value(meta.input)?;
+            } else if meta.path.is_ident("skip") || meta.path.is_ident("skip_deserializing") {
+                parsed.skip = true;
+            } else if meta.path.is_ident("flatten") {
+                parsed.flatten = true;
+            } else {
+                skip_meta_value(meta.input)?;
+            }
+            Ok(())
+        })?;
+    }
+    Ok(parsed)
+}
+
+/// 跳过不关心的属性值（`= expr` 或 `(...)`）
+fn skip_meta_value(input: ParseStream) -> Result<()> {
+    if input.peek(Token![=]) {
+        input.parse::<Token![=]>()?;
+        input.parse::<Expr>()?;
+    } else if input.peek(syn::token::Paren) {
+        let content;
+        syn::parenthesized!(content in input);
+        content.parse::<TokenStream2>()?;
+    }
+    Ok(())
+}
+
+/// 按 serde `rename_all` 规则转换字段名称（字段名为蛇形命名）
+pub(crate) fn rename_field(name: &str, rule: Option<&str>) -> String {
+    match rule {
+        Some("lowercase") => name.to_lowercase(),
+        Some("UPPERCASE") | Some("SCREAMING_SNAKE_CASE") => name.to_uppercase(),
+        Some("camelCase") => to_camel_case(name),
+        Some("PascalCase") => to_pascal_case(name),
+        Some("kebab-case") => name.replace('_', "-"),
+        Some("SCREAMING-KEBAB-CASE") => name.replace('_', "-").to_uppercase(),
+        _ => name.to_string(),
+    }
+}
+
+/// 按 serde `rename_all` 规则转换变体名称（变体名为帕斯卡命名）
+fn rename_variant(name: &str, rule: Option<&str>) -> String {
+    match rule {
+        Some("lowercase") => name.to_lowercase(),
+        Some("UPPERCASE") => name.to_uppercase(),
+        Some("camelCase") => to_camel_case(&to_snake_case(name)),
+        Some("snake_case") => to_snake_case(name),
+        Some("SCREAMING_SNAKE_CASE") => to_snake_case(name).to_uppercase(),
+        Some("kebab-case") => to_snake_case(name).replace('_', "-"),
+        Some("SCREAMING-KEBAB-CASE") => to_snake_case(name).replace('_', "-").to_uppercase(),
+        _ => name.to_string(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_rename_rules() {
+        assert_eq!(rename_field("max_connections", Some("camelCase")), "maxConnections");
+        assert_eq!(rename_field("max_connections", Some("kebab-case")), "max-connections");
+        assert_eq!(rename_variant("RoundRobin", Some("snake_case")), "round_robin");
+        assert_eq!(rename_variant("RoundRobin", Some("camelCase")), "roundRobin");
+        assert_eq!(rename_variant("RoundRobin", None), "RoundRobin");
+    }
+}
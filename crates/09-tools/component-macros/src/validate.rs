@@ -0,0 +1,232 @@
+//! 声明式配置验证派生宏实现
+
+use proc_macro::TokenStream;
+use proc_macro2::TokenStream as TokenStream2;
+use quote::quote;
+use syn::{meta::ParseNestedMeta, Data, DeriveInput, Expr, ExprPath, Fields, LitStr, Result};
+
+use crate::schema::{parse_serde_container_attrs, parse_serde_field_attrs, rename_field};
+use crate::utils::is_option_type;
+
+/// 字段验证规则
+enum FieldRule {
+    /// 数值范围
+    Range {
+        min: Option<Expr>,
+        max: Option<Expr>,
+    },
+    /// 长度范围
+    Length {
+        min: Option<Expr>,
+        max: Option<Expr>,
+    },
+    /// 正则表达式
+    Regex(LitStr),
+    /// URL
+    Url,
+    /// 自定义验证函数
+    Custom(ExprPath),
+}
+
+/// 实现 #[derive(Validate)] 宏
+pub fn derive_validate_impl(input: DeriveInput) -> TokenStream {
+    match expand(&input) {
+        Ok(tokens) => tokens.into(),
+        Err(e) => e.to_compile_error().into(),
+    }
+}
+
+/// 生成 ValidateConfig 实现
+fn expand(input: &DeriveInput) -> Result<TokenStream2> {
+    let name = &input.ident;
+    let (impl_generics, ty_generics, where_clause) = input.generics.split_for_impl();
+
+    let fields = match &input.data {
+        Data::Struct(data) => match &data.fields {
+            Fields::Named(fields) => &fields.named,
+            _ => {
+                return Err(syn::Error::new_spanned(name, "Validate 仅支持命名字段结构体"));
+            }
+        },
+        _ => {
+            return Err(syn::Error::new_spanned(name, "Validate 仅支持命名字段结构体"));
+        }
+    };
+
+    let container = parse_serde_container_attrs(&input.attrs)?;
+    let mut checks = Vec::new();
+
+    for field in fields {
+        let rules = parse_field_rules(&field.attrs)?;
+        if rules.is_empty() {
+            continue;
+        }
+
+        let ident = field.ident.as_ref().expect("命名字段必须有名称");
+        let field_name = parse_serde_field_attrs(&field.attrs)?
+            .rename
+            .unwrap_or_else(|| rename_field(&ident.to_string(), container.rename_all.as_deref()));
+        let rule_checks: Vec<TokenStream2> = rules
+            .iter()
+            .map(|rule| generate_rule_check(rule, &field_name))
+            .collect();
+
+        // Option 字段仅在有值时验证
+        if is_option_type(&field.ty) {
+            checks.push(quote! {
+                if let Some(value) = &self.#ident {
+                    #(#rule_checks)*
+                }
+            });
+        } else {
+            checks.push(quote! {
+                {
+                    let value = &self.#ident;
+                    #(#rule_checks)*
+                }
+            });
+        }
+    }
+
+    // 结构体级别的跨字段规则
+    for function in parse_struct_rules(&input.attrs)? {
+        checks.push(quote! {
+            if let Err(error) = #function(self) {
+                errors.push(error);
+            }
+        });
+    }
+
+    Ok(quote! {
+        impl #impl_generics ::infrastructure_common::ValidateConfig for #name #ty_generics #where_clause {
+            fn validation_errors(&self) -> Vec<::infrastructure_common::ValidationError> {
+                let mut errors = Vec::new();
+                #(#checks)*
+                errors
+            }
+        }
+    })
+}
+
+/// 生成单条规则的检查代码
+fn generate_rule_check(rule: &FieldRule, field_name: &str) -> TokenStream2 {
+    match rule {
+        FieldRule::Range { min, max } => {
+            let min = optional_bound(min.as_ref(), quote! { f64 });
+            let max = optional_bound(max.as_ref(), quote! { f64 });
+            quote! {
+                errors.extend(::infrastructure_common::check_range(#field_name, value, #min, #max));
+            }
+        }
+        FieldRule::Length { min, max } => {
+            let min = optional_bound(min.as_ref(), quote! { usize });
+            let max = optional_bound(max.as_ref(), quote! { usize });
+            quote! {
+                errors.extend(::infrastructure_common::check_length(#field_name, value, #min, #max));
+            }
+        }
+        FieldRule::Regex(pattern) => quote! {
+            errors.extend(::infrastructure_common::check_pattern(
+                #field_name,
+                ::std::convert::AsRef::<str>::as_ref(value),
+                #pattern,
+            ));
+        },
+        FieldRule::Url => quote! {
+            errors.extend(::infrastructure_common::check_url(
+                #field_name,
+                ::std::convert::AsRef::<str>::as_ref(value),
+            ));
+        },
+        FieldRule::Custom(function) => quote! {
+            if let Err(error) = #function(value) {
+                errors.push(error);
+            }
+        },
+    }
+}
+
+/// 生成可选的边界值
+fn optional_bound(bound: Option<&Expr>, ty: TokenStream2) -> TokenStream2 {
+    match bound {
+        Some(expr) => quote! { ::std::option::Option::Some((#expr) as #ty) },
+        None => quote! { ::std::option::Option::None },
+    }
+}
+
+/// 解析字段上的 `#[validate(...)]` 属性
+fn parse_field_rules(attrs: &[syn::Attribute]) -> Result<Vec<FieldRule>> {
+    let mut rules = Vec::new();
+    for attr in attrs.iter().filter(|attr| attr.path().is_ident("validate")) {
+        attr.parse_nested_meta(|meta| {
+            if meta.path.is_ident("range") {
+                let (min, max) = parse_bounds(&meta)?;
+                rules.push(FieldRule::Range { min, max });
+            } else if meta.path.is_ident("length") {
+                let (min, max) = parse_bounds(&meta)?;
+                rules.push(FieldRule::Length { min, max });
+            } else if meta.path.is_ident("regex") {
+                let pattern: LitStr = meta.value()?.parse()?;
+                check_regex_literal(&pattern)?;
+                rules.push(FieldRule::Regex(pattern));
+            } else if meta.path.is_ident("url") {
+                rules.push(FieldRule::Url);
+            } else if meta.path.is_ident("custom") {
+                rules.push(FieldRule::Custom(parse_function(&meta)?));
+            } else {
+                return Err(meta.error("未知的验证规则，支持 range、length、regex、url、custom"));
+            }
+            Ok(())
+        })?;
+    }
+    Ok(rules)
+}
+
+/// 在编译期检查正则表达式字面量，无效的模式报告为编译错误
+pub(crate) fn check_regex_literal(pattern: &LitStr) -> Result<()> {
+    regex::Regex::new(&pattern.value())
+        .map(|_| ())
+        .map_err(|e| syn::Error::new_spanned(pattern, format!("无效的正则表达式: {}", e)))
+}
+
+/// 解析结构体上的 `#[validate(custom = "...")]` 跨字段规则
+fn parse_struct_rules(attrs: &[syn::Attribute]) -> Result<Vec<ExprPath>> {
+    let mut functions = Vec::new();
+    for attr in attrs.iter().filter(|attr| attr.path().is_ident("validate")) {
+        attr.parse_nested_meta(|meta| {
+            if meta.path.is_ident("custom") {
+                functions.push(parse_function(&meta)?);
+                Ok(())
+            } else {
+                Err(meta.error("结构体级别仅支持 custom 规则"))
+            }
+        })?;
+    }
+    Ok(functions)
+}
+
+/// 解析 `(min = .., max = ..)` 形式的边界
+fn parse_bounds(meta: &ParseNestedMeta) -> Result<(Option<Expr>, Option<Expr>)> {
+    let mut min = None;
+    let mut max = None;
+    meta.parse_nested_meta(|bound| {
+        if bound.path.is_ident("min") {
+            min = Some(bound.value()?.parse()?);
+        } else if bound.path.is_ident("max") {
+            max = Some(bound.value()?.parse()?);
+        } else {
+            return Err(bound.error("仅支持 min 和 max"));
+        }
+        Ok(())
+    })?;
+    if min.is_none() && max.is_none() {
+        return Err(meta.error("至少需要指定 min 或 max"));
+    }
+    Ok((min, max))
+}
+
+/// 解析 `custom = "path::to::function"` 中的函数路径
+fn parse_function(meta: &ParseNestedMeta) -> Result<ExprPath> {
+    let function: LitStr = meta.value()?.parse()?;
+    function.parse()
+}
@@ -0,0 +1,122 @@
+//! 配置文件加密工具
+//!
+//! 原地加密 TOML/JSON/YAML 配置文件中的敏感配置项（保存为 `ENC[...]`），
+//! 以及使用新密钥重新加密已有的加密值：
+//!
+//! ```text
+//! adsp-config-crypt keygen > config.key
+//! adsp-config-crypt encrypt config/app.toml database.password dsp.api_key --key-file config.key
+//! adsp-config-crypt rotate config/app.toml --key-file config.key --new-key-file config.new.key
+//! ```
+//!
+//! 未指定密钥文件时从 `ADSP_CONFIG_KEY` 环境变量读取 Base64 编码的密钥
+
+use clap::{Args, Parser, Subcommand};
+use config_abstractions::ExposeSecret;
+use config_impl::encryption::{ConfigFileEncryptor, DEFAULT_CONFIG_KEY_ENV};
+use config_impl::secrets::SecretEncryptionKey;
+use infrastructure_common::ConfigError;
+use std::path::PathBuf;
+use std::process::ExitCode;
+
+/// 配置文件加密工具
+#[derive(Debug, Parser)]
+#[command(name = "adsp-config-crypt", version, about = "加密配置文件中的敏感配置项并轮换密钥")]
+struct Cli {
+    #[command(subcommand)]
+    command: Command,
+}
+
+/// 子命令
+#[derive(Debug, Subcommand)]
+enum Command {
+    /// 生成新的 AES-256 密钥并以 Base64 输出
+    Keygen,
+    /// 原地加密配置文件中的指定配置项
+    Encrypt {
+        /// 配置文件路径
+        file: PathBuf,
+        /// 要加密的配置键（点分路径）
+        #[arg(required = true)]
+        keys: Vec<String>,
+        #[command(flatten)]
+        key: KeySource,
+    },
+    /// 使用新密钥重新加密配置文件中的所有加密值
+    Rotate {
+        /// 配置文件路径
+        file: PathBuf,
+        #[command(flatten)]
+        key: KeySource,
+        /// 新密钥文件路径
+        #[arg(long, conflicts_with = "new_key_env")]
+        new_key_file: Option<PathBuf>,
+        /// 保存新密钥的环境变量
+        #[arg(long)]
+        new_key_env: Option<String>,
+    },
+}
+
+/// 当前密钥来源
+#[derive(Debug, Args)]
+struct KeySource {
+    /// 密钥文件路径
+    #[arg(long, conflicts_with = "key_env")]
+    key_file: Option<PathBuf>,
+    /// 保存密钥的环境变量
+    #[arg(long, default_value = DEFAULT_CONFIG_KEY_ENV)]
+    key_env: String,
+}
+
+impl KeySource {
+    /// 读取密钥
+    fn load(&self) -> Result<SecretEncryptionKey, ConfigError> {
+        match &self.key_file {
+            Some(path) => SecretEncryptionKey::from_file(path),
+            None => SecretEncryptionKey::from_env(&self.key_env),
+        }
+    }
+}
+
+fn main() -> ExitCode {
+    match run(Cli::parse()) {
+        Ok(()) => ExitCode::SUCCESS,
+        Err(e) => {
+            eprintln!("错误: {}", e);
+            ExitCode::FAILURE
+        }
+    }
+}
+
+/// 执行子命令
+fn run(cli: Cli) -> Result<(), ConfigError> {
+    match cli.command {
+        Command::Keygen => {
+            println!("{}", SecretEncryptionKey::generate().to_base64().expose_secret());
+        }
+        Command::Encrypt { file, keys, key } => {
+            let keys: Vec<&str> = keys.iter().map(String::as_str).collect();
+            let count = ConfigFileEncryptor::new(key.load()?).encrypt_keys(&file, &keys)?;
+            println!("已加密 {} 个配置项: {}", count, file.display());
+        }
+        Command::Rotate {
+            file,
+            key,
+            new_key_file,
+            new_key_env,
+        } => {
+            let new_key = match (new_key_file, new_key_env) {
+                (Some(path), _) => SecretEncryptionKey::from_file(path)?,
+                (None, Some(var_name)) => SecretEncryptionKey::from_env(&var_name)?,
+                (None, None) => {
+                    return Err(ConfigError::ValidationError {
+                        message: "必须通过 --new-key-file 或 --new-key-env 指定新密钥".to_string(),
+                    })
+                }
+            };
+            let count = ConfigFileEncryptor::new(key.load()?).rotate(&file, &new_key)?;
+            println!("已使用新密钥重新加密 {} 个配置项: {}", count, file.display());
+        }
+    }
+    Ok(())
+}
@@ -141,3 +141,202 @@ fn test_default_config() {
     assert_eq!(default_config.timeout, 30);
     assert_eq!(default_config.max_connections, 100);
 }
+
+/// 负载均衡策略
+#[derive(Debug, Clone, Deserialize, Serialize, component_macros::ConfigSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum BalanceStrategy {
+    RoundRobin,
+    LeastConnections,
+}
+
+#[derive(Debug, Clone, Deserialize, Serialize, component_macros::ConfigSchema)]
+#[serde(deny_unknown_fields)]
+pub struct UpstreamConfig {
+    /// 上游地址
+    #[schema(pattern = "^https?://", example = "http://127.0.0.1:8080")]
+    pub url: String,
+    #[schema(minimum = 1, maximum = 100, default = 10)]
+    #[serde(default, rename = "weight")]
+    pub weight_value: u32,
+    pub strategy: Option<BalanceStrategy>,
+}
+
+#[derive(Debug)]
+#[configurable(path = "services.upstream", schema)]
+pub struct UpstreamService {
+    config: UpstreamConfig,
+}
+impl UpstreamService {
+    pub fn url(&self) -> &str {
+        &self.config.url
+    }
+}
+
+#[test]
+fn test_config_schema_derive() {
+    use infrastructure_common::ConfigSchema;
+    use serde_json::json;
+
+    let schema = UpstreamConfig::config_schema();
+    assert_eq!(schema["title"], json!("UpstreamConfig"));
+    assert_eq!(schema["additionalProperties"], json!(false));
+    assert_eq!(schema["required"], json!(["url"]));
+    assert_eq!(schema["properties"]["url"]["description"], json!("上游地址"));
+    assert_eq!(schema["properties"]["url"]["pattern"], json!("^https?://"));
+    assert_eq!(
+        schema["properties"]["url"]["examples"],
+        json!(["http://127.0.0.1:8080"])
+    );
+    assert_eq!(schema["properties"]["weight"]["minimum"], json!(1));
+    assert_eq!(schema["properties"]["weight"]["default"], json!(10));
+    assert_eq!(
+        schema["properties"]["strategy"]["type"],
+        json!(["string", "null"])
+    );
+    assert_eq!(
+        schema["properties"]["strategy"]["enum"],
+        json!(["round_robin", "least_connections"])
+    );
+}
+
+#[test]
+fn test_configurable_schema_flag() {
+    use infrastructure_common::ConfigSchema;
+
+    assert_eq!(
+        UpstreamService::config_schema(),
+        Some(UpstreamConfig::config_schema())
+    );
+    assert_eq!(TestService::config_schema(), None);
+
+    let service = UpstreamService {
+        config: UpstreamConfig {
+            url: "http://a".into(),
+            weight_value: 1,
+            strategy: Some(BalanceStrategy::LeastConnections),
+        },
+    };
+    assert_eq!(service.url(), "http://a");
+}
+
+fn check_pool_bounds(config: &PoolConfig) -> Result<(), infrastructure_common::ValidationError> {
+    if config.min_size > config.max_size {
+        return Err(infrastructure_common::ValidationError::invalid_field_value(
+            "min_size",
+            config.min_size.to_string(),
+            "min_size 不能大于 max_size",
+        ));
+    }
+    Ok(())
+}
+
+fn check_not_localhost(host: &String) -> Result<(), infrastructure_common::ValidationError> {
+    if host == "localhost" {
+        return Err(infrastructure_common::ValidationError::invalid_field_value(
+            "host",
+            host.clone(),
+            "不能使用 localhost",
+        ));
+    }
+    Ok(())
+}
+
+#[derive(Debug, Clone, Deserialize, Serialize, component_macros::Validate)]
+#[serde(rename_all = "camelCase")]
+#[validate(custom = "check_pool_bounds")]
+pub struct PoolConfig {
+    #[validate(url)]
+    pub endpoint: String,
+    #[validate(custom = "check_not_localhost", length(min = 1, max = 16))]
+    pub host: String,
+    #[validate(range(min = 1, max = 100))]
+    pub min_size: u32,
+    #[validate(range(min = 1, max = 100))]
+    pub max_size: u32,
+    #[validate(regex = "^[a-z_]+$", length(max = 8))]
+    pub pool_name: Option<String>,
+    #[validate(length(min = 1))]
+    pub replicas: Vec<String>,
+}
+
+impl PoolConfig {
+    fn valid() -> Self {
+        Self {
+            endpoint: "postgres://db.internal:5432".into(),
+            host: "db.internal".into(),
+            min_size: 1,
+            max_size: 10,
+            pool_name: None,
+            replicas: vec!["replica-1".into()],
+        }
+    }
+}
+
+#[derive(Debug)]
+#[configurable(path = "storage.pool", validate)]
+pub struct PoolService {
+    config: PoolConfig,
+}
+
+#[test]
+fn test_validate_derive_accepts_valid_config() {
+    use infrastructure_common::ValidateConfig;
+
+    assert!(PoolConfig::valid().validation_errors().is_empty());
+    assert!(PoolConfig::valid().validate_config().is_ok());
+}
+
+#[test]
+fn test_validate_derive_reports_field_and_struct_errors() {
+    use infrastructure_common::{ValidateConfig, ValidationError};
+
+    let config = PoolConfig {
+        endpoint: "not a url".into(),
+        host: "localhost".into(),
+        min_size: 5,
+        max_size: 0,
+        pool_name: Some("Invalid-Name".into()),
+        replicas: Vec::new(),
+    };
+    let errors = config.validation_errors();
+    let fields: Vec<String> = errors
+        .iter()
+        .map(|error| match error {
+            ValidationError::InvalidFieldValue { field_name, .. }
+            | ValidationError::ValueOutOfRange { field_name, .. }
+            | ValidationError::FormatError { field_name, .. }
+            | ValidationError::RequiredFieldMissing { field_name } => field_name.clone(),
+            ValidationError::ValidationFailed { message } => message.clone(),
+        })
+        .collect();
+    assert_eq!(
+        fields,
+        vec![
+            "endpoint",
+            "host",
+            "maxSize",
+            "poolName",
+            "poolName",
+            "replicas",
+            "min_size"
+        ]
+    );
+    assert!(config.validate_config().is_err());
+}
+
+#[test]
+fn test_configurable_validate_flag() {
+    use infrastructure_common::Configurable;
+
+    let validator = PoolService::config_validator().expect("应生成配置验证器");
+    let service = PoolService {
+        config: PoolConfig::valid(),
+    };
+    assert!(validator.validate(&service.config).is_ok());
+
+    let mut invalid = PoolConfig::valid();
+    invalid.min_size = 0;
+    assert!(validator.validate(&invalid).is_err());
+    assert!(TestService::config_validator().is_none());
+}